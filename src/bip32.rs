@@ -0,0 +1,61 @@
+//! The `bip32` module provides the derivation of the
+//! [BIP-0032](https://github.com/bitcoin/bips/blob/master/bip-0032.mediawiki) master key and
+//! its fingerprint from a seed phrase.
+//!
+//! The master key and the fingerprint can be used to confirm that a reconstructed seed phrase
+//! belongs to the expected wallet without entering the seed phrase into other software.
+//! The module is only available if the `bip32` feature is enabled.
+//!
+
+use crate::bip85::get_bip39_seed;
+use crate::bitcoin::{ExtendedPrivateKey, Network};
+use crate::seed_phrase::SeedPhrase;
+use crate::HarpoResult;
+
+/// The function derives the BIP-0032 master key for the given seed phrase and passphrase.
+///
+/// * `seed_phrase` - The seed phrase.
+/// * `passphrase` - The passphrase.
+/// * `network` - The network of the extended private key.
+pub(crate) fn derive_master_key(
+    seed_phrase: &SeedPhrase,
+    passphrase: &str,
+    network: Network,
+) -> HarpoResult<ExtendedPrivateKey> {
+    ExtendedPrivateKey::new_master(&get_bip39_seed(seed_phrase, passphrase), network)
+}
+
+// ******************************** TESTS ********************************
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::seed_phrase::parse_phrase;
+
+    /// The function encodes the given bytes as a lower-case hex string.
+    ///
+    /// * `bytes` - The bytes.
+    fn encode_hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+    }
+
+    #[test]
+    /// The function tests the derivation of the master key and its fingerprint.
+    fn test_master_key() {
+        let seed_phrase = parse_phrase(
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon \
+            abandon about",
+        )
+        .unwrap();
+        let master_key = derive_master_key(&seed_phrase, "", Network::Mainnet).unwrap();
+        assert_eq!(
+            master_key.to_string(),
+            "xprv9s21ZrQH143K3GJpoapnV8SFfukcVBSfeCficPSGfubmSFDxo1kuHnLisriDvSnRRuL2Qrg5ggqHKNVp\
+            xR86QEC8w35uxmGoggxtQTPvfUu"
+        );
+        assert_eq!(encode_hex(&master_key.get_fingerprint()), "73c5da0a");
+        // The passphrase results in a different master key.
+        let protected_key = derive_master_key(&seed_phrase, "TREZOR", Network::Mainnet).unwrap();
+        assert_ne!(protected_key, master_key);
+    }
+}
@@ -0,0 +1,204 @@
+//! The `bip85` module provides the derivation of child seed phrases from a master seed phrase
+//! according to the [BIP-0085](https://github.com/bitcoin/bips/blob/master/bip-0085.mediawiki)
+//! specification.
+//!
+//! The master seed phrase is turned into a BIP-0039 seed, from which the BIP-0032 master key is
+//! derived. The child seed phrase is obtained from the private key at the hardened derivation
+//! path `m/83696968'/39'/{language}'/{words}'/{index}'`.
+//!
+
+use crate::bitcoin::{ExtendedPrivateKey, Network};
+use crate::normalization::normalize;
+use crate::seed_phrase::{get_seed_phrase_for_entropy, SeedPhrase};
+use crate::word_list::DEFAULT_WORD_LIST;
+use crate::{HarpoError, SeedPhraseResult};
+use hmac::{Hmac, Mac};
+use sha2::Sha512;
+use zeroize::Zeroizing;
+
+/// The numbers of words that child seed phrases may have.
+pub(crate) const VALID_CHILD_NUM_WORDS: &[usize] = &[12, 18, 24];
+
+/// The number of bytes of the BIP-0039 seed.
+const SEED_LENGTH: usize = 64;
+/// The number of PBKDF2 iterations used to derive the BIP-0039 seed.
+const NUM_PBKDF2_ITERATIONS: u32 = 2048;
+/// The application number of BIP-0085.
+const BIP85_PURPOSE: u32 = 83696968;
+/// The BIP-0085 application number for BIP-0039 seed phrases.
+const BIP39_APPLICATION: u32 = 39;
+/// The BIP-0085 language code of the English word list.
+const ENGLISH_LANGUAGE: u32 = 0;
+/// The HMAC key used to derive the BIP-0085 entropy.
+const ENTROPY_HMAC_KEY: &[u8] = b"bip-entropy-from-k";
+
+/// The function computes HMAC-SHA512 for the given key and data.
+///
+/// The output is wiped from memory when it is dropped.
+///
+/// * `key` - The HMAC key.
+/// * `data` - The data.
+pub(crate) fn get_hmac_sha512(key: &[u8], data: &[u8]) -> Zeroizing<Vec<u8>> {
+    // The unwrap() call is okay because HMAC accepts keys of any length.
+    let mut mac = Hmac::<Sha512>::new_from_slice(key).unwrap();
+    mac.update(data);
+    Zeroizing::new(mac.finalize().into_bytes().to_vec())
+}
+
+/// The function computes the BIP-0039 seed for the given seed phrase and passphrase.
+///
+/// The seed is derived using PBKDF2 with HMAC-SHA512. As required by BIP-0039, the seed phrase
+/// and the passphrase are NFKD-normalized first.
+///
+/// * `seed_phrase` - The seed phrase.
+/// * `passphrase` - The passphrase.
+pub(crate) fn get_bip39_seed(seed_phrase: &SeedPhrase, passphrase: &str) -> Zeroizing<Vec<u8>> {
+    let mnemonic = Zeroizing::new(seed_phrase.get_words().join(" "));
+    let mnemonic = Zeroizing::new(normalize(&mnemonic).into_owned());
+    let passphrase = Zeroizing::new(normalize(passphrase).into_owned());
+    let salt = Zeroizing::new(format!("mnemonic{}", *passphrase));
+    let mut seed = Zeroizing::new(vec![0; SEED_LENGTH]);
+    pbkdf2::pbkdf2_hmac::<Sha512>(
+        mnemonic.as_bytes(),
+        salt.as_bytes(),
+        NUM_PBKDF2_ITERATIONS,
+        &mut seed,
+    );
+    seed
+}
+
+/// The function derives the BIP-0085 child seed phrase for the given master key.
+///
+/// The child seed phrase uses the English word list.
+///
+/// * `master_key` - The master key.
+/// * `num_words` - The number of words of the child seed phrase.
+/// * `index` - The index of the child seed phrase.
+pub(crate) fn derive_child_seed_phrase_for_master_key(
+    master_key: &ExtendedPrivateKey,
+    num_words: usize,
+    index: u32,
+) -> SeedPhraseResult {
+    if !VALID_CHILD_NUM_WORDS.contains(&num_words) {
+        return Err(HarpoError::InvalidParameter(format!(
+            "A child seed phrase must have {:?} words.",
+            VALID_CHILD_NUM_WORDS
+        )));
+    }
+    let path = [
+        BIP85_PURPOSE,
+        BIP39_APPLICATION,
+        ENGLISH_LANGUAGE,
+        num_words as u32,
+        index,
+    ];
+    let derived_key = master_key.derive_hardened_path(&path)?;
+    let entropy = get_hmac_sha512(ENTROPY_HMAC_KEY, derived_key.get_key());
+    // 12 words encode 16 bytes, 18 words 24 bytes, and 24 words 32 bytes.
+    let num_bytes = num_words * 4 / 3;
+    get_seed_phrase_for_entropy(&entropy[..num_bytes], DEFAULT_WORD_LIST)
+}
+
+/// The function derives the BIP-0085 child seed phrase for the given master seed phrase.
+///
+/// * `seed_phrase` - The master seed phrase.
+/// * `passphrase` - The passphrase of the master seed phrase.
+/// * `num_words` - The number of words of the child seed phrase.
+/// * `index` - The index of the child seed phrase.
+pub(crate) fn derive_child_seed_phrase(
+    seed_phrase: &SeedPhrase,
+    passphrase: &str,
+    num_words: usize,
+    index: u32,
+) -> SeedPhraseResult {
+    let seed = get_bip39_seed(seed_phrase, passphrase);
+    let master_key = ExtendedPrivateKey::new_master(&seed, Network::Mainnet)?;
+    derive_child_seed_phrase_for_master_key(&master_key, num_words, index)
+}
+
+// ******************************** TESTS ********************************
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bitcoin::HARDENED_OFFSET;
+
+    #[test]
+    /// The function tests the derivation using the test vectors of the BIP-0085 specification.
+    fn test_bip85_test_vectors() {
+        let master_key = "xprv9s21ZrQH143K2LBWUUQRFXhucrQqBpKdRRxNVq2zBqsx8HVqFk2uYo8kmbaLLHRdqtQp\
+            Um98uKfu3vca1LqdGhUtyoFnCNkfmXRyPXLjbKb"
+            .parse::<ExtendedPrivateKey>()
+            .unwrap();
+        let test_vectors = [
+            (
+                12,
+                "girl mad pet galaxy egg matter matrix prison refuse sense ordinary nose",
+            ),
+            (
+                18,
+                "near account window bike charge season chef number sketch tomorrow excuse sniff \
+                circle vital hockey outdoor supply token",
+            ),
+            (
+                24,
+                "puppy ocean match cereal symbol another shed magic wrap hammer bulb intact \
+                gadget divorce twin tonight reason outdoor destroy simple truth cigar social \
+                volcano",
+            ),
+        ];
+        for (num_words, expected) in test_vectors {
+            let seed_phrase =
+                derive_child_seed_phrase_for_master_key(&master_key, num_words, 0).unwrap();
+            assert_eq!(seed_phrase.to_string(), expected);
+        }
+        assert!(derive_child_seed_phrase_for_master_key(&master_key, 15, 0).is_err());
+        assert!(derive_child_seed_phrase_for_master_key(&master_key, 12, HARDENED_OFFSET).is_err());
+    }
+
+    #[test]
+    /// The function tests the derivation of the BIP-0039 seed and the child seed phrases for a
+    /// master seed phrase.
+    fn test_derive_child_seed_phrase() {
+        let words: Vec<String> = "abandon abandon abandon abandon abandon abandon abandon \
+            abandon abandon abandon abandon about"
+            .split(' ')
+            .map(str::to_string)
+            .collect();
+        let seed_phrase = SeedPhrase::new(&words);
+        let seed = get_bip39_seed(&seed_phrase, "");
+        let expected_seed = "5eb00bbddcf069084889a8ab9155568165f5c453ccb85e70811aaed6f6da5fc1\
+            9a5ac40b389cd370d086206dec8aa6c43daea6690f20ad3d8d48b2d2ce9e38e4";
+        let seed_string: String = seed.iter().map(|byte| format!("{:02x}", byte)).collect();
+        assert_eq!(seed_string, expected_seed);
+        let child = derive_child_seed_phrase(&seed_phrase, "", 12, 0).unwrap();
+        assert_eq!(
+            child.to_string(),
+            "prosper short ramp prepare exchange stove life snack client enough purpose fold"
+        );
+        let child = derive_child_seed_phrase(&seed_phrase, "", 24, 1).unwrap();
+        assert_eq!(
+            child.to_string(),
+            "trade clock mom turtle clutch love surge truth bus reward hover truck palm paddle \
+            fossil near group cactus alley gas borrow amateur learn leisure"
+        );
+    }
+
+    #[test]
+    /// The function tests that the seed phrase and the passphrase are NFKD-normalized using a
+    /// Japanese test vector of the BIP-0039 specification.
+    fn test_bip39_seed_normalization() {
+        let words: Vec<String> =
+            "そつう　れきだい　ほんやく　わかす　りくつ　ばいか　ろせん　やちん　\
+            そつう　れきだい　ほんやく　わかめ"
+                .split_whitespace()
+                .map(str::to_string)
+                .collect();
+        let seed_phrase = SeedPhrase::new(&words);
+        let seed = get_bip39_seed(&seed_phrase, "㍍ガバヴァぱばぐゞちぢ十人十色");
+        let expected_seed = "aee025cbe6ca256862f889e48110a6a382365142f7d16f2b9545285b3af64e54\
+            2143a577e9c144e101a6bdca18f8d97ec3366ebf5b088b1c1af9bc31346e60d9";
+        let seed_string: String = seed.iter().map(|byte| format!("{:02x}", byte)).collect();
+        assert_eq!(seed_string, expected_seed);
+    }
+}
@@ -0,0 +1,705 @@
+//! The `bitcoin` module provides Bitcoin private keys in the wallet import format (WIF) and
+//! extended private keys (xprv), as found in output descriptors.
+//!
+//! The secret bytes of these keys can be secret-shared in the same way as seed phrases. The
+//! remaining information, such as the network, is not secret and must be provided again when
+//! re-emitting the key after the reconstruction.
+//!
+
+use crate::bip85::get_hmac_sha512;
+use crate::secp256k1::{add_tweak, get_public_key, is_valid_private_key, KEY_LENGTH};
+use crate::{HarpoError, HarpoResult};
+use ripemd::Ripemd160;
+use sha2::{Digest, Sha256};
+use std::fmt;
+use std::str::FromStr;
+use subtle::ConstantTimeEq;
+use zeroize::{Zeroize, ZeroizeOnDrop, Zeroizing};
+
+/// The WIF version byte for mainnet.
+const WIF_MAINNET_VERSION: u8 = 0x80;
+/// The WIF version byte for testnet.
+const WIF_TESTNET_VERSION: u8 = 0xef;
+/// The suffix byte of WIF keys whose public keys are compressed.
+const WIF_COMPRESSION_FLAG: u8 = 0x01;
+/// The version bytes of extended private keys for mainnet (xprv).
+const XPRV_MAINNET_VERSION: [u8; 4] = [0x04, 0x88, 0xad, 0xe4];
+/// The version bytes of extended private keys for testnet (tprv).
+const XPRV_TESTNET_VERSION: [u8; 4] = [0x04, 0x35, 0x83, 0x94];
+/// The number of bytes of a serialized extended key.
+const EXTENDED_KEY_LENGTH: usize = 78;
+/// The number of bytes of the serialized metadata of an extended key.
+const EXTENDED_KEY_METADATA_LENGTH: usize = 13;
+/// The number of bytes of a key fingerprint.
+const FINGERPRINT_LENGTH: usize = 4;
+/// The offset of hardened child indices.
+pub(crate) const HARDENED_OFFSET: u32 = 1 << 31;
+/// The HMAC key used to derive the BIP-0032 master key.
+const MASTER_KEY_HMAC_KEY: &[u8] = b"Bitcoin seed";
+
+/// This enumeration type defines the Bitcoin network of a key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Network {
+    /// The Bitcoin main network.
+    Mainnet,
+    /// The Bitcoin test networks.
+    Testnet,
+}
+
+/// This struct holds a Bitcoin private key in the wallet import format.
+///
+/// The private key is wiped from memory when it is dropped, and private keys are compared in
+/// constant time.
+#[derive(Clone, Eq)]
+pub struct WifPrivateKey {
+    /// The private key in big-endian format.
+    key: [u8; KEY_LENGTH],
+    /// The network.
+    network: Network,
+    /// Flag indicating whether the corresponding public key is compressed.
+    compressed: bool,
+}
+
+impl WifPrivateKey {
+    /// The function creates a WIF private key.
+    ///
+    /// An error is returned if the bytes do not encode a valid secp256k1 private key.
+    ///
+    /// * `key` - The private key in big-endian format.
+    /// * `network` - The network.
+    /// * `compressed` - Flag indicating whether the corresponding public key is compressed.
+    pub fn new(key: [u8; KEY_LENGTH], network: Network, compressed: bool) -> HarpoResult<Self> {
+        if !is_valid_private_key(&key) {
+            return Err(HarpoError::InvalidParameter(
+                "The private key is not a valid secp256k1 private key.".to_string(),
+            ));
+        }
+        Ok(WifPrivateKey {
+            key,
+            network,
+            compressed,
+        })
+    }
+
+    /// The function returns the private key in big-endian format.
+    pub fn get_bytes(&self) -> &[u8; KEY_LENGTH] {
+        &self.key
+    }
+
+    /// The function returns the network.
+    pub fn get_network(&self) -> Network {
+        self.network
+    }
+
+    /// The function returns true if the corresponding public key is compressed.
+    pub fn is_compressed(&self) -> bool {
+        self.compressed
+    }
+
+    /// The function returns the serialization of the WIF private key, i.e., the Base58Check
+    /// payload without the checksum, consisting of the version byte, the private key, and the
+    /// compression flag if the public key is compressed.
+    pub(crate) fn get_serialized_bytes(&self) -> Vec<u8> {
+        let mut payload = vec![match self.network {
+            Network::Mainnet => WIF_MAINNET_VERSION,
+            Network::Testnet => WIF_TESTNET_VERSION,
+        }];
+        payload.extend_from_slice(&self.key);
+        if self.compressed {
+            payload.push(WIF_COMPRESSION_FLAG);
+        }
+        payload
+    }
+
+    /// The function parses the serialization of a WIF private key.
+    ///
+    /// The network and the compression flag are derived from the serialization.
+    ///
+    /// * `payload` - The serialized WIF private key.
+    pub(crate) fn from_serialized_bytes(payload: &[u8]) -> HarpoResult<Self> {
+        let network = match payload.first() {
+            Some(&WIF_MAINNET_VERSION) => Network::Mainnet,
+            Some(&WIF_TESTNET_VERSION) => Network::Testnet,
+            _ => {
+                return Err(HarpoError::InvalidParameter(
+                    "Unknown version byte of the WIF private key.".to_string(),
+                ))
+            }
+        };
+        let compressed = match payload.len() {
+            length if length == KEY_LENGTH + 1 => false,
+            length if length == KEY_LENGTH + 2 && payload[length - 1] == WIF_COMPRESSION_FLAG => {
+                true
+            }
+            _ => {
+                return Err(HarpoError::InvalidParameter(
+                    "Invalid length of the WIF private key.".to_string(),
+                ))
+            }
+        };
+        let mut key = Zeroizing::new([0; KEY_LENGTH]);
+        key.copy_from_slice(&payload[1..=KEY_LENGTH]);
+        WifPrivateKey::new(*key, network, compressed)
+    }
+}
+
+impl PartialEq for WifPrivateKey {
+    /// The private keys are compared in constant time.
+    ///
+    /// * `other` - The other private key.
+    fn eq(&self, other: &Self) -> bool {
+        bool::from(self.key.ct_eq(&other.key))
+            && self.network == other.network
+            && self.compressed == other.compressed
+    }
+}
+
+impl Zeroize for WifPrivateKey {
+    /// The private key is overwritten with zeros.
+    fn zeroize(&mut self) {
+        self.key.zeroize();
+    }
+}
+
+impl Drop for WifPrivateKey {
+    /// The private key is wiped from memory when it is dropped.
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for WifPrivateKey {}
+
+impl fmt::Debug for WifPrivateKey {
+    /// The private key is not revealed in debug output.
+    ///
+    /// * `formatter` - The formatter.
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "WifPrivateKey({:?}, compressed: {})",
+            self.network, self.compressed
+        )
+    }
+}
+
+impl fmt::Display for WifPrivateKey {
+    /// A WIF private key is displayed in Base58Check encoding.
+    ///
+    /// * `formatter` - The formatter.
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let payload = Zeroizing::new(self.get_serialized_bytes());
+        write!(formatter, "{}", encode_base58_check(&payload))
+    }
+}
+
+impl FromStr for WifPrivateKey {
+    type Err = HarpoError;
+
+    /// The function parses a WIF private key.
+    ///
+    /// The network and the compression flag are derived from the encoding.
+    ///
+    /// * `input` - The WIF private key in Base58Check encoding.
+    fn from_str(input: &str) -> HarpoResult<Self> {
+        let payload = Zeroizing::new(decode_base58_check(input.trim())?);
+        WifPrivateKey::from_serialized_bytes(&payload)
+    }
+}
+
+/// This struct holds the information of an extended key that is not secret, i.e., the network,
+/// the depth, the fingerprint of the parent key, and the child number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExtendedKeyMetadata {
+    /// The network.
+    network: Network,
+    /// The depth in the derivation tree.
+    depth: u8,
+    /// The fingerprint of the parent key.
+    parent_fingerprint: [u8; 4],
+    /// The child number.
+    child_number: u32,
+}
+
+impl ExtendedKeyMetadata {
+    /// The function creates the metadata of an extended key.
+    ///
+    /// * `network` - The network.
+    /// * `depth` - The depth in the derivation tree.
+    /// * `parent_fingerprint` - The fingerprint of the parent key.
+    /// * `child_number` - The child number.
+    pub fn new(
+        network: Network,
+        depth: u8,
+        parent_fingerprint: [u8; 4],
+        child_number: u32,
+    ) -> Self {
+        ExtendedKeyMetadata {
+            network,
+            depth,
+            parent_fingerprint,
+            child_number,
+        }
+    }
+
+    /// The function returns the network.
+    pub fn get_network(&self) -> Network {
+        self.network
+    }
+
+    /// The function returns the depth in the derivation tree.
+    pub fn get_depth(&self) -> u8 {
+        self.depth
+    }
+
+    /// The function returns the fingerprint of the parent key.
+    pub fn get_parent_fingerprint(&self) -> [u8; 4] {
+        self.parent_fingerprint
+    }
+
+    /// The function returns the child number.
+    pub fn get_child_number(&self) -> u32 {
+        self.child_number
+    }
+
+    /// The function returns the serialized metadata, starting with the version bytes.
+    fn get_bytes(&self) -> Vec<u8> {
+        let mut bytes = match self.network {
+            Network::Mainnet => XPRV_MAINNET_VERSION.to_vec(),
+            Network::Testnet => XPRV_TESTNET_VERSION.to_vec(),
+        };
+        bytes.push(self.depth);
+        bytes.extend_from_slice(&self.parent_fingerprint);
+        bytes.extend_from_slice(&self.child_number.to_be_bytes());
+        bytes
+    }
+
+    /// The function parses the serialized metadata, starting with the version bytes.
+    ///
+    /// * `bytes` - The serialized metadata.
+    fn from_bytes(bytes: &[u8]) -> HarpoResult<Self> {
+        if bytes.len() != EXTENDED_KEY_METADATA_LENGTH {
+            return Err(HarpoError::InvalidParameter(
+                "Invalid length of the extended key metadata.".to_string(),
+            ));
+        }
+        let network = if bytes[0..4] == XPRV_MAINNET_VERSION {
+            Network::Mainnet
+        } else if bytes[0..4] == XPRV_TESTNET_VERSION {
+            Network::Testnet
+        } else {
+            return Err(HarpoError::InvalidParameter(
+                "Unknown version bytes of the extended private key.".to_string(),
+            ));
+        };
+        let mut parent_fingerprint = [0; 4];
+        parent_fingerprint.copy_from_slice(&bytes[5..9]);
+        let mut child_number = [0; 4];
+        child_number.copy_from_slice(&bytes[9..13]);
+        Ok(ExtendedKeyMetadata {
+            network,
+            depth: bytes[4],
+            parent_fingerprint,
+            child_number: u32::from_be_bytes(child_number),
+        })
+    }
+}
+
+impl fmt::Display for ExtendedKeyMetadata {
+    /// The metadata is displayed as a hex string of the serialized metadata.
+    ///
+    /// * `formatter` - The formatter.
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for byte in self.get_bytes() {
+            write!(formatter, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for ExtendedKeyMetadata {
+    type Err = HarpoError;
+
+    /// The function parses the metadata from a hex string.
+    ///
+    /// * `input` - The hex string.
+    fn from_str(input: &str) -> HarpoResult<Self> {
+        let input = input.trim();
+        if input.len() != 2 * EXTENDED_KEY_METADATA_LENGTH || !input.is_ascii() {
+            return Err(HarpoError::InvalidParameter(format!(
+                "The extended key metadata must consist of {} hex characters.",
+                2 * EXTENDED_KEY_METADATA_LENGTH
+            )));
+        }
+        let bytes = (0..EXTENDED_KEY_METADATA_LENGTH)
+            .map(|index| u8::from_str_radix(&input[2 * index..2 * index + 2], 16))
+            .collect::<Result<Vec<u8>, _>>()?;
+        ExtendedKeyMetadata::from_bytes(&bytes)
+    }
+}
+
+/// This struct holds a BIP-0032 extended private key.
+///
+/// The chain code and the private key are wiped from memory when the key is dropped, and
+/// extended private keys are compared in constant time.
+#[derive(Clone, Eq)]
+pub struct ExtendedPrivateKey {
+    /// The metadata.
+    metadata: ExtendedKeyMetadata,
+    /// The chain code.
+    chain_code: [u8; KEY_LENGTH],
+    /// The private key in big-endian format.
+    key: [u8; KEY_LENGTH],
+}
+
+impl ExtendedPrivateKey {
+    /// The function creates an extended private key.
+    ///
+    /// An error is returned if the key is not a valid secp256k1 private key.
+    ///
+    /// * `metadata` - The metadata.
+    /// * `chain_code` - The chain code.
+    /// * `key` - The private key in big-endian format.
+    pub fn new(
+        metadata: ExtendedKeyMetadata,
+        chain_code: [u8; KEY_LENGTH],
+        key: [u8; KEY_LENGTH],
+    ) -> HarpoResult<Self> {
+        if !is_valid_private_key(&key) {
+            return Err(HarpoError::InvalidParameter(
+                "The private key is not a valid secp256k1 private key.".to_string(),
+            ));
+        }
+        Ok(ExtendedPrivateKey {
+            metadata,
+            chain_code,
+            key,
+        })
+    }
+
+    /// The function returns the metadata.
+    pub fn get_metadata(&self) -> &ExtendedKeyMetadata {
+        &self.metadata
+    }
+
+    /// The function returns the chain code.
+    pub fn get_chain_code(&self) -> &[u8; KEY_LENGTH] {
+        &self.chain_code
+    }
+
+    /// The function returns the private key in big-endian format.
+    pub fn get_key(&self) -> &[u8; KEY_LENGTH] {
+        &self.key
+    }
+
+    /// The function returns the fingerprint of the key, i.e., the first four bytes of the
+    /// HASH160 of the compressed public key.
+    ///
+    /// The fingerprint of a master key identifies the wallet, e.g., in output descriptors.
+    pub fn get_fingerprint(&self) -> [u8; FINGERPRINT_LENGTH] {
+        let (x, y) = get_public_key(&self.key);
+        // The prefix of the compressed public key encodes the parity of the y-coordinate.
+        let mut public_key = vec![0x02 | (y[KEY_LENGTH - 1] & 1)];
+        public_key.extend_from_slice(&x);
+        let hash = Ripemd160::digest(Sha256::digest(&public_key));
+        let mut fingerprint = [0; FINGERPRINT_LENGTH];
+        fingerprint.copy_from_slice(&hash[..FINGERPRINT_LENGTH]);
+        fingerprint
+    }
+
+    /// The function derives the BIP-0032 master key from the given seed.
+    ///
+    /// * `seed` - The seed.
+    /// * `network` - The network of the master key.
+    pub(crate) fn new_master(seed: &[u8], network: Network) -> HarpoResult<Self> {
+        let output = get_hmac_sha512(MASTER_KEY_HMAC_KEY, seed);
+        let (key, chain_code) = split_hmac_output(&output);
+        if !is_valid_private_key(&*key) {
+            return Err(HarpoError::InvalidSeedPhrase(
+                "The seed does not result in a valid master key.".to_string(),
+            ));
+        }
+        ExtendedPrivateKey::new(
+            ExtendedKeyMetadata::new(network, 0, [0; FINGERPRINT_LENGTH], 0),
+            *chain_code,
+            *key,
+        )
+    }
+
+    /// The function derives the hardened child key with the given index.
+    ///
+    /// * `index` - The index of the child key, which must be less than 2^31.
+    pub(crate) fn derive_hardened_child(&self, index: u32) -> HarpoResult<Self> {
+        if index >= HARDENED_OFFSET {
+            return Err(HarpoError::InvalidParameter(format!(
+                "The derivation index must be less than {}.",
+                HARDENED_OFFSET
+            )));
+        }
+        let depth = self.metadata.depth.checked_add(1).ok_or_else(|| {
+            HarpoError::InvalidParameter("The maximum derivation depth is reached.".to_string())
+        })?;
+        // The data is 0x00 || private key || index + 2^31.
+        let mut data = Zeroizing::new(vec![0]);
+        data.extend_from_slice(&self.key);
+        data.extend_from_slice(&(index + HARDENED_OFFSET).to_be_bytes());
+        let output = get_hmac_sha512(&self.chain_code, &data);
+        let (tweak, chain_code) = split_hmac_output(&output);
+        // This fails with negligible probability. BIP-0032 requires to skip the index.
+        let key = Zeroizing::new(add_tweak(&self.key, &tweak).ok_or_else(|| {
+            HarpoError::InvalidParameter(format!(
+                "The derivation index {} does not result in a valid key.",
+                index
+            ))
+        })?);
+        let metadata = ExtendedKeyMetadata::new(
+            self.metadata.network,
+            depth,
+            self.get_fingerprint(),
+            index + HARDENED_OFFSET,
+        );
+        ExtendedPrivateKey::new(metadata, *chain_code, *key)
+    }
+
+    /// The function derives the key at the given hardened derivation path.
+    ///
+    /// * `path` - The indices of the hardened derivation path.
+    pub(crate) fn derive_hardened_path(&self, path: &[u32]) -> HarpoResult<Self> {
+        let mut extended_key = self.clone();
+        for index in path {
+            extended_key = extended_key.derive_hardened_child(*index)?;
+        }
+        Ok(extended_key)
+    }
+
+    /// The function returns the 78-byte serialization of the extended private key, i.e., the
+    /// Base58Check payload without the checksum.
+    pub(crate) fn get_serialized_bytes(&self) -> Vec<u8> {
+        let mut payload = self.metadata.get_bytes();
+        payload.extend_from_slice(&self.chain_code);
+        payload.push(0);
+        payload.extend_from_slice(&self.key);
+        payload
+    }
+
+    /// The function parses the 78-byte serialization of an extended private key.
+    ///
+    /// * `payload` - The serialized extended private key.
+    pub(crate) fn from_serialized_bytes(payload: &[u8]) -> HarpoResult<Self> {
+        if payload.len() != EXTENDED_KEY_LENGTH {
+            return Err(HarpoError::InvalidParameter(
+                "Invalid length of the extended private key.".to_string(),
+            ));
+        }
+        let metadata = ExtendedKeyMetadata::from_bytes(&payload[..EXTENDED_KEY_METADATA_LENGTH])?;
+        let key_offset = EXTENDED_KEY_METADATA_LENGTH + KEY_LENGTH;
+        // Private keys are preceded by a zero byte.
+        if payload[key_offset] != 0 {
+            return Err(HarpoError::InvalidParameter(
+                "The extended key is not a private key.".to_string(),
+            ));
+        }
+        let mut chain_code = Zeroizing::new([0; KEY_LENGTH]);
+        chain_code.copy_from_slice(&payload[EXTENDED_KEY_METADATA_LENGTH..key_offset]);
+        let mut key = Zeroizing::new([0; KEY_LENGTH]);
+        key.copy_from_slice(&payload[key_offset + 1..]);
+        ExtendedPrivateKey::new(metadata, *chain_code, *key)
+    }
+}
+
+impl PartialEq for ExtendedPrivateKey {
+    /// The chain codes and the private keys are compared in constant time.
+    ///
+    /// * `other` - The other extended private key.
+    fn eq(&self, other: &Self) -> bool {
+        let is_secret_equal = self.chain_code.ct_eq(&other.chain_code) & self.key.ct_eq(&other.key);
+        bool::from(is_secret_equal) && self.metadata == other.metadata
+    }
+}
+
+impl Zeroize for ExtendedPrivateKey {
+    /// The chain code and the private key are overwritten with zeros.
+    fn zeroize(&mut self) {
+        self.chain_code.zeroize();
+        self.key.zeroize();
+    }
+}
+
+impl Drop for ExtendedPrivateKey {
+    /// The chain code and the private key are wiped from memory when the key is dropped.
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for ExtendedPrivateKey {}
+
+impl fmt::Debug for ExtendedPrivateKey {
+    /// The chain code and the private key are not revealed in debug output.
+    ///
+    /// * `formatter` - The formatter.
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "ExtendedPrivateKey({:?})", self.metadata)
+    }
+}
+
+impl fmt::Display for ExtendedPrivateKey {
+    /// An extended private key is displayed in Base58Check encoding.
+    ///
+    /// * `formatter` - The formatter.
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let payload = Zeroizing::new(self.get_serialized_bytes());
+        write!(formatter, "{}", encode_base58_check(&payload))
+    }
+}
+
+impl FromStr for ExtendedPrivateKey {
+    type Err = HarpoError;
+
+    /// The function parses an extended private key.
+    ///
+    /// * `input` - The extended private key in Base58Check encoding.
+    fn from_str(input: &str) -> HarpoResult<Self> {
+        let payload = Zeroizing::new(decode_base58_check(input.trim())?);
+        ExtendedPrivateKey::from_serialized_bytes(&payload)
+    }
+}
+
+/// The function splits the output of HMAC-SHA512 into its left half, i.e., the key or tweak,
+/// and its right half, i.e., the chain code.
+///
+/// * `output` - The HMAC-SHA512 output.
+fn split_hmac_output(output: &[u8]) -> (Zeroizing<[u8; KEY_LENGTH]>, Zeroizing<[u8; KEY_LENGTH]>) {
+    let mut left = Zeroizing::new([0; KEY_LENGTH]);
+    let mut right = Zeroizing::new([0; KEY_LENGTH]);
+    left.copy_from_slice(&output[..KEY_LENGTH]);
+    right.copy_from_slice(&output[KEY_LENGTH..2 * KEY_LENGTH]);
+    (left, right)
+}
+
+/// The function encodes the given bytes in Base58 encoding without a checksum.
+///
+/// * `bytes` - The bytes.
+pub(crate) fn encode_base58(bytes: &[u8]) -> String {
+    bs58::encode(bytes).into_string()
+}
+
+/// The function decodes the given string in Base58 encoding without a checksum.
+///
+/// * `input` - The encoded string.
+pub(crate) fn decode_base58(input: &str) -> HarpoResult<Vec<u8>> {
+    bs58::decode(input).into_vec().map_err(|_| {
+        HarpoError::InvalidParameter("Invalid character in the Base58 string.".to_string())
+    })
+}
+
+/// The function encodes the given payload in Base58Check encoding.
+///
+/// * `payload` - The payload.
+fn encode_base58_check(payload: &[u8]) -> String {
+    bs58::encode(payload).with_check().into_string()
+}
+
+/// The function decodes the given string in Base58Check encoding and verifies the checksum.
+///
+/// * `input` - The encoded string.
+fn decode_base58_check(input: &str) -> HarpoResult<Vec<u8>> {
+    bs58::decode(input)
+        .with_check(None)
+        .into_vec()
+        .map_err(|error| match error {
+            bs58::decode::Error::InvalidChecksum { .. } => {
+                HarpoError::InvalidParameter("Invalid checksum of the Base58 string.".to_string())
+            }
+            bs58::decode::Error::NoChecksum => {
+                HarpoError::InvalidParameter("The Base58 string is too short.".to_string())
+            }
+            _ => {
+                HarpoError::InvalidParameter("Invalid character in the Base58 string.".to_string())
+            }
+        })
+}
+
+// ******************************** TESTS ********************************
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// The function tests the parsing and encoding of WIF private keys.
+    fn test_wif_private_key() {
+        let test_vectors = [
+            (
+                "5HueCGU8rMjxEXxiPuD5BDku4MkFqeZyd4dZ1jvhTVqvbTLvyTJ",
+                Network::Mainnet,
+                false,
+            ),
+            (
+                "KwdMAjGmerYanjeui5SHS7JkmpZvVipYvB2LJGU1ZxJwYvP98617",
+                Network::Mainnet,
+                true,
+            ),
+        ];
+        for (wif, network, compressed) in test_vectors {
+            let key = wif.parse::<WifPrivateKey>().unwrap();
+            assert_eq!(key.get_network(), network);
+            assert_eq!(key.is_compressed(), compressed);
+            assert_eq!(
+                key.get_bytes()[..4],
+                [0x0c, 0x28, 0xfc, 0xa3],
+                "The key must be 0x0C28FCA3..."
+            );
+            assert_eq!(key.to_string(), wif);
+        }
+        // A corrupted checksum is detected.
+        assert!("5HueCGU8rMjxEXxiPuD5BDku4MkFqeZyd4dZ1jvhTVqvbTLvyTK"
+            .parse::<WifPrivateKey>()
+            .is_err());
+    }
+
+    #[test]
+    /// The function tests the parsing and encoding of extended private keys using BIP-0032 test
+    /// vector 1.
+    fn test_extended_private_key() {
+        let xprv = "xprv9s21ZrQH143K3QTDL4LXw2F7HEK3wJUD2nW2nRk4stbPy6cq3jPPqjiChkVvvNKmPGJxWUtg6L\
+            nF5kejMRNNU3TGtRBeJgk33yuGBxrMPHi";
+        let key = xprv.parse::<ExtendedPrivateKey>().unwrap();
+        assert_eq!(key.get_metadata().get_network(), Network::Mainnet);
+        assert_eq!(key.get_metadata().get_depth(), 0);
+        assert_eq!(key.get_key()[..4], [0xe8, 0xf3, 0x2e, 0x72]);
+        assert_eq!(key.get_chain_code()[..4], [0x87, 0x3d, 0xff, 0x81]);
+        assert_eq!(key.to_string(), xprv);
+        let metadata = key
+            .get_metadata()
+            .to_string()
+            .parse::<ExtendedKeyMetadata>()
+            .unwrap();
+        assert_eq!(&metadata, key.get_metadata());
+        // Extended public keys are rejected.
+        let xpub = "xpub661MyMwAqRbcFtXgS5sYJABqqG9YLmC4Q1Rdap9gSE8NqtwybGhePY2gZ29ESFjqJoCu1Rupje\
+            8YtGqsefD265TMg7usUDFdp6W1EGMcet8";
+        assert!(xpub.parse::<ExtendedPrivateKey>().is_err());
+    }
+
+    #[test]
+    /// The function tests the derivation of the master key and a hardened child key using
+    /// BIP-0032 test vector 1.
+    fn test_extended_key_derivation() {
+        let seed: Vec<u8> = (0..16).collect();
+        let master_key = ExtendedPrivateKey::new_master(&seed, Network::Mainnet).unwrap();
+        assert_eq!(
+            master_key.to_string(),
+            "xprv9s21ZrQH143K3QTDL4LXw2F7HEK3wJUD2nW2nRk4stbPy6cq3jPPqjiChkVvvNKmPGJxWUtg6LnF5kejMR\
+            NNU3TGtRBeJgk33yuGBxrMPHi"
+        );
+        assert_eq!(master_key.get_fingerprint(), [0x34, 0x42, 0x19, 0x3e]);
+        let child_key = master_key.derive_hardened_path(&[0]).unwrap();
+        assert_eq!(
+            child_key.to_string(),
+            "xprv9uHRZZhk6KAJC1avXpDAp4MDc3sQKNxDiPvvkX8Br5ngLNv1TxvUxt4cV1rGL5hj6KCesnDYUhd7oWgT11\
+            eZG7XnxHrnYeSvkzY7d2bhkJ7"
+        );
+        assert!(master_key.derive_hardened_child(HARDENED_OFFSET).is_err());
+    }
+}
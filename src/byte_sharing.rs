@@ -0,0 +1,608 @@
+//! The `byte_sharing` module provides the secret sharing of arbitrary byte arrays, e.g., keys or
+//! small files that are not seed phrases.
+//!
+//! The secret is prefixed with its length and padded with zeros to a multiple of
+//! [CHUNK_LENGTH](crate::byte_sharing::CHUNK_LENGTH) bytes. Each chunk is secret-shared
+//! independently using a polynomial over the 256-bit finite field, and the secret share of a
+//! chunk is appended to the [ByteShare](crate::byte_sharing::ByteShare) with the corresponding
+//! index.
+//!
+//! Alternatively, the secret can be shared byte-wise over GF(2^8) as in SLIP-0039 or `ssss`,
+//! see [SharingBackend](crate::byte_sharing::SharingBackend). In this case, each byte of the
+//! secret is shared independently using a polynomial over GF(2^8), the secret shares have
+//! exactly the length of the secret, and no big-integer arithmetic is used.
+//!
+
+use crate::secret_sharing::{
+    reconstruct_secret, FiniteFieldElement, SecretPolynomial, SecretShare,
+};
+use crate::{HarpoError, HarpoResult};
+use rand::{rngs::OsRng, RngCore};
+use std::convert::TryFrom;
+use std::fmt;
+use std::str::FromStr;
+use zeroize::{Zeroize, Zeroizing};
+
+/// The number of secret bytes that are encoded in a single finite field element.
+///
+/// The highest-order byte of each element is zero, which ensures that the element is smaller
+/// than the 256-bit modulus.
+pub const CHUNK_LENGTH: usize = 31;
+/// The number of bytes of a secret share of a single chunk.
+const ELEMENT_LENGTH: usize = 32;
+/// The number of bytes that encode the length of the secret.
+const LENGTH_PREFIX_LENGTH: usize = 4;
+/// The maximum length of a secret in bytes.
+pub const MAX_SECRET_LENGTH: usize = 1 << 20;
+/// The maximum number of secret shares when sharing over GF(2^8).
+pub const MAX_GF256_SHARES: usize = 255;
+/// The prefix of the data of secret shares over GF(2^8) in their string representation.
+const GF256_PREFIX: &str = "gf256:";
+
+/// The finite field over which a byte array is secret-shared.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum SharingBackend {
+    /// The secret is split into chunks of [CHUNK_LENGTH](crate::byte_sharing::CHUNK_LENGTH)
+    /// bytes, which are shared over the 256-bit prime field. The secret shares encode the length
+    /// of the secret and inconsistent secret shares are detected with high probability.
+    #[default]
+    PrimeField,
+    /// Each byte is shared over GF(2^8) using the reduction polynomial `x^8 + x^4 + x^3 + x + 1`
+    /// as in SLIP-0039. The secret shares have the length of the secret, at most
+    /// [MAX_GF256_SHARES](crate::byte_sharing::MAX_GF256_SHARES) secret shares are supported,
+    /// and inconsistent secret shares are not detected.
+    Gf256,
+}
+
+/// This struct holds a secret share of a byte array.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ByteShare {
+    /// The index of the secret share.
+    index: u32,
+    /// The concatenated secret shares of all chunks in little-endian format, or the secret
+    /// shares of all bytes if the secret is shared over GF(2^8).
+    data: Vec<u8>,
+    /// The finite field over which the secret is shared.
+    backend: SharingBackend,
+}
+
+impl ByteShare {
+    /// The function creates a secret share of a byte array using the given index and data.
+    ///
+    /// An error is returned if the index is 0 or the data does not consist of secret shares of
+    /// whole chunks.
+    ///
+    /// * `index` - The index of the secret share.
+    /// * `data` - The concatenated secret shares of all chunks.
+    pub fn new(index: u32, data: &[u8]) -> HarpoResult<Self> {
+        ByteShare::new_with_backend(index, data, SharingBackend::PrimeField)
+    }
+
+    /// The function creates a secret share of a byte array over the given finite field using
+    /// the given index and data.
+    ///
+    /// An error is returned if the index is 0 or it is too large for GF(2^8), or if the data
+    /// does not consist of secret shares of whole chunks when sharing over the prime field.
+    ///
+    /// * `index` - The index of the secret share.
+    /// * `data` - The secret share data.
+    /// * `backend` - The finite field over which the secret is shared.
+    pub fn new_with_backend(index: u32, data: &[u8], backend: SharingBackend) -> HarpoResult<Self> {
+        if index == 0 {
+            return Err(HarpoError::InvalidParameter(
+                "The index of a secret share must not be 0.".to_string(),
+            ));
+        }
+        match backend {
+            SharingBackend::PrimeField => {
+                if data.is_empty() || !data.len().is_multiple_of(ELEMENT_LENGTH) {
+                    return Err(HarpoError::InvalidParameter(format!(
+                        "The data of a secret share must consist of a positive multiple of {} bytes.",
+                        ELEMENT_LENGTH
+                    )));
+                }
+            }
+            SharingBackend::Gf256 => {
+                if index as usize > MAX_GF256_SHARES {
+                    return Err(HarpoError::InvalidParameter(format!(
+                        "The index of a secret share over GF(2^8) must not exceed {}.",
+                        MAX_GF256_SHARES
+                    )));
+                }
+            }
+        }
+        Ok(ByteShare {
+            index,
+            data: data.to_vec(),
+            backend,
+        })
+    }
+
+    /// The function returns the index of the secret share.
+    pub fn get_index(&self) -> u32 {
+        self.index
+    }
+
+    /// The function returns the data of the secret share.
+    pub fn get_data(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// The function returns the finite field over which the secret is shared.
+    pub fn get_backend(&self) -> SharingBackend {
+        self.backend
+    }
+}
+
+impl Drop for ByteShare {
+    /// The data of the secret share is wiped from memory when the secret share is dropped.
+    fn drop(&mut self) {
+        self.data.zeroize();
+    }
+}
+
+impl fmt::Debug for ByteShare {
+    /// The data of the secret share is not revealed in debug output.
+    ///
+    /// * `formatter` - The formatter.
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "ByteShare(index {}, {} bytes, {:?})",
+            self.index,
+            self.data.len(),
+            self.backend
+        )
+    }
+}
+
+impl fmt::Display for ByteShare {
+    /// A secret share of a byte array is displayed as the index followed by a colon and the data
+    /// as a lower-case hex string. The data of secret shares over GF(2^8) is prefixed with
+    /// `gf256:`.
+    ///
+    /// * `formatter` - The formatter.
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "{}: ", self.index)?;
+        if self.backend == SharingBackend::Gf256 {
+            write!(formatter, "{}", GF256_PREFIX)?;
+        }
+        for byte in &self.data {
+            write!(formatter, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for ByteShare {
+    type Err = HarpoError;
+
+    /// The function parses a secret share of a byte array in the form `[INDEX]: [HEX DATA]`,
+    /// or `[INDEX]: gf256:[HEX DATA]` for secret shares over GF(2^8).
+    ///
+    /// * `input` - The secret share as a string.
+    fn from_str(input: &str) -> HarpoResult<Self> {
+        let (index_string, data_string) = input.trim().split_once(':').ok_or_else(|| {
+            HarpoError::InvalidParameter(
+                "A secret share must have the form '[INDEX]: [HEX DATA]'.".to_string(),
+            )
+        })?;
+        let index = index_string.trim().parse::<u32>()?;
+        let data_string = data_string.trim();
+        let (data_string, backend) = match data_string.strip_prefix(GF256_PREFIX) {
+            Some(data_string) => (data_string, SharingBackend::Gf256),
+            None => (data_string, SharingBackend::PrimeField),
+        };
+        if !data_string.len().is_multiple_of(2)
+            || !data_string.chars().all(|c| c.is_ascii_hexdigit())
+        {
+            return Err(HarpoError::InvalidParameter(
+                "The data of a secret share must be a hex string.".to_string(),
+            ));
+        }
+        let data = (0..data_string.len())
+            .step_by(2)
+            .map(|position| u8::from_str_radix(&data_string[position..position + 2], 16))
+            .collect::<Result<Vec<u8>, _>>()?;
+        ByteShare::new_with_backend(index, &data, backend)
+    }
+}
+
+/// The function creates secret shares of the given byte array over the given finite field.
+///
+/// * `secret` - The secret.
+/// * `threshold` - The threshold.
+/// * `num_shares` - The number of secret shares.
+/// * `backend` - The finite field over which the secret is shared.
+pub(crate) fn split_bytes(
+    secret: &[u8],
+    threshold: usize,
+    num_shares: usize,
+    backend: SharingBackend,
+) -> HarpoResult<Vec<ByteShare>> {
+    if secret.len() > MAX_SECRET_LENGTH {
+        return Err(HarpoError::InvalidParameter(format!(
+            "The secret must not exceed {} bytes.",
+            MAX_SECRET_LENGTH
+        )));
+    }
+    if threshold < 1 || threshold > num_shares {
+        return Err(HarpoError::InvalidParameter(
+            "The threshold must be at least 1 and must not exceed the number of secret shares."
+                .to_string(),
+        ));
+    }
+    let num_shares = u32::try_from(num_shares).map_err(|_| {
+        HarpoError::InvalidParameter("The number of secret shares is too large.".to_string())
+    })?;
+    match backend {
+        SharingBackend::PrimeField => split_bytes_over_prime_field(secret, threshold, num_shares),
+        SharingBackend::Gf256 => split_bytes_over_gf256(secret, threshold, num_shares),
+    }
+}
+
+/// The function creates secret shares of the given byte array over the 256-bit prime field.
+///
+/// * `secret` - The secret.
+/// * `threshold` - The threshold.
+/// * `num_shares` - The number of secret shares.
+fn split_bytes_over_prime_field(
+    secret: &[u8],
+    threshold: usize,
+    num_shares: u32,
+) -> HarpoResult<Vec<ByteShare>> {
+    // Prefix the secret with its length and pad it to a multiple of the chunk length.
+    // The buffer is allocated at its final size so that no copies of the secret are left
+    // behind when it grows.
+    let padded_length = (LENGTH_PREFIX_LENGTH + secret.len()).div_ceil(CHUNK_LENGTH) * CHUNK_LENGTH;
+    let mut framed_secret = Zeroizing::new(Vec::with_capacity(padded_length));
+    framed_secret.extend_from_slice(&(secret.len() as u32).to_be_bytes());
+    framed_secret.extend_from_slice(secret);
+    framed_secret.resize(padded_length, 0);
+    let mut byte_shares: Vec<ByteShare> = (1..=num_shares)
+        .map(|index| ByteShare {
+            index,
+            data: Vec::with_capacity(padded_length / CHUNK_LENGTH * ELEMENT_LENGTH),
+            backend: SharingBackend::PrimeField,
+        })
+        .collect();
+    for chunk in framed_secret.chunks(CHUNK_LENGTH) {
+        // The highest-order byte of the element is zero.
+        let mut bytes = Zeroizing::new(Vec::with_capacity(ELEMENT_LENGTH));
+        bytes.extend_from_slice(chunk);
+        bytes.push(0);
+        let element = FiniteFieldElement::from_bytes(&bytes)?;
+        let polynomial = SecretPolynomial::from_secret(&element, threshold - 1)?;
+        for (byte_share, secret_share) in byte_shares
+            .iter_mut()
+            .zip(polynomial.get_secret_shares(num_shares))
+        {
+            byte_share
+                .data
+                .extend_from_slice(&Zeroizing::new(secret_share.get_element().get_bytes()));
+        }
+    }
+    Ok(byte_shares)
+}
+
+/// The function reconstructs a byte array from the given secret shares.
+///
+/// The finite field is determined by the secret shares, which must all use the same finite field.
+///
+/// * `byte_shares` - The secret shares.
+pub(crate) fn reconstruct_bytes(byte_shares: &[ByteShare]) -> HarpoResult<Vec<u8>> {
+    let first_share = match byte_shares.first() {
+        Some(byte_share) => byte_share,
+        None => {
+            return Err(HarpoError::InvalidParameter(
+                "At least one secret share is required.".to_string(),
+            ))
+        }
+    };
+    if byte_shares
+        .iter()
+        .any(|byte_share| byte_share.data.len() != first_share.data.len())
+    {
+        return Err(HarpoError::InvalidParameter(
+            "The secret shares have different lengths.".to_string(),
+        ));
+    }
+    if byte_shares
+        .iter()
+        .any(|byte_share| byte_share.backend != first_share.backend)
+    {
+        return Err(HarpoError::InvalidParameter(
+            "The secret shares use different finite fields.".to_string(),
+        ));
+    }
+    match first_share.backend {
+        SharingBackend::PrimeField => reconstruct_bytes_over_prime_field(byte_shares),
+        SharingBackend::Gf256 => reconstruct_bytes_over_gf256(byte_shares),
+    }
+}
+
+/// The function reconstructs a byte array from the given secret shares over the 256-bit prime
+/// field.
+///
+/// The secret shares must be non-empty and have the same length.
+///
+/// * `byte_shares` - The secret shares.
+fn reconstruct_bytes_over_prime_field(byte_shares: &[ByteShare]) -> HarpoResult<Vec<u8>> {
+    let data_length = byte_shares[0].data.len();
+    let mut framed_secret = Zeroizing::new(Vec::with_capacity(
+        data_length / ELEMENT_LENGTH * CHUNK_LENGTH,
+    ));
+    for position in (0..data_length).step_by(ELEMENT_LENGTH) {
+        let secret_shares = byte_shares
+            .iter()
+            .map(|byte_share| {
+                let bytes = &byte_share.data[position..position + ELEMENT_LENGTH];
+                Ok(SecretShare::new(
+                    &FiniteFieldElement::from_bytes(bytes)?,
+                    byte_share.index,
+                ))
+            })
+            .collect::<HarpoResult<Vec<SecretShare>>>()?;
+        let bytes = Zeroizing::new(reconstruct_secret(&secret_shares)?.get_bytes());
+        // The highest-order byte must be zero if the secret shares are consistent.
+        if bytes[CHUNK_LENGTH] != 0 {
+            return Err(HarpoError::InvalidParameter(
+                "The secret shares are inconsistent.".to_string(),
+            ));
+        }
+        framed_secret.extend_from_slice(&bytes[..CHUNK_LENGTH]);
+    }
+    // Remove the length prefix and the padding.
+    let mut length_bytes = [0; LENGTH_PREFIX_LENGTH];
+    length_bytes.copy_from_slice(&framed_secret[..LENGTH_PREFIX_LENGTH]);
+    let length = u32::from_be_bytes(length_bytes) as usize;
+    let padding = framed_secret
+        .get(LENGTH_PREFIX_LENGTH + length..)
+        .filter(|padding| padding.len() < CHUNK_LENGTH && padding.iter().all(|byte| *byte == 0));
+    if padding.is_none() {
+        return Err(HarpoError::InvalidParameter(
+            "The secret shares are inconsistent.".to_string(),
+        ));
+    }
+    Ok(framed_secret[LENGTH_PREFIX_LENGTH..LENGTH_PREFIX_LENGTH + length].to_vec())
+}
+
+/// The function returns the product of the given elements of GF(2^8).
+///
+/// The reduction polynomial is `x^8 + x^4 + x^3 + x + 1`. The multiplication does not use
+/// lookup tables and does not branch on the factors, i.e., it runs in constant time.
+///
+/// * `first` - The first factor.
+/// * `second` - The second factor.
+pub(crate) fn gf256_mul(first: u8, second: u8) -> u8 {
+    let mut first = first;
+    let mut product = 0;
+    for bit in 0..8 {
+        product ^= first & 0u8.wrapping_sub((second >> bit) & 1);
+        // Multiply by x and reduce if the highest bit was set.
+        first = (first << 1) ^ (0x1b & 0u8.wrapping_sub(first >> 7));
+    }
+    product
+}
+
+/// The function returns the inverse of the given element of GF(2^8).
+///
+/// The inverse is computed as `a^254`. The inverse of zero is zero.
+///
+/// * `value` - The element that is to be inverted.
+pub(crate) fn gf256_inverse(value: u8) -> u8 {
+    // The bits of the public exponent are processed from the most significant bit.
+    let mut result = 1;
+    for bit in (0..8).rev() {
+        result = gf256_mul(result, result);
+        if (254 >> bit) & 1 == 1 {
+            result = gf256_mul(result, value);
+        }
+    }
+    result
+}
+
+/// The function creates secret shares of the given byte array over GF(2^8).
+///
+/// * `secret` - The secret.
+/// * `threshold` - The threshold.
+/// * `num_shares` - The number of secret shares.
+fn split_bytes_over_gf256(
+    secret: &[u8],
+    threshold: usize,
+    num_shares: u32,
+) -> HarpoResult<Vec<ByteShare>> {
+    if num_shares as usize > MAX_GF256_SHARES {
+        return Err(HarpoError::InvalidParameter(format!(
+            "At most {} secret shares are supported over GF(2^8).",
+            MAX_GF256_SHARES
+        )));
+    }
+    let mut byte_shares: Vec<ByteShare> = (1..=num_shares)
+        .map(|index| ByteShare {
+            index,
+            data: Vec::with_capacity(secret.len()),
+            backend: SharingBackend::Gf256,
+        })
+        .collect();
+    // The coefficients of the polynomial are the secret byte followed by random bytes.
+    let mut coefficients = Zeroizing::new(vec![0; threshold]);
+    for byte in secret {
+        coefficients[0] = *byte;
+        OsRng.fill_bytes(&mut coefficients[1..]);
+        for byte_share in byte_shares.iter_mut() {
+            // Evaluate the polynomial at the index using Horner's method.
+            let value = coefficients.iter().rev().fold(0, |value, coefficient| {
+                gf256_mul(value, byte_share.index as u8) ^ coefficient
+            });
+            byte_share.data.push(value);
+        }
+    }
+    Ok(byte_shares)
+}
+
+/// The function inverts all given non-zero elements in GF(2^8) using a single inversion.
+///
+/// The prefix products of the elements are computed first. The inverse of the full product
+/// then yields the inverse of each element when the prefix products are traversed backwards.
+///
+/// * `values` - The non-zero elements to be inverted.
+fn gf256_batch_inverse(values: &[u8]) -> Vec<u8> {
+    let mut prefix_products = Vec::with_capacity(values.len());
+    let mut product = 1;
+    for value in values {
+        prefix_products.push(product);
+        product = gf256_mul(product, *value);
+    }
+    let mut inverse = gf256_inverse(product);
+    let mut inverses = vec![0; values.len()];
+    for position in (0..values.len()).rev() {
+        inverses[position] = gf256_mul(inverse, prefix_products[position]);
+        inverse = gf256_mul(inverse, values[position]);
+    }
+    inverses
+}
+
+/// The function reconstructs a byte array from the given secret shares over GF(2^8).
+///
+/// The secret shares must be non-empty and have the same length.
+///
+/// * `byte_shares` - The secret shares.
+fn reconstruct_bytes_over_gf256(byte_shares: &[ByteShare]) -> HarpoResult<Vec<u8>> {
+    let mut indices = byte_shares
+        .iter()
+        .map(|byte_share| byte_share.index)
+        .collect::<Vec<u32>>();
+    indices.sort_unstable();
+    indices.dedup();
+    if indices.len() != byte_shares.len() {
+        return Err(HarpoError::InvalidParameter(
+            "The secret shares must have distinct indices.".to_string(),
+        ));
+    }
+    // The Lagrange coefficient of each secret share for the evaluation at 0 is the product of
+    // the terms x_j / (x_j - x_i) over all other indices, where subtraction is XOR.
+    // The numerators and denominators are accumulated separately so that all denominators can
+    // be inverted with a single inversion.
+    let (numerators, denominators): (Vec<u8>, Vec<u8>) = byte_shares
+        .iter()
+        .map(|byte_share| {
+            let index = byte_share.index as u8;
+            byte_shares
+                .iter()
+                .filter(|other_share| other_share.index != byte_share.index)
+                .fold((1, 1), |(numerator, denominator), other_share| {
+                    let other_index = other_share.index as u8;
+                    (
+                        gf256_mul(numerator, other_index),
+                        gf256_mul(denominator, other_index ^ index),
+                    )
+                })
+        })
+        .unzip();
+    let inverses = gf256_batch_inverse(&denominators);
+    let lagrange_coefficients: Vec<u8> = numerators
+        .iter()
+        .zip(&inverses)
+        .map(|(numerator, inverse)| gf256_mul(*numerator, *inverse))
+        .collect();
+    let secret = (0..byte_shares[0].data.len())
+        .map(|position| {
+            byte_shares.iter().zip(&lagrange_coefficients).fold(
+                0,
+                |value, (byte_share, coefficient)| {
+                    value ^ gf256_mul(byte_share.data[position], *coefficient)
+                },
+            )
+        })
+        .collect();
+    Ok(secret)
+}
+
+// ******************************** TESTS ********************************
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{seq::SliceRandom, Rng};
+
+    #[test]
+    /// The function tests the secret sharing and reconstruction of byte arrays.
+    fn test_split_and_reconstruct_bytes() {
+        let mut rng = rand::thread_rng();
+        for length in [0, 1, 26, 27, 31, 32, 100, 1000] {
+            let secret: Vec<u8> = (0..length).map(|_| rng.gen()).collect();
+            let mut byte_shares = split_bytes(&secret, 3, 5, SharingBackend::PrimeField)
+                .expect("The split should work.");
+            assert_eq!(byte_shares.len(), 5);
+            byte_shares.shuffle(&mut rng);
+            let reconstructed =
+                reconstruct_bytes(&byte_shares[..3]).expect("The reconstruction should work.");
+            assert_eq!(reconstructed, secret);
+            // The string representation can be parsed again.
+            let parsed: ByteShare = byte_shares[0].to_string().parse().unwrap();
+            assert_eq!(parsed, byte_shares[0]);
+        }
+        // Insufficiently many secret shares almost certainly result in an error.
+        let secret = vec![1; 100];
+        let byte_shares = split_bytes(&secret, 3, 5, SharingBackend::PrimeField).unwrap();
+        assert!(reconstruct_bytes(&byte_shares[..2]).is_err());
+        // Invalid parameters are rejected.
+        assert!(split_bytes(&secret, 0, 5, SharingBackend::PrimeField).is_err());
+        assert!(split_bytes(&secret, 6, 5, SharingBackend::PrimeField).is_err());
+        assert!(reconstruct_bytes(&[]).is_err());
+        assert!(ByteShare::new(0, &[0; 32]).is_err());
+        assert!(ByteShare::new(1, &[0; 31]).is_err());
+        assert!("1: 0g".parse::<ByteShare>().is_err());
+    }
+
+    #[test]
+    /// The function tests the secret sharing and reconstruction of byte arrays over GF(2^8).
+    fn test_split_and_reconstruct_bytes_over_gf256() {
+        // The multiplication matches the AES field, in which 0x53 and 0xca are inverses.
+        assert_eq!(gf256_mul(0x53, 0xca), 0x01);
+        assert_eq!(gf256_mul(0x57, 0x83), 0xc1);
+        assert_eq!(gf256_inverse(0), 0);
+        for value in 1..=255 {
+            assert_eq!(gf256_mul(value, gf256_inverse(value)), 1);
+        }
+        let values: Vec<u8> = (1..=255).collect();
+        let inverses = gf256_batch_inverse(&values);
+        for (value, inverse) in values.iter().zip(&inverses) {
+            assert_eq!(*inverse, gf256_inverse(*value));
+        }
+        let mut rng = rand::thread_rng();
+        for length in [0, 1, 16, 32, 100] {
+            let secret: Vec<u8> = (0..length).map(|_| rng.gen()).collect();
+            let mut byte_shares =
+                split_bytes(&secret, 3, 5, SharingBackend::Gf256).expect("The split should work.");
+            assert!(byte_shares
+                .iter()
+                .all(|byte_share| byte_share.get_data().len() == length
+                    && byte_share.get_backend() == SharingBackend::Gf256));
+            byte_shares.shuffle(&mut rng);
+            let reconstructed =
+                reconstruct_bytes(&byte_shares[..3]).expect("The reconstruction should work.");
+            assert_eq!(reconstructed, secret);
+            // The string representation can be parsed again.
+            let parsed: ByteShare = byte_shares[0].to_string().parse().unwrap();
+            assert!(byte_shares[0].to_string().contains(": gf256:"));
+            assert_eq!(parsed, byte_shares[0]);
+        }
+        // The maximum number of secret shares is supported.
+        let byte_shares = split_bytes(&[42], 255, 255, SharingBackend::Gf256).unwrap();
+        assert_eq!(reconstruct_bytes(&byte_shares).unwrap(), vec![42]);
+        assert!(split_bytes(&[42], 2, 256, SharingBackend::Gf256).is_err());
+        assert!(ByteShare::new_with_backend(256, &[0], SharingBackend::Gf256).is_err());
+        // Secret shares over different finite fields cannot be combined.
+        let gf256_shares = split_bytes(&[1; 32], 2, 3, SharingBackend::Gf256).unwrap();
+        let prime_field_shares = split_bytes(&[1; 32], 2, 3, SharingBackend::PrimeField).unwrap();
+        assert!(reconstruct_bytes(&[
+            gf256_shares[0].clone(),
+            ByteShare::new(2, &prime_field_shares[1].get_data()[..32]).unwrap()
+        ])
+        .is_err());
+        // Duplicate indices are rejected.
+        assert!(reconstruct_bytes(&[gf256_shares[0].clone(), gf256_shares[0].clone()]).is_err());
+    }
+}
@@ -0,0 +1,126 @@
+//! The `commitment` module provides commitments to the secret polynomial used to create
+//! secret-shared seed phrases.
+//!
+//! A commitment can be stored publicly after the creation of the secret-shared seed phrases.
+//! Given sufficiently many secret-shared seed phrases, it can later be verified that they belong
+//! to the polynomial that was committed to.
+//!
+
+use crate::{HarpoError, HarpoResult};
+use std::fmt;
+use std::str::FromStr;
+
+/// The number of bytes of a polynomial commitment.
+pub const COMMITMENT_LENGTH: usize = 32;
+
+/// This struct represents a hash commitment to the coefficients of a secret polynomial.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PolynomialCommitment {
+    /// The bytes of the commitment.
+    bytes: [u8; COMMITMENT_LENGTH],
+}
+
+impl PolynomialCommitment {
+    /// The function creates a polynomial commitment using the given bytes.
+    ///
+    /// * `bytes` - The bytes of the commitment.
+    pub fn new(bytes: [u8; COMMITMENT_LENGTH]) -> Self {
+        PolynomialCommitment { bytes }
+    }
+
+    /// The function returns the bytes of the commitment.
+    pub fn get_bytes(&self) -> &[u8; COMMITMENT_LENGTH] {
+        &self.bytes
+    }
+}
+
+impl fmt::Display for PolynomialCommitment {
+    /// A polynomial commitment is displayed as a lower-case hex string.
+    ///
+    /// * `formatter` - The formatter.
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for byte in &self.bytes {
+            write!(formatter, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for PolynomialCommitment {
+    type Err = HarpoError;
+
+    /// The function parses a polynomial commitment from a hex string.
+    ///
+    /// * `input` - The hex string.
+    fn from_str(input: &str) -> HarpoResult<Self> {
+        let input = input.trim();
+        if input.len() != 2 * COMMITMENT_LENGTH || !input.is_ascii() {
+            return Err(HarpoError::InvalidParameter(format!(
+                "A commitment must consist of {} hex characters.",
+                2 * COMMITMENT_LENGTH
+            )));
+        }
+        let mut bytes = [0; COMMITMENT_LENGTH];
+        for (index, byte) in bytes.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&input[2 * index..2 * index + 2], 16)?;
+        }
+        Ok(PolynomialCommitment { bytes })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for PolynomialCommitment {
+    /// A polynomial commitment is serialized as a lower-case hex string.
+    ///
+    /// * `serializer` - The serializer.
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for PolynomialCommitment {
+    /// A polynomial commitment is deserialized from a hex string.
+    ///
+    /// * `deserializer` - The deserializer.
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let encoding = String::deserialize(deserializer)?;
+        encoding.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+// ******************************** TESTS ********************************
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// The function tests that the hex encoding of a commitment can be parsed.
+    fn test_commitment_parsing() {
+        let mut bytes = [0; COMMITMENT_LENGTH];
+        for (index, byte) in bytes.iter_mut().enumerate() {
+            *byte = (index * 7) as u8;
+        }
+        let commitment = PolynomialCommitment::new(bytes);
+        let encoding = commitment.to_string();
+        assert_eq!(encoding.len(), 2 * COMMITMENT_LENGTH);
+        assert_eq!(
+            encoding.parse::<PolynomialCommitment>().unwrap(),
+            commitment
+        );
+        assert_eq!(
+            encoding
+                .to_uppercase()
+                .parse::<PolynomialCommitment>()
+                .unwrap(),
+            commitment
+        );
+        // Invalid encodings are rejected.
+        assert!(encoding[1..].parse::<PolynomialCommitment>().is_err());
+        assert!(encoding
+            .replace('0', "g")
+            .parse::<PolynomialCommitment>()
+            .is_err());
+    }
+}
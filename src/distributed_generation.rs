@@ -0,0 +1,393 @@
+//! The `distributed_generation` module provides a dealer-less protocol in which `n` parties
+//! jointly generate a random seed phrase that is secret-shared among them.
+//!
+//! Each [Party](crate::distributed_generation::Party) chooses a random contribution and
+//! secret-shares it among all parties using a random polynomial of degree `threshold - 1`. The
+//! secret shares are sent to the other parties in
+//! [DealingMessage](crate::distributed_generation::DealingMessage)s. Once a party has received
+//! the messages of all other parties, it adds up the received values, which results in its
+//! secret share of the sum of all contributions. The seed phrase encoding this sum can be
+//! reconstructed from the threshold number of secret-shared seed phrases using
+//! [reconstruct_seed_phrase](crate::reconstruct_seed_phrase), but it is never held by any
+//! single party during the generation. The seed phrase is random as long as at least one party
+//! chooses its contribution at random.
+//!
+//! The protocol assumes that the parties follow the protocol and that the messages are sent
+//! over private and authenticated channels. The messages contain secret shares and must not be
+//! published.
+//!
+//! A dealing message has the following text encoding:
+//!
+//! ```text
+//! harpo-dealing 1 [SENDER]>[RECIPIENT] t[THRESHOLD]/n[NUMBER OF PARTIES]: [HEX VALUE]
+//! ```
+//!
+
+use crate::secret_sharing::{get_modulus_for_words, FiniteFieldElement, SecretPolynomial};
+use crate::seed_phrase::get_seed_phrase_for_element_with_embedding;
+use crate::word_list::{WordList, DEFAULT_WORD_LIST};
+use crate::{HarpoError, HarpoResult, SeedPhraseResult};
+use rand::rngs::OsRng;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+
+/// The version of the text encoding of dealing messages.
+pub const DEALING_MESSAGE_VERSION: u32 = 1;
+
+/// The prefix of the text encoding of dealing messages.
+const DEALING_MESSAGE_PREFIX: &str = "harpo-dealing";
+
+/// This struct holds the secret share of a contribution that one party sends to another.
+#[derive(Clone, PartialEq)]
+pub struct DealingMessage {
+    /// The index of the sending party.
+    sender: u32,
+    /// The index of the receiving party.
+    recipient: u32,
+    /// The threshold.
+    threshold: usize,
+    /// The number of parties.
+    num_parties: usize,
+    /// The secret share of the contribution of the sender for the recipient.
+    value: FiniteFieldElement,
+}
+
+impl DealingMessage {
+    /// The function returns the index of the sending party.
+    pub fn get_sender(&self) -> u32 {
+        self.sender
+    }
+
+    /// The function returns the index of the receiving party.
+    pub fn get_recipient(&self) -> u32 {
+        self.recipient
+    }
+}
+
+impl fmt::Debug for DealingMessage {
+    /// The secret share of the contribution is not revealed in debug output.
+    ///
+    /// * `formatter` - The formatter.
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "DealingMessage({}>{}, t{}/n{})",
+            self.sender, self.recipient, self.threshold, self.num_parties
+        )
+    }
+}
+
+impl fmt::Display for DealingMessage {
+    /// A dealing message is displayed using its text encoding.
+    ///
+    /// * `formatter` - The formatter.
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "{} {} {}>{} t{}/n{}: ",
+            DEALING_MESSAGE_PREFIX,
+            DEALING_MESSAGE_VERSION,
+            self.sender,
+            self.recipient,
+            self.threshold,
+            self.num_parties
+        )?;
+        for byte in self.value.get_bytes() {
+            write!(formatter, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for DealingMessage {
+    type Err = HarpoError;
+
+    /// The function parses a dealing message from its text encoding.
+    ///
+    /// * `input` - The text encoding.
+    fn from_str(input: &str) -> HarpoResult<Self> {
+        let invalid = || HarpoError::InvalidParameter("Invalid dealing message.".to_string());
+        let (header, value) = input.trim().split_once(": ").ok_or_else(invalid)?;
+        let fields: Vec<&str> = header.split(' ').collect();
+        if fields.len() != 4 || fields[0] != DEALING_MESSAGE_PREFIX {
+            return Err(invalid());
+        }
+        if fields[1].parse::<u32>()? != DEALING_MESSAGE_VERSION {
+            return Err(HarpoError::InvalidParameter(format!(
+                "The dealing message version {} is not supported.",
+                fields[1]
+            )));
+        }
+        let (sender, recipient) = fields[2].split_once('>').ok_or_else(invalid)?;
+        let (threshold, num_parties) = fields[3]
+            .strip_prefix('t')
+            .and_then(|parameters| parameters.split_once("/n"))
+            .ok_or_else(invalid)?;
+        if !value.is_ascii() || value.len() % 2 != 0 {
+            return Err(invalid());
+        }
+        let bytes = (0..value.len())
+            .step_by(2)
+            .map(|position| u8::from_str_radix(&value[position..position + 2], 16))
+            .collect::<Result<Vec<u8>, _>>()?;
+        Ok(DealingMessage {
+            sender: sender.parse()?,
+            recipient: recipient.parse()?,
+            threshold: threshold.parse()?,
+            num_parties: num_parties.parse()?,
+            value: FiniteFieldElement::from_bytes(&bytes)?,
+        })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for DealingMessage {
+    /// A dealing message is serialized using its text encoding.
+    ///
+    /// * `serializer` - The serializer.
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for DealingMessage {
+    /// A dealing message is deserialized from its text encoding.
+    ///
+    /// * `deserializer` - The deserializer.
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let encoding = String::deserialize(deserializer)?;
+        encoding.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// This struct holds the state of a party in the distributed generation of a secret-shared seed
+/// phrase.
+pub struct Party {
+    /// The index of the party.
+    index: u32,
+    /// The threshold.
+    threshold: usize,
+    /// The number of parties.
+    num_parties: usize,
+    /// The number of words of the generated seed phrase.
+    num_words: usize,
+    /// The number of bits of the finite field elements.
+    num_bits: usize,
+    /// The polynomial that secret-shares the contribution of the party.
+    polynomial: SecretPolynomial,
+    /// The received secret shares of the contributions, indexed by the sending party.
+    received_values: BTreeMap<u32, FiniteFieldElement>,
+}
+
+impl Party {
+    /// The function creates a party with a random contribution.
+    ///
+    /// * `index` - The index of the party, between 1 and the number of parties.
+    /// * `threshold` - The threshold.
+    /// * `num_parties` - The number of parties.
+    /// * `num_words` - The number of words of the generated seed phrase.
+    pub fn new(
+        index: u32,
+        threshold: usize,
+        num_parties: usize,
+        num_words: usize,
+    ) -> HarpoResult<Self> {
+        if threshold < 1 || threshold > num_parties {
+            return Err(HarpoError::InvalidParameter(
+                "The threshold must be between 1 and the number of parties.".to_string(),
+            ));
+        }
+        if index < 1 || index as usize > num_parties {
+            return Err(HarpoError::InvalidParameter(format!(
+                "The index {} is not between 1 and {}.",
+                index, num_parties
+            )));
+        }
+        let modulus = get_modulus_for_words(num_words)
+            .ok_or(HarpoError::WrongWordCount { got: num_words })?;
+        let num_bits = modulus.get_num_bits();
+        let contribution = FiniteFieldElement::new_random_with_rng(num_bits, modulus, &mut OsRng);
+        let polynomial =
+            SecretPolynomial::new(&contribution, num_bits, threshold - 1).ok_or_else(|| {
+                HarpoError::InvalidParameter(
+                    "Could not instantiate the required secret polynomial.".to_string(),
+                )
+            })?;
+        let mut received_values = BTreeMap::new();
+        received_values.insert(
+            index,
+            polynomial.get_secret_shares_for_indices(&[index])[0]
+                .get_element()
+                .clone(),
+        );
+        Ok(Party {
+            index,
+            threshold,
+            num_parties,
+            num_words,
+            num_bits,
+            polynomial,
+            received_values,
+        })
+    }
+
+    /// The function returns the index of the party.
+    pub fn get_index(&self) -> u32 {
+        self.index
+    }
+
+    /// The function returns the messages that the party must send to the other parties.
+    pub fn get_messages(&self) -> Vec<DealingMessage> {
+        let recipients: Vec<u32> = (1..=self.num_parties as u32)
+            .filter(|recipient| *recipient != self.index)
+            .collect();
+        self.polynomial
+            .get_secret_shares_for_indices(&recipients)
+            .iter()
+            .map(|secret_share| DealingMessage {
+                sender: self.index,
+                recipient: secret_share.get_index(),
+                threshold: self.threshold,
+                num_parties: self.num_parties,
+                value: secret_share.get_element().clone(),
+            })
+            .collect()
+    }
+
+    /// The function processes a message received from another party.
+    ///
+    /// An error is returned if the message is not addressed to the party, if its parameters do
+    /// not match, or if a message from the sender has already been received.
+    ///
+    /// * `message` - The received message.
+    pub fn receive(&mut self, message: &DealingMessage) -> HarpoResult<()> {
+        if message.recipient != self.index {
+            return Err(HarpoError::InvalidParameter(format!(
+                "The message is addressed to party {}, not to party {}.",
+                message.recipient, self.index
+            )));
+        }
+        if message.threshold != self.threshold
+            || message.num_parties != self.num_parties
+            || message.value.get_num_bits() != self.num_bits
+        {
+            return Err(HarpoError::InvalidParameter(format!(
+                "The parameters of the message from party {} do not match.",
+                message.sender
+            )));
+        }
+        if message.sender < 1 || message.sender as usize > self.num_parties {
+            return Err(HarpoError::InvalidParameter(format!(
+                "The sender {} is not between 1 and {}.",
+                message.sender, self.num_parties
+            )));
+        }
+        if self.received_values.contains_key(&message.sender) {
+            return Err(HarpoError::InvalidParameter(format!(
+                "A message from party {} has already been received.",
+                message.sender
+            )));
+        }
+        self.received_values
+            .insert(message.sender, message.value.clone());
+        Ok(())
+    }
+
+    /// The function returns the indices of the parties whose messages are still missing.
+    pub fn get_missing_senders(&self) -> Vec<u32> {
+        (1..=self.num_parties as u32)
+            .filter(|sender| !self.received_values.contains_key(sender))
+            .collect()
+    }
+
+    /// The function returns true if the messages of all other parties have been received.
+    pub fn is_complete(&self) -> bool {
+        self.received_values.len() == self.num_parties
+    }
+
+    /// The function returns the secret-shared seed phrase of the party once the messages of all
+    /// other parties have been received.
+    pub fn finalize(self) -> SeedPhraseResult {
+        self.finalize_for_word_list(DEFAULT_WORD_LIST)
+    }
+
+    /// The function returns the secret-shared seed phrase of the party for the given word list
+    /// once the messages of all other parties have been received.
+    ///
+    /// * `word_list` - The word list for the seed phrase.
+    pub fn finalize_for_word_list(self, word_list: &(impl WordList + ?Sized)) -> SeedPhraseResult {
+        let word_list = word_list.get_words();
+        if !self.is_complete() {
+            return Err(HarpoError::InvalidParameter(format!(
+                "The messages of {} other parties are missing.",
+                self.get_missing_senders().len()
+            )));
+        }
+        let mut values = self.received_values.values().cloned();
+        // The unwrap() call is okay because the party's own value is always present.
+        let first_value = values.next().unwrap();
+        let value = values.fold(first_value, |sum, value| sum + value);
+        let seed_phrase =
+            get_seed_phrase_for_element_with_embedding(&value, Some(self.index), false, word_list)?;
+        debug_assert_eq!(seed_phrase.len(), self.num_words);
+        Ok(seed_phrase.with_threshold(self.threshold))
+    }
+}
+
+// ******************************** TESTS ********************************
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::seed_phrase::SeedPhrase;
+    use crate::{reconstruct_seed_phrase, validate_seed_phrase};
+
+    #[test]
+    /// The function tests the distributed generation of a secret-shared seed phrase.
+    fn test_distributed_generation() {
+        let mut parties: Vec<Party> = (1..=3)
+            .map(|index| Party::new(index, 2, 3, 12).expect("The creation should work."))
+            .collect();
+        // The messages are exchanged in their text encoding.
+        let messages: Vec<String> = parties
+            .iter()
+            .flat_map(Party::get_messages)
+            .map(|message| message.to_string())
+            .collect();
+        assert_eq!(messages.len(), 6);
+        assert!(messages[0].starts_with("harpo-dealing 1 1>2 t2/n3: "));
+        for message in &messages {
+            let message: DealingMessage = message.parse().expect("The parsing should work.");
+            let party = &mut parties[message.get_recipient() as usize - 1];
+            assert!(party.get_missing_senders().contains(&message.get_sender()));
+            party
+                .receive(&message)
+                .expect("The message should be accepted.");
+        }
+        // Messages cannot be processed twice or by the wrong party.
+        let message: DealingMessage = messages[0].parse().unwrap();
+        // The secret share is not revealed in debug output.
+        assert_eq!(format!("{:?}", message), "DealingMessage(1>2, t2/n3)");
+        assert!(parties[1].receive(&message).is_err());
+        assert!(parties[2].receive(&message).is_err());
+        let shares: Vec<SeedPhrase> = parties
+            .into_iter()
+            .map(|party| party.finalize().expect("The finalization should work."))
+            .collect();
+        assert_eq!(shares[1].get_index(), Some(2));
+        let seed_phrase =
+            reconstruct_seed_phrase(&shares[..2]).expect("The reconstruction should work.");
+        assert!(validate_seed_phrase(&seed_phrase).is_ok());
+        assert_eq!(
+            reconstruct_seed_phrase(&shares[1..]).expect("The reconstruction should work."),
+            seed_phrase
+        );
+        // Incomplete parties cannot finalize and invalid parameters are rejected.
+        assert!(Party::new(1, 2, 3, 12).unwrap().finalize().is_err());
+        assert!(Party::new(4, 2, 3, 12).is_err());
+        assert!(Party::new(1, 4, 3, 12).is_err());
+        assert!(Party::new(1, 2, 3, 13).is_err());
+    }
+}
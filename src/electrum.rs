@@ -0,0 +1,216 @@
+//! The `electrum` module provides the conversion between Electrum seed phrases and BIP-0039
+//! seed phrases that carry them.
+//!
+//! Electrum seed phrases use the same word lists as BIP-0039 but a different encoding: The
+//! words are the digits of an integer in base 2048, starting with the least significant digit,
+//! and there are no checksum bits. Instead, the HMAC-SHA512 of the seed phrase with the key
+//! `Seed version` must start with the hex prefix of a seed version.
+//! Since the integer has more bits than the entropy of a BIP-0039 seed phrase with the same
+//! number of words, it is stored as the entropy of a longer BIP-0039 seed phrase, e.g., 15
+//! words for a 12-word Electrum seed phrase, which can then be secret-shared like any other
+//! seed phrase.
+//!
+
+use crate::bip85::get_hmac_sha512;
+use crate::seed_phrase::{
+    get_num_bits_per_word, get_seed_phrase_for_entropy, get_word_suggestions, SeedPhrase,
+    MAX_WORD_SUGGESTIONS,
+};
+use crate::word_list::get_word_trie;
+use crate::{HarpoError, HarpoResult, SeedPhraseResult};
+use num_bigint::BigUint;
+use num_traits::{ToPrimitive, Zero};
+use zeroize::Zeroizing;
+
+/// The HMAC key that is used to compute the seed version.
+const SEED_VERSION_HMAC_KEY: &[u8] = b"Seed version";
+/// The hex prefixes of the seed versions: standard, segwit, 2FA, and 2FA segwit.
+const SEED_VERSION_PREFIXES: [&str; 4] = ["01", "100", "101", "102"];
+/// The minimum number of words of an Electrum seed phrase.
+const MIN_NUM_WORDS: usize = 12;
+/// The maximum number of entropy bits of the carrying BIP-0039 seed phrase.
+const MAX_NUM_ENTROPY_BITS: usize = 256;
+/// The entropy of the carrying BIP-0039 seed phrase is a multiple of this number of bits.
+const ENTROPY_INCREMENT: usize = 32;
+
+/// The function returns the indices of the words of the given Electrum seed phrase.
+///
+/// An error is returned if the number of words is not supported, if a word is not in the word
+/// list, or if the last word is the first word of the word list, which would be lost when
+/// encoding the seed phrase as an integer.
+///
+/// * `seed_phrase` - The Electrum seed phrase.
+/// * `word_list` - The word list.
+fn get_index_list(seed_phrase: &SeedPhrase, word_list: &[&str]) -> HarpoResult<Vec<usize>> {
+    let num_bits_per_word = get_num_bits_per_word(word_list);
+    let max_num_words = (MAX_NUM_ENTROPY_BITS - 1) / num_bits_per_word;
+    if seed_phrase.len() < MIN_NUM_WORDS || seed_phrase.len() > max_num_words {
+        return Err(HarpoError::InvalidSeedPhrase(format!(
+            "Electrum seed phrases must have between {} and {} words.",
+            MIN_NUM_WORDS, max_num_words
+        )));
+    }
+    let trie = get_word_trie(word_list);
+    let mut index_list = vec![];
+    for (position, word) in seed_phrase.get_words().into_iter().enumerate() {
+        match trie.get_index(word) {
+            Some(index) => index_list.push(index),
+            None => {
+                return Err(HarpoError::UnknownWord {
+                    word: word.to_string(),
+                    position,
+                    suggestions: get_word_suggestions(word, word_list)
+                        .into_iter()
+                        .take(MAX_WORD_SUGGESTIONS)
+                        .map(str::to_string)
+                        .collect(),
+                })
+            }
+        }
+    }
+    // The most significant digit is stored last and must not be zero.
+    if index_list.last() == Some(&0) {
+        return Err(HarpoError::InvalidSeedPhrase(format!(
+            "The last word of an Electrum seed phrase cannot be '{}'.",
+            word_list[0]
+        )));
+    }
+    Ok(index_list)
+}
+
+/// The function checks whether the given seed phrase has a valid Electrum seed version.
+///
+/// The seed version is determined by the HMAC-SHA512 of the words separated by single spaces.
+///
+/// * `seed_phrase` - The seed phrase.
+fn has_seed_version(seed_phrase: &SeedPhrase) -> bool {
+    let normalized_phrase = Zeroizing::new(seed_phrase.get_words().join(" "));
+    let hash = get_hmac_sha512(SEED_VERSION_HMAC_KEY, normalized_phrase.as_bytes());
+    let prefix = format!("{:02x}{:02x}", hash[0], hash[1]);
+    SEED_VERSION_PREFIXES
+        .iter()
+        .any(|version_prefix| prefix.starts_with(version_prefix))
+}
+
+/// The function checks the compliance of the given Electrum seed phrase for the given word list.
+///
+/// An error is returned if the seed phrase cannot be encoded or if it does not have a valid
+/// seed version.
+///
+/// * `seed_phrase` - The Electrum seed phrase.
+/// * `word_list` - The word list.
+pub(crate) fn check_electrum_compliance(
+    seed_phrase: &SeedPhrase,
+    word_list: &[&str],
+) -> HarpoResult<()> {
+    get_index_list(seed_phrase, word_list)?;
+    if !has_seed_version(seed_phrase) {
+        return Err(HarpoError::InvalidSeedPhrase(
+            "The seed phrase does not have a valid Electrum seed version.".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// The function returns the BIP-0039 seed phrase that carries the given Electrum seed phrase.
+///
+/// The entropy of the returned seed phrase is the integer encoded by the Electrum seed phrase,
+/// using the smallest supported entropy length that holds all bits.
+///
+/// * `seed_phrase` - The Electrum seed phrase.
+/// * `word_list` - The word list.
+pub(crate) fn get_carrier_seed_phrase(
+    seed_phrase: &SeedPhrase,
+    word_list: &[&str],
+) -> SeedPhraseResult {
+    check_electrum_compliance(seed_phrase, word_list)?;
+    let index_list = Zeroizing::new(get_index_list(seed_phrase, word_list)?);
+    let num_bits_per_word = get_num_bits_per_word(word_list);
+    // The first word is the least significant digit.
+    let mut value = BigUint::zero();
+    for index in index_list.iter().rev() {
+        value = (value << num_bits_per_word) + *index;
+    }
+    let num_entropy_bits =
+        (index_list.len() * num_bits_per_word).div_ceil(ENTROPY_INCREMENT) * ENTROPY_INCREMENT;
+    let value_bytes = Zeroizing::new(value.to_bytes_be());
+    let mut entropy = Zeroizing::new(vec![0; num_entropy_bits >> 3]);
+    let offset = entropy.len() - value_bytes.len();
+    entropy[offset..].copy_from_slice(&value_bytes);
+    get_seed_phrase_for_entropy(&entropy, word_list)
+}
+
+/// The function returns the Electrum seed phrase carried by the given BIP-0039 seed phrase.
+///
+/// An error is returned if the carrying seed phrase is not BIP-0039 compliant or if the
+/// resulting seed phrase is not a valid Electrum seed phrase.
+///
+/// * `carrier_seed_phrase` - The BIP-0039 seed phrase that carries the Electrum seed phrase.
+/// * `word_list` - The word list.
+pub(crate) fn get_electrum_seed_phrase(
+    carrier_seed_phrase: &SeedPhrase,
+    word_list: &[&str],
+) -> SeedPhraseResult {
+    let entropy = Zeroizing::new(carrier_seed_phrase.to_entropy_for_word_list(word_list)?);
+    let num_bits_per_word = get_num_bits_per_word(word_list);
+    let mut value = BigUint::from_bytes_be(&entropy);
+    let mut words = Zeroizing::new(vec![]);
+    while !value.is_zero() {
+        // The remainder is smaller than the word list size, so it always fits into a usize.
+        let index = (&value % word_list.len()).to_usize().unwrap_or(0);
+        words.push(word_list[index].to_string());
+        value >>= num_bits_per_word;
+    }
+    let seed_phrase = SeedPhrase::new(&words);
+    check_electrum_compliance(&seed_phrase, word_list).map_err(|_| {
+        HarpoError::InvalidSeedPhrase(
+            "The seed phrase does not carry a valid Electrum seed phrase.".to_string(),
+        )
+    })?;
+    Ok(seed_phrase)
+}
+
+// ******************************** TESTS ********************************
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::seed_phrase::{is_compliant, parse_phrase};
+    use crate::word_list::DEFAULT_WORD_LIST;
+
+    #[test]
+    /// The function tests the compliance check and the conversion of Electrum seed phrases.
+    fn test_electrum_seed_phrase() {
+        // A segwit seed phrase and a standard seed phrase, neither of which is BIP-0039
+        // compliant.
+        for phrase in [
+            "wild father tree among universe such mobile favorite target dynamic credit identify",
+            "since sick check reward swamp mind board moral cross bounce mutual equip",
+        ] {
+            let seed_phrase = parse_phrase(phrase).unwrap();
+            assert!(!is_compliant(&seed_phrase, DEFAULT_WORD_LIST));
+            assert!(check_electrum_compliance(&seed_phrase, DEFAULT_WORD_LIST).is_ok());
+            let carrier = get_carrier_seed_phrase(&seed_phrase, DEFAULT_WORD_LIST).unwrap();
+            assert_eq!(carrier.len(), 15);
+            assert!(is_compliant(&carrier, DEFAULT_WORD_LIST));
+            assert_eq!(
+                get_electrum_seed_phrase(&carrier, DEFAULT_WORD_LIST).unwrap(),
+                seed_phrase
+            );
+        }
+        // A BIP-0039 seed phrase does not have an Electrum seed version.
+        let seed_phrase = parse_phrase(
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon \
+            abandon about",
+        )
+        .unwrap();
+        assert!(check_electrum_compliance(&seed_phrase, DEFAULT_WORD_LIST).is_err());
+        let carrier = parse_phrase(
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon \
+            abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon \
+            abandon abandon abandon art",
+        )
+        .unwrap();
+        assert!(get_electrum_seed_phrase(&carrier, DEFAULT_WORD_LIST).is_err());
+    }
+}
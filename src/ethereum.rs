@@ -0,0 +1,218 @@
+//! The `ethereum` module provides Ethereum private keys, which can be secret-shared in the same
+//! way as seed phrases.
+//!
+//! When reconstructing an Ethereum private key, the derived address can be used to confirm that
+//! the expected key was reconstructed.
+//!
+
+use crate::secp256k1::{get_public_key, is_valid_private_key, KEY_LENGTH};
+use crate::{HarpoError, HarpoResult};
+use sha3::{Digest, Keccak256};
+use std::fmt;
+use std::str::FromStr;
+use subtle::ConstantTimeEq;
+use zeroize::{Zeroize, ZeroizeOnDrop, Zeroizing};
+
+/// The number of bytes of an Ethereum address.
+const ADDRESS_LENGTH: usize = 20;
+/// This struct holds an Ethereum private key.
+///
+/// The private key is wiped from memory when it is dropped, and private keys are compared in
+/// constant time.
+#[derive(Clone, Eq)]
+pub struct EthereumPrivateKey {
+    /// The private key in big-endian format.
+    bytes: [u8; KEY_LENGTH],
+}
+
+impl EthereumPrivateKey {
+    /// The function creates an Ethereum private key from the given big-endian bytes.
+    ///
+    /// An error is returned if the bytes do not encode a valid secp256k1 private key.
+    ///
+    /// * `bytes` - The private key in big-endian format.
+    pub fn new(bytes: [u8; KEY_LENGTH]) -> HarpoResult<Self> {
+        if !is_valid_private_key(&bytes) {
+            return Err(HarpoError::InvalidParameter(
+                "The private key is not a valid secp256k1 private key.".to_string(),
+            ));
+        }
+        Ok(EthereumPrivateKey { bytes })
+    }
+
+    /// The function returns the private key in big-endian format.
+    pub fn get_bytes(&self) -> &[u8; KEY_LENGTH] {
+        &self.bytes
+    }
+
+    /// The function returns the Ethereum address of the private key.
+    ///
+    /// The address is returned as a hex string with the `0x` prefix and the mixed-case checksum
+    /// defined in EIP-55.
+    pub fn get_address(&self) -> String {
+        let (x, y) = get_public_key(&self.bytes);
+        let mut public_key = x.to_vec();
+        public_key.extend_from_slice(&y);
+        // The address consists of the last 20 bytes of the hash of the public key.
+        let hash = get_keccak_256(&public_key);
+        let address = encode_hex(&hash[KEY_LENGTH - ADDRESS_LENGTH..]);
+        // The checksum is encoded in the case of the letters.
+        let address_hash = get_keccak_256(address.as_bytes());
+        let checksummed_address: String = address
+            .chars()
+            .enumerate()
+            .map(|(position, character)| {
+                let nibble = (address_hash[position / 2] >> (4 * (1 - position % 2))) & 0x0f;
+                if nibble >= 8 {
+                    character.to_ascii_uppercase()
+                } else {
+                    character
+                }
+            })
+            .collect();
+        format!("0x{}", checksummed_address)
+    }
+}
+
+impl PartialEq for EthereumPrivateKey {
+    /// The private keys are compared in constant time.
+    ///
+    /// * `other` - The other private key.
+    fn eq(&self, other: &Self) -> bool {
+        self.bytes.ct_eq(&other.bytes).into()
+    }
+}
+
+impl Zeroize for EthereumPrivateKey {
+    /// The private key is overwritten with zeros.
+    fn zeroize(&mut self) {
+        self.bytes.zeroize();
+    }
+}
+
+impl Drop for EthereumPrivateKey {
+    /// The private key is wiped from memory when it is dropped.
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for EthereumPrivateKey {}
+
+impl fmt::Debug for EthereumPrivateKey {
+    /// The private key is not revealed in debug output.
+    ///
+    /// * `formatter` - The formatter.
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "EthereumPrivateKey({})", self.get_address())
+    }
+}
+
+impl fmt::Display for EthereumPrivateKey {
+    /// An Ethereum private key is displayed as a lower-case hex string with the `0x` prefix.
+    ///
+    /// * `formatter` - The formatter.
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "0x{}", encode_hex(&self.bytes))
+    }
+}
+
+impl FromStr for EthereumPrivateKey {
+    type Err = HarpoError;
+
+    /// The function parses an Ethereum private key from a hex string.
+    ///
+    /// The hex string must consist of 64 hex characters, optionally preceded by `0x`.
+    ///
+    /// * `input` - The hex string.
+    fn from_str(input: &str) -> HarpoResult<Self> {
+        let input = input.trim();
+        let input = input
+            .strip_prefix("0x")
+            .or_else(|| input.strip_prefix("0X"))
+            .unwrap_or(input);
+        if input.len() != 2 * KEY_LENGTH || !input.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(HarpoError::InvalidParameter(format!(
+                "A private key must consist of {} hex characters.",
+                2 * KEY_LENGTH
+            )));
+        }
+        let mut bytes = Zeroizing::new([0; KEY_LENGTH]);
+        for (index, byte) in bytes.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&input[2 * index..2 * index + 2], 16)?;
+        }
+        EthereumPrivateKey::new(*bytes)
+    }
+}
+
+/// The function encodes the given bytes as a lower-case hex string.
+///
+/// * `bytes` - The bytes.
+fn encode_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// The function computes the Keccak-256 hash of the given data as used by Ethereum.
+///
+/// Note that Keccak-256 uses a different padding than the standardized SHA3-256.
+///
+/// * `data` - The data.
+pub(crate) fn get_keccak_256(data: &[u8]) -> [u8; 32] {
+    Keccak256::digest(data).into()
+}
+
+// ******************************** TESTS ********************************
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// The function tests the Keccak-256 hash function.
+    fn test_keccak_256() {
+        assert_eq!(
+            encode_hex(&get_keccak_256(b"")),
+            "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"
+        );
+        assert_eq!(
+            encode_hex(&get_keccak_256(b"abc")),
+            "4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45"
+        );
+        // The input spans two blocks.
+        assert_eq!(
+            encode_hex(&get_keccak_256(&[0x61; 200])),
+            "96ea54061def936c4be90b518992fdc6f12f535068a256229aca54267b4d084d"
+        );
+    }
+
+    #[test]
+    /// The function tests the parsing of private keys and the derivation of addresses.
+    fn test_ethereum_private_key() {
+        let key = "0x4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318"
+            .parse::<EthereumPrivateKey>()
+            .unwrap();
+        assert_eq!(
+            key.get_address(),
+            "0x2c7536E3605D9C16a7a3D7b1898e529396a65c23"
+        );
+        let key_without_prefix = "4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318"
+            .parse::<EthereumPrivateKey>()
+            .unwrap();
+        assert_eq!(key, key_without_prefix);
+        assert_eq!(
+            key.to_string(),
+            "0x4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318"
+        );
+        let mut bytes = [0; KEY_LENGTH];
+        bytes[KEY_LENGTH - 1] = 1;
+        let key = EthereumPrivateKey::new(bytes).unwrap();
+        assert_eq!(
+            key.get_address(),
+            "0x7E5F4552091A69125d5DfCb7b8C2659029395Bdf"
+        );
+        // Invalid keys are rejected.
+        assert!("0x1234".parse::<EthereumPrivateKey>().is_err());
+        assert!(EthereumPrivateKey::new([0; KEY_LENGTH]).is_err());
+        assert!(EthereumPrivateKey::new([0xff; KEY_LENGTH]).is_err());
+    }
+}
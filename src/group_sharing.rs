@@ -0,0 +1,259 @@
+//! The `group_sharing` module provides the secret-shared seed phrases of the two-level sharing
+//! scheme, in which the seed phrase is split among groups and each group secret is split among
+//! the members of the group, similar to SLIP-0039.
+//!
+//! The seed phrase is secret-shared among the groups using the group threshold, which results in
+//! one group secret per group. Each group secret is in turn secret-shared among the members of
+//! the group using the member threshold of the group. The seed phrase can be reconstructed from
+//! the group threshold number of groups, where each group must provide at least its member
+//! threshold number of secret-shared seed phrases.
+//!
+//! A [GroupShare](crate::group_sharing::GroupShare) is displayed as
+//! `g[GROUP INDEX]/t[GROUP THRESHOLD] [SECRET-SHARED SEED PHRASE]`, where the secret-shared seed
+//! phrase of the member always has an explicit index, e.g.:
+//!
+//! ```text
+//! g2/t2 3/1a2b/t2: [WORDS]
+//! ```
+//!
+
+use crate::seed_phrase::{parse_phrase, SeedPhrase};
+use crate::{HarpoError, HarpoResult};
+use std::fmt;
+use std::str::FromStr;
+
+/// The prefix of the group index in the string representation of a group share.
+const GROUP_INDEX_PREFIX: char = 'g';
+
+/// The prefix of the group threshold in the string representation of a group share.
+const GROUP_THRESHOLD_PREFIX: char = 't';
+
+/// This struct holds a secret-shared seed phrase of a member of a group.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GroupShare {
+    /// The index of the group.
+    group_index: u32,
+    /// The number of groups required for the reconstruction.
+    group_threshold: usize,
+    /// The secret-shared seed phrase of the member, which has an explicit index.
+    seed_phrase: SeedPhrase,
+}
+
+impl GroupShare {
+    /// The function creates a group share.
+    ///
+    /// An error is returned if the group index or the group threshold is 0 or if the
+    /// secret-shared seed phrase does not have an explicit index.
+    ///
+    /// * `group_index` - The index of the group.
+    /// * `group_threshold` - The number of groups required for the reconstruction.
+    /// * `seed_phrase` - The secret-shared seed phrase of the member.
+    pub fn new(
+        group_index: u32,
+        group_threshold: usize,
+        seed_phrase: SeedPhrase,
+    ) -> HarpoResult<Self> {
+        if group_index < 1 || group_threshold < 1 {
+            return Err(HarpoError::InvalidParameter(
+                "The group index and the group threshold must be at least 1.".to_string(),
+            ));
+        }
+        if seed_phrase.get_index().is_none() {
+            return Err(HarpoError::InvalidSeedPhrase(
+                "The secret-shared seed phrase of a group member must have an explicit index."
+                    .to_string(),
+            ));
+        }
+        Ok(GroupShare {
+            group_index,
+            group_threshold,
+            seed_phrase,
+        })
+    }
+
+    /// The function returns the index of the group.
+    pub fn get_group_index(&self) -> u32 {
+        self.group_index
+    }
+
+    /// The function returns the number of groups required for the reconstruction.
+    pub fn get_group_threshold(&self) -> usize {
+        self.group_threshold
+    }
+
+    /// The function returns the index of the member within the group.
+    pub fn get_member_index(&self) -> u32 {
+        // The unwrap() call is okay because the index is checked upon creation.
+        self.seed_phrase.get_index().unwrap()
+    }
+
+    /// The function returns the number of members of the group required for the reconstruction
+    /// of the group secret, if it is recorded.
+    pub fn get_member_threshold(&self) -> Option<usize> {
+        self.seed_phrase.get_threshold()
+    }
+
+    /// The function returns the secret-shared seed phrase of the member.
+    pub fn get_seed_phrase(&self) -> &SeedPhrase {
+        &self.seed_phrase
+    }
+}
+
+impl fmt::Display for GroupShare {
+    /// A group share is displayed as the group index and the group threshold followed by the
+    /// secret-shared seed phrase of the member.
+    ///
+    /// * `formatter` - The formatter.
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "{}{}/{}{} {}",
+            GROUP_INDEX_PREFIX,
+            self.group_index,
+            GROUP_THRESHOLD_PREFIX,
+            self.group_threshold,
+            self.seed_phrase
+        )
+    }
+}
+
+impl FromStr for GroupShare {
+    type Err = HarpoError;
+
+    /// A group share is parsed from its string representation, see
+    /// [GroupShare](crate::group_sharing::GroupShare).
+    ///
+    /// * `input` - The group share as a string.
+    fn from_str(input: &str) -> HarpoResult<Self> {
+        let invalid = || {
+            HarpoError::InvalidSeedPhrase(format!(
+                "The group share '{}' does not start with a group prefix such as 'g1/t2'.",
+                input.split_whitespace().next().unwrap_or_default()
+            ))
+        };
+        let (prefix, seed_phrase) = input.trim().split_once(' ').ok_or_else(invalid)?;
+        let (group_index, group_threshold) = prefix
+            .strip_prefix(GROUP_INDEX_PREFIX)
+            .and_then(|prefix| prefix.split_once('/'))
+            .and_then(|(group_index, group_threshold)| {
+                Some((
+                    group_index.parse::<u32>().ok()?,
+                    group_threshold
+                        .strip_prefix(GROUP_THRESHOLD_PREFIX)?
+                        .parse::<usize>()
+                        .ok()?,
+                ))
+            })
+            .ok_or_else(invalid)?;
+        GroupShare::new(group_index, group_threshold, parse_phrase(seed_phrase)?)
+    }
+}
+
+/// This struct holds an access policy that requires a number of mandatory secret-shared seed
+/// phrases in addition to a threshold number of the remaining ones.
+///
+/// For example, the policy "the executor's secret-shared seed phrase plus any 2 of 4 family
+/// secret-shared seed phrases" is `AccessPolicy::new(2, 4).mandatory_shares(1)`. The policy is
+/// implemented by nested sharing: each mandatory secret-shared seed phrase forms a group with a
+/// single member, the remaining secret-shared seed phrases form a group with the given
+/// threshold, and all groups are required.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AccessPolicy {
+    /// The number of mandatory secret-shared seed phrases.
+    num_mandatory_shares: usize,
+    /// The threshold for the optional secret-shared seed phrases.
+    threshold: usize,
+    /// The number of optional secret-shared seed phrases.
+    num_shares: usize,
+}
+
+impl AccessPolicy {
+    /// The function creates an access policy that requires the given threshold number of
+    /// secret-shared seed phrases out of the given number.
+    ///
+    /// * `threshold` - The threshold for the optional secret-shared seed phrases.
+    /// * `num_shares` - The number of optional secret-shared seed phrases.
+    pub fn new(threshold: usize, num_shares: usize) -> Self {
+        AccessPolicy {
+            num_mandatory_shares: 0,
+            threshold,
+            num_shares,
+        }
+    }
+
+    /// The function sets the number of mandatory secret-shared seed phrases.
+    ///
+    /// * `num_mandatory_shares` - The number of mandatory secret-shared seed phrases.
+    pub fn mandatory_shares(mut self, num_mandatory_shares: usize) -> Self {
+        self.num_mandatory_shares = num_mandatory_shares;
+        self
+    }
+
+    /// The function returns the number of mandatory secret-shared seed phrases.
+    pub fn get_num_mandatory_shares(&self) -> usize {
+        self.num_mandatory_shares
+    }
+
+    /// The function returns the threshold for the optional secret-shared seed phrases.
+    pub fn get_threshold(&self) -> usize {
+        self.threshold
+    }
+
+    /// The function returns the number of optional secret-shared seed phrases.
+    pub fn get_num_shares(&self) -> usize {
+        self.num_shares
+    }
+
+    /// The function returns the member threshold and the number of members of each group that
+    /// implements the policy.
+    ///
+    /// The groups of the mandatory secret-shared seed phrases come first. The group of the
+    /// optional secret-shared seed phrases is omitted if there are none.
+    pub fn get_groups(&self) -> Vec<(usize, usize)> {
+        let mut groups = vec![(1, 1); self.num_mandatory_shares];
+        if self.num_shares > 0 || self.threshold > 0 {
+            groups.push((self.threshold, self.num_shares));
+        }
+        groups
+    }
+}
+
+// ******************************** TESTS ********************************
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// The function tests the string representation of group shares.
+    fn test_group_share_encoding() {
+        let words = "abandon ability able about above absent absorb abstract absurd abuse access \
+            accident";
+        let seed_phrase =
+            parse_phrase(&format!("3/1a2b/t2: {}", words)).expect("The parsing should work.");
+        let group_share =
+            GroupShare::new(2, 3, seed_phrase.clone()).expect("The creation should work.");
+        assert_eq!(group_share.get_member_index(), 3);
+        assert_eq!(group_share.get_member_threshold(), Some(2));
+        let encoding = group_share.to_string();
+        assert!(encoding.starts_with("g2/t3 3/1a2b/t2: abandon ability"));
+        assert_eq!(
+            encoding
+                .parse::<GroupShare>()
+                .expect("The parsing should work."),
+            group_share
+        );
+        // Invalid group shares are rejected.
+        assert!(GroupShare::new(0, 3, seed_phrase.clone()).is_err());
+        assert!(GroupShare::new(1, 3, parse_phrase(words).unwrap()).is_err());
+        assert!(encoding[1..].parse::<GroupShare>().is_err());
+        assert!(encoding
+            .replace("g2/t3", "g2/3")
+            .parse::<GroupShare>()
+            .is_err());
+        assert!(encoding
+            .replace("3/1a2b/t2: ", "")
+            .parse::<GroupShare>()
+            .is_err());
+    }
+}
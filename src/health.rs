@@ -0,0 +1,395 @@
+//! The `health` module provides known-answer tests that check the integrity of `harpo` at runtime.
+//!
+//! The known-answer tests cover the finite field arithmetic, the default word list, the
+//! conversion between seed phrases and finite field elements using BIP-0039 test vectors, and
+//! the creation and reconstruction of secret shares for a fixed polynomial.
+//! A failing test indicates a miscompilation, a corrupted binary, or a corrupted word list.
+//!
+
+use crate::math::FiniteFieldElement;
+use crate::secret_sharing::{
+    get_field_modulus_for_bits, get_modulus_for_words, reconstruct_secret, SecretPolynomial,
+};
+use crate::seed_phrase::{
+    get_element_and_index_for_seed_phrase, get_element_for_seed_phrase,
+    get_seed_phrase_for_element, get_seed_phrase_for_element_with_embedding, is_compliant,
+    SeedPhrase,
+};
+use crate::validate_word_list;
+use num_bigint::BigUint;
+use num_traits::One;
+use sha2::{Digest, Sha256};
+use std::fmt;
+
+/// The SHA-256 hash of the default word list, with one word per line, as specified here:
+/// <https://github.com/bitcoin/bips/blob/master/bip-0039/english.txt>
+const DEFAULT_WORD_LIST_HASH: &str =
+    "2f5eed53a4727b4bf8880d8f3f199efc90e58503646d9ff8eff3a2ed3b24dbda";
+
+/// BIP-0039 test vectors for the default word list, consisting of the entropy in hex and the
+/// corresponding seed phrase.
+const BIP39_TEST_VECTORS: &[(&str, &str)] = &[
+    (
+        "00000000000000000000000000000000",
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon \
+        about",
+    ),
+    (
+        "7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f",
+        "legal winner thank year wave sausage worth useful legal winner thank yellow",
+    ),
+    (
+        "80808080808080808080808080808080",
+        "letter advice cage absurd amount doctor acoustic avoid letter advice cage above",
+    ),
+    (
+        "9e885d952ad362caeb4efe34a8e91bd2",
+        "ozone drill grab fiber curtain grace pudding thank cruise elder eight picnic",
+    ),
+    (
+        "0000000000000000000000000000000000000000000000000000000000000000",
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon \
+        abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon \
+        abandon art",
+    ),
+    (
+        "68a79eaca2324873eacc50cb9c6eca8cc68ea5d936f98787c60c7ebc74e6ce7c",
+        "hamster diagram private dutch cause delay private meat slide toddler razor book happy \
+        fancy gospel tennis maple dilemma loan word shrug inflict delay length",
+    ),
+];
+
+/// The number of secret shares created in the secret-sharing known-answer test.
+const NUM_TEST_SHARES: u32 = 5;
+
+/// This struct holds the result of a single known-answer test.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HealthCheckResult {
+    /// The name of the known-answer test.
+    name: &'static str,
+    /// The error message if the known-answer test failed.
+    error: Option<String>,
+}
+
+impl HealthCheckResult {
+    /// The function returns the name of the known-answer test.
+    pub fn get_name(&self) -> &str {
+        self.name
+    }
+
+    /// The function returns true if the known-answer test passed.
+    pub fn is_passed(&self) -> bool {
+        self.error.is_none()
+    }
+
+    /// The function returns the error message if the known-answer test failed.
+    pub fn get_error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+}
+
+/// This struct holds the results of all known-answer tests.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HealthReport {
+    /// The results of the known-answer tests.
+    results: Vec<HealthCheckResult>,
+}
+
+impl HealthReport {
+    /// The function returns true if all known-answer tests passed.
+    pub fn is_healthy(&self) -> bool {
+        self.results.iter().all(|result| result.is_passed())
+    }
+
+    /// The function returns the results of the individual known-answer tests.
+    pub fn get_results(&self) -> &[HealthCheckResult] {
+        &self.results
+    }
+}
+
+impl fmt::Display for HealthReport {
+    /// The function defines how a health report is printed.
+    ///
+    /// Every known-answer test is printed on a separate line.
+    ///
+    /// * `formatter` - The formatter.
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (position, result) in self.results.iter().enumerate() {
+            if position > 0 {
+                writeln!(formatter)?;
+            }
+            match &result.error {
+                None => write!(formatter, "[passed] {}", result.name)?,
+                Some(error) => write!(formatter, "[FAILED] {}: {}", result.name, error)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The function returns an error message if the given condition does not hold.
+///
+/// * `condition` - The condition that must hold.
+/// * `message` - The error message.
+fn ensure(condition: bool, message: &str) -> Result<(), String> {
+    if condition {
+        Ok(())
+    } else {
+        Err(message.to_string())
+    }
+}
+
+/// The function decodes the given hex string.
+///
+/// * `input` - The hex string.
+fn decode_hex(input: &str) -> Result<Vec<u8>, String> {
+    (0..input.len())
+        .step_by(2)
+        .map(|index| {
+            u8::from_str_radix(&input[index..index + 2], 16).map_err(|error| error.to_string())
+        })
+        .collect()
+}
+
+/// The function converts the given string into a seed phrase.
+///
+/// * `input` - The space-delimited seed phrase.
+fn get_seed_phrase(input: &str) -> SeedPhrase {
+    let words: Vec<String> = input
+        .split_whitespace()
+        .map(|word| word.to_string())
+        .collect();
+    SeedPhrase::new(&words)
+}
+
+/// The function runs the known-answer test for the finite field arithmetic.
+///
+/// The test uses the 256-bit modulus `p = 2^256-189`.
+fn check_finite_field_arithmetic() -> Result<(), String> {
+    let modulus = get_field_modulus_for_bits(256).ok_or("The 256-bit modulus is missing.")?;
+    let big_modulus = modulus.to_big_uint();
+    ensure(
+        big_modulus.clone() + BigUint::from(189u32) == BigUint::one() << 256usize,
+        "The 256-bit modulus is incorrect.",
+    )?;
+    let zero = FiniteFieldElement::new_integer(0, modulus);
+    let one = FiniteFieldElement::new_integer(1, modulus);
+    let two = FiniteFieldElement::new_integer(2, modulus);
+    let three = FiniteFieldElement::new_integer(3, modulus);
+    let maximum = FiniteFieldElement::new(&(big_modulus.clone() - 1u32).to_bytes_le(), modulus);
+    let power = FiniteFieldElement::new(&(BigUint::one() << 255usize).to_bytes_le(), modulus);
+    // (p-1) + 1 = 0.
+    ensure(
+        (maximum.clone() + one.clone()).is_zero(),
+        "The addition returned a wrong result.",
+    )?;
+    // 0 - 1 = p-1.
+    ensure(
+        zero - one.clone() == maximum,
+        "The subtraction returned a wrong result.",
+    )?;
+    // 3 - 3 = 0.
+    ensure(
+        (three.clone() - three.clone()).is_zero(),
+        "The subtraction of equal elements returned a wrong result.",
+    )?;
+    // 2^255 * 2 = 2^256 = 189.
+    ensure(
+        (power * two.clone()).to_big_uint() == BigUint::from(189u32),
+        "The multiplication returned a wrong result.",
+    )?;
+    // (1/2) * 2 = 1 and 1/2 = (p+1)/2.
+    let half = one.clone() / two.clone();
+    ensure(
+        half.to_big_uint() == (big_modulus.clone() + 1u32) >> 1,
+        "The division returned a wrong result.",
+    )?;
+    ensure(
+        (half * two).to_big_uint().is_one(),
+        "The division returned a wrong result.",
+    )?;
+    // The bytes of the element p-1 are recovered.
+    ensure(
+        maximum.get_bytes() == (big_modulus - 1u32).to_bytes_le(),
+        "The byte encoding of finite field elements is incorrect.",
+    )
+}
+
+/// The function runs the known-answer test for the given word list.
+///
+/// The word list must be the default word list.
+///
+/// * `word_list` - The word list.
+fn check_word_list(word_list: &[&str]) -> Result<(), String> {
+    validate_word_list(word_list).map_err(|error| error.to_string())?;
+    let mut hasher = Sha256::new();
+    for word in word_list {
+        hasher.update(word.as_bytes());
+        hasher.update(b"\n");
+    }
+    let hash: String = hasher
+        .finalize()
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect();
+    ensure(
+        hash == DEFAULT_WORD_LIST_HASH,
+        "The word list does not match the BIP-0039 English word list.",
+    )
+}
+
+/// The function runs the known-answer test for the BIP-0039 test vectors.
+///
+/// * `word_list` - The word list.
+fn check_bip39_test_vectors(word_list: &[&str]) -> Result<(), String> {
+    for (entropy, phrase) in BIP39_TEST_VECTORS {
+        let entropy = decode_hex(entropy)?;
+        let seed_phrase = get_seed_phrase(phrase);
+        ensure(
+            is_compliant(&seed_phrase, word_list),
+            &format!("The test vector '{}' is not compliant.", phrase),
+        )?;
+        // The finite field element must hold the entropy.
+        let element = get_element_for_seed_phrase(&seed_phrase, word_list)
+            .map_err(|error| error.to_string())?;
+        ensure(
+            element.get_bytes() == entropy,
+            &format!("The test vector '{}' is decoded incorrectly.", phrase),
+        )?;
+        // The entropy must be encoded as the seed phrase.
+        let modulus = get_modulus_for_words(seed_phrase.len())
+            .ok_or("The modulus for the test vector is missing.")?;
+        let encoded_seed_phrase =
+            get_seed_phrase_for_element(&FiniteFieldElement::new(&entropy, modulus), word_list)
+                .map_err(|error| error.to_string())?;
+        ensure(
+            encoded_seed_phrase == seed_phrase,
+            &format!("The test vector '{}' is encoded incorrectly.", phrase),
+        )?;
+    }
+    Ok(())
+}
+
+/// The function runs the known-answer test for the creation and reconstruction of secret
+/// shares.
+///
+/// The polynomial `f(x) = s + x + 2x^2` is used, where `s` is the secret defined by a BIP-0039
+/// test vector. Every secret share must hold the value `f(i)` and every subset of three secret
+/// shares must reconstruct the secret. Moreover, the embedding of indices must be reversible.
+///
+/// * `word_list` - The word list.
+fn check_secret_sharing(word_list: &[&str]) -> Result<(), String> {
+    let (_, phrase) = BIP39_TEST_VECTORS[1];
+    let seed_phrase = get_seed_phrase(phrase);
+    let secret =
+        get_element_for_seed_phrase(&seed_phrase, word_list).map_err(|error| error.to_string())?;
+    let modulus = secret.modulus;
+    let polynomial = SecretPolynomial::from_coefficients(vec![
+        secret.clone(),
+        FiniteFieldElement::new_integer(1, modulus),
+        FiniteFieldElement::new_integer(2, modulus),
+    ]);
+    let secret_shares = polynomial.get_secret_shares(NUM_TEST_SHARES);
+    for secret_share in &secret_shares {
+        let index = BigUint::from(secret_share.index);
+        let expected_value =
+            (secret.to_big_uint() + index.clone() + BigUint::from(2u32) * &index * &index)
+                % modulus.to_big_uint();
+        ensure(
+            secret_share.element.to_big_uint() == expected_value,
+            "The polynomial evaluation returned a wrong result.",
+        )?;
+        // The index must be recovered from the seed phrase with the embedded index.
+        let share_seed_phrase = get_seed_phrase_for_element_with_embedding(
+            &secret_share.element,
+            Some(secret_share.index),
+            true,
+            word_list,
+        )
+        .map_err(|error| error.to_string())?;
+        let (element, index) = get_element_and_index_for_seed_phrase(&share_seed_phrase, word_list)
+            .map_err(|error| error.to_string())?;
+        ensure(
+            element == secret_share.element && index == secret_share.index,
+            "The embedding of the index is not reversible.",
+        )?;
+    }
+    // Every subset of three secret shares must reconstruct the secret.
+    for first in 0..secret_shares.len() {
+        for second in first + 1..secret_shares.len() {
+            for third in second + 1..secret_shares.len() {
+                let subset = [
+                    secret_shares[first].clone(),
+                    secret_shares[second].clone(),
+                    secret_shares[third].clone(),
+                ];
+                ensure(
+                    reconstruct_secret(&subset).ok().as_ref() == Some(&secret),
+                    "The reconstruction returned a wrong secret.",
+                )?;
+            }
+        }
+    }
+    // The reconstructed secret must yield the original seed phrase.
+    let reconstructed_seed_phrase = get_seed_phrase_for_element(
+        &reconstruct_secret(&secret_shares[..3]).map_err(|error| error.to_string())?,
+        word_list,
+    )
+    .map_err(|error| error.to_string())?;
+    ensure(
+        reconstructed_seed_phrase == seed_phrase,
+        "The reconstructed seed phrase does not match.",
+    )
+}
+
+/// The function runs all known-answer tests using the given word list and returns the report.
+///
+/// * `word_list` - The word list, which must be the default word list.
+pub(crate) fn run_health_check(word_list: &[&str]) -> HealthReport {
+    let checks: [(&'static str, Result<(), String>); 4] = [
+        ("finite field arithmetic", check_finite_field_arithmetic()),
+        ("default word list", check_word_list(word_list)),
+        ("BIP-0039 test vectors", check_bip39_test_vectors(word_list)),
+        ("secret sharing", check_secret_sharing(word_list)),
+    ];
+    HealthReport {
+        results: checks
+            .iter()
+            .map(|(name, result)| HealthCheckResult {
+                name,
+                error: result.clone().err(),
+            })
+            .collect(),
+    }
+}
+
+// ******************************** TESTS ********************************
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::word_list::DEFAULT_WORD_LIST;
+
+    #[test]
+    /// The function tests that all known-answer tests pass and that a corrupted word list is
+    /// detected.
+    fn test_health_check() {
+        let report = run_health_check(DEFAULT_WORD_LIST);
+        assert!(report.is_healthy(), "{}", report);
+        assert_eq!(report.get_results().len(), 4);
+        assert!(report.to_string().contains("[passed] default word list"));
+        // Swap two words in the word list.
+        let mut corrupted_word_list = DEFAULT_WORD_LIST.to_vec();
+        corrupted_word_list.swap(100, 200);
+        let report = run_health_check(&corrupted_word_list);
+        assert!(!report.is_healthy());
+        let failed: Vec<&str> = report
+            .get_results()
+            .iter()
+            .filter(|result| !result.is_passed())
+            .map(|result| result.get_name())
+            .collect();
+        assert!(failed.contains(&"default word list"));
+        assert!(report.to_string().contains("[FAILED]"));
+    }
+}
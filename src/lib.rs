@@ -10,35 +10,134 @@
 //!   Given a valid seed phrase, create the requested number of
 //!   secret-shared seed phrases. A threshold must be provided as well, specifying how many
 //!   secret-shared seed phrases are required to reconstruct the original seed phrase.
+//! * [SplitRequest](crate::SplitRequest): Configure the creation of secret-shared seed phrases,
+//!   e.g., the index encoding and the word list, step by step.
 //! * [reconstruct_seed_phrase](crate::reconstruct_seed_phrase): Given a set of
 //!   secret-shared seed phrases, the function
 //!   reconstructs a seed phrase.
+//! * [split_bytes](crate::split_bytes) and [reconstruct_bytes](crate::reconstruct_bytes):
+//!   Secret-share arbitrary byte arrays, e.g., keys that are not seed phrases.
 //!
 //! The additional functionality that `harpo` provides is documented below.
 //!
 
+// The trace module provides optional instrumentation. It is declared first so that its macros
+// are available in all other modules.
+#[macro_use]
+mod trace;
+
+// The bip32 module provides the derivation of the BIP-0032 master key from a seed phrase.
+#[cfg(feature = "bip32")]
+mod bip32;
+
+// The bip85 module provides the derivation of child seed phrases.
+mod bip85;
+
+// The bitcoin module provides Bitcoin private keys (WIF and xprv), which can be secret-shared.
+pub mod bitcoin;
+
+// The byte_sharing module provides the secret sharing of arbitrary byte arrays.
+pub mod byte_sharing;
+
+// The commitment module provides commitments to secret polynomials.
+pub mod commitment;
+
+// The distributed_generation module provides the dealer-less generation of secret-shared seed
+// phrases.
+pub mod distributed_generation;
+
+// The electrum module provides the conversion of Electrum seed phrases for secret sharing.
+mod electrum;
+
+// The ethereum module provides Ethereum private keys, which can be secret-shared.
+pub mod ethereum;
+
+// The group_sharing module provides the two-level secret sharing among groups of custodians.
+pub mod group_sharing;
+
+// The health module provides known-answer tests to check the integrity of the library.
+pub mod health;
+
 // The math module provides the required finite field operations.
 mod math;
 
+// The normalization module provides the NFKD normalization of words.
+mod normalization;
+
+// The secp256k1 module provides the required elliptic curve operations.
+mod secp256k1;
+
 // The seed phrase module provides the conversion between seed phrases and the representation as
 // a finite field element.
 pub mod seed_phrase;
 
+// The seed_xor module provides the Coldcard-style Seed XOR scheme.
+mod seed_xor;
+
 // The secret_sharing module provides the secret-sharing functionality.
-mod secret_sharing;
+pub mod secret_sharing;
+
+// The slip39 module provides the import and export of SLIP-0039 shares.
+pub mod slip39;
+
+// The solana module provides Solana private keys, which can be secret-shared.
+pub mod solana;
 
-// The default word list is loaded from the word list module.
-mod word_list;
+// The ssss module provides the import and export of secret shares in the format of ssss.
+pub mod ssss;
 
-use secret_sharing::{reconstruct_secret, SecretPolynomial, SecretShare};
+// The test_vectors module provides reproducible test vectors for independent implementations.
+pub mod test_vectors;
+
+// The verification module provides the public data to verify secret-shared seed phrases.
+pub mod verification;
+
+// The weighted_sharing module provides bundles of secret-shared seed phrases for weighted
+// secret sharing.
+pub mod weighted_sharing;
+
+// The word_list module provides the default word list and the trait for arbitrary word lists.
+pub mod word_list;
+
+use bitcoin::{ExtendedKeyMetadata, ExtendedPrivateKey, Network, WifPrivateKey};
+use byte_sharing::{ByteShare, SharingBackend};
+use commitment::PolynomialCommitment;
+use electrum::{check_electrum_compliance, get_carrier_seed_phrase, get_electrum_seed_phrase};
+use ethereum::EthereumPrivateKey;
+use group_sharing::{AccessPolicy, GroupShare};
+use health::{run_health_check, HealthReport};
+use math::FiniteFieldElement;
+use num_bigint::BigUint;
+use num_traits::ToPrimitive;
+use rand::{rngs::OsRng, CryptoRng, RngCore};
+use secp256k1::KEY_LENGTH;
+use secret_sharing::{
+    combine_lagrange_partials, find_inconsistent_shares, get_modulus_for_words,
+    interpolate_polynomial, reconstruct_secret, LagrangePartial, SecretPolynomial, SecretShare,
+};
 use seed_phrase::{
-    get_element_and_index_for_seed_phrase, get_element_for_seed_phrase, get_random_seed_phrase,
-    get_seed_phrase_for_element, get_seed_phrase_for_element_with_embedding, is_compliant,
-    SeedPhrase, NUM_BITS_FOR_INDEX,
+    append_index_word, can_embed_index, check_compliance, get_element_and_index_for_seed_phrase,
+    get_element_for_seed_phrase, get_random_seed_phrase, get_seed_phrase_fingerprint,
+    get_seed_phrase_for_element, get_seed_phrase_for_element_with_embedding,
+    get_seed_phrase_for_entropy, has_index_word, is_compliant, is_valid_num_words,
+    remove_index_word, segment_tokens, validate_share, IndexEncoding, ParseMode, ParseReport,
+    PhraseStandard, ReconstructionReport, SeedPhrase, SeedPhraseDiagnostics, ShareProvenance,
+    ShareValidation, TypoPolicy, INVALID_NUM_WORDS_MESSAGE, MAX_INDEX_WORD_SHARES,
+    NUM_BITS_FOR_INDEX, SHARE_FORMAT_VERSION, WILDCARD_WORD,
 };
-use std::collections::{HashMap, HashSet};
+use slip39::{combine_shares, split_master_secret, Slip39Share};
+use solana::SolanaPrivateKey;
+use ssss::SsssShare;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fmt::Display;
-use word_list::DEFAULT_WORD_LIST;
+use std::path::{Path, PathBuf};
+use test_vectors::{create_test_vector_suite, TestVectorSuite};
+use verification::VerificationData;
+use weighted_sharing::WeightedShare;
+use word_list::{
+    get_word_trie, Language, SharedPrefix, WordList, BUNDLED_WORD_LISTS, DEFAULT_WORD_LIST,
+};
+use zeroize::Zeroizing;
 
 /// The maximum number of shares that can be embedded.
 /// It is `2^NUM_BITS_FOR_INDEX = 16` because 4 bits are used to encode the index in the embedding.
@@ -46,20 +145,137 @@ use word_list::DEFAULT_WORD_LIST;
 /// when using a 12-word seed phrase (12*11 = 132 bits to encode a secret of 128 bits).
 pub const MAX_EMBEDDED_SHARES: usize = 1 << NUM_BITS_FOR_INDEX;
 
-/// Every word list must have exactly this number of words.
-const NUM_WORDS_IN_LIST: usize = 2048;
-
 /// This enumeration type is returned by the main library functions if there is an error.
 #[derive(Debug)]
 pub enum HarpoError {
     /// This variant is used if the error relates to a seed phrase.
     InvalidSeedPhrase(String),
+    /// This variant is used if a word of a seed phrase is not in the word list.
+    UnknownWord {
+        /// The unknown word.
+        word: String,
+        /// The position of the word in the seed phrase, starting at 0.
+        position: usize,
+        /// The words in the word list that differ from the unknown word by a single typo.
+        suggestions: Vec<String>,
+    },
+    /// This variant is used if a seed phrase has an invalid number of words.
+    WrongWordCount {
+        /// The number of words of the seed phrase.
+        got: usize,
+    },
+    /// This variant is used if the checksum of a seed phrase does not match.
+    ChecksumMismatch,
+    /// This variant is used if secret-shared seed phrases have different numbers of words.
+    LengthMismatchAcrossShares,
+    /// This variant is used if fewer secret-shared seed phrases than the threshold are provided.
+    NotEnoughShares {
+        /// The number of provided secret-shared seed phrases.
+        provided: usize,
+        /// The number of secret-shared seed phrases required for the reconstruction.
+        required: usize,
+    },
     /// This variant is used if the error relates to a parameter.
     InvalidParameter(String),
     /// This variant is used if there is an I/O error.
     IoError(std::io::Error),
     /// This variant is used if there is an error parsing an integer.
     ParseIntError(std::num::ParseIntError),
+    /// This variant attaches context, e.g., a file path or a word position, to an error.
+    WithContext(ErrorContext, Box<HarpoError>),
+}
+
+/// This enumeration type holds the context in which an error occurred.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ErrorContext {
+    /// The error occurred while processing the file with the given path.
+    File(PathBuf),
+    /// The error relates to the word at the given position in a seed phrase, starting at 0.
+    WordPosition(usize),
+}
+
+impl Display for ErrorContext {
+    /// The function defines how an [ErrorContext](crate::ErrorContext) is formatted.
+    ///
+    /// Word positions are displayed starting at 1.
+    ///
+    /// * `formatter` - The formatter.
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ErrorContext::File(path) => write!(formatter, "file {}", path.display()),
+            ErrorContext::WordPosition(position) => write!(formatter, "word {}", position + 1),
+        }
+    }
+}
+
+impl HarpoError {
+    /// The function returns a stable, machine-readable code that identifies the kind of error.
+    ///
+    /// The codes are `invalid-seed-phrase`, `unknown-word`, `wrong-word-count`,
+    /// `checksum-mismatch`, `length-mismatch-across-shares`, `not-enough-shares`,
+    /// `invalid-parameter`, `io-error`, and `parse-int-error`. Context that is attached to an error does not change its code.
+    pub fn get_code(&self) -> &'static str {
+        match self {
+            HarpoError::InvalidSeedPhrase(_) => "invalid-seed-phrase",
+            HarpoError::UnknownWord { .. } => "unknown-word",
+            HarpoError::WrongWordCount { .. } => "wrong-word-count",
+            HarpoError::ChecksumMismatch => "checksum-mismatch",
+            HarpoError::LengthMismatchAcrossShares => "length-mismatch-across-shares",
+            HarpoError::NotEnoughShares { .. } => "not-enough-shares",
+            HarpoError::InvalidParameter(_) => "invalid-parameter",
+            HarpoError::IoError(_) => "io-error",
+            HarpoError::ParseIntError(_) => "parse-int-error",
+            HarpoError::WithContext(_, error) => error.get_code(),
+        }
+    }
+
+    /// The function returns the error with the given context attached.
+    ///
+    /// * `context` - The context.
+    pub fn with_context(self, context: ErrorContext) -> Self {
+        HarpoError::WithContext(context, Box::new(self))
+    }
+
+    /// The function returns all context attached to the error, starting with the outermost
+    /// context.
+    pub fn get_context(&self) -> Vec<&ErrorContext> {
+        let mut contexts = vec![];
+        let mut error = self;
+        while let HarpoError::WithContext(context, inner_error) = error {
+            contexts.push(context);
+            error = inner_error;
+        }
+        contexts
+    }
+
+    /// The function returns the path of the file in which the error occurred, if known.
+    pub fn get_file_path(&self) -> Option<&Path> {
+        self.get_context()
+            .into_iter()
+            .find_map(|context| match context {
+                ErrorContext::File(path) => Some(path.as_path()),
+                _ => None,
+            })
+    }
+
+    /// The function returns the position of the word to which the error relates, if known.
+    ///
+    /// The position starts at 0.
+    pub fn get_word_position(&self) -> Option<usize> {
+        let mut error = self;
+        while let HarpoError::WithContext(_, inner_error) = error {
+            error = inner_error;
+        }
+        if let HarpoError::UnknownWord { position, .. } = error {
+            return Some(*position);
+        }
+        self.get_context()
+            .into_iter()
+            .find_map(|context| match context {
+                ErrorContext::WordPosition(position) => Some(*position),
+                _ => None,
+            })
+    }
 }
 
 impl Display for HarpoError {
@@ -71,11 +287,69 @@ impl Display for HarpoError {
             HarpoError::InvalidSeedPhrase(message) => {
                 write!(formatter, "Invalid seed phrase error: {}", message)
             }
+            HarpoError::UnknownWord {
+                word,
+                position,
+                suggestions,
+            } => {
+                write!(
+                    formatter,
+                    "Invalid seed phrase error: The word '{}' at position {} is not in the word \
+                     list",
+                    word,
+                    position + 1
+                )?;
+                if !suggestions.is_empty() {
+                    let suggestions: Vec<String> = suggestions
+                        .iter()
+                        .map(|suggestion| format!("'{}'", suggestion))
+                        .collect();
+                    write!(formatter, " (did you mean {}?)", suggestions.join(" or "))?;
+                }
+                write!(formatter, ".")
+            }
+            HarpoError::WrongWordCount { got } => write!(
+                formatter,
+                "Invalid seed phrase error: The seed phrase has {} words. {}",
+                got, INVALID_NUM_WORDS_MESSAGE
+            ),
+            HarpoError::ChecksumMismatch => write!(
+                formatter,
+                "Invalid seed phrase error: The checksum of the seed phrase does not match."
+            ),
+            HarpoError::LengthMismatchAcrossShares => write!(
+                formatter,
+                "Invalid seed phrase error: Found seed phrases with different lengths."
+            ),
+            HarpoError::NotEnoughShares { provided, required } => write!(
+                formatter,
+                "Invalid seed phrase error: {} secret-shared seed phrase{} provided but {} \
+                 are required.",
+                provided,
+                if *provided == 1 { " was" } else { "s were" },
+                required
+            ),
             HarpoError::InvalidParameter(message) => {
                 write!(formatter, "Invalid parameter error: {}", message)
             }
             HarpoError::IoError(error) => write!(formatter, "I/O error: {}", error),
             HarpoError::ParseIntError(error) => write!(formatter, "Parsing error: {}", error),
+            HarpoError::WithContext(context, error) => write!(formatter, "{} ({})", error, context),
+        }
+    }
+}
+
+impl std::error::Error for HarpoError {
+    /// The function returns the underlying error, if any.
+    ///
+    /// Context is transparent, i.e., the source of an error with context is the source of the
+    /// wrapped error.
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            HarpoError::IoError(error) => Some(error),
+            HarpoError::ParseIntError(error) => Some(error),
+            HarpoError::WithContext(_, error) => error.source(),
+            _ => None,
         }
     }
 }
@@ -100,6 +374,29 @@ impl From<std::num::ParseIntError> for HarpoError {
     }
 }
 
+/// This trait is implemented by callbacks that are informed about the progress of long
+/// operations.
+///
+/// The trait is implemented for all closures of the form `FnMut(usize, usize)`.
+pub trait Progress {
+    /// The function is called whenever progress is made.
+    ///
+    /// * `completed` - The number of completed steps.
+    /// * `total` - The total number of steps.
+    fn update(&mut self, completed: usize, total: usize);
+}
+
+impl<F: FnMut(usize, usize)> Progress for F {
+    /// The function calls the closure with the number of completed steps and the total number
+    /// of steps.
+    ///
+    /// * `completed` - The number of completed steps.
+    /// * `total` - The total number of steps.
+    fn update(&mut self, completed: usize, total: usize) {
+        self(completed, total)
+    }
+}
+
 /// A result that contains a [HarpoError](crate::HarpoError) in the `Err` case.
 pub type HarpoResult<R> = Result<R, HarpoError>;
 
@@ -107,25 +404,97 @@ pub type HarpoResult<R> = Result<R, HarpoError>;
 /// [SeedPhrase](crate::seed_phrase::SeedPhrase) in the `Ok` case.
 pub type SeedPhraseResult = HarpoResult<SeedPhrase>;
 
+/// The function returns the maximum number of secret-shared seed phrases for the given number of
+/// words and index encoding.
+///
+/// With embedded indices and index words, the limit is given by the number of bits available to
+/// encode the index. When indices are provided explicitly, the indices must be distinct non-zero
+/// elements of the finite field and fit into 32 bits. The function returns `None` if the number
+/// of words is not supported and 0 if the index encoding is not supported for the given number
+/// of words.
+///
+/// * `num_words` - The number of words of the seed phrase that is secret-shared.
+/// * `index_encoding` - The encoding of the seed phrase indices.
+pub fn get_max_num_shares(num_words: usize, index_encoding: IndexEncoding) -> Option<usize> {
+    let modulus = get_modulus_for_words(num_words)?;
+    let max_num_shares = match index_encoding {
+        IndexEncoding::Embedded if can_embed_index(num_words) => MAX_EMBEDDED_SHARES,
+        IndexEncoding::Embedded => 0,
+        IndexEncoding::IndexWord => MAX_INDEX_WORD_SHARES,
+        IndexEncoding::Explicit => {
+            // The index 0 is reserved for the secret.
+            let max_index = modulus.to_big_uint() - 1u32;
+            max_index.to_u32().unwrap_or(u32::MAX) as usize
+        }
+    };
+    Some(max_num_shares)
+}
+
+/// The function is called to generate a reproducible suite of test vectors.
+///
+/// The secrets and the coefficients of the secret polynomials are derived deterministically from
+/// the provided seed. The suite, which can be displayed as a JSON document, contains the secret
+/// seed phrases, the parameters, the coefficients, and the expected secret-shared seed phrases.
+/// It is intended for validating independent implementations. The derived secrets must never be
+/// used to protect real secrets.
+///
+/// * `seed` - The seed.
+pub fn generate_test_vectors(seed: &[u8]) -> HarpoResult<TestVectorSuite> {
+    generate_test_vectors_for_word_list(seed, DEFAULT_WORD_LIST)
+}
+
+/// The function is called to generate a reproducible suite of test vectors for the given word
+/// list.
+///
+/// The secrets and the coefficients of the secret polynomials are derived deterministically from
+/// the provided seed. The derived secrets must never be used to protect real secrets.
+///
+/// * `seed` - The seed.
+/// * `word_list` - The word list for the seed phrases.
+pub fn generate_test_vectors_for_word_list(
+    seed: &[u8],
+    word_list: &(impl WordList + ?Sized),
+) -> HarpoResult<TestVectorSuite> {
+    let word_list = word_list.get_words();
+    validate_word_list(word_list)?;
+    if seed.is_empty() {
+        return Err(HarpoError::InvalidParameter(
+            "The seed must not be empty.".to_string(),
+        ));
+    }
+    create_test_vector_suite(seed, word_list)
+}
+
+/// The function runs built-in known-answer tests and returns the report.
+///
+/// The known-answer tests cover the finite field arithmetic, the default word list, BIP-0039
+/// test vectors, and the creation and reconstruction of secret shares for a fixed polynomial.
+/// It is recommended to call this function at startup in safety-critical applications to detect
+/// miscompilation or corrupted word lists.
+pub fn health_check() -> HealthReport {
+    run_health_check(DEFAULT_WORD_LIST)
+}
+
 /// The function checks the validity of the provided word list.
 ///
 /// Specifically, it checks that the list contains exactly the required
-/// number of distinct words.
+/// number of distinct words without white space.
 ///
 /// * `word_list` - The word list.
-fn validate_word_list(word_list: &[&str]) -> HarpoResult<()> {
-    let mut word_set: HashSet<&str> = HashSet::new();
-    for word in word_list {
-        word_set.insert(word);
-    }
-    if word_set.len() != NUM_WORDS_IN_LIST {
-        return Err(HarpoError::InvalidSeedPhrase(format!(
-            "The word list contains {} distinct words instead of {}.",
-            word_set.len(),
-            NUM_WORDS_IN_LIST
-        )));
-    }
-    Ok(())
+pub(crate) fn validate_word_list(word_list: &[&str]) -> HarpoResult<()> {
+    word_list::check_word_list(word_list).map(|_| ())
+}
+
+/// The function is called to check a custom word list.
+///
+/// An error is returned if the word list does not consist of exactly 2048 distinct, non-empty
+/// words without white space. Otherwise, the function returns the prefixes of four characters
+/// that are shared by several words. Such a word list can be used, but seed phrases cannot be
+/// stored reliably using only the first four characters of each word.
+///
+/// * `word_list` - The word list.
+pub fn check_word_list(word_list: &(impl WordList + ?Sized)) -> HarpoResult<Vec<SharedPrefix>> {
+    word_list::check_word_list(word_list.get_words())
 }
 
 /// The function is called to create secret-shared seed phrases.
@@ -178,324 +547,5198 @@ pub fn create_secret_shared_seed_phrases_for_word_list(
     threshold: usize,
     num_shares: usize,
     embed_indices: bool,
-    word_list: &[&str],
+    word_list: &(impl WordList + ?Sized),
 ) -> HarpoResult<Vec<SeedPhrase>> {
-    // Validate the word list.
-    validate_word_list(word_list)?;
-    // Make sure that the threshold is not greater than the number of shares.
-    if threshold > num_shares {
-        return Err(HarpoError::InvalidParameter(
-            "The threshold must not exceed the number of secret-shared seed phrases.".to_string(),
-        ));
-    }
-    // Make sure that the threshold at least 1.
-    if threshold < 1 {
-        return Err(HarpoError::InvalidParameter(
-            "The threshold must be at least 1.".to_string(),
-        ));
-    }
-    // Embedding is only possible if there are at most `MAX_EMBEDDED_SHARES` shares.
-    if (num_shares > MAX_EMBEDDED_SHARES) && embed_indices {
-        return Err(HarpoError::InvalidParameter(format!(
-            "Only {} secret-shared pass phrases can be created with embedded indices.\n\
-            Use a smaller number of shares or turn off index embedding ('--no-embedding').",
-            MAX_EMBEDDED_SHARES
-        )));
-    }
-    // Make sure that the seed phrase is BIP-0039-compliant.
-    if !is_compliant(seed_phrase, word_list) {
-        return Err(HarpoError::InvalidSeedPhrase(
-            "The seed phrase is not BIP-0039-compliant.".to_string(),
-        ));
-    }
-    // Turn the seed_phrase into a finite field element.
-    let secret = get_element_for_seed_phrase(seed_phrase, word_list)?;
-    // The degree is 1 lower than the threshold.
-    let degree = threshold - 1;
-    // Get the number of bits of security.
-    let num_bits = seed_phrase.get_num_bits();
-    // Create a secret polynomial.
-    match SecretPolynomial::new(&secret, num_bits, degree) {
-        Some(polynomial) => {
-            // Create the secret shares for the finite field element.
-            let secret_shares = polynomial.get_secret_shares(num_shares as u32);
-            // Turn the secret shares into seed phrases and return them.
-            let mut seed_phrases = vec![];
-            for share in secret_shares {
-                let element = get_seed_phrase_for_element_with_embedding(
-                    &share.element,
-                    Some(share.index),
-                    embed_indices,
-                    word_list,
-                )?;
-                seed_phrases.push(element);
-            }
-            Ok(seed_phrases)
-        }
-        None => Err(HarpoError::InvalidParameter(
-            "Could not instantiate the required secret polynomial.".to_string(),
-        )),
-    }
+    let word_list = word_list.get_words();
+    let index_encoding = if embed_indices {
+        IndexEncoding::Embedded
+    } else {
+        IndexEncoding::Explicit
+    };
+    create_secret_shared_seed_phrases_with_index_encoding_for_word_list(
+        seed_phrase,
+        threshold,
+        num_shares,
+        index_encoding,
+        word_list,
+    )
 }
 
-/// The function is called to reconstruct a seed phrase.
+/// The function is called to create secret-shared seed phrases with the given index encoding.
 ///
-/// Given a list of secret-shared seed phrases, the function
-/// reconstructs the seed phrase that was originally used to generate the given seed phrases.
+/// Given a seed phrase, threshold, total number of secret-shared seed phrases, and an index
+/// encoding, the function returns a vector of seed phrases.
+/// The vector size corresponds to the specified total number of seed phrases.
+/// The index encoding determines how the index of each seed phrase is represented,
+/// see [IndexEncoding](crate::seed_phrase::IndexEncoding).
 ///
-/// * `seed_phrases` - The input seed phrases.
-pub fn reconstruct_seed_phrase(seed_phrases: &[SeedPhrase]) -> SeedPhraseResult {
-    // Reconstruct the seed phrase using the default word list.
-    reconstruct_seed_phrase_for_word_list(seed_phrases, DEFAULT_WORD_LIST)
+/// * `seed_phrase` - The input seed phrase.
+/// * `threshold` - The threshold.
+/// * `num_seed_phrases` - The number of seed phrases.
+/// * `index_encoding` - The encoding of the seed phrase indices.
+pub fn create_secret_shared_seed_phrases_with_index_encoding(
+    seed_phrase: &SeedPhrase,
+    threshold: usize,
+    num_seed_phrases: usize,
+    index_encoding: IndexEncoding,
+) -> HarpoResult<Vec<SeedPhrase>> {
+    // Create the seed phrases using the default word list.
+    create_secret_shared_seed_phrases_with_index_encoding_for_word_list(
+        seed_phrase,
+        threshold,
+        num_seed_phrases,
+        index_encoding,
+        DEFAULT_WORD_LIST,
+    )
 }
 
-/// The function is called to reconstruct a seed phrase.
+/// The function is called to create secret-shared seed phrases with the given index encoding.
 ///
-/// Given a list of secret-shared seed phrases and a list of permissible words, the function
-/// reconstructs the seed phrase that was originally used to generate the given seed phrases.
+/// Given a seed phrase, threshold, total number of secret-shared seed phrases, an index
+/// encoding, and a word list, the function returns a vector of seed phrases.
+/// The vector size corresponds to the specified total number of seed phrases.
+/// The index encoding determines how the index of each seed phrase is represented,
+/// see [IndexEncoding](crate::seed_phrase::IndexEncoding).
 ///
-/// * `seed_phrases` - The input seed phrases.
+/// * `seed_phrase` - The input seed phrase.
+/// * `threshold` - The threshold.
+/// * `num_seed_phrases` - The number of seed phrases.
+/// * `index_encoding` - The encoding of the seed phrase indices.
 /// * `word_list` - The word list for the seed phrases.
-pub fn reconstruct_seed_phrase_for_word_list(
-    seed_phrases: &[SeedPhrase],
-    word_list: &[&str],
-) -> SeedPhraseResult {
-    // Validate the word list.
-    validate_word_list(word_list)?;
-    // Ensure that all seed phrases have the same length and that the length is valid.
-    if seed_phrases.is_empty() {
-        return Err(HarpoError::InvalidSeedPhrase(
-            "No seed phrases provided.".to_string(),
-        ));
-    }
-    let num_words = seed_phrases[0].len();
-    if !(12..=24).contains(&num_words) || num_words % 3 != 0 {
-        return Err(HarpoError::InvalidSeedPhrase(
-            "Invalid number of words.".to_string(),
-        ));
-    }
-    if seed_phrases.iter().any(|code| code.len() != num_words) {
-        return Err(HarpoError::InvalidSeedPhrase(
-            "Found seed phrases with different lengths.".to_string(),
-        ));
-    }
-    // Ensure that the seed phrases are BIP-0039-compliant if there is no embedding.
-    for seed_phrase in seed_phrases {
-        if seed_phrase.get_index().is_some() && !is_compliant(seed_phrase, word_list) {
-            return Err(HarpoError::InvalidSeedPhrase(format!(
-                "Seed phrase is not BIP-0039-compliant: {}",
-                seed_phrase
-            )));
-        }
-    }
-    // Get the corresponding secret shares.
-    let mut secret_shares_map = HashMap::new();
-    for seed_phrase in seed_phrases {
-        let (element, index) = get_element_and_index_for_seed_phrase(seed_phrase, word_list)?;
-        // If there are multiple entries for the same index, keep the last one.
-        secret_shares_map.insert(index, SecretShare::new(&element, index));
-    }
-    let secret_shares: Vec<SecretShare> = secret_shares_map.into_values().collect();
-    // Reconstruct the secret element and turn it into a seed phrase.
-    let secret_element = reconstruct_secret(&secret_shares);
-    get_seed_phrase_for_element(&secret_element, word_list)
+pub fn create_secret_shared_seed_phrases_with_index_encoding_for_word_list(
+    seed_phrase: &SeedPhrase,
+    threshold: usize,
+    num_shares: usize,
+    index_encoding: IndexEncoding,
+    word_list: &(impl WordList + ?Sized),
+) -> HarpoResult<Vec<SeedPhrase>> {
+    let word_list = word_list.get_words();
+    create_secret_shared_seed_phrases_with_progress_for_word_list(
+        seed_phrase,
+        threshold,
+        num_shares,
+        index_encoding,
+        word_list,
+        &mut |_, _| {},
+    )
 }
 
-/// The function generates and returns a random seed phrase.
+/// The function is called to create secret-shared seed phrases while reporting the progress.
 ///
-/// A random, BIP-0039-compliant seed phrase is returned if the requested number of words is
-/// valid.
+/// The function behaves like
+/// [create_secret_shared_seed_phrases_with_index_encoding](crate::create_secret_shared_seed_phrases_with_index_encoding)
+/// and additionally reports the number of created secret-shared seed phrases to the provided
+/// [Progress](crate::Progress) callback after each secret-shared seed phrase.
 ///
-/// * `num_words` - The number of words in the seed phrase.
-/// * `word_list` - The word list to be used.
-pub fn generate_seed_phrase_for_word_list(
-    num_words: usize,
-    word_list: &[&str],
-) -> SeedPhraseResult {
-    // Validate the word list.
-    validate_word_list(word_list)?;
-    get_random_seed_phrase(num_words, word_list)
+/// * `seed_phrase` - The input seed phrase.
+/// * `threshold` - The threshold.
+/// * `num_seed_phrases` - The number of seed phrases.
+/// * `index_encoding` - The encoding of the seed phrase indices.
+/// * `progress` - The progress callback.
+pub fn create_secret_shared_seed_phrases_with_progress(
+    seed_phrase: &SeedPhrase,
+    threshold: usize,
+    num_seed_phrases: usize,
+    index_encoding: IndexEncoding,
+    progress: &mut dyn Progress,
+) -> HarpoResult<Vec<SeedPhrase>> {
+    // Create the seed phrases using the default word list.
+    create_secret_shared_seed_phrases_with_progress_for_word_list(
+        seed_phrase,
+        threshold,
+        num_seed_phrases,
+        index_encoding,
+        DEFAULT_WORD_LIST,
+        progress,
+    )
 }
 
-/// The function generates and returns a random seed phrase.
+/// The function is called to create secret-shared seed phrases for the given word list while
+/// reporting the progress.
 ///
-/// A random, BIP-0039-compliant seed phrase is returned if the requested number of words is
-/// valid.
+/// The function behaves like
+/// [create_secret_shared_seed_phrases_with_index_encoding_for_word_list](crate::create_secret_shared_seed_phrases_with_index_encoding_for_word_list)
+/// and additionally reports the number of created secret-shared seed phrases to the provided
+/// [Progress](crate::Progress) callback after each secret-shared seed phrase.
 ///
-/// * `num_words` - The number of words in the seed phrase.
-pub fn generate_seed_phrase(num_words: usize) -> SeedPhraseResult {
-    generate_seed_phrase_for_word_list(num_words, DEFAULT_WORD_LIST)
+/// * `seed_phrase` - The input seed phrase.
+/// * `threshold` - The threshold.
+/// * `num_seed_phrases` - The number of seed phrases.
+/// * `index_encoding` - The encoding of the seed phrase indices.
+/// * `word_list` - The word list for the seed phrases.
+/// * `progress` - The progress callback.
+pub fn create_secret_shared_seed_phrases_with_progress_for_word_list(
+    seed_phrase: &SeedPhrase,
+    threshold: usize,
+    num_shares: usize,
+    index_encoding: IndexEncoding,
+    word_list: &(impl WordList + ?Sized),
+    progress: &mut dyn Progress,
+) -> HarpoResult<Vec<SeedPhrase>> {
+    let word_list = word_list.get_words();
+    create_secret_shared_seed_phrases_for_seed_phrase(
+        seed_phrase,
+        threshold,
+        num_shares,
+        index_encoding,
+        word_list,
+        &mut OsRng,
+        progress,
+    )
 }
 
-/// The function validates a given seed phrase using the standard word list.
+/// The function is called to create secret-shared seed phrases using the given random number
+/// generator.
 ///
-/// The function checks BIP-0039 compliance for the given seed phrase.
+/// The function behaves like
+/// [create_secret_shared_seed_phrases_with_index_encoding](crate::create_secret_shared_seed_phrases_with_index_encoding)
+/// but draws the random coefficients of the secret polynomial from the given cryptographically
+/// secure random number generator instead of the operating system's random number generator.
 ///
-/// * `seed_phrase` - The given seed phrase.
-pub fn validate_seed_phrase(seed_phrase: &SeedPhrase) -> HarpoResult<()> {
-    validate_seed_phrase_for_word_list(seed_phrase, DEFAULT_WORD_LIST)
+/// * `seed_phrase` - The input seed phrase.
+/// * `threshold` - The threshold.
+/// * `num_shares` - The number of secret-shared seed phrases.
+/// * `index_encoding` - The encoding of the seed phrase indices.
+/// * `rng` - The cryptographically secure random number generator.
+pub fn create_secret_shared_seed_phrases_with_rng<R: RngCore + CryptoRng>(
+    seed_phrase: &SeedPhrase,
+    threshold: usize,
+    num_shares: usize,
+    index_encoding: IndexEncoding,
+    rng: &mut R,
+) -> HarpoResult<Vec<SeedPhrase>> {
+    create_secret_shared_seed_phrases_with_rng_for_word_list(
+        seed_phrase,
+        threshold,
+        num_shares,
+        index_encoding,
+        DEFAULT_WORD_LIST,
+        rng,
+    )
+}
+
+/// The function is called to create secret-shared seed phrases for the given word list using
+/// the given random number generator.
+///
+/// The function behaves like
+/// [create_secret_shared_seed_phrases_with_index_encoding_for_word_list](crate::create_secret_shared_seed_phrases_with_index_encoding_for_word_list)
+/// but draws the random coefficients of the secret polynomial from the given cryptographically
+/// secure random number generator instead of the operating system's random number generator.
+///
+/// * `seed_phrase` - The input seed phrase.
+/// * `threshold` - The threshold.
+/// * `num_shares` - The number of secret-shared seed phrases.
+/// * `index_encoding` - The encoding of the seed phrase indices.
+/// * `word_list` - The word list for the seed phrases.
+/// * `rng` - The cryptographically secure random number generator.
+pub fn create_secret_shared_seed_phrases_with_rng_for_word_list<R: RngCore + CryptoRng>(
+    seed_phrase: &SeedPhrase,
+    threshold: usize,
+    num_shares: usize,
+    index_encoding: IndexEncoding,
+    word_list: &(impl WordList + ?Sized),
+    rng: &mut R,
+) -> HarpoResult<Vec<SeedPhrase>> {
+    let word_list = word_list.get_words();
+    create_secret_shared_seed_phrases_for_seed_phrase(
+        seed_phrase,
+        threshold,
+        num_shares,
+        index_encoding,
+        word_list,
+        rng,
+        &mut |_, _| {},
+    )
+}
+
+/// The function is called to create secret-shared seed phrases whose polynomial coefficients
+/// are derived from the seed phrase and a salt.
+///
+/// The function behaves like
+/// [create_secret_shared_seed_phrases_with_index_encoding](crate::create_secret_shared_seed_phrases_with_index_encoding)
+/// but derives the coefficients of the secret polynomial deterministically from the seed phrase
+/// and the provided salt instead of drawing them at random. Calling the function again with the
+/// same seed phrase, salt, threshold, and index encoding results in the same secret-shared seed
+/// phrases, which makes it possible to re-create a damaged secret-shared seed phrase without
+/// having stored any of them. The reconstruction is unchanged.
+///
+/// * `seed_phrase` - The input seed phrase.
+/// * `threshold` - The threshold.
+/// * `num_shares` - The number of secret-shared seed phrases.
+/// * `index_encoding` - The encoding of the seed phrase indices.
+/// * `salt` - The salt from which the coefficients are derived together with the seed phrase.
+pub fn create_secret_shared_seed_phrases_with_salt(
+    seed_phrase: &SeedPhrase,
+    threshold: usize,
+    num_shares: usize,
+    index_encoding: IndexEncoding,
+    salt: &[u8],
+) -> HarpoResult<Vec<SeedPhrase>> {
+    create_secret_shared_seed_phrases_with_salt_for_word_list(
+        seed_phrase,
+        threshold,
+        num_shares,
+        index_encoding,
+        salt,
+        DEFAULT_WORD_LIST,
+    )
+}
+
+/// The function is called to create secret-shared seed phrases for the given word list whose
+/// polynomial coefficients are derived from the seed phrase and a salt.
+///
+/// The function behaves like
+/// [create_secret_shared_seed_phrases_with_index_encoding_for_word_list](crate::create_secret_shared_seed_phrases_with_index_encoding_for_word_list)
+/// but derives the coefficients of the secret polynomial deterministically from the seed phrase
+/// and the provided salt, see
+/// [create_secret_shared_seed_phrases_with_salt](crate::create_secret_shared_seed_phrases_with_salt).
+///
+/// * `seed_phrase` - The input seed phrase.
+/// * `threshold` - The threshold.
+/// * `num_shares` - The number of secret-shared seed phrases.
+/// * `index_encoding` - The encoding of the seed phrase indices.
+/// * `salt` - The salt from which the coefficients are derived together with the seed phrase.
+/// * `word_list` - The word list for the seed phrases.
+pub fn create_secret_shared_seed_phrases_with_salt_for_word_list(
+    seed_phrase: &SeedPhrase,
+    threshold: usize,
+    num_shares: usize,
+    index_encoding: IndexEncoding,
+    salt: &[u8],
+    word_list: &(impl WordList + ?Sized),
+) -> HarpoResult<Vec<SeedPhrase>> {
+    let word_list = word_list.get_words();
+    // Validate the word list.
+    validate_word_list(word_list)?;
+    check_create_parameters(seed_phrase.len(), threshold, num_shares, index_encoding)?;
+    // Make sure that the seed phrase is BIP-0039-compliant.
+    check_compliance(seed_phrase, word_list)?;
+    let secret = get_element_for_seed_phrase(seed_phrase, word_list)?;
+    // The degree is 1 lower than the threshold.
+    match SecretPolynomial::new_derived(&secret, seed_phrase.get_num_bits(), threshold - 1, salt) {
+        Some(polynomial) => create_secret_shared_seed_phrases_for_polynomial(
+            &polynomial,
+            num_shares,
+            index_encoding,
+            word_list,
+            &mut |_, _| {},
+        ),
+        None => Err(HarpoError::InvalidParameter(
+            "Could not instantiate the required secret polynomial.".to_string(),
+        )),
+    }
+}
+
+/// The function creates secret-shared seed phrases for the given seed phrase.
+///
+/// * `seed_phrase` - The input seed phrase.
+/// * `threshold` - The threshold.
+/// * `num_shares` - The number of secret-shared seed phrases.
+/// * `index_encoding` - The encoding of the seed phrase indices.
+/// * `word_list` - The word list for the seed phrases.
+/// * `rng` - The cryptographically secure random number generator.
+/// * `progress` - The progress callback.
+fn create_secret_shared_seed_phrases_for_seed_phrase<R: RngCore + CryptoRng>(
+    seed_phrase: &SeedPhrase,
+    threshold: usize,
+    num_shares: usize,
+    index_encoding: IndexEncoding,
+    word_list: &[&str],
+    rng: &mut R,
+    progress: &mut dyn Progress,
+) -> HarpoResult<Vec<SeedPhrase>> {
+    // Validate the word list.
+    validate_word_list(word_list)?;
+    check_create_parameters(seed_phrase.len(), threshold, num_shares, index_encoding)?;
+    // Make sure that the seed phrase is BIP-0039-compliant.
+    check_compliance(seed_phrase, word_list)?;
+    // Turn the seed_phrase into a finite field element.
+    let secret = get_element_for_seed_phrase(seed_phrase, word_list)?;
+    create_secret_shared_seed_phrases_for_element(
+        &secret,
+        seed_phrase.get_num_bits(),
+        threshold,
+        num_shares,
+        index_encoding,
+        word_list,
+        rng,
+        progress,
+    )
+}
+
+/// The function returns an iterator over secret-shared seed phrases.
+///
+/// The function behaves like
+/// [create_secret_shared_seed_phrases_with_index_encoding](crate::create_secret_shared_seed_phrases_with_index_encoding)
+/// but creates each secret-shared seed phrase only when it is requested. This makes it possible
+/// to stream a large number of secret-shared seed phrases without holding all of them in
+/// memory. The parameters are checked before the iterator is returned.
+///
+/// * `seed_phrase` - The input seed phrase.
+/// * `threshold` - The threshold.
+/// * `num_shares` - The number of secret-shared seed phrases.
+/// * `index_encoding` - The encoding of the seed phrase indices.
+pub fn iter_secret_shared_seed_phrases(
+    seed_phrase: &SeedPhrase,
+    threshold: usize,
+    num_shares: usize,
+    index_encoding: IndexEncoding,
+) -> HarpoResult<impl Iterator<Item = SeedPhraseResult>> {
+    iter_secret_shared_seed_phrases_for_word_list(
+        seed_phrase,
+        threshold,
+        num_shares,
+        index_encoding,
+        DEFAULT_WORD_LIST,
+    )
+}
+
+/// The function returns an iterator over secret-shared seed phrases using the given word list.
+///
+/// The function behaves like
+/// [create_secret_shared_seed_phrases_with_index_encoding_for_word_list](crate::create_secret_shared_seed_phrases_with_index_encoding_for_word_list)
+/// but creates each secret-shared seed phrase only when it is requested. This makes it possible
+/// to stream a large number of secret-shared seed phrases without holding all of them in
+/// memory. The parameters are checked before the iterator is returned.
+///
+/// * `seed_phrase` - The input seed phrase.
+/// * `threshold` - The threshold.
+/// * `num_shares` - The number of secret-shared seed phrases.
+/// * `index_encoding` - The encoding of the seed phrase indices.
+/// * `word_list` - The word list for the seed phrases.
+pub fn iter_secret_shared_seed_phrases_for_word_list<'a>(
+    seed_phrase: &SeedPhrase,
+    threshold: usize,
+    num_shares: usize,
+    index_encoding: IndexEncoding,
+    word_list: &'a (impl WordList + ?Sized),
+) -> HarpoResult<impl Iterator<Item = SeedPhraseResult> + 'a> {
+    let word_list = word_list.get_words();
+    validate_word_list(word_list)?;
+    check_create_parameters(seed_phrase.len(), threshold, num_shares, index_encoding)?;
+    // Make sure that the seed phrase is BIP-0039-compliant.
+    check_compliance(seed_phrase, word_list)?;
+    let secret = get_element_for_seed_phrase(seed_phrase, word_list)?;
+    // The degree is 1 lower than the threshold.
+    let polynomial = SecretPolynomial::new(&secret, seed_phrase.get_num_bits(), threshold - 1)
+        .ok_or_else(|| {
+            HarpoError::InvalidParameter(
+                "Could not instantiate the required secret polynomial.".to_string(),
+            )
+        })?;
+    // The secret shares correspond to the polynomial points `f(1), f(2), ..., f(num_shares)`.
+    Ok((1..=num_shares as u32).map(move |index| {
+        let secret_shares = polynomial.get_secret_shares_for_indices(&[index]);
+        get_seed_phrase_for_share(&secret_shares[0], &polynomial, index_encoding, word_list)
+    }))
+}
+
+/// The function is called to create secret-shared seed phrases for the given entropy.
+///
+/// The function behaves like
+/// [create_secret_shared_seed_phrases](crate::create_secret_shared_seed_phrases) for the
+/// seed phrase that encodes the entropy, see
+/// [SeedPhrase::from_entropy](crate::seed_phrase::SeedPhrase::from_entropy), without creating
+/// this seed phrase first. The reconstructed seed phrase encodes the given entropy.
+///
+/// * `entropy` - The entropy, which must consist of 16, 20, 24, 28, or 32 bytes.
+/// * `threshold` - The threshold.
+/// * `num_shares` - The number of secret-shared seed phrases.
+/// * `embed_indices` - Flag indicating whether seed phrase indices should be embedded.
+pub fn create_secret_shared_seed_phrases_from_entropy(
+    entropy: &[u8],
+    threshold: usize,
+    num_shares: usize,
+    embed_indices: bool,
+) -> HarpoResult<Vec<SeedPhrase>> {
+    create_secret_shared_seed_phrases_from_entropy_for_word_list(
+        entropy,
+        threshold,
+        num_shares,
+        embed_indices,
+        DEFAULT_WORD_LIST,
+    )
+}
+
+/// The function is called to create secret-shared seed phrases for the given entropy using
+/// the given word list.
+///
+/// The function behaves like
+/// [create_secret_shared_seed_phrases_for_word_list](crate::create_secret_shared_seed_phrases_for_word_list)
+/// for the seed phrase that encodes the entropy, see
+/// [SeedPhrase::from_entropy_for_word_list](crate::seed_phrase::SeedPhrase::from_entropy_for_word_list),
+/// without creating this seed phrase first. The reconstructed seed phrase encodes the given
+/// entropy.
+///
+/// * `entropy` - The entropy, which must consist of 16, 20, 24, 28, or 32 bytes.
+/// * `threshold` - The threshold.
+/// * `num_shares` - The number of secret-shared seed phrases.
+/// * `embed_indices` - Flag indicating whether seed phrase indices should be embedded.
+/// * `word_list` - The word list for the seed phrases.
+pub fn create_secret_shared_seed_phrases_from_entropy_for_word_list(
+    entropy: &[u8],
+    threshold: usize,
+    num_shares: usize,
+    embed_indices: bool,
+    word_list: &(impl WordList + ?Sized),
+) -> HarpoResult<Vec<SeedPhrase>> {
+    let word_list = word_list.get_words();
+    validate_word_list(word_list)?;
+    // Every 4 bytes of entropy are encoded using 3 words.
+    let num_words = entropy.len() * 3 / 4;
+    let modulus = match get_modulus_for_words(num_words) {
+        Some(modulus) if entropy.len().is_multiple_of(4) => modulus,
+        _ => {
+            return Err(HarpoError::InvalidParameter(format!(
+                "The entropy length of {} bytes is not supported.",
+                entropy.len()
+            )))
+        }
+    };
+    let index_encoding = if embed_indices {
+        IndexEncoding::Embedded
+    } else {
+        IndexEncoding::Explicit
+    };
+    check_create_parameters(num_words, threshold, num_shares, index_encoding)?;
+    // The entropy is interpreted in the same way as the bytes encoded in a seed phrase.
+    let secret = FiniteFieldElement::new(entropy, modulus);
+    create_secret_shared_seed_phrases_for_element(
+        &secret,
+        entropy.len() << 3,
+        threshold,
+        num_shares,
+        index_encoding,
+        word_list,
+        &mut OsRng,
+        &mut |_, _| {},
+    )
+}
+
+/// This struct configures the creation of secret-shared seed phrases.
+///
+/// It is an alternative to the functions with positional parameters, e.g.,
+/// [create_secret_shared_seed_phrases_with_index_encoding_for_word_list](crate::create_secret_shared_seed_phrases_with_index_encoding_for_word_list).
+/// By default, indices are embedded and the default word list is used. The threshold and the
+/// number of secret-shared seed phrases must always be set.
+#[derive(Debug, Clone, Copy)]
+pub struct SplitRequest<'a> {
+    /// The seed phrase that is secret-shared.
+    seed_phrase: &'a SeedPhrase,
+    /// The threshold.
+    threshold: usize,
+    /// The number of secret-shared seed phrases.
+    num_shares: usize,
+    /// The number of corrupted secret-shared seed phrases that can be corrected.
+    num_correctable_errors: usize,
+    /// The encoding of the seed phrase indices.
+    index_encoding: IndexEncoding,
+    /// The standard that the seed phrase complies with.
+    standard: PhraseStandard,
+    /// The word list for the seed phrases.
+    word_list: &'a [&'a str],
+}
+
+impl<'a> SplitRequest<'a> {
+    /// The function creates a request to secret-share the given seed phrase.
+    ///
+    /// * `seed_phrase` - The seed phrase that is secret-shared.
+    pub fn new(seed_phrase: &'a SeedPhrase) -> Self {
+        SplitRequest {
+            seed_phrase,
+            threshold: 0,
+            num_shares: 0,
+            num_correctable_errors: 0,
+            index_encoding: IndexEncoding::Embedded,
+            standard: PhraseStandard::Bip39,
+            word_list: DEFAULT_WORD_LIST,
+        }
+    }
+
+    /// The function sets the threshold.
+    ///
+    /// * `threshold` - The threshold.
+    pub fn threshold(mut self, threshold: usize) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    /// The function sets the number of secret-shared seed phrases.
+    ///
+    /// * `num_shares` - The number of secret-shared seed phrases.
+    pub fn shares(mut self, num_shares: usize) -> Self {
+        self.num_shares = num_shares;
+        self
+    }
+
+    /// The function sets the number of corrupted secret-shared seed phrases that can be corrected
+    /// when all secret-shared seed phrases are provided.
+    ///
+    /// Two additional secret-shared seed phrases are created for each correctable error. Since
+    /// the secret-shared seed phrases form a Reed-Solomon codeword, `n` secret-shared seed
+    /// phrases with threshold `t` tolerate up to `(n - t) / 2` corrupted ones, e.g., due to
+    /// transcription errors on paper backups. Note that the additional secret-shared seed phrases
+    /// are regular secret-shared seed phrases, i.e., any `t` of them reveal the seed phrase.
+    ///
+    /// * `num_errors` - The number of correctable errors.
+    pub fn correctable_errors(mut self, num_errors: usize) -> Self {
+        self.num_correctable_errors = num_errors;
+        self
+    }
+
+    /// The function sets whether indices are embedded or provided explicitly.
+    ///
+    /// * `embed_indices` - Flag indicating whether seed phrase indices should be embedded.
+    pub fn embed_indices(mut self, embed_indices: bool) -> Self {
+        self.index_encoding = if embed_indices {
+            IndexEncoding::Embedded
+        } else {
+            IndexEncoding::Explicit
+        };
+        self
+    }
+
+    /// The function sets the encoding of the seed phrase indices.
+    ///
+    /// * `index_encoding` - The encoding of the seed phrase indices.
+    pub fn index_encoding(mut self, index_encoding: IndexEncoding) -> Self {
+        self.index_encoding = index_encoding;
+        self
+    }
+
+    /// The function sets the standard that the seed phrase complies with.
+    ///
+    /// By default, the seed phrase must be BIP-0039-compliant. Electrum seed phrases are
+    /// secret-shared as the entropy of a longer BIP-0039 seed phrase and must be reconstructed
+    /// using [reconstruct_seed_phrase_for_standard](crate::reconstruct_seed_phrase_for_standard).
+    ///
+    /// * `standard` - The standard of the seed phrase.
+    pub fn standard(mut self, standard: PhraseStandard) -> Self {
+        self.standard = standard;
+        self
+    }
+
+    /// The function sets the word list for the seed phrases.
+    ///
+    /// * `word_list` - The word list for the seed phrases.
+    pub fn word_list(mut self, word_list: &'a (impl WordList + ?Sized)) -> Self {
+        self.word_list = word_list.get_words();
+        self
+    }
+
+    /// The function creates the secret-shared seed phrases.
+    pub fn create(&self) -> HarpoResult<Vec<SeedPhrase>> {
+        self.create_with_progress(&mut |_, _| {})
+    }
+
+    /// The function creates the secret-shared seed phrases while reporting the progress to the
+    /// provided [Progress](crate::Progress) callback.
+    ///
+    /// * `progress` - The progress callback.
+    pub fn create_with_progress(
+        &self,
+        progress: &mut dyn Progress,
+    ) -> HarpoResult<Vec<SeedPhrase>> {
+        let carrier_seed_phrase;
+        let seed_phrase = match self.standard {
+            PhraseStandard::Bip39 => self.seed_phrase,
+            PhraseStandard::Electrum => {
+                carrier_seed_phrase = get_carrier_seed_phrase(self.seed_phrase, self.word_list)?;
+                &carrier_seed_phrase
+            }
+        };
+        create_secret_shared_seed_phrases_with_progress_for_word_list(
+            seed_phrase,
+            self.threshold,
+            self.get_total_num_shares(),
+            self.index_encoding,
+            self.word_list,
+            progress,
+        )
+    }
+
+    /// The function creates the secret-shared seed phrases and returns them together with the
+    /// creation parameters and the polynomial commitment, see [ShareSet](crate::ShareSet).
+    pub fn create_share_set(&self) -> HarpoResult<ShareSet> {
+        let shares = self.create()?;
+        get_share_set_for_shares(shares, self.threshold, self.index_encoding, self.word_list)
+    }
+
+    /// The function returns the number of secret-shared seed phrases including the additional
+    /// ones for error correction.
+    fn get_total_num_shares(&self) -> usize {
+        self.num_shares
+            .saturating_add(self.num_correctable_errors.saturating_mul(2))
+    }
+}
+
+/// This struct holds secret-shared seed phrases together with the parameters used to create
+/// them.
+///
+/// The parameters are not secret and should be stored alongside the secret-shared seed phrases
+/// so that it is known later how many secret-shared seed phrases are required. The polynomial
+/// commitment can be used to verify the reconstruction, see
+/// [verify_polynomial_commitment](crate::verify_polynomial_commitment). The
+/// [VerificationData](crate::verification::VerificationData) combines the parameters and
+/// commitments in a form that can be stored publicly.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ShareSet {
+    /// The threshold.
+    threshold: usize,
+    /// The number of secret-shared seed phrases.
+    num_shares: usize,
+    /// The number of words of the seed phrase that was secret-shared.
+    num_words: usize,
+    /// The encoding of the seed phrase indices.
+    index_encoding: IndexEncoding,
+    /// The version of the share format.
+    share_format_version: u32,
+    /// The commitment to the polynomial underlying the secret-shared seed phrases.
+    commitment: PolynomialCommitment,
+    /// The public data to verify the secret-shared seed phrases.
+    verification_data: VerificationData,
+    /// The secret-shared seed phrases.
+    shares: Vec<SeedPhrase>,
+}
+
+impl ShareSet {
+    /// The function returns the threshold.
+    pub fn get_threshold(&self) -> usize {
+        self.threshold
+    }
+
+    /// The function returns the number of secret-shared seed phrases.
+    pub fn get_num_shares(&self) -> usize {
+        self.num_shares
+    }
+
+    /// The function returns the number of words of the seed phrase that was secret-shared.
+    pub fn get_num_words(&self) -> usize {
+        self.num_words
+    }
+
+    /// The function returns the encoding of the seed phrase indices.
+    pub fn get_index_encoding(&self) -> IndexEncoding {
+        self.index_encoding
+    }
+
+    /// The function returns the version of the share format.
+    pub fn get_share_format_version(&self) -> u32 {
+        self.share_format_version
+    }
+
+    /// The function returns the commitment to the polynomial underlying the secret-shared seed
+    /// phrases.
+    pub fn get_commitment(&self) -> PolynomialCommitment {
+        self.commitment
+    }
+
+    /// The function returns the public data to verify the secret-shared seed phrases.
+    pub fn get_verification_data(&self) -> &VerificationData {
+        &self.verification_data
+    }
+
+    /// The function returns the secret-shared seed phrases.
+    pub fn get_shares(&self) -> &[SeedPhrase] {
+        &self.shares
+    }
+
+    /// The function returns the secret-shared seed phrases, consuming the share set.
+    pub fn into_shares(self) -> Vec<SeedPhrase> {
+        self.shares
+    }
+}
+
+/// The function is called to create a set of secret-shared seed phrases.
+///
+/// The function behaves like
+/// [create_secret_shared_seed_phrases](crate::create_secret_shared_seed_phrases) but returns a
+/// [ShareSet](crate::ShareSet), which additionally contains the creation parameters and the
+/// polynomial commitment.
+///
+/// * `seed_phrase` - The input seed phrase.
+/// * `threshold` - The threshold.
+/// * `num_shares` - The number of secret-shared seed phrases.
+/// * `embed_indices` - Flag indicating whether seed phrase indices should be embedded.
+pub fn create_share_set(
+    seed_phrase: &SeedPhrase,
+    threshold: usize,
+    num_shares: usize,
+    embed_indices: bool,
+) -> HarpoResult<ShareSet> {
+    SplitRequest::new(seed_phrase)
+        .threshold(threshold)
+        .shares(num_shares)
+        .embed_indices(embed_indices)
+        .create_share_set()
+}
+
+/// The function is called to create a set of secret-shared seed phrases using the given word
+/// list.
+///
+/// The function behaves like
+/// [create_secret_shared_seed_phrases_for_word_list](crate::create_secret_shared_seed_phrases_for_word_list)
+/// but returns a [ShareSet](crate::ShareSet), which additionally contains the creation
+/// parameters and the polynomial commitment.
+///
+/// * `seed_phrase` - The input seed phrase.
+/// * `threshold` - The threshold.
+/// * `num_shares` - The number of secret-shared seed phrases.
+/// * `embed_indices` - Flag indicating whether seed phrase indices should be embedded.
+/// * `word_list` - The word list for the seed phrases.
+pub fn create_share_set_for_word_list(
+    seed_phrase: &SeedPhrase,
+    threshold: usize,
+    num_shares: usize,
+    embed_indices: bool,
+    word_list: &(impl WordList + ?Sized),
+) -> HarpoResult<ShareSet> {
+    let word_list = word_list.get_words();
+    SplitRequest::new(seed_phrase)
+        .threshold(threshold)
+        .shares(num_shares)
+        .embed_indices(embed_indices)
+        .word_list(word_list)
+        .create_share_set()
+}
+
+/// The function returns the share set for newly created secret-shared seed phrases.
+///
+/// * `shares` - The secret-shared seed phrases in the order of their indices.
+/// * `threshold` - The threshold.
+/// * `index_encoding` - The encoding of the seed phrase indices.
+/// * `word_list` - The word list for the seed phrases.
+fn get_share_set_for_shares(
+    shares: Vec<SeedPhrase>,
+    threshold: usize,
+    index_encoding: IndexEncoding,
+    word_list: &[&str],
+) -> HarpoResult<ShareSet> {
+    let verification_data =
+        get_verification_data_for_word_list(&shares, threshold, index_encoding, word_list)?;
+    Ok(ShareSet {
+        threshold,
+        num_shares: shares.len(),
+        num_words: verification_data.get_num_words(),
+        index_encoding,
+        share_format_version: SHARE_FORMAT_VERSION,
+        commitment: verification_data.get_commitment(),
+        verification_data,
+        shares,
+    })
 }
 
-/// The function validates a given seed phrase.
-///
-/// The function checks BIP-0039 compliance for the given seed phrase.
-///
-/// * `seed_phrase` - The given seed phrase.
-/// * `word_list` - The word list to be used.
-pub fn validate_seed_phrase_for_word_list(
-    seed_phrase: &SeedPhrase,
-    word_list: &[&str],
-) -> HarpoResult<()> {
-    if is_compliant(seed_phrase, word_list) {
-        Ok(())
-    } else {
-        Err(HarpoError::InvalidSeedPhrase(
-            "The seed phrase is not BIP-0039-compliant.".to_string(),
-        ))
+/// The function checks the parameters for the creation of secret-shared seed phrases.
+///
+/// * `num_words` - The number of words of the seed phrase that is secret-shared.
+/// * `threshold` - The threshold.
+/// * `num_shares` - The number of secret-shared seed phrases.
+/// * `index_encoding` - The encoding of the seed phrase indices.
+fn check_create_parameters(
+    num_words: usize,
+    threshold: usize,
+    num_shares: usize,
+    index_encoding: IndexEncoding,
+) -> HarpoResult<()> {
+    // Make sure that the threshold is not greater than the number of shares.
+    if threshold > num_shares {
+        return Err(HarpoError::InvalidParameter(
+            "The threshold must not exceed the number of secret-shared seed phrases.".to_string(),
+        ));
+    }
+    // Make sure that the threshold at least 1.
+    if threshold < 1 {
+        return Err(HarpoError::InvalidParameter(
+            "The threshold must be at least 1.".to_string(),
+        ));
+    }
+    let embed_indices = index_encoding == IndexEncoding::Embedded;
+    // Embedding is only possible if there are at most `MAX_EMBEDDED_SHARES` shares.
+    if (num_shares > MAX_EMBEDDED_SHARES) && embed_indices {
+        return Err(HarpoError::InvalidParameter(format!(
+            "Only {} secret-shared pass phrases can be created with embedded indices.\n\
+            Use a smaller number of shares or turn off index embedding ('--no-embedding').",
+            MAX_EMBEDDED_SHARES
+        )));
+    }
+    // Index words can encode at most `MAX_INDEX_WORD_SHARES` indices.
+    if (num_shares > MAX_INDEX_WORD_SHARES) && index_encoding == IndexEncoding::IndexWord {
+        return Err(HarpoError::InvalidParameter(format!(
+            "Only {} secret-shared pass phrases can be created with index words.\n\
+            Use a smaller number of shares or turn off index embedding ('--no-embedding').",
+            MAX_INDEX_WORD_SHARES
+        )));
+    }
+    // Embedding is not possible for the (insecure) demo seed phrases.
+    if embed_indices && !can_embed_index(num_words) {
+        return Err(HarpoError::InvalidParameter(
+            "Indices cannot be embedded in seed phrases with fewer than 12 words.\n\
+            Turn off index embedding ('--no-embedding')."
+                .to_string(),
+        ));
+    }
+    // The indices must be distinct non-zero field elements that fit into 32 bits.
+    if let Some(max_num_shares) = get_max_num_shares(num_words, index_encoding) {
+        if num_shares > max_num_shares {
+            return Err(HarpoError::InvalidParameter(format!(
+                "At most {} secret-shared seed phrases can be created for a seed phrase with \
+                {} words because the indices must be distinct non-zero finite field elements.",
+                max_num_shares, num_words
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// The function creates secret-shared seed phrases for the given secret.
+///
+/// The parameters must have been checked using `check_create_parameters`.
+///
+/// * `secret` - The secret.
+/// * `num_bits` - The security level in bits.
+/// * `threshold` - The threshold.
+/// * `num_shares` - The number of secret-shared seed phrases.
+/// * `index_encoding` - The encoding of the seed phrase indices.
+/// * `word_list` - The word list for the seed phrases.
+/// * `rng` - The cryptographically secure random number generator.
+/// * `progress` - The progress callback.
+#[allow(clippy::too_many_arguments)]
+fn create_secret_shared_seed_phrases_for_element<R: RngCore + CryptoRng>(
+    secret: &FiniteFieldElement,
+    num_bits: usize,
+    threshold: usize,
+    num_shares: usize,
+    index_encoding: IndexEncoding,
+    word_list: &[&str],
+    rng: &mut R,
+    progress: &mut dyn Progress,
+) -> HarpoResult<Vec<SeedPhrase>> {
+    trace_span!("split");
+    trace_event!(
+        "split.parameters",
+        "threshold" => threshold,
+        "num_shares" => num_shares,
+        "num_bits" => num_bits,
+    );
+    // The degree is 1 lower than the threshold.
+    let degree = threshold - 1;
+    // Create a secret polynomial.
+    match SecretPolynomial::new_with_rng(secret, num_bits, degree, rng) {
+        Some(polynomial) => create_secret_shared_seed_phrases_for_polynomial(
+            &polynomial,
+            num_shares,
+            index_encoding,
+            word_list,
+            progress,
+        ),
+        None => Err(HarpoError::InvalidParameter(
+            "Could not instantiate the required secret polynomial.".to_string(),
+        )),
+    }
+}
+
+/// The function creates secret-shared seed phrases for the given secret polynomial.
+///
+/// * `polynomial` - The secret polynomial.
+/// * `num_shares` - The number of secret-shared seed phrases.
+/// * `index_encoding` - The encoding of the seed phrase indices.
+/// * `word_list` - The word list for the seed phrases.
+/// * `progress` - The progress callback.
+fn create_secret_shared_seed_phrases_for_polynomial(
+    polynomial: &SecretPolynomial,
+    num_shares: usize,
+    index_encoding: IndexEncoding,
+    word_list: &[&str],
+    progress: &mut dyn Progress,
+) -> HarpoResult<Vec<SeedPhrase>> {
+    // Create the secret shares for the finite field element.
+    let secret_shares = polynomial.get_secret_shares(num_shares as u32);
+    // Turn the secret shares into seed phrases and return them.
+    let mut seed_phrases = vec![];
+    progress.update(0, num_shares);
+    for share in secret_shares {
+        seed_phrases.push(get_seed_phrase_for_share(
+            &share,
+            polynomial,
+            index_encoding,
+            word_list,
+        )?);
+        progress.update(seed_phrases.len(), num_shares);
+    }
+    Ok(seed_phrases)
+}
+
+/// The function converts the given secret share into a secret-shared seed phrase.
+///
+/// The identifier of the set of secret shares and the threshold of the polynomial are attached
+/// to the secret-shared seed phrase.
+///
+/// * `share` - The secret share.
+/// * `polynomial` - The secret polynomial that the secret share stems from.
+/// * `index_encoding` - The encoding of the index.
+/// * `word_list` - The word list.
+fn get_seed_phrase_for_share(
+    share: &SecretShare,
+    polynomial: &SecretPolynomial,
+    index_encoding: IndexEncoding,
+    word_list: &[&str],
+) -> SeedPhraseResult {
+    let seed_phrase = get_seed_phrase_for_element_with_embedding(
+        &share.element,
+        Some(share.index),
+        index_encoding == IndexEncoding::Embedded,
+        word_list,
+    )?;
+    // The index word is appended to the BIP-0039-compliant seed phrase.
+    let seed_phrase = if index_encoding == IndexEncoding::IndexWord {
+        append_index_word(&seed_phrase, share.index, word_list)?
+    } else {
+        seed_phrase
+    };
+    Ok(seed_phrase
+        .with_set_id(polynomial.get_set_id())
+        .with_threshold(polynomial.get_threshold()))
+}
+
+/// The function is called to reconstruct a seed phrase.
+///
+/// Given a list of secret-shared seed phrases, the function
+/// reconstructs the seed phrase that was originally used to generate the given seed phrases.
+///
+/// * `seed_phrases` - The input seed phrases.
+pub fn reconstruct_seed_phrase(seed_phrases: &[SeedPhrase]) -> SeedPhraseResult {
+    // Reconstruct the seed phrase using the default word list.
+    reconstruct_seed_phrase_for_word_list(seed_phrases, DEFAULT_WORD_LIST)
+}
+
+/// The function is called to reconstruct a seed phrase.
+///
+/// Given a list of secret-shared seed phrases and a list of permissible words, the function
+/// reconstructs the seed phrase that was originally used to generate the given seed phrases.
+/// An error is returned if an index is 0, if two secret-shared seed phrases have the same index,
+/// if embedded indices are combined with explicit indices or index words, if fewer
+/// secret-shared seed phrases than the threshold recorded in them are provided, or if more
+/// secret-shared seed phrases are provided and they are inconsistent with the threshold.
+/// Up to `(n - t) / 2` corrupted secret-shared seed phrases are tolerated for `n` provided
+/// secret-shared seed phrases and the recorded threshold `t`, see
+/// [SplitRequest::correctable_errors](crate::SplitRequest::correctable_errors).
+///
+/// * `seed_phrases` - The input seed phrases.
+/// * `word_list` - The word list for the seed phrases.
+pub fn reconstruct_seed_phrase_for_word_list(
+    seed_phrases: &[SeedPhrase],
+    word_list: &(impl WordList + ?Sized),
+) -> SeedPhraseResult {
+    let reconstruction =
+        reconstruct_seed_phrase_with_consistency(seed_phrases, word_list.get_words())?;
+    if reconstruction.consistency == Some(false) && !reconstruction.is_corrected {
+        return Err(HarpoError::InvalidSeedPhrase(
+            get_inconsistent_shares_message(&reconstruction.corrupted_positions),
+        ));
+    }
+    Ok(reconstruction.seed_phrase)
+}
+
+/// The function is called to reconstruct a seed phrase that complies with the given standard.
+///
+/// The function behaves like [reconstruct_seed_phrase](crate::reconstruct_seed_phrase) but
+/// additionally converts the reconstructed seed phrase according to the standard, which must be
+/// the standard used when creating the secret-shared seed phrases, see
+/// [SplitRequest::standard](crate::SplitRequest::standard).
+///
+/// * `seed_phrases` - The input seed phrases.
+/// * `standard` - The standard of the reconstructed seed phrase.
+pub fn reconstruct_seed_phrase_for_standard(
+    seed_phrases: &[SeedPhrase],
+    standard: PhraseStandard,
+) -> SeedPhraseResult {
+    reconstruct_seed_phrase_for_standard_for_word_list(seed_phrases, standard, DEFAULT_WORD_LIST)
+}
+
+/// The function is called to reconstruct a seed phrase that complies with the given standard.
+///
+/// The function behaves like
+/// [reconstruct_seed_phrase_for_word_list](crate::reconstruct_seed_phrase_for_word_list) but
+/// additionally converts the reconstructed seed phrase according to the standard. An error is
+/// returned if the reconstructed seed phrase does not comply with the standard.
+///
+/// * `seed_phrases` - The input seed phrases.
+/// * `standard` - The standard of the reconstructed seed phrase.
+/// * `word_list` - The word list for the seed phrases.
+pub fn reconstruct_seed_phrase_for_standard_for_word_list(
+    seed_phrases: &[SeedPhrase],
+    standard: PhraseStandard,
+    word_list: &(impl WordList + ?Sized),
+) -> SeedPhraseResult {
+    let word_list = word_list.get_words();
+    let seed_phrase = reconstruct_seed_phrase_for_word_list(seed_phrases, word_list)?;
+    match standard {
+        PhraseStandard::Bip39 => Ok(seed_phrase),
+        PhraseStandard::Electrum => get_electrum_seed_phrase(&seed_phrase, word_list),
+    }
+}
+
+/// The function computes the Lagrange-weighted contribution of a secret-shared seed phrase to
+/// the secret.
+///
+/// Each holder computes the partial for the indices of all participating secret-shared seed
+/// phrases, and the seed phrase is obtained by combining the partials using
+/// [reconstruct_seed_phrase_from_partials](crate::reconstruct_seed_phrase_from_partials).
+/// A partial does not hide the secret-shared seed phrase: The Lagrange coefficient only depends
+/// on the public indices, so anyone who receives the partial can recover the secret-shared seed
+/// phrase. Partials must therefore be handled with the same care as secret-shared seed phrases.
+/// The returned [LagrangePartial](crate::secret_sharing::LagrangePartial) does not reveal its
+/// value in debug output and is serialized explicitly using
+/// [to_bytes](crate::secret_sharing::LagrangePartial::to_bytes).
+/// An error is returned if fewer indices than the recorded threshold are provided or if the
+/// index of the secret-shared seed phrase is not among them.
+///
+/// * `seed_phrase` - The secret-shared seed phrase of the holder.
+/// * `participating_indices` - The indices of all participating secret-shared seed phrases.
+pub fn compute_lagrange_partial(
+    seed_phrase: &SeedPhrase,
+    participating_indices: &[u32],
+) -> HarpoResult<LagrangePartial> {
+    compute_lagrange_partial_for_word_list(seed_phrase, participating_indices, DEFAULT_WORD_LIST)
+}
+
+/// The function computes the Lagrange-weighted contribution of a secret-shared seed phrase to
+/// the secret, see [compute_lagrange_partial](crate::compute_lagrange_partial).
+///
+/// * `seed_phrase` - The secret-shared seed phrase of the holder.
+/// * `participating_indices` - The indices of all participating secret-shared seed phrases.
+/// * `word_list` - The word list for the seed phrase.
+pub fn compute_lagrange_partial_for_word_list(
+    seed_phrase: &SeedPhrase,
+    participating_indices: &[u32],
+    word_list: &(impl WordList + ?Sized),
+) -> HarpoResult<LagrangePartial> {
+    let word_list = word_list.get_words();
+    if let Some(required) = seed_phrase.get_threshold() {
+        if participating_indices.len() < required {
+            return Err(HarpoError::NotEnoughShares {
+                provided: participating_indices.len(),
+                required,
+            });
+        }
+    }
+    let secret_shares =
+        get_secret_shares_for_seed_phrases(std::slice::from_ref(seed_phrase), word_list)?;
+    secret_sharing::compute_lagrange_partial(&secret_shares[0], participating_indices)
+}
+
+/// The function reconstructs a seed phrase from the Lagrange-weighted contributions of the
+/// participating secret-shared seed phrases.
+///
+/// * `partials` - The partials computed using
+///   [compute_lagrange_partial](crate::compute_lagrange_partial).
+pub fn reconstruct_seed_phrase_from_partials(partials: &[LagrangePartial]) -> SeedPhraseResult {
+    reconstruct_seed_phrase_from_partials_for_word_list(partials, DEFAULT_WORD_LIST)
+}
+
+/// The function reconstructs a seed phrase for the given word list from the Lagrange-weighted
+/// contributions of the participating secret-shared seed phrases.
+///
+/// * `partials` - The partials computed using
+///   [compute_lagrange_partial_for_word_list](crate::compute_lagrange_partial_for_word_list).
+/// * `word_list` - The word list for the seed phrase.
+pub fn reconstruct_seed_phrase_from_partials_for_word_list(
+    partials: &[LagrangePartial],
+    word_list: &(impl WordList + ?Sized),
+) -> SeedPhraseResult {
+    let word_list = word_list.get_words();
+    let secret_element = combine_lagrange_partials(partials)?;
+    get_seed_phrase_for_element(&secret_element, word_list)
+}
+
+/// The function returns the message used when the secret-shared seed phrases are inconsistent
+/// with their threshold.
+///
+/// * `corrupted_positions` - The positions of the identified corrupted secret-shared seed
+///   phrases, if any.
+fn get_inconsistent_shares_message(corrupted_positions: &[usize]) -> String {
+    let message = "The secret-shared seed phrases do not lie on a polynomial of the recorded \
+        threshold, so at least one of them is corrupted or stems from a different set.";
+    match corrupted_positions {
+        [] => message.to_string(),
+        [position] => format!(
+            "{} Secret-shared seed phrase {} appears to be corrupted.",
+            message,
+            position + 1
+        ),
+        positions => {
+            let positions: Vec<String> = positions
+                .iter()
+                .map(|position| (position + 1).to_string())
+                .collect();
+            format!(
+                "{} Secret-shared seed phrases {} appear to be corrupted.",
+                message,
+                positions.join(", ")
+            )
+        }
+    }
+}
+
+/// This struct holds a reconstructed seed phrase together with the result of the consistency
+/// check of the secret-shared seed phrases.
+struct CheckedReconstruction {
+    /// The reconstructed seed phrase.
+    seed_phrase: SeedPhrase,
+    /// The consistency of the secret-shared seed phrases, if it could be checked.
+    consistency: Option<bool>,
+    /// The positions of the secret-shared seed phrases that were identified as corrupted.
+    corrupted_positions: Vec<usize>,
+    /// Flag indicating whether the remaining secret-shared seed phrases determine the seed
+    /// phrase uniquely.
+    is_corrected: bool,
+}
+
+/// The function reconstructs a seed phrase and checks the consistency of the given
+/// secret-shared seed phrases.
+///
+/// A reconstructed seed phrase is always BIP-0039-compliant because its checksum is derived from
+/// the reconstructed secret. Therefore, corrupted secret-shared seed phrases can only be
+/// detected if more secret-shared seed phrases than the recorded threshold are provided: they
+/// are consistent if the interpolated polynomial does not exceed the degree implied by the
+/// threshold. The consistency is `None` if it cannot be checked.
+/// If the secret-shared seed phrases are inconsistent and a consistent majority exists, the
+/// seed phrase is reconstructed from the majority and the positions of the other secret-shared
+/// seed phrases are returned as well. Mistyped secret-shared seed phrases are treated as
+/// corrupted as long as sufficiently many other secret-shared seed phrases remain.
+///
+/// The secret shares form a Reed-Solomon codeword, so `s` mistyped and `e` other corrupted
+/// secret-shared seed phrases can be corrected if `2e + s <= n - t` for `n` secret-shared seed
+/// phrases and the threshold `t`.
+///
+/// * `seed_phrases` - The input seed phrases.
+/// * `word_list` - The word list for the seed phrases.
+fn reconstruct_seed_phrase_with_consistency(
+    seed_phrases: &[SeedPhrase],
+    word_list: &[&str],
+) -> HarpoResult<CheckedReconstruction> {
+    trace_span!("reconstruct");
+    trace_event!("reconstruct.parameters", "num_shares" => seed_phrases.len());
+    let threshold = get_recorded_threshold(seed_phrases);
+    // Mistyped secret-shared seed phrases are erased if the remaining ones reach the threshold.
+    let erased_positions: Vec<usize> = match threshold {
+        Some(threshold) if seed_phrases.len() > threshold => {
+            let positions: Vec<usize> = seed_phrases
+                .iter()
+                .enumerate()
+                .filter(|(_, seed_phrase)| {
+                    validate_share(seed_phrase, word_list).get_error().is_some()
+                })
+                .map(|(position, _)| position)
+                .collect();
+            if seed_phrases.len() - positions.len() >= threshold {
+                positions
+            } else {
+                vec![]
+            }
+        }
+        _ => vec![],
+    };
+    let remaining_positions: Vec<usize> = (0..seed_phrases.len())
+        .filter(|position| !erased_positions.contains(position))
+        .collect();
+    let remaining_seed_phrases: Vec<SeedPhrase> = remaining_positions
+        .iter()
+        .map(|position| seed_phrases[*position].clone())
+        .collect();
+    // Get the secret shares.
+    let secret_shares = get_secret_shares_for_seed_phrases(&remaining_seed_phrases, word_list)?;
+    check_threshold(seed_phrases)?;
+    let (consistency, error_positions) = match threshold {
+        Some(threshold) if secret_shares.len() > threshold => {
+            if interpolate_polynomial(&secret_shares)?.get_threshold() <= threshold {
+                (Some(true), Some(vec![]))
+            } else {
+                (
+                    Some(false),
+                    find_inconsistent_shares(&secret_shares, threshold)?,
+                )
+            }
+        }
+        _ => (None, Some(vec![])),
+    };
+    let num_errors = error_positions.as_ref().map_or(0, Vec::len);
+    let is_corrected = error_positions.is_some()
+        && 2 * num_errors + erased_positions.len() + threshold.unwrap_or_default()
+            <= seed_phrases.len();
+    // Reconstruct the secret element from the secret shares that are not corrupted and turn it
+    // into a seed phrase.
+    let error_positions = error_positions.unwrap_or_default();
+    let consistent_shares: Vec<SecretShare> = secret_shares
+        .into_iter()
+        .enumerate()
+        .filter(|(position, _)| !error_positions.contains(position))
+        .map(|(_, share)| share)
+        .collect();
+    let secret_element = reconstruct_secret(&consistent_shares)?;
+    let seed_phrase = get_seed_phrase_for_element(&secret_element, word_list)?;
+    let mut corrupted_positions: Vec<usize> = error_positions
+        .iter()
+        .map(|position| remaining_positions[*position])
+        .chain(erased_positions.iter().copied())
+        .collect();
+    corrupted_positions.sort_unstable();
+    Ok(CheckedReconstruction {
+        seed_phrase,
+        consistency: if erased_positions.is_empty() {
+            consistency
+        } else {
+            Some(false)
+        },
+        corrupted_positions,
+        is_corrected,
+    })
+}
+
+/// The function is called to reconstruct a seed phrase and report the provenance of the
+/// secret-shared seed phrases.
+///
+/// The function behaves like [reconstruct_seed_phrase](crate::reconstruct_seed_phrase) but
+/// returns a [ReconstructionReport](crate::seed_phrase::ReconstructionReport), which contains
+/// the used and ignored secret-shared seed phrases, detected warnings, and the fingerprint of
+/// the reconstructed seed phrase. Duplicates are ignored. The labels, e.g., the source files,
+/// are optional: either no labels or one label per secret-shared seed phrase must be provided.
+///
+/// * `seed_phrases` - The input seed phrases.
+/// * `labels` - The labels of the input seed phrases.
+pub fn reconstruct_seed_phrase_with_report(
+    seed_phrases: &[SeedPhrase],
+    labels: &[&str],
+) -> HarpoResult<ReconstructionReport> {
+    reconstruct_seed_phrase_with_report_for_word_list(seed_phrases, labels, DEFAULT_WORD_LIST)
+}
+
+/// The function is called to reconstruct a seed phrase and report the provenance of the
+/// secret-shared seed phrases using the given word list.
+///
+/// The function behaves like
+/// [reconstruct_seed_phrase_for_word_list](crate::reconstruct_seed_phrase_for_word_list) but
+/// returns a [ReconstructionReport](crate::seed_phrase::ReconstructionReport). Either no labels
+/// or one label per secret-shared seed phrase must be provided. Secret-shared seed phrases that
+/// are inconsistent with their threshold do not cause an error but are reported in the
+/// consistency status and the warnings.
+///
+/// * `seed_phrases` - The input seed phrases.
+/// * `labels` - The labels of the input seed phrases.
+/// * `word_list` - The word list for the seed phrases.
+pub fn reconstruct_seed_phrase_with_report_for_word_list(
+    seed_phrases: &[SeedPhrase],
+    labels: &[&str],
+    word_list: &(impl WordList + ?Sized),
+) -> HarpoResult<ReconstructionReport> {
+    let word_list = word_list.get_words();
+    if !labels.is_empty() && labels.len() != seed_phrases.len() {
+        return Err(HarpoError::InvalidParameter(format!(
+            "Expected {} labels but received {}.",
+            seed_phrases.len(),
+            labels.len()
+        )));
+    }
+    validate_word_list(word_list)?;
+    let validations = validate_shares_for_word_list(seed_phrases, word_list);
+    let mut used_shares: Vec<ShareProvenance> = vec![];
+    let mut ignored_shares = vec![];
+    let mut warnings = vec![];
+    for (position, validation) in validations.iter().enumerate() {
+        let provenance = ShareProvenance::new(position, labels.get(position).copied(), validation);
+        if provenance.get_duplicate_of().is_some() {
+            ignored_shares.push(provenance);
+            continue;
+        }
+        if let Some(error) = validation.get_error() {
+            warnings.push(format!(
+                "Secret-shared seed phrase {} appears to be mistyped: {}",
+                position + 1,
+                error
+            ));
+        }
+        // Only the last secret-shared seed phrase with a given index is used.
+        let earlier_position = used_shares.iter().position(|used_share| {
+            used_share.get_index().is_some() && used_share.get_index() == provenance.get_index()
+        });
+        if let Some(earlier_position) = earlier_position {
+            let earlier_share = used_shares.remove(earlier_position);
+            warnings.push(format!(
+                "Secret-shared seed phrases {} and {} differ but have the same index {}, so only \
+                the latter is used.",
+                earlier_share.get_position() + 1,
+                position + 1,
+                validation.get_index().unwrap_or_default()
+            ));
+            ignored_shares.push(earlier_share);
+        }
+        used_shares.push(provenance);
+    }
+    if used_shares.len() == 1 {
+        warnings.push(
+            "Only one secret-shared seed phrase was used, so the result is only correct for \
+            threshold 1."
+                .to_string(),
+        );
+    }
+    let unique_seed_phrases: Vec<SeedPhrase> = used_shares
+        .iter()
+        .map(|used_share| seed_phrases[used_share.get_position()].clone())
+        .collect();
+    let CheckedReconstruction {
+        seed_phrase,
+        consistency,
+        corrupted_positions,
+        ..
+    } = reconstruct_seed_phrase_with_consistency(&unique_seed_phrases, word_list)?;
+    // The corrupted secret-shared seed phrases are not used for the reconstruction.
+    let mut corrupted_shares = vec![];
+    for position in corrupted_positions.iter().rev() {
+        corrupted_shares.insert(0, used_shares.remove(*position));
+    }
+    if consistency == Some(false) {
+        let positions: Vec<usize> = corrupted_shares
+            .iter()
+            .map(|corrupted_share| corrupted_share.get_position())
+            .collect();
+        warnings.push(get_inconsistent_shares_message(&positions));
+    }
+    let fingerprint = get_seed_phrase_fingerprint(&seed_phrase, word_list)?;
+    ignored_shares.sort_by_key(|ignored_share| ignored_share.get_position());
+    Ok(ReconstructionReport::new(
+        seed_phrase,
+        fingerprint,
+        used_shares,
+        ignored_shares,
+        corrupted_shares,
+        warnings,
+        consistency,
+    ))
+}
+
+/// This struct collects secret-shared seed phrases one at a time until the seed phrase can be
+/// reconstructed.
+///
+/// It is intended for interactive applications that receive the secret-shared seed phrases one
+/// by one and want to report the progress. Each secret-shared seed phrase is checked when it is
+/// added so that mistyped seed phrases can be corrected immediately.
+#[derive(Debug, Clone)]
+pub struct Reconstructor<'a> {
+    /// The threshold.
+    threshold: usize,
+    /// The standard that the reconstructed seed phrase complies with.
+    standard: PhraseStandard,
+    /// The word list for the seed phrases.
+    word_list: &'a [&'a str],
+    /// The collected secret-shared seed phrases.
+    shares: Vec<SeedPhrase>,
+    /// The validation results of the collected secret-shared seed phrases.
+    validations: Vec<ShareValidation>,
+}
+
+impl<'a> Reconstructor<'a> {
+    /// The function creates a reconstructor for the given threshold using the default word
+    /// list.
+    ///
+    /// * `threshold` - The threshold.
+    pub fn new(threshold: usize) -> Self {
+        Reconstructor {
+            threshold,
+            standard: PhraseStandard::Bip39,
+            word_list: DEFAULT_WORD_LIST,
+            shares: vec![],
+            validations: vec![],
+        }
+    }
+
+    /// The function sets the standard that the reconstructed seed phrase complies with.
+    ///
+    /// * `standard` - The standard of the reconstructed seed phrase.
+    pub fn standard(mut self, standard: PhraseStandard) -> Self {
+        self.standard = standard;
+        self
+    }
+
+    /// The function sets the word list for the seed phrases.
+    ///
+    /// * `word_list` - The word list for the seed phrases.
+    pub fn word_list(mut self, word_list: &'a (impl WordList + ?Sized)) -> Self {
+        self.word_list = word_list.get_words();
+        self
+    }
+
+    /// The function adds the given secret-shared seed phrase.
+    ///
+    /// The function returns false if the secret-shared seed phrase has already been added, in
+    /// which case it is ignored. An error is returned if the secret-shared seed phrase appears
+    /// to be mistyped, if its number of words differs from the earlier ones, if it stems from a
+    /// different set of secret-shared seed phrases, or if an earlier secret-shared seed phrase
+    /// has the same index.
+    ///
+    /// * `seed_phrase` - The secret-shared seed phrase.
+    pub fn add_share(&mut self, seed_phrase: SeedPhrase) -> HarpoResult<bool> {
+        validate_word_list(self.word_list)?;
+        let validation = validate_share(&seed_phrase, self.word_list);
+        if let Some(error) = validation.get_error() {
+            return Err(HarpoError::InvalidSeedPhrase(error.to_string()));
+        }
+        if self
+            .validations
+            .iter()
+            .any(|earlier| earlier.get_fingerprint() == validation.get_fingerprint())
+        {
+            return Ok(false);
+        }
+        if let Some(earlier) = self.shares.first() {
+            if earlier.len() != seed_phrase.len() {
+                return Err(HarpoError::InvalidSeedPhrase(format!(
+                    "The secret-shared seed phrase has {} words but the earlier ones have {} \
+                    words.",
+                    seed_phrase.len(),
+                    earlier.len()
+                )));
+            }
+        }
+        let earlier_set_id = self.shares.iter().find_map(SeedPhrase::get_set_id);
+        if let (Some(earlier_set_id), Some(set_id)) = (earlier_set_id, seed_phrase.get_set_id()) {
+            if earlier_set_id != set_id {
+                return Err(HarpoError::InvalidSeedPhrase(format!(
+                    "The secret-shared seed phrase belongs to the set {:04x} but the earlier ones \
+                    belong to the set {:04x}.",
+                    set_id, earlier_set_id
+                )));
+            }
+        }
+        if validation.get_index().is_some()
+            && self
+                .validations
+                .iter()
+                .any(|earlier| earlier.get_index() == validation.get_index())
+        {
+            return Err(HarpoError::InvalidSeedPhrase(format!(
+                "A different secret-shared seed phrase with index {} has already been added.",
+                validation.get_index().unwrap_or_default()
+            )));
+        }
+        self.shares.push(seed_phrase);
+        self.validations.push(validation);
+        Ok(true)
+    }
+
+    /// The function returns the number of collected secret-shared seed phrases.
+    pub fn get_num_shares(&self) -> usize {
+        self.shares.len()
+    }
+
+    /// The function returns the number of secret-shared seed phrases that are still required.
+    ///
+    /// If the collected secret-shared seed phrases record a higher threshold than the threshold
+    /// of the reconstructor, the recorded threshold is used.
+    pub fn missing_count(&self) -> usize {
+        let threshold = get_recorded_threshold(&self.shares)
+            .map_or(self.threshold, |recorded_threshold| {
+                recorded_threshold.max(self.threshold)
+            });
+        threshold.saturating_sub(self.shares.len())
+    }
+
+    /// The function reconstructs the seed phrase if the threshold number of secret-shared seed
+    /// phrases has been collected.
+    ///
+    /// An error is returned if secret-shared seed phrases are missing.
+    pub fn try_reconstruct(&self) -> SeedPhraseResult {
+        match self.missing_count() {
+            0 => reconstruct_seed_phrase_for_standard_for_word_list(
+                &self.shares,
+                self.standard,
+                self.word_list,
+            ),
+            1 => Err(HarpoError::InvalidParameter(
+                "One more secret-shared seed phrase is required.".to_string(),
+            )),
+            missing_count => Err(HarpoError::InvalidParameter(format!(
+                "{} more secret-shared seed phrases are required.",
+                missing_count
+            ))),
+        }
+    }
+}
+
+/// The function creates secret-shared seed phrases with the given indices from existing ones.
+///
+/// The polynomial underlying the given secret-shared seed phrases is interpolated internally and
+/// evaluated at the new indices, i.e., the secret is never returned. If at least the threshold
+/// number of secret-shared seed phrases is provided, the new secret-shared seed phrases can be
+/// combined with the existing ones. This makes it possible, e.g., to renumber custodians.
+/// The new secret-shared seed phrases use the same index encoding as the provided ones.
+///
+/// * `seed_phrases` - The input seed phrases.
+/// * `new_indices` - The indices of the new secret-shared seed phrases.
+pub fn reindex_shares(
+    seed_phrases: &[SeedPhrase],
+    new_indices: &[u32],
+) -> HarpoResult<Vec<SeedPhrase>> {
+    reindex_shares_for_word_list(seed_phrases, new_indices, DEFAULT_WORD_LIST)
+}
+
+/// The function creates secret-shared seed phrases with the given indices from existing ones.
+///
+/// The polynomial underlying the given secret-shared seed phrases is interpolated internally and
+/// evaluated at the new indices, i.e., the secret is never returned. If at least the threshold
+/// number of secret-shared seed phrases is provided, the new secret-shared seed phrases can be
+/// combined with the existing ones. This makes it possible, e.g., to renumber custodians.
+/// The new secret-shared seed phrases use the same index encoding as the provided ones.
+///
+/// * `seed_phrases` - The input seed phrases.
+/// * `new_indices` - The indices of the new secret-shared seed phrases.
+/// * `word_list` - The word list for the seed phrases.
+pub fn reindex_shares_for_word_list(
+    seed_phrases: &[SeedPhrase],
+    new_indices: &[u32],
+    word_list: &(impl WordList + ?Sized),
+) -> HarpoResult<Vec<SeedPhrase>> {
+    let word_list = word_list.get_words();
+    trace_span!("reindex");
+    trace_event!(
+        "reindex.parameters",
+        "num_shares" => seed_phrases.len(),
+        "num_new_shares" => new_indices.len(),
+    );
+    // Get the secret shares, which also validates the word list and the seed phrases.
+    let secret_shares = get_secret_shares_for_seed_phrases(seed_phrases, word_list)?;
+    check_threshold(seed_phrases)?;
+    // The index encoding of the first seed phrase is used for the new seed phrases.
+    let (index_encoding, num_words) = get_index_encoding_for_seed_phrase(&seed_phrases[0]);
+    // The new indices must be distinct and encodable using the index encoding.
+    if new_indices.is_empty() {
+        return Err(HarpoError::InvalidParameter(
+            "No new indices provided.".to_string(),
+        ));
+    }
+    let max_index = get_max_num_shares(num_words, index_encoding).unwrap_or(0);
+    if let Some(index) = new_indices
+        .iter()
+        .find(|index| **index < 1 || **index as usize > max_index)
+    {
+        return Err(HarpoError::InvalidParameter(format!(
+            "The index {} is not between 1 and {}.",
+            index, max_index
+        )));
+    }
+    let distinct_indices: HashSet<&u32> = new_indices.iter().collect();
+    if distinct_indices.len() != new_indices.len() {
+        return Err(HarpoError::InvalidParameter(
+            "The new indices must be distinct.".to_string(),
+        ));
+    }
+    let polynomial = interpolate_polynomial(&secret_shares)?;
+    polynomial
+        .get_secret_shares_for_indices(new_indices)
+        .iter()
+        .map(|share| get_seed_phrase_for_share(share, &polynomial, index_encoding, word_list))
+        .collect()
+}
+
+/// The function re-creates the secret-shared seed phrase with the given index.
+///
+/// The polynomial underlying the given secret-shared seed phrases is interpolated internally and
+/// evaluated at the given index. If at least the threshold number of secret-shared seed phrases
+/// of the set is provided, the result is identical to the original secret-shared seed phrase
+/// with this index, e.g., to replace a destroyed secret-shared seed phrase without affecting the
+/// other custodians. The secret-shared seed phrase uses the same index encoding as the provided
+/// ones.
+///
+/// * `seed_phrases` - The surviving seed phrases.
+/// * `index` - The index of the secret-shared seed phrase to be re-created.
+pub fn regenerate_share(seed_phrases: &[SeedPhrase], index: u32) -> SeedPhraseResult {
+    regenerate_share_for_word_list(seed_phrases, index, DEFAULT_WORD_LIST)
+}
+
+/// The function re-creates the secret-shared seed phrase with the given index.
+///
+/// The polynomial underlying the given secret-shared seed phrases is interpolated internally and
+/// evaluated at the given index, see [regenerate_share](crate::regenerate_share).
+///
+/// * `seed_phrases` - The surviving seed phrases.
+/// * `index` - The index of the secret-shared seed phrase to be re-created.
+/// * `word_list` - The word list for the seed phrases.
+pub fn regenerate_share_for_word_list(
+    seed_phrases: &[SeedPhrase],
+    index: u32,
+    word_list: &(impl WordList + ?Sized),
+) -> SeedPhraseResult {
+    let word_list = word_list.get_words();
+    match reindex_shares_for_word_list(seed_phrases, &[index], word_list)?.pop() {
+        Some(seed_phrase) => Ok(seed_phrase),
+        None => Err(HarpoError::InvalidParameter(format!(
+            "Could not re-create the secret-shared seed phrase with index {}.",
+            index
+        ))),
+    }
+}
+
+/// The function creates additional secret-shared seed phrases for an existing set.
+///
+/// The new secret-shared seed phrases receive the indices following the indices of the existing
+/// secret-shared seed phrases, i.e., the indices `num_existing_shares + 1` up to
+/// `num_existing_shares + num_new_shares`, so that they do not conflict with any secret-shared
+/// seed phrase that has already been distributed. At least the threshold number of existing
+/// secret-shared seed phrases must be provided. The existing secret-shared seed phrases remain
+/// valid and can be combined with the new ones.
+/// If the existing secret-shared seed phrases were created with a salt, see
+/// [create_secret_shared_seed_phrases_with_salt](crate::create_secret_shared_seed_phrases_with_salt),
+/// additional secret-shared seed phrases can also be created from the seed phrase and the salt
+/// by requesting a larger number of secret-shared seed phrases.
+///
+/// * `seed_phrases` - The existing seed phrases.
+/// * `num_existing_shares` - The number of secret-shared seed phrases in the existing set.
+/// * `num_new_shares` - The number of additional secret-shared seed phrases.
+pub fn extend_shares(
+    seed_phrases: &[SeedPhrase],
+    num_existing_shares: usize,
+    num_new_shares: usize,
+) -> HarpoResult<Vec<SeedPhrase>> {
+    extend_shares_for_word_list(
+        seed_phrases,
+        num_existing_shares,
+        num_new_shares,
+        DEFAULT_WORD_LIST,
+    )
+}
+
+/// The function creates additional secret-shared seed phrases for an existing set.
+///
+/// The new secret-shared seed phrases receive the indices following the indices of the existing
+/// secret-shared seed phrases, see [extend_shares](crate::extend_shares).
+///
+/// * `seed_phrases` - The existing seed phrases.
+/// * `num_existing_shares` - The number of secret-shared seed phrases in the existing set.
+/// * `num_new_shares` - The number of additional secret-shared seed phrases.
+/// * `word_list` - The word list for the seed phrases.
+pub fn extend_shares_for_word_list(
+    seed_phrases: &[SeedPhrase],
+    num_existing_shares: usize,
+    num_new_shares: usize,
+    word_list: &(impl WordList + ?Sized),
+) -> HarpoResult<Vec<SeedPhrase>> {
+    let word_list = word_list.get_words();
+    let secret_shares = get_secret_shares_for_seed_phrases(seed_phrases, word_list)?;
+    // The provided secret-shared seed phrases must belong to the existing set.
+    if let Some(share) = secret_shares
+        .iter()
+        .find(|share| share.get_index() as usize > num_existing_shares)
+    {
+        return Err(HarpoError::InvalidParameter(format!(
+            "The index {} exceeds the number of existing secret-shared seed phrases ({}).",
+            share.get_index(),
+            num_existing_shares
+        )));
+    }
+    let new_indices: Vec<u32> = (num_existing_shares + 1..=num_existing_shares + num_new_shares)
+        .map(|index| index.min(u32::MAX as usize) as u32)
+        .collect();
+    reindex_shares_for_word_list(seed_phrases, &new_indices, word_list)
+}
+
+/// The function returns the index encoding of the given secret-shared seed phrase and the number
+/// of words of the seed phrase that was secret-shared.
+///
+/// * `seed_phrase` - The secret-shared seed phrase.
+fn get_index_encoding_for_seed_phrase(seed_phrase: &SeedPhrase) -> (IndexEncoding, usize) {
+    if has_index_word(seed_phrase.len()) {
+        (IndexEncoding::IndexWord, seed_phrase.len() - 1)
+    } else if seed_phrase.get_index().is_some() {
+        (IndexEncoding::Explicit, seed_phrase.len())
+    } else {
+        (IndexEncoding::Embedded, seed_phrase.len())
+    }
+}
+
+/// The function creates a fresh set of secret-shared seed phrases for the same seed phrase.
+///
+/// The polynomial underlying the given secret-shared seed phrases is interpolated internally and
+/// a new polynomial with the same secret and the same threshold but fresh random coefficients is
+/// created, i.e., the secret is never returned. The new secret-shared seed phrases cannot be
+/// combined with the old ones, so secret-shared seed phrases that leaked before the refresh
+/// become useless once the old ones are destroyed.
+/// The threshold recorded in the secret-shared seed phrases is used. If no threshold is
+/// recorded, the number of provided secret-shared seed phrases is used as the threshold. The new
+/// secret-shared seed phrases use the same index encoding as the provided ones.
+///
+/// * `seed_phrases` - The input seed phrases.
+/// * `num_shares` - The number of new secret-shared seed phrases.
+pub fn refresh_shares(seed_phrases: &[SeedPhrase], num_shares: usize) -> HarpoResult<ShareSet> {
+    refresh_shares_for_word_list(seed_phrases, num_shares, DEFAULT_WORD_LIST)
+}
+
+/// The function creates a fresh set of secret-shared seed phrases for the same seed phrase.
+///
+/// The polynomial underlying the given secret-shared seed phrases is interpolated internally and
+/// a new polynomial with the same secret and the same threshold but fresh random coefficients is
+/// created, i.e., the secret is never returned. The new secret-shared seed phrases cannot be
+/// combined with the old ones, so secret-shared seed phrases that leaked before the refresh
+/// become useless once the old ones are destroyed.
+/// The threshold recorded in the secret-shared seed phrases is used. If no threshold is
+/// recorded, the number of provided secret-shared seed phrases is used as the threshold. The new
+/// secret-shared seed phrases use the same index encoding as the provided ones.
+///
+/// * `seed_phrases` - The input seed phrases.
+/// * `num_shares` - The number of new secret-shared seed phrases.
+/// * `word_list` - The word list for the seed phrases.
+pub fn refresh_shares_for_word_list(
+    seed_phrases: &[SeedPhrase],
+    num_shares: usize,
+    word_list: &(impl WordList + ?Sized),
+) -> HarpoResult<ShareSet> {
+    let word_list = word_list.get_words();
+    reshare_secret_shared_seed_phrases(
+        seed_phrases,
+        None,
+        num_shares,
+        None,
+        word_list,
+        &mut |_, _| {},
+    )
+}
+
+/// The function creates a fresh set of secret-shared seed phrases with new parameters for the
+/// same seed phrase.
+///
+/// The polynomial underlying the given secret-shared seed phrases is interpolated internally and
+/// a new polynomial with the same secret is created for the given threshold, i.e., the secret is
+/// never returned. At least the threshold number of the existing secret-shared seed phrases must
+/// be provided. The new secret-shared seed phrases cannot be combined with the old ones.
+///
+/// * `seed_phrases` - The input seed phrases.
+/// * `threshold` - The new threshold.
+/// * `num_shares` - The number of new secret-shared seed phrases.
+/// * `index_encoding` - The encoding of the new seed phrase indices.
+pub fn reshare_shares(
+    seed_phrases: &[SeedPhrase],
+    threshold: usize,
+    num_shares: usize,
+    index_encoding: IndexEncoding,
+) -> HarpoResult<ShareSet> {
+    reshare_shares_for_word_list(
+        seed_phrases,
+        threshold,
+        num_shares,
+        index_encoding,
+        DEFAULT_WORD_LIST,
+    )
+}
+
+/// The function creates a fresh set of secret-shared seed phrases with new parameters for the
+/// same seed phrase.
+///
+/// The polynomial underlying the given secret-shared seed phrases is interpolated internally and
+/// a new polynomial with the same secret is created for the given threshold, i.e., the secret is
+/// never returned. At least the threshold number of the existing secret-shared seed phrases must
+/// be provided. The new secret-shared seed phrases cannot be combined with the old ones.
+///
+/// * `seed_phrases` - The input seed phrases.
+/// * `threshold` - The new threshold.
+/// * `num_shares` - The number of new secret-shared seed phrases.
+/// * `index_encoding` - The encoding of the new seed phrase indices.
+/// * `word_list` - The word list for the seed phrases.
+pub fn reshare_shares_for_word_list(
+    seed_phrases: &[SeedPhrase],
+    threshold: usize,
+    num_shares: usize,
+    index_encoding: IndexEncoding,
+    word_list: &(impl WordList + ?Sized),
+) -> HarpoResult<ShareSet> {
+    reshare_shares_with_progress_for_word_list(
+        seed_phrases,
+        threshold,
+        num_shares,
+        index_encoding,
+        word_list,
+        &mut |_, _| {},
+    )
+}
+
+/// The function creates a fresh set of secret-shared seed phrases with new parameters for the
+/// same seed phrase while reporting the progress.
+///
+/// The function behaves like
+/// [reshare_shares_for_word_list](crate::reshare_shares_for_word_list) and additionally reports
+/// the number of created secret-shared seed phrases to the provided
+/// [Progress](crate::Progress) callback after each secret-shared seed phrase.
+///
+/// * `seed_phrases` - The input seed phrases.
+/// * `threshold` - The new threshold.
+/// * `num_shares` - The number of new secret-shared seed phrases.
+/// * `index_encoding` - The encoding of the new seed phrase indices.
+/// * `word_list` - The word list for the seed phrases.
+/// * `progress` - The progress callback.
+pub fn reshare_shares_with_progress_for_word_list(
+    seed_phrases: &[SeedPhrase],
+    threshold: usize,
+    num_shares: usize,
+    index_encoding: IndexEncoding,
+    word_list: &(impl WordList + ?Sized),
+    progress: &mut dyn Progress,
+) -> HarpoResult<ShareSet> {
+    let word_list = word_list.get_words();
+    reshare_secret_shared_seed_phrases(
+        seed_phrases,
+        Some(threshold),
+        num_shares,
+        Some(index_encoding),
+        word_list,
+        progress,
+    )
+}
+
+/// The function creates a fresh set of secret-shared seed phrases for the seed phrase underlying
+/// the given secret-shared seed phrases.
+///
+/// If no threshold or index encoding is provided, the threshold and index encoding of the given
+/// secret-shared seed phrases are kept.
+///
+/// * `seed_phrases` - The input seed phrases.
+/// * `new_threshold` - The new threshold, if any.
+/// * `num_shares` - The number of new secret-shared seed phrases.
+/// * `new_index_encoding` - The encoding of the new seed phrase indices, if any.
+/// * `word_list` - The word list for the seed phrases.
+/// * `progress` - The progress callback.
+fn reshare_secret_shared_seed_phrases(
+    seed_phrases: &[SeedPhrase],
+    new_threshold: Option<usize>,
+    num_shares: usize,
+    new_index_encoding: Option<IndexEncoding>,
+    word_list: &[&str],
+    progress: &mut dyn Progress,
+) -> HarpoResult<ShareSet> {
+    trace_span!("reshare");
+    trace_event!(
+        "reshare.parameters",
+        "num_shares" => seed_phrases.len(),
+        "num_new_shares" => num_shares,
+    );
+    // Get the secret shares, which also validates the word list and the seed phrases.
+    let secret_shares = get_secret_shares_for_seed_phrases(seed_phrases, word_list)?;
+    check_threshold(seed_phrases)?;
+    let (index_encoding, num_words) = get_index_encoding_for_seed_phrase(&seed_phrases[0]);
+    let polynomial = interpolate_polynomial(&secret_shares)?;
+    let threshold = get_recorded_threshold(seed_phrases).unwrap_or(secret_shares.len());
+    if polynomial.get_threshold() > threshold {
+        return Err(HarpoError::InvalidSeedPhrase(
+            "The secret-shared seed phrases are inconsistent.".to_string(),
+        ));
+    }
+    let threshold = new_threshold.unwrap_or(threshold);
+    let index_encoding = new_index_encoding.unwrap_or(index_encoding);
+    check_create_parameters(num_words, threshold, num_shares, index_encoding)?;
+    let new_polynomial = polynomial.refresh(threshold - 1)?;
+    let shares = create_secret_shared_seed_phrases_for_polynomial(
+        &new_polynomial,
+        num_shares,
+        index_encoding,
+        word_list,
+        progress,
+    )?;
+    get_share_set_for_shares(shares, threshold, index_encoding, word_list)
+}
+
+/// The function is called to secret-share a seed phrase among groups of custodians.
+///
+/// The seed phrase is secret-shared among the groups using the group threshold and each
+/// resulting group secret is secret-shared among the members of the group using the member
+/// threshold of the group, see [group_sharing](crate::group_sharing). The group shares are
+/// returned ordered by group and member. The seed phrase can be reconstructed using
+/// [reconstruct_seed_phrase_from_groups](crate::reconstruct_seed_phrase_from_groups).
+///
+/// * `seed_phrase` - The input seed phrase.
+/// * `group_threshold` - The number of groups required for the reconstruction.
+/// * `groups` - The member threshold and the number of members of each group.
+pub fn create_group_shares(
+    seed_phrase: &SeedPhrase,
+    group_threshold: usize,
+    groups: &[(usize, usize)],
+) -> HarpoResult<Vec<GroupShare>> {
+    create_group_shares_for_word_list(seed_phrase, group_threshold, groups, DEFAULT_WORD_LIST)
+}
+
+/// The function is called to secret-share a seed phrase among groups of custodians using the
+/// given word list.
+///
+/// The function behaves like [create_group_shares](crate::create_group_shares) but uses the
+/// given word list.
+///
+/// * `seed_phrase` - The input seed phrase.
+/// * `group_threshold` - The number of groups required for the reconstruction.
+/// * `groups` - The member threshold and the number of members of each group.
+/// * `word_list` - The word list for the seed phrases.
+pub fn create_group_shares_for_word_list(
+    seed_phrase: &SeedPhrase,
+    group_threshold: usize,
+    groups: &[(usize, usize)],
+    word_list: &(impl WordList + ?Sized),
+) -> HarpoResult<Vec<GroupShare>> {
+    let word_list = word_list.get_words();
+    trace_span!("split_groups");
+    trace_event!(
+        "split_groups.parameters",
+        "group_threshold" => group_threshold,
+        "num_groups" => groups.len(),
+    );
+    // Validate the word list.
+    validate_word_list(word_list)?;
+    // Check all parameters before creating any secret shares.
+    check_create_parameters(
+        seed_phrase.len(),
+        group_threshold,
+        groups.len(),
+        IndexEncoding::Explicit,
+    )?;
+    for (member_threshold, num_members) in groups {
+        check_create_parameters(
+            seed_phrase.len(),
+            *member_threshold,
+            *num_members,
+            IndexEncoding::Explicit,
+        )?;
+    }
+    // Make sure that the seed phrase is BIP-0039-compliant.
+    check_compliance(seed_phrase, word_list)?;
+    let secret = get_element_for_seed_phrase(seed_phrase, word_list)?;
+    let num_bits = seed_phrase.get_num_bits();
+    let new_polynomial = |secret: &FiniteFieldElement, threshold: usize| {
+        SecretPolynomial::new(secret, num_bits, threshold - 1).ok_or_else(|| {
+            HarpoError::InvalidParameter(
+                "Could not instantiate the required secret polynomial.".to_string(),
+            )
+        })
+    };
+    // Create one group secret per group.
+    let group_secrets =
+        new_polynomial(&secret, group_threshold)?.get_secret_shares(groups.len() as u32);
+    let mut group_shares = vec![];
+    for (group_secret, (member_threshold, num_members)) in group_secrets.iter().zip(groups) {
+        let member_polynomial = new_polynomial(group_secret.get_element(), *member_threshold)?;
+        for seed_phrase in create_secret_shared_seed_phrases_for_polynomial(
+            &member_polynomial,
+            *num_members,
+            IndexEncoding::Explicit,
+            word_list,
+            &mut |_, _| {},
+        )? {
+            group_shares.push(GroupShare::new(
+                group_secret.get_index(),
+                group_threshold,
+                seed_phrase,
+            )?);
+        }
+    }
+    Ok(group_shares)
+}
+
+/// The function is called to secret-share a seed phrase according to the given access policy.
+///
+/// The policy is implemented by nested sharing, see
+/// [AccessPolicy](crate::group_sharing::AccessPolicy): all groups are required, i.e., the
+/// seed phrase can only be reconstructed using
+/// [reconstruct_seed_phrase_from_groups](crate::reconstruct_seed_phrase_from_groups) if all
+/// mandatory secret-shared seed phrases and the threshold number of optional ones are provided.
+/// The group shares of the mandatory secret-shared seed phrases come first.
+///
+/// * `seed_phrase` - The input seed phrase.
+/// * `policy` - The access policy.
+pub fn create_policy_shares(
+    seed_phrase: &SeedPhrase,
+    policy: &AccessPolicy,
+) -> HarpoResult<Vec<GroupShare>> {
+    create_policy_shares_for_word_list(seed_phrase, policy, DEFAULT_WORD_LIST)
+}
+
+/// The function is called to secret-share a seed phrase according to the given access policy
+/// using the given word list.
+///
+/// The function behaves like [create_policy_shares](crate::create_policy_shares) but uses the
+/// given word list.
+///
+/// * `seed_phrase` - The input seed phrase.
+/// * `policy` - The access policy.
+/// * `word_list` - The word list for the seed phrases.
+pub fn create_policy_shares_for_word_list(
+    seed_phrase: &SeedPhrase,
+    policy: &AccessPolicy,
+    word_list: &(impl WordList + ?Sized),
+) -> HarpoResult<Vec<GroupShare>> {
+    let groups = policy.get_groups();
+    create_group_shares_for_word_list(seed_phrase, groups.len(), &groups, word_list)
+}
+
+/// The function is called to reconstruct a seed phrase from group shares.
+///
+/// The group secret of each group is reconstructed from the secret-shared seed phrases of its
+/// members. Groups with fewer secret-shared seed phrases than their recorded member threshold
+/// are skipped. The seed phrase is then reconstructed from the group secrets, which requires the
+/// group threshold number of groups.
+///
+/// * `group_shares` - The group shares.
+pub fn reconstruct_seed_phrase_from_groups(group_shares: &[GroupShare]) -> SeedPhraseResult {
+    reconstruct_seed_phrase_from_groups_for_word_list(group_shares, DEFAULT_WORD_LIST)
+}
+
+/// The function is called to reconstruct a seed phrase from group shares using the given word
+/// list.
+///
+/// The function behaves like
+/// [reconstruct_seed_phrase_from_groups](crate::reconstruct_seed_phrase_from_groups) but uses
+/// the given word list.
+///
+/// * `group_shares` - The group shares.
+/// * `word_list` - The word list for the seed phrases.
+pub fn reconstruct_seed_phrase_from_groups_for_word_list(
+    group_shares: &[GroupShare],
+    word_list: &(impl WordList + ?Sized),
+) -> SeedPhraseResult {
+    let word_list = word_list.get_words();
+    trace_span!("reconstruct_groups");
+    trace_event!("reconstruct_groups.parameters", "num_shares" => group_shares.len());
+    // All group shares must record the same group threshold.
+    let group_threshold = match group_shares.first() {
+        Some(group_share) => group_share.get_group_threshold(),
+        None => {
+            return Err(HarpoError::InvalidSeedPhrase(
+                "No group shares provided.".to_string(),
+            ))
+        }
+    };
+    if let Some(group_share) = group_shares
+        .iter()
+        .find(|group_share| group_share.get_group_threshold() != group_threshold)
+    {
+        return Err(HarpoError::InvalidSeedPhrase(format!(
+            "The group shares have different group thresholds ({} and {}).",
+            group_threshold,
+            group_share.get_group_threshold()
+        )));
+    }
+    // Collect the secret-shared seed phrases of each group.
+    let mut groups: BTreeMap<u32, Vec<SeedPhrase>> = BTreeMap::new();
+    for group_share in group_shares {
+        groups
+            .entry(group_share.get_group_index())
+            .or_default()
+            .push(group_share.get_seed_phrase().clone());
+    }
+    // Reconstruct the group secrets of all groups with sufficiently many members.
+    let mut group_secrets = vec![];
+    for (group_index, seed_phrases) in &groups {
+        if check_threshold(seed_phrases).is_err() {
+            continue;
+        }
+        let secret_shares = get_secret_shares_for_seed_phrases(seed_phrases, word_list)?;
+        group_secrets.push(SecretShare::new(
+            &reconstruct_secret(&secret_shares)?,
+            *group_index,
+        ));
+    }
+    if group_secrets.len() < group_threshold {
+        return Err(HarpoError::InvalidSeedPhrase(format!(
+            "{} complete group(s) provided but {} are required.",
+            group_secrets.len(),
+            group_threshold
+        )));
+    }
+    let secret = reconstruct_secret(&group_secrets[..group_threshold])?;
+    get_seed_phrase_for_element(&secret, word_list)
+}
+
+/// The function is called to create SLIP-0039 shares of a master secret.
+///
+/// The master secret is encrypted with the passphrase and secret-shared among groups, see
+/// [slip39](crate::slip39). The shares are returned ordered by group and member and can be
+/// restored on hardware wallets that support SLIP-0039 or reconstructed using
+/// [reconstruct_slip39_master_secret](crate::reconstruct_slip39_master_secret).
+///
+/// * `master_secret` - The master secret, consisting of an even number of at least 16 bytes.
+/// * `passphrase` - The passphrase, consisting of printable ASCII characters.
+/// * `group_threshold` - The number of groups required for the reconstruction.
+/// * `groups` - The member threshold and the number of members of each group.
+pub fn create_slip39_shares(
+    master_secret: &[u8],
+    passphrase: &str,
+    group_threshold: usize,
+    groups: &[(usize, usize)],
+) -> HarpoResult<Vec<Slip39Share>> {
+    trace_span!("split_slip39");
+    trace_event!(
+        "split_slip39.parameters",
+        "group_threshold" => group_threshold,
+        "num_groups" => groups.len(),
+    );
+    split_master_secret(master_secret, passphrase, group_threshold, groups)
+}
+
+/// The function is called to reconstruct a master secret from SLIP-0039 shares.
+///
+/// The shares can stem from any implementation of SLIP-0039, e.g., the Shamir backup of a
+/// hardware wallet. Surplus shares are ignored. Note that a wrong passphrase cannot be detected
+/// and results in a different master secret.
+///
+/// * `shares` - The SLIP-0039 shares.
+/// * `passphrase` - The passphrase.
+pub fn reconstruct_slip39_master_secret(
+    shares: &[Slip39Share],
+    passphrase: &str,
+) -> HarpoResult<Vec<u8>> {
+    trace_span!("reconstruct_slip39");
+    trace_event!("reconstruct_slip39.parameters", "num_shares" => shares.len());
+    Ok(combine_shares(shares, passphrase)?.to_vec())
+}
+
+/// The function is called to create SLIP-0039 shares of a seed phrase.
+///
+/// The entropy of the seed phrase is used as the master secret without a passphrase. The seed
+/// phrase can be reconstructed using
+/// [reconstruct_seed_phrase_from_slip39_shares](crate::reconstruct_seed_phrase_from_slip39_shares).
+/// Note that a hardware wallet that restores the shares uses the master secret directly as the
+/// BIP-0032 seed and therefore derives a different wallet than the seed phrase.
+///
+/// * `seed_phrase` - The input seed phrase.
+/// * `group_threshold` - The number of groups required for the reconstruction.
+/// * `groups` - The member threshold and the number of members of each group.
+pub fn create_slip39_shares_for_seed_phrase(
+    seed_phrase: &SeedPhrase,
+    group_threshold: usize,
+    groups: &[(usize, usize)],
+) -> HarpoResult<Vec<Slip39Share>> {
+    create_slip39_shares_for_seed_phrase_for_word_list(
+        seed_phrase,
+        group_threshold,
+        groups,
+        DEFAULT_WORD_LIST,
+    )
+}
+
+/// The function is called to create SLIP-0039 shares of a seed phrase using the given word list.
+///
+/// The function behaves like
+/// [create_slip39_shares_for_seed_phrase](crate::create_slip39_shares_for_seed_phrase) but uses
+/// the given word list.
+///
+/// * `seed_phrase` - The input seed phrase.
+/// * `group_threshold` - The number of groups required for the reconstruction.
+/// * `groups` - The member threshold and the number of members of each group.
+/// * `word_list` - The word list of the seed phrase.
+pub fn create_slip39_shares_for_seed_phrase_for_word_list(
+    seed_phrase: &SeedPhrase,
+    group_threshold: usize,
+    groups: &[(usize, usize)],
+    word_list: &(impl WordList + ?Sized),
+) -> HarpoResult<Vec<Slip39Share>> {
+    let word_list = word_list.get_words();
+    let entropy = Zeroizing::new(seed_phrase.to_entropy_for_word_list(word_list)?);
+    create_slip39_shares(&entropy, "", group_threshold, groups)
+}
+
+/// The function is called to reconstruct a seed phrase from SLIP-0039 shares.
+///
+/// The reconstructed master secret without a passphrase is used as the entropy of the seed
+/// phrase. An error is returned if the length of the master secret is not a valid entropy
+/// length.
+///
+/// * `shares` - The SLIP-0039 shares.
+pub fn reconstruct_seed_phrase_from_slip39_shares(shares: &[Slip39Share]) -> SeedPhraseResult {
+    reconstruct_seed_phrase_from_slip39_shares_for_word_list(shares, DEFAULT_WORD_LIST)
+}
+
+/// The function is called to reconstruct a seed phrase from SLIP-0039 shares using the given
+/// word list.
+///
+/// The function behaves like
+/// [reconstruct_seed_phrase_from_slip39_shares](crate::reconstruct_seed_phrase_from_slip39_shares)
+/// but uses the given word list.
+///
+/// * `shares` - The SLIP-0039 shares.
+/// * `word_list` - The word list of the seed phrase.
+pub fn reconstruct_seed_phrase_from_slip39_shares_for_word_list(
+    shares: &[Slip39Share],
+    word_list: &(impl WordList + ?Sized),
+) -> SeedPhraseResult {
+    let word_list = word_list.get_words();
+    let entropy = Zeroizing::new(reconstruct_slip39_master_secret(shares, "")?);
+    get_seed_phrase_for_entropy(&entropy, word_list)
+}
+
+/// The function is called to split a seed phrase into Seed XOR parts.
+///
+/// Unlike secret-shared seed phrases, the parts are regular BIP-0039-compliant seed phrases of
+/// the same length and all of them are required to reconstruct the seed phrase: All parts but
+/// the last are random and the entropy of the last part is the XOR of the entropies of the seed
+/// phrase and the other parts. The parts are compatible with the Seed XOR feature of the
+/// Coldcard hardware wallet and can be combined using
+/// [reconstruct_seed_phrase_from_xor_parts](crate::reconstruct_seed_phrase_from_xor_parts).
+///
+/// * `seed_phrase` - The input seed phrase.
+/// * `num_parts` - The number of parts, either 2 or 3.
+pub fn create_seed_xor_parts(
+    seed_phrase: &SeedPhrase,
+    num_parts: usize,
+) -> HarpoResult<Vec<SeedPhrase>> {
+    create_seed_xor_parts_for_word_list(seed_phrase, num_parts, DEFAULT_WORD_LIST)
+}
+
+/// The function is called to split a seed phrase into Seed XOR parts using the given word list.
+///
+/// The function behaves like [create_seed_xor_parts](crate::create_seed_xor_parts) but uses the
+/// given word list.
+///
+/// * `seed_phrase` - The input seed phrase.
+/// * `num_parts` - The number of parts, either 2 or 3.
+/// * `word_list` - The word list for the seed phrases.
+pub fn create_seed_xor_parts_for_word_list(
+    seed_phrase: &SeedPhrase,
+    num_parts: usize,
+    word_list: &(impl WordList + ?Sized),
+) -> HarpoResult<Vec<SeedPhrase>> {
+    let word_list = word_list.get_words();
+    trace_span!("split_seed_xor");
+    trace_event!("split_seed_xor.parameters", "num_parts" => num_parts);
+    seed_xor::split_seed_phrase(seed_phrase, num_parts, word_list)
+}
+
+/// The function is called to reconstruct a seed phrase from all of its Seed XOR parts.
+///
+/// The order of the parts does not matter and, for compatibility with Coldcard, more than three
+/// parts are accepted as well. Note that a missing part cannot be detected: the XOR of any subset
+/// of the parts is a valid seed phrase as well.
+///
+/// * `parts` - The Seed XOR parts.
+pub fn reconstruct_seed_phrase_from_xor_parts(parts: &[SeedPhrase]) -> SeedPhraseResult {
+    reconstruct_seed_phrase_from_xor_parts_for_word_list(parts, DEFAULT_WORD_LIST)
+}
+
+/// The function is called to reconstruct a seed phrase from all of its Seed XOR parts using the
+/// given word list.
+///
+/// The function behaves like
+/// [reconstruct_seed_phrase_from_xor_parts](crate::reconstruct_seed_phrase_from_xor_parts) but
+/// uses the given word list.
+///
+/// * `parts` - The Seed XOR parts.
+/// * `word_list` - The word list for the seed phrases.
+pub fn reconstruct_seed_phrase_from_xor_parts_for_word_list(
+    parts: &[SeedPhrase],
+    word_list: &(impl WordList + ?Sized),
+) -> SeedPhraseResult {
+    let word_list = word_list.get_words();
+    trace_span!("reconstruct_seed_xor");
+    trace_event!("reconstruct_seed_xor.parameters", "num_parts" => parts.len());
+    seed_xor::combine_parts(parts, word_list)
+}
+
+/// The function is called to create weighted shares.
+///
+/// Each holder receives a [WeightedShare](crate::weighted_sharing::WeightedShare) that bundles
+/// as many secret-shared seed phrases of the same set as the weight of the holder. The seed
+/// phrase can be reconstructed using
+/// [reconstruct_seed_phrase_from_weighted_shares](crate::reconstruct_seed_phrase_from_weighted_shares)
+/// once the weights of the participating holders add up to the threshold.
+///
+/// * `seed_phrase` - The input seed phrase.
+/// * `threshold` - The threshold, i.e., the required total weight.
+/// * `weights` - The weight of each holder.
+/// * `embed_indices` - Flag indicating whether seed phrase indices should be embedded.
+pub fn create_weighted_shares(
+    seed_phrase: &SeedPhrase,
+    threshold: usize,
+    weights: &[usize],
+    embed_indices: bool,
+) -> HarpoResult<Vec<WeightedShare>> {
+    create_weighted_shares_for_word_list(
+        seed_phrase,
+        threshold,
+        weights,
+        embed_indices,
+        DEFAULT_WORD_LIST,
+    )
+}
+
+/// The function is called to create weighted shares using the given word list.
+///
+/// The function behaves like [create_weighted_shares](crate::create_weighted_shares) but uses
+/// the given word list.
+///
+/// * `seed_phrase` - The input seed phrase.
+/// * `threshold` - The threshold, i.e., the required total weight.
+/// * `weights` - The weight of each holder.
+/// * `embed_indices` - Flag indicating whether seed phrase indices should be embedded.
+/// * `word_list` - The word list for the seed phrases.
+pub fn create_weighted_shares_for_word_list(
+    seed_phrase: &SeedPhrase,
+    threshold: usize,
+    weights: &[usize],
+    embed_indices: bool,
+    word_list: &(impl WordList + ?Sized),
+) -> HarpoResult<Vec<WeightedShare>> {
+    if weights.contains(&0) {
+        return Err(HarpoError::InvalidParameter(
+            "The weight of each holder must be at least 1.".to_string(),
+        ));
+    }
+    let num_shares = weights.iter().sum();
+    let mut seed_phrases = create_secret_shared_seed_phrases_for_word_list(
+        seed_phrase,
+        threshold,
+        num_shares,
+        embed_indices,
+        word_list,
+    )?
+    .into_iter();
+    weights
+        .iter()
+        .map(|weight| WeightedShare::new(seed_phrases.by_ref().take(*weight).collect()))
+        .collect()
+}
+
+/// The function is called to reconstruct a seed phrase from weighted shares.
+///
+/// The secret-shared seed phrases of all weighted shares are combined, see
+/// [reconstruct_seed_phrase](crate::reconstruct_seed_phrase).
+///
+/// * `weighted_shares` - The weighted shares.
+pub fn reconstruct_seed_phrase_from_weighted_shares(
+    weighted_shares: &[WeightedShare],
+) -> SeedPhraseResult {
+    reconstruct_seed_phrase_from_weighted_shares_for_word_list(weighted_shares, DEFAULT_WORD_LIST)
+}
+
+/// The function is called to reconstruct a seed phrase from weighted shares using the given
+/// word list.
+///
+/// The secret-shared seed phrases of all weighted shares are combined, see
+/// [reconstruct_seed_phrase_for_word_list](crate::reconstruct_seed_phrase_for_word_list).
+///
+/// * `weighted_shares` - The weighted shares.
+/// * `word_list` - The word list for the seed phrases.
+pub fn reconstruct_seed_phrase_from_weighted_shares_for_word_list(
+    weighted_shares: &[WeightedShare],
+    word_list: &(impl WordList + ?Sized),
+) -> SeedPhraseResult {
+    let seed_phrases: Vec<SeedPhrase> = weighted_shares
+        .iter()
+        .flat_map(|weighted_share| weighted_share.get_seed_phrases().iter().cloned())
+        .collect();
+    reconstruct_seed_phrase_for_word_list(&seed_phrases, word_list)
+}
+
+/// The function is called to secret-share an arbitrary byte array.
+///
+/// Unlike seed phrases, the secret can have any length up to
+/// [MAX_SECRET_LENGTH](crate::byte_sharing::MAX_SECRET_LENGTH) bytes. The length of the secret
+/// is encoded in the secret shares and restored by
+/// [reconstruct_bytes](crate::reconstruct_bytes). Note that the length of the secret shares
+/// reveals the approximate length of the secret.
+///
+/// * `secret` - The secret.
+/// * `threshold` - The threshold.
+/// * `num_shares` - The number of secret shares.
+pub fn split_bytes(
+    secret: &[u8],
+    threshold: usize,
+    num_shares: usize,
+) -> HarpoResult<Vec<ByteShare>> {
+    split_bytes_with_backend(secret, threshold, num_shares, SharingBackend::PrimeField)
+}
+
+/// The function is called to secret-share an arbitrary byte array over the given finite field.
+///
+/// Over GF(2^8), each byte is shared independently as in SLIP-0039, i.e., the secret shares
+/// have exactly the length of the secret and at most
+/// [MAX_GF256_SHARES](crate::byte_sharing::MAX_GF256_SHARES) secret shares can be created.
+/// The finite field is stored in the secret shares so that
+/// [reconstruct_bytes](crate::reconstruct_bytes) can be used for both finite fields.
+///
+/// * `secret` - The secret.
+/// * `threshold` - The threshold.
+/// * `num_shares` - The number of secret shares.
+/// * `backend` - The finite field over which the secret is shared.
+pub fn split_bytes_with_backend(
+    secret: &[u8],
+    threshold: usize,
+    num_shares: usize,
+    backend: SharingBackend,
+) -> HarpoResult<Vec<ByteShare>> {
+    trace_span!("split_bytes");
+    trace_event!(
+        "split_bytes.parameters",
+        "threshold" => threshold,
+        "num_shares" => num_shares,
+    );
+    byte_sharing::split_bytes(secret, threshold, num_shares, backend)
+}
+
+/// The function is called to reconstruct a byte array from the given secret shares.
+///
+/// At least the threshold number of secret shares created by
+/// [split_bytes](crate::split_bytes) or [split_bytes_with_backend](crate::split_bytes_with_backend)
+/// must be provided. If too few or inconsistent secret shares over the prime field are provided,
+/// an error is returned with overwhelming probability. Over GF(2^8), such secret shares result in
+/// a wrong secret.
+///
+/// * `byte_shares` - The secret shares.
+pub fn reconstruct_bytes(byte_shares: &[ByteShare]) -> HarpoResult<Vec<u8>> {
+    trace_span!("reconstruct_bytes");
+    trace_event!("reconstruct_bytes.parameters", "num_shares" => byte_shares.len());
+    byte_sharing::reconstruct_bytes(byte_shares)
+}
+
+/// The function is called to secret-share a string, e.g., a password.
+///
+/// The string is secret-shared in the form of its UTF-8 encoding using
+/// [split_bytes](crate::split_bytes), which preserves the exact length of the string.
+///
+/// * `secret` - The secret string.
+/// * `threshold` - The threshold.
+/// * `num_shares` - The number of secret shares.
+pub fn split_string(
+    secret: &str,
+    threshold: usize,
+    num_shares: usize,
+) -> HarpoResult<Vec<ByteShare>> {
+    split_bytes(secret.as_bytes(), threshold, num_shares)
+}
+
+/// The function is called to reconstruct a string from the given secret shares.
+///
+/// The secret shares must have been created using [split_string](crate::split_string). An
+/// error is returned if the reconstructed bytes are not a valid UTF-8 encoding.
+///
+/// * `byte_shares` - The secret shares.
+pub fn reconstruct_string(byte_shares: &[ByteShare]) -> HarpoResult<String> {
+    String::from_utf8(reconstruct_bytes(byte_shares)?).map_err(|_| {
+        HarpoError::InvalidParameter("The reconstructed secret is not a valid string.".to_string())
+    })
+}
+
+/// The function is called to create secret shares in the format of the `ssss-split` tool.
+///
+/// The security level is 8 bits per byte of the secret, which corresponds to the default of
+/// `ssss-split` for a secret entered as text, see [ssss](crate::ssss). The secret shares can be
+/// reconstructed using `ssss-combine` or [reconstruct_ssss_secret](crate::reconstruct_ssss_secret).
+///
+/// * `secret` - The secret, consisting of at most 128 bytes.
+/// * `threshold` - The threshold, which must be at least 2.
+/// * `num_shares` - The number of secret shares.
+/// * `token` - The optional token that precedes the index of each secret share.
+/// * `diffusion` - Flag indicating whether the diffusion layer is applied, which is the default
+///   of `ssss-split` (option `-D` disables it).
+pub fn create_ssss_shares(
+    secret: &[u8],
+    threshold: usize,
+    num_shares: usize,
+    token: Option<&str>,
+    diffusion: bool,
+) -> HarpoResult<Vec<SsssShare>> {
+    trace_span!("split_ssss");
+    trace_event!(
+        "split_ssss.parameters",
+        "threshold" => threshold,
+        "num_shares" => num_shares,
+    );
+    ssss::split_secret(secret, threshold, num_shares, token, diffusion)
+}
+
+/// The function is called to reconstruct a secret from secret shares in the format of `ssss`.
+///
+/// Since the secret shares do not record the threshold, it must be provided, and the first
+/// `threshold` secret shares are used. The secret is returned as a big-endian byte array whose
+/// length corresponds to the security level, which matches the output of `ssss-combine -x`.
+/// Inconsistent secret shares or a wrong threshold result in a wrong secret.
+///
+/// * `shares` - The secret shares.
+/// * `threshold` - The threshold.
+/// * `diffusion` - Flag indicating whether the diffusion layer was applied.
+pub fn reconstruct_ssss_secret(
+    shares: &[SsssShare],
+    threshold: usize,
+    diffusion: bool,
+) -> HarpoResult<Vec<u8>> {
+    trace_span!("reconstruct_ssss");
+    trace_event!("reconstruct_ssss.parameters", "num_shares" => shares.len());
+    Ok(ssss::combine_shares(shares, threshold, diffusion)?.to_vec())
+}
+
+/// The function is called to reconstruct a text secret from secret shares in the format of
+/// `ssss`.
+///
+/// As in `ssss-combine`, leading zero bytes are removed, which occur if the security level
+/// exceeds the length of the text. An error is returned if the remaining bytes are not a valid
+/// UTF-8 encoding.
+///
+/// * `shares` - The secret shares.
+/// * `threshold` - The threshold.
+/// * `diffusion` - Flag indicating whether the diffusion layer was applied.
+pub fn reconstruct_ssss_string(
+    shares: &[SsssShare],
+    threshold: usize,
+    diffusion: bool,
+) -> HarpoResult<String> {
+    let secret = Zeroizing::new(reconstruct_ssss_secret(shares, threshold, diffusion)?);
+    let start = secret
+        .iter()
+        .position(|byte| *byte != 0)
+        .unwrap_or(secret.len());
+    String::from_utf8(secret[start..].to_vec()).map_err(|_| {
+        HarpoError::InvalidParameter("The reconstructed secret is not a valid string.".to_string())
+    })
+}
+
+/// The number of words of the secret-shared seed phrases that encode a private key.
+const NUM_WORDS_FOR_KEY: usize = 24;
+
+/// The function is called to create secret-shared seed phrases for an Ethereum private key.
+///
+/// The private key is secret-shared over the 256-bit finite field. Each secret-shared seed phrase
+/// consists of 24 words and can be handled like any other secret-shared seed phrase, e.g., it
+/// can be printed, checked, and reconstructed using
+/// [reconstruct_ethereum_private_key](crate::reconstruct_ethereum_private_key).
+///
+/// * `private_key` - The Ethereum private key.
+/// * `threshold` - The threshold.
+/// * `num_shares` - The number of secret-shared seed phrases.
+/// * `index_encoding` - The encoding of the seed phrase indices.
+pub fn split_ethereum_private_key(
+    private_key: &EthereumPrivateKey,
+    threshold: usize,
+    num_shares: usize,
+    index_encoding: IndexEncoding,
+) -> HarpoResult<Vec<SeedPhrase>> {
+    split_ethereum_private_key_for_word_list(
+        private_key,
+        threshold,
+        num_shares,
+        index_encoding,
+        DEFAULT_WORD_LIST,
+    )
+}
+
+/// The function is called to create secret-shared seed phrases for an Ethereum private key for
+/// the given word list.
+///
+/// The private key is secret-shared over the 256-bit finite field. Each secret-shared seed phrase
+/// consists of 24 words and can be handled like any other secret-shared seed phrase, e.g., it
+/// can be printed, checked, and reconstructed using
+/// [reconstruct_ethereum_private_key_for_word_list](crate::reconstruct_ethereum_private_key_for_word_list).
+///
+/// * `private_key` - The Ethereum private key.
+/// * `threshold` - The threshold.
+/// * `num_shares` - The number of secret-shared seed phrases.
+/// * `index_encoding` - The encoding of the seed phrase indices.
+/// * `word_list` - The word list for the seed phrases.
+pub fn split_ethereum_private_key_for_word_list(
+    private_key: &EthereumPrivateKey,
+    threshold: usize,
+    num_shares: usize,
+    index_encoding: IndexEncoding,
+    word_list: &(impl WordList + ?Sized),
+) -> HarpoResult<Vec<SeedPhrase>> {
+    let word_list = word_list.get_words();
+    split_key_bytes(
+        private_key.get_bytes(),
+        threshold,
+        num_shares,
+        index_encoding,
+        word_list,
+    )
+}
+
+/// The function is called to reconstruct an Ethereum private key.
+///
+/// Given a list of secret-shared seed phrases created using
+/// [split_ethereum_private_key](crate::split_ethereum_private_key), the function reconstructs
+/// the Ethereum private key. The address of the returned key, available through
+/// [get_address](crate::ethereum::EthereumPrivateKey::get_address), can be used to confirm that
+/// the expected key was reconstructed.
+///
+/// * `seed_phrases` - The input seed phrases.
+pub fn reconstruct_ethereum_private_key(
+    seed_phrases: &[SeedPhrase],
+) -> HarpoResult<EthereumPrivateKey> {
+    reconstruct_ethereum_private_key_for_word_list(seed_phrases, DEFAULT_WORD_LIST)
+}
+
+/// The function is called to reconstruct an Ethereum private key for the given word list.
+///
+/// Given a list of secret-shared seed phrases created using
+/// [split_ethereum_private_key_for_word_list](crate::split_ethereum_private_key_for_word_list),
+/// the function reconstructs the Ethereum private key. The address of the returned key,
+/// available through [get_address](crate::ethereum::EthereumPrivateKey::get_address), can be
+/// used to confirm that the expected key was reconstructed.
+///
+/// * `seed_phrases` - The input seed phrases.
+/// * `word_list` - The word list for the seed phrases.
+pub fn reconstruct_ethereum_private_key_for_word_list(
+    seed_phrases: &[SeedPhrase],
+    word_list: &(impl WordList + ?Sized),
+) -> HarpoResult<EthereumPrivateKey> {
+    let word_list = word_list.get_words();
+    EthereumPrivateKey::new(reconstruct_key_bytes(seed_phrases, word_list)?)
+}
+
+/// The function is called to create secret-shared seed phrases for a Bitcoin private key in the
+/// wallet import format (WIF).
+///
+/// The private key is secret-shared in the same way as an Ethereum private key, see
+/// [split_ethereum_private_key](crate::split_ethereum_private_key). The network and the
+/// compression flag are not encoded in the secret-shared seed phrases and must be provided again
+/// when reconstructing the key using
+/// [reconstruct_wif_private_key](crate::reconstruct_wif_private_key).
+///
+/// * `private_key` - The WIF private key.
+/// * `threshold` - The threshold.
+/// * `num_shares` - The number of secret-shared seed phrases.
+/// * `index_encoding` - The encoding of the seed phrase indices.
+pub fn split_wif_private_key(
+    private_key: &WifPrivateKey,
+    threshold: usize,
+    num_shares: usize,
+    index_encoding: IndexEncoding,
+) -> HarpoResult<Vec<SeedPhrase>> {
+    split_wif_private_key_for_word_list(
+        private_key,
+        threshold,
+        num_shares,
+        index_encoding,
+        DEFAULT_WORD_LIST,
+    )
+}
+
+/// The function is called to create secret-shared seed phrases for a Bitcoin private key in the
+/// wallet import format (WIF) for the given word list.
+///
+/// The network and the compression flag are not encoded in the secret-shared seed phrases and
+/// must be provided again when reconstructing the key using
+/// [reconstruct_wif_private_key_for_word_list](crate::reconstruct_wif_private_key_for_word_list).
+///
+/// * `private_key` - The WIF private key.
+/// * `threshold` - The threshold.
+/// * `num_shares` - The number of secret-shared seed phrases.
+/// * `index_encoding` - The encoding of the seed phrase indices.
+/// * `word_list` - The word list for the seed phrases.
+pub fn split_wif_private_key_for_word_list(
+    private_key: &WifPrivateKey,
+    threshold: usize,
+    num_shares: usize,
+    index_encoding: IndexEncoding,
+    word_list: &(impl WordList + ?Sized),
+) -> HarpoResult<Vec<SeedPhrase>> {
+    let word_list = word_list.get_words();
+    split_key_bytes(
+        private_key.get_bytes(),
+        threshold,
+        num_shares,
+        index_encoding,
+        word_list,
+    )
+}
+
+/// The function is called to reconstruct a Bitcoin private key in the wallet import format
+/// (WIF).
+///
+/// Given a list of secret-shared seed phrases created using
+/// [split_wif_private_key](crate::split_wif_private_key), the function reconstructs the private
+/// key and re-emits it for the given network and compression flag.
+///
+/// * `seed_phrases` - The input seed phrases.
+/// * `network` - The network.
+/// * `compressed` - Flag indicating whether the corresponding public key is compressed.
+pub fn reconstruct_wif_private_key(
+    seed_phrases: &[SeedPhrase],
+    network: Network,
+    compressed: bool,
+) -> HarpoResult<WifPrivateKey> {
+    reconstruct_wif_private_key_for_word_list(seed_phrases, network, compressed, DEFAULT_WORD_LIST)
+}
+
+/// The function is called to reconstruct a Bitcoin private key in the wallet import format
+/// (WIF) for the given word list.
+///
+/// Given a list of secret-shared seed phrases created using
+/// [split_wif_private_key_for_word_list](crate::split_wif_private_key_for_word_list), the
+/// function reconstructs the private key and re-emits it for the given network and compression
+/// flag.
+///
+/// * `seed_phrases` - The input seed phrases.
+/// * `network` - The network.
+/// * `compressed` - Flag indicating whether the corresponding public key is compressed.
+/// * `word_list` - The word list for the seed phrases.
+pub fn reconstruct_wif_private_key_for_word_list(
+    seed_phrases: &[SeedPhrase],
+    network: Network,
+    compressed: bool,
+    word_list: &(impl WordList + ?Sized),
+) -> HarpoResult<WifPrivateKey> {
+    let word_list = word_list.get_words();
+    WifPrivateKey::new(
+        reconstruct_key_bytes(seed_phrases, word_list)?,
+        network,
+        compressed,
+    )
+}
+
+/// The function is called to secret-share the complete serialization of a Bitcoin private key
+/// in the wallet import format (WIF).
+///
+/// Unlike [split_wif_private_key](crate::split_wif_private_key), the function secret-shares the
+/// version byte and the compression flag together with the private key using
+/// [split_bytes](crate::split_bytes). Therefore,
+/// [reconstruct_serialized_wif_private_key](crate::reconstruct_serialized_wif_private_key)
+/// returns exactly the original WIF private key, including its network, without any additional
+/// input. The secret shares are not encoded as seed phrases.
+///
+/// * `private_key` - The WIF private key.
+/// * `threshold` - The threshold.
+/// * `num_shares` - The number of secret shares.
+pub fn split_serialized_wif_private_key(
+    private_key: &WifPrivateKey,
+    threshold: usize,
+    num_shares: usize,
+) -> HarpoResult<Vec<ByteShare>> {
+    let serialized_key = Zeroizing::new(private_key.get_serialized_bytes());
+    split_bytes(&serialized_key, threshold, num_shares)
+}
+
+/// The function is called to reconstruct a Bitcoin private key in the wallet import format
+/// (WIF) from the secret shares of its serialization.
+///
+/// The secret shares must have been created using
+/// [split_serialized_wif_private_key](crate::split_serialized_wif_private_key). An error is
+/// returned if the reconstructed bytes are not a valid WIF private key.
+///
+/// * `byte_shares` - The secret shares.
+pub fn reconstruct_serialized_wif_private_key(
+    byte_shares: &[ByteShare],
+) -> HarpoResult<WifPrivateKey> {
+    let serialized_key = Zeroizing::new(reconstruct_bytes(byte_shares)?);
+    WifPrivateKey::from_serialized_bytes(&serialized_key)
+}
+
+/// The function is called to secret-share a Solana private key.
+///
+/// The Ed25519 secret key is secret-shared together with its encoding using
+/// [split_bytes](crate::split_bytes), so that
+/// [reconstruct_solana_private_key](crate::reconstruct_solana_private_key) returns the key in
+/// its original encoding, i.e., as a Base58-encoded keypair or as a raw hex-encoded secret key.
+/// The secret shares are not encoded as seed phrases.
+///
+/// * `private_key` - The Solana private key.
+/// * `threshold` - The threshold.
+/// * `num_shares` - The number of secret shares.
+pub fn split_solana_private_key(
+    private_key: &SolanaPrivateKey,
+    threshold: usize,
+    num_shares: usize,
+) -> HarpoResult<Vec<ByteShare>> {
+    let serialized_key = Zeroizing::new(private_key.get_serialized_bytes());
+    split_bytes(&serialized_key, threshold, num_shares)
+}
+
+/// The function is called to reconstruct a Solana private key.
+///
+/// The secret shares must have been created using
+/// [split_solana_private_key](crate::split_solana_private_key). The address of the returned
+/// key, available through [get_address](crate::solana::SolanaPrivateKey::get_address), can be
+/// used to confirm that the expected key was reconstructed.
+///
+/// * `byte_shares` - The secret shares.
+pub fn reconstruct_solana_private_key(byte_shares: &[ByteShare]) -> HarpoResult<SolanaPrivateKey> {
+    let serialized_key = Zeroizing::new(reconstruct_bytes(byte_shares)?);
+    SolanaPrivateKey::from_serialized_bytes(&serialized_key)
+}
+
+/// The function is called to create secret-shared seed phrases for an extended private key
+/// (xprv).
+///
+/// The chain code and the private key are secret-shared separately. The function returns pairs
+/// of secret-shared seed phrases, where the first seed phrase of each pair encodes a share of the
+/// chain code and the second seed phrase encodes a share of the private key. Both seed phrases of
+/// a pair must be kept together. The metadata of the extended key, available through
+/// [get_metadata](crate::bitcoin::ExtendedPrivateKey::get_metadata), is not secret and must be
+/// provided again when reconstructing the key using
+/// [reconstruct_extended_private_key](crate::reconstruct_extended_private_key).
+///
+/// * `private_key` - The extended private key.
+/// * `threshold` - The threshold.
+/// * `num_shares` - The number of pairs of secret-shared seed phrases.
+/// * `index_encoding` - The encoding of the seed phrase indices.
+pub fn split_extended_private_key(
+    private_key: &ExtendedPrivateKey,
+    threshold: usize,
+    num_shares: usize,
+    index_encoding: IndexEncoding,
+) -> HarpoResult<Vec<(SeedPhrase, SeedPhrase)>> {
+    split_extended_private_key_for_word_list(
+        private_key,
+        threshold,
+        num_shares,
+        index_encoding,
+        DEFAULT_WORD_LIST,
+    )
+}
+
+/// The function is called to create secret-shared seed phrases for an extended private key
+/// (xprv) for the given word list.
+///
+/// The first seed phrase of each returned pair encodes a share of the chain code and the second
+/// seed phrase encodes a share of the private key. The metadata of the extended key must be
+/// provided again when reconstructing the key using
+/// [reconstruct_extended_private_key_for_word_list](crate::reconstruct_extended_private_key_for_word_list).
+///
+/// * `private_key` - The extended private key.
+/// * `threshold` - The threshold.
+/// * `num_shares` - The number of pairs of secret-shared seed phrases.
+/// * `index_encoding` - The encoding of the seed phrase indices.
+/// * `word_list` - The word list for the seed phrases.
+pub fn split_extended_private_key_for_word_list(
+    private_key: &ExtendedPrivateKey,
+    threshold: usize,
+    num_shares: usize,
+    index_encoding: IndexEncoding,
+    word_list: &(impl WordList + ?Sized),
+) -> HarpoResult<Vec<(SeedPhrase, SeedPhrase)>> {
+    let word_list = word_list.get_words();
+    let chain_code_shares = split_key_bytes(
+        private_key.get_chain_code(),
+        threshold,
+        num_shares,
+        index_encoding,
+        word_list,
+    )?;
+    let key_shares = split_key_bytes(
+        private_key.get_key(),
+        threshold,
+        num_shares,
+        index_encoding,
+        word_list,
+    )?;
+    Ok(chain_code_shares.into_iter().zip(key_shares).collect())
+}
+
+/// The function is called to reconstruct an extended private key (xprv).
+///
+/// Given a list of pairs of secret-shared seed phrases created using
+/// [split_extended_private_key](crate::split_extended_private_key), the function reconstructs
+/// the chain code and the private key and re-emits the extended private key with the given
+/// metadata.
+///
+/// * `shares` - The pairs of secret-shared seed phrases for the chain code and the private key.
+/// * `metadata` - The metadata of the extended key.
+pub fn reconstruct_extended_private_key(
+    shares: &[(SeedPhrase, SeedPhrase)],
+    metadata: &ExtendedKeyMetadata,
+) -> HarpoResult<ExtendedPrivateKey> {
+    reconstruct_extended_private_key_for_word_list(shares, metadata, DEFAULT_WORD_LIST)
+}
+
+/// The function is called to reconstruct an extended private key (xprv) for the given word
+/// list.
+///
+/// Given a list of pairs of secret-shared seed phrases created using
+/// [split_extended_private_key_for_word_list](crate::split_extended_private_key_for_word_list),
+/// the function reconstructs the chain code and the private key and re-emits the extended
+/// private key with the given metadata.
+///
+/// * `shares` - The pairs of secret-shared seed phrases for the chain code and the private key.
+/// * `metadata` - The metadata of the extended key.
+/// * `word_list` - The word list for the seed phrases.
+pub fn reconstruct_extended_private_key_for_word_list(
+    shares: &[(SeedPhrase, SeedPhrase)],
+    metadata: &ExtendedKeyMetadata,
+    word_list: &[&str],
+) -> HarpoResult<ExtendedPrivateKey> {
+    let (chain_code_shares, key_shares): (Vec<SeedPhrase>, Vec<SeedPhrase>) =
+        shares.iter().cloned().unzip();
+    ExtendedPrivateKey::new(
+        *metadata,
+        reconstruct_key_bytes(&chain_code_shares, word_list)?,
+        reconstruct_key_bytes(&key_shares, word_list)?,
+    )
+}
+
+/// The function is called to secret-share the complete serialization of an extended private
+/// key (xprv).
+///
+/// Unlike [split_extended_private_key](crate::split_extended_private_key), the function
+/// secret-shares all 78 bytes of the serialized key, including the metadata, using
+/// [split_bytes](crate::split_bytes). Therefore, no information must be provided again and
+/// [reconstruct_serialized_extended_private_key](crate::reconstruct_serialized_extended_private_key)
+/// returns exactly the original extended private key. Since the serialized key is larger than
+/// any seed phrase, the secret shares are not encoded as seed phrases.
+///
+/// * `private_key` - The extended private key.
+/// * `threshold` - The threshold.
+/// * `num_shares` - The number of secret shares.
+pub fn split_serialized_extended_private_key(
+    private_key: &ExtendedPrivateKey,
+    threshold: usize,
+    num_shares: usize,
+) -> HarpoResult<Vec<ByteShare>> {
+    let serialized_key = Zeroizing::new(private_key.get_serialized_bytes());
+    split_bytes(&serialized_key, threshold, num_shares)
+}
+
+/// The function is called to reconstruct an extended private key (xprv) from the secret shares
+/// of its serialization.
+///
+/// The secret shares must have been created using
+/// [split_serialized_extended_private_key](crate::split_serialized_extended_private_key). An
+/// error is returned if the reconstructed bytes are not a valid extended private key.
+///
+/// * `byte_shares` - The secret shares.
+pub fn reconstruct_serialized_extended_private_key(
+    byte_shares: &[ByteShare],
+) -> HarpoResult<ExtendedPrivateKey> {
+    let serialized_key = Zeroizing::new(reconstruct_bytes(byte_shares)?);
+    ExtendedPrivateKey::from_serialized_bytes(&serialized_key)
+}
+
+/// The function creates secret-shared seed phrases with 24 words for the given 32 bytes.
+///
+/// * `key_bytes` - The bytes in big-endian format.
+/// * `threshold` - The threshold.
+/// * `num_shares` - The number of secret-shared seed phrases.
+/// * `index_encoding` - The encoding of the seed phrase indices.
+/// * `word_list` - The word list for the seed phrases.
+fn split_key_bytes(
+    key_bytes: &[u8; KEY_LENGTH],
+    threshold: usize,
+    num_shares: usize,
+    index_encoding: IndexEncoding,
+    word_list: &[&str],
+) -> HarpoResult<Vec<SeedPhrase>> {
+    validate_word_list(word_list)?;
+    check_create_parameters(NUM_WORDS_FOR_KEY, threshold, num_shares, index_encoding)?;
+    // The unwrap() call is okay because there is a modulus for 24 words.
+    let modulus = get_modulus_for_words(NUM_WORDS_FOR_KEY).unwrap();
+    // Any valid private key is smaller than the modulus but other bytes, e.g., a chain code,
+    // may not be.
+    if BigUint::from_bytes_be(key_bytes) >= modulus.to_big_uint() {
+        return Err(HarpoError::InvalidParameter(
+            "The bytes exceed the modulus of the finite field.".to_string(),
+        ));
+    }
+    // Finite field elements are created from bytes in little-endian format.
+    let mut bytes = key_bytes.to_vec();
+    bytes.reverse();
+    let secret = FiniteFieldElement::new(&bytes, modulus);
+    create_secret_shared_seed_phrases_for_element(
+        &secret,
+        bytes.len() << 3,
+        threshold,
+        num_shares,
+        index_encoding,
+        word_list,
+        &mut OsRng,
+        &mut |_, _| {},
+    )
+}
+
+/// The function reconstructs 32 bytes from secret-shared seed phrases with 24 words.
+///
+/// * `seed_phrases` - The input seed phrases.
+/// * `word_list` - The word list for the seed phrases.
+fn reconstruct_key_bytes(
+    seed_phrases: &[SeedPhrase],
+    word_list: &[&str],
+) -> HarpoResult<[u8; KEY_LENGTH]> {
+    let secret_shares = get_secret_shares_for_seed_phrases(seed_phrases, word_list)?;
+    check_threshold(seed_phrases)?;
+    // The index word is not part of the secret-bearing words.
+    let num_words = match seed_phrases[0].len() {
+        length if has_index_word(length) => length - 1,
+        length => length,
+    };
+    if num_words != NUM_WORDS_FOR_KEY {
+        return Err(HarpoError::InvalidSeedPhrase(format!(
+            "A private key is encoded in seed phrases with {} words.",
+            NUM_WORDS_FOR_KEY
+        )));
+    }
+    let mut bytes = reconstruct_secret(&secret_shares)?.get_bytes();
+    bytes.reverse();
+    let mut key_bytes = [0; KEY_LENGTH];
+    key_bytes.copy_from_slice(&bytes);
+    Ok(key_bytes)
+}
+
+/// The function returns the secret shares encoded in the given secret-shared seed phrases.
+///
+/// The function validates the word list and the seed phrases before extracting the secret
+/// shares.
+///
+/// * `seed_phrases` - The input seed phrases.
+/// * `word_list` - The word list for the seed phrases.
+fn get_secret_shares_for_seed_phrases(
+    seed_phrases: &[SeedPhrase],
+    word_list: &[&str],
+) -> HarpoResult<Vec<SecretShare>> {
+    // Validate the word list.
+    validate_word_list(word_list)?;
+    // Ensure that all seed phrases have the same length and that the length is valid.
+    if seed_phrases.is_empty() {
+        return Err(HarpoError::InvalidSeedPhrase(
+            "No seed phrases provided.".to_string(),
+        ));
+    }
+    // Embedded indices cannot be combined with explicit indices or index words because the
+    // secret-shared seed phrases then stem from different sets.
+    let has_embedded_index = |seed_phrase: &SeedPhrase| {
+        !has_index_word(seed_phrase.len()) && seed_phrase.get_index().is_none()
+    };
+    if seed_phrases.iter().any(has_embedded_index) && !seed_phrases.iter().all(has_embedded_index) {
+        return Err(HarpoError::InvalidSeedPhrase(
+            "Secret-shared seed phrases with embedded indices cannot be combined with \
+            secret-shared seed phrases with explicit indices or index words."
+                .to_string(),
+        ));
+    }
+    // Seed phrases with index words are converted into seed phrases with explicit indices.
+    let seed_phrases = &seed_phrases
+        .iter()
+        .map(|seed_phrase| {
+            if has_index_word(seed_phrase.len()) {
+                remove_index_word(seed_phrase, word_list)
+            } else {
+                Ok(seed_phrase.clone())
+            }
+        })
+        .collect::<HarpoResult<Vec<SeedPhrase>>>()?;
+    let num_words = seed_phrases[0].len();
+    if !is_valid_num_words(num_words) {
+        return Err(HarpoError::WrongWordCount { got: num_words });
+    }
+    if seed_phrases.iter().any(|code| code.len() != num_words) {
+        return Err(HarpoError::LengthMismatchAcrossShares);
+    }
+    // Secret-shared seed phrases with different set identifiers stem from different sets.
+    let mut set_ids = seed_phrases
+        .iter()
+        .enumerate()
+        .filter_map(|(position, seed_phrase)| Some((position, seed_phrase.get_set_id()?)));
+    if let Some((first_position, first_set_id)) = set_ids.next() {
+        if let Some((position, set_id)) = set_ids.find(|(_, set_id)| *set_id != first_set_id) {
+            return Err(HarpoError::InvalidSeedPhrase(format!(
+                "Secret-shared seed phrases {} and {} stem from different sets ({:04x} and \
+                {:04x}).",
+                first_position + 1,
+                position + 1,
+                first_set_id,
+                set_id
+            )));
+        }
+    }
+    // Secret-shared seed phrases with different thresholds stem from different sets as well.
+    let mut thresholds = seed_phrases
+        .iter()
+        .enumerate()
+        .filter_map(|(position, seed_phrase)| Some((position, seed_phrase.get_threshold()?)));
+    if let Some((first_position, first_threshold)) = thresholds.next() {
+        if let Some((position, threshold)) =
+            thresholds.find(|(_, threshold)| *threshold != first_threshold)
+        {
+            return Err(HarpoError::InvalidSeedPhrase(format!(
+                "Secret-shared seed phrases {} and {} have different thresholds ({} and {}).",
+                first_position + 1,
+                position + 1,
+                first_threshold,
+                threshold
+            )));
+        }
+    }
+    // Ensure that there is an index for each seed phrase if indices cannot be embedded.
+    if !can_embed_index(num_words) && seed_phrases.iter().any(|code| code.get_index().is_none()) {
+        return Err(HarpoError::InvalidSeedPhrase(
+            "The seed phrases are too short to hold embedded indices.".to_string(),
+        ));
+    }
+    // Ensure that the seed phrases are BIP-0039-compliant if there is no embedding.
+    for seed_phrase in seed_phrases {
+        if seed_phrase.get_index().is_some() && !is_compliant(seed_phrase, word_list) {
+            return Err(HarpoError::InvalidSeedPhrase(format!(
+                "Seed phrase is not BIP-0039-compliant: {}",
+                seed_phrase
+            )));
+        }
+    }
+    // Get the corresponding secret shares and reject invalid or colliding indices.
+    let mut secret_shares = vec![];
+    let mut positions = HashMap::new();
+    for (position, seed_phrase) in seed_phrases.iter().enumerate() {
+        let (element, index) = get_element_and_index_for_seed_phrase(seed_phrase, word_list)?;
+        if index == 0 {
+            return Err(HarpoError::InvalidSeedPhrase(format!(
+                "Secret-shared seed phrase {} has the invalid index 0.",
+                position + 1
+            )));
+        }
+        if let Some(earlier_position) = positions.insert(index, position) {
+            return Err(HarpoError::InvalidSeedPhrase(format!(
+                "Secret-shared seed phrases {} and {} have the same index {}.",
+                earlier_position + 1,
+                position + 1,
+                index
+            )));
+        }
+        secret_shares.push(SecretShare::new(&element, index));
+    }
+    Ok(secret_shares)
+}
+
+/// The function checks that at least the threshold number of secret-shared seed phrases is
+/// provided if the threshold is recorded in the secret-shared seed phrases.
+///
+/// The secret-shared seed phrases must have distinct indices, which is ensured by
+/// [get_secret_shares_for_seed_phrases].
+///
+/// * `seed_phrases` - The input seed phrases.
+fn check_threshold(seed_phrases: &[SeedPhrase]) -> HarpoResult<()> {
+    match get_recorded_threshold(seed_phrases) {
+        Some(required) if seed_phrases.len() < required => Err(HarpoError::NotEnoughShares {
+            provided: seed_phrases.len(),
+            required,
+        }),
+        _ => Ok(()),
+    }
+}
+
+/// The function returns the highest threshold recorded in the given secret-shared seed phrases,
+/// if any.
+///
+/// * `seed_phrases` - The input seed phrases.
+fn get_recorded_threshold(seed_phrases: &[SeedPhrase]) -> Option<usize> {
+    seed_phrases
+        .iter()
+        .filter_map(SeedPhrase::get_threshold)
+        .max()
+}
+
+/// The function returns the commitment to the polynomial underlying the given seed phrases.
+///
+/// The polynomial is interpolated from the given secret-shared seed phrases. If at least the
+/// threshold number of secret-shared seed phrases is provided, the commitment matches the
+/// commitment obtained when creating the secret-shared seed phrases.
+/// The commitment can be published without revealing any information about the secret.
+///
+/// * `seed_phrases` - The input seed phrases.
+pub fn get_polynomial_commitment(seed_phrases: &[SeedPhrase]) -> HarpoResult<PolynomialCommitment> {
+    get_polynomial_commitment_for_word_list(seed_phrases, DEFAULT_WORD_LIST)
+}
+
+/// The function returns the commitment to the polynomial underlying the given seed phrases.
+///
+/// The polynomial is interpolated from the given secret-shared seed phrases. If at least the
+/// threshold number of secret-shared seed phrases is provided, the commitment matches the
+/// commitment obtained when creating the secret-shared seed phrases.
+/// The commitment can be published without revealing any information about the secret.
+///
+/// * `seed_phrases` - The input seed phrases.
+/// * `word_list` - The word list for the seed phrases.
+pub fn get_polynomial_commitment_for_word_list(
+    seed_phrases: &[SeedPhrase],
+    word_list: &(impl WordList + ?Sized),
+) -> HarpoResult<PolynomialCommitment> {
+    let word_list = word_list.get_words();
+    let secret_shares = get_secret_shares_for_seed_phrases(seed_phrases, word_list)?;
+    let polynomial = interpolate_polynomial(&secret_shares)?;
+    Ok(PolynomialCommitment::new(polynomial.get_commitment()))
+}
+
+/// The function verifies that the given seed phrases match the given polynomial commitment.
+///
+/// The function returns true if the polynomial interpolated from the given secret-shared seed
+/// phrases matches the commitment. In this case, the reconstructed seed phrase is the seed phrase
+/// that was secret-shared when the commitment was created.
+///
+/// * `seed_phrases` - The input seed phrases.
+/// * `commitment` - The polynomial commitment.
+pub fn verify_polynomial_commitment(
+    seed_phrases: &[SeedPhrase],
+    commitment: &PolynomialCommitment,
+) -> HarpoResult<bool> {
+    verify_polynomial_commitment_for_word_list(seed_phrases, commitment, DEFAULT_WORD_LIST)
+}
+
+/// The function verifies that the given seed phrases match the given polynomial commitment.
+///
+/// The function returns true if the polynomial interpolated from the given secret-shared seed
+/// phrases matches the commitment. In this case, the reconstructed seed phrase is the seed phrase
+/// that was secret-shared when the commitment was created.
+///
+/// * `seed_phrases` - The input seed phrases.
+/// * `commitment` - The polynomial commitment.
+/// * `word_list` - The word list for the seed phrases.
+pub fn verify_polynomial_commitment_for_word_list(
+    seed_phrases: &[SeedPhrase],
+    commitment: &PolynomialCommitment,
+    word_list: &(impl WordList + ?Sized),
+) -> HarpoResult<bool> {
+    let word_list = word_list.get_words();
+    trace_span!("verify_commitment");
+    let is_valid = get_polynomial_commitment_for_word_list(seed_phrases, word_list)? == *commitment;
+    trace_event!("verify_commitment.result", "valid" => is_valid);
+    Ok(is_valid)
+}
+
+/// The function returns the verification data for newly created secret-shared seed phrases.
+///
+/// All secret-shared seed phrases of the set must be provided in the order of their indices so
+/// that a commitment to each of them is included.
+///
+/// * `seed_phrases` - The secret-shared seed phrases.
+/// * `threshold` - The threshold.
+/// * `index_encoding` - The encoding of the share indices.
+pub fn get_verification_data(
+    seed_phrases: &[SeedPhrase],
+    threshold: usize,
+    index_encoding: IndexEncoding,
+) -> HarpoResult<VerificationData> {
+    get_verification_data_for_word_list(seed_phrases, threshold, index_encoding, DEFAULT_WORD_LIST)
+}
+
+/// The function returns the verification data for newly created secret-shared seed phrases.
+///
+/// All secret-shared seed phrases of the set must be provided in the order of their indices so
+/// that a commitment to each of them is included.
+///
+/// * `seed_phrases` - The secret-shared seed phrases.
+/// * `threshold` - The threshold.
+/// * `index_encoding` - The encoding of the share indices.
+/// * `word_list` - The word list for the seed phrases.
+pub fn get_verification_data_for_word_list(
+    seed_phrases: &[SeedPhrase],
+    threshold: usize,
+    index_encoding: IndexEncoding,
+    word_list: &(impl WordList + ?Sized),
+) -> HarpoResult<VerificationData> {
+    let word_list = word_list.get_words();
+    if threshold < 1 || seed_phrases.len() < threshold {
+        return Err(HarpoError::NotEnoughShares {
+            provided: seed_phrases.len(),
+            required: threshold.max(1),
+        });
+    }
+    let secret_shares = get_secret_shares_for_seed_phrases(seed_phrases, word_list)?;
+    if secret_shares
+        .iter()
+        .zip(1..)
+        .any(|(share, index)| share.get_index() != index)
+    {
+        return Err(HarpoError::InvalidParameter(
+            "The secret-shared seed phrases must be provided in the order of their indices."
+                .to_string(),
+        ));
+    }
+    let commitment =
+        get_polynomial_commitment_for_word_list(&seed_phrases[..threshold], word_list)?;
+    let num_words = match index_encoding {
+        IndexEncoding::IndexWord => seed_phrases[0].len() - 1,
+        _ => seed_phrases[0].len(),
+    };
+    Ok(VerificationData::new(
+        threshold,
+        num_words,
+        index_encoding,
+        SHARE_FORMAT_VERSION,
+        seed_phrases[0].get_set_id().unwrap_or_default(),
+        commitment,
+        secret_shares
+            .iter()
+            .map(SecretShare::get_commitment)
+            .collect(),
+    ))
+}
+
+/// The function verifies a single secret-shared seed phrase using the given verification data.
+///
+/// The function returns true if the secret-shared seed phrase matches the commitment to the
+/// secret-shared seed phrase with the same index. No other secret-shared seed phrases are
+/// required, so each custodian can verify their secret-shared seed phrase independently.
+///
+/// * `seed_phrase` - The secret-shared seed phrase.
+/// * `verification_data` - The verification data.
+pub fn verify_share(
+    seed_phrase: &SeedPhrase,
+    verification_data: &VerificationData,
+) -> HarpoResult<bool> {
+    verify_share_for_word_list(seed_phrase, verification_data, DEFAULT_WORD_LIST)
+}
+
+/// The function verifies a single secret-shared seed phrase using the given verification data.
+///
+/// The function returns true if the secret-shared seed phrase matches the commitment to the
+/// secret-shared seed phrase with the same index. No other secret-shared seed phrases are
+/// required, so each custodian can verify their secret-shared seed phrase independently.
+///
+/// * `seed_phrase` - The secret-shared seed phrase.
+/// * `verification_data` - The verification data.
+/// * `word_list` - The word list for the seed phrase.
+pub fn verify_share_for_word_list(
+    seed_phrase: &SeedPhrase,
+    verification_data: &VerificationData,
+    word_list: &(impl WordList + ?Sized),
+) -> HarpoResult<bool> {
+    let word_list = word_list.get_words();
+    if seed_phrase
+        .get_set_id()
+        .is_some_and(|set_id| set_id != verification_data.get_set_id())
+    {
+        return Ok(false);
+    }
+    let share =
+        match get_secret_shares_for_seed_phrases(std::slice::from_ref(seed_phrase), word_list)?
+            .pop()
+        {
+            Some(share) => share,
+            None => return Ok(false),
+        };
+    Ok(verification_data.get_share_commitment(share.get_index()) == Some(&share.get_commitment()))
+}
+
+/// The function verifies a set of secret-shared seed phrases using the given verification data.
+///
+/// The function returns true if each secret-shared seed phrase matches its commitment, at least
+/// the threshold number of secret-shared seed phrases is provided, and the polynomial
+/// interpolated from them matches the polynomial commitment. In this case, the reconstructed
+/// seed phrase is the seed phrase that was secret-shared.
+///
+/// * `seed_phrases` - The secret-shared seed phrases.
+/// * `verification_data` - The verification data.
+pub fn verify_set(
+    seed_phrases: &[SeedPhrase],
+    verification_data: &VerificationData,
+) -> HarpoResult<bool> {
+    verify_set_for_word_list(seed_phrases, verification_data, DEFAULT_WORD_LIST)
+}
+
+/// The function verifies a set of secret-shared seed phrases using the given verification data.
+///
+/// The function returns true if each secret-shared seed phrase matches its commitment, at least
+/// the threshold number of secret-shared seed phrases is provided, and the polynomial
+/// interpolated from them matches the polynomial commitment. In this case, the reconstructed
+/// seed phrase is the seed phrase that was secret-shared.
+///
+/// * `seed_phrases` - The secret-shared seed phrases.
+/// * `verification_data` - The verification data.
+/// * `word_list` - The word list for the seed phrases.
+pub fn verify_set_for_word_list(
+    seed_phrases: &[SeedPhrase],
+    verification_data: &VerificationData,
+    word_list: &(impl WordList + ?Sized),
+) -> HarpoResult<bool> {
+    let word_list = word_list.get_words();
+    trace_span!("verify_set");
+    for seed_phrase in seed_phrases {
+        if !verify_share_for_word_list(seed_phrase, verification_data, word_list)? {
+            return Ok(false);
+        }
+    }
+    if seed_phrases.len() < verification_data.get_threshold() {
+        return Err(HarpoError::NotEnoughShares {
+            provided: seed_phrases.len(),
+            required: verification_data.get_threshold(),
+        });
+    }
+    let is_valid = verify_polynomial_commitment_for_word_list(
+        seed_phrases,
+        &verification_data.get_commitment(),
+        word_list,
+    )?;
+    trace_event!("verify_set.result", "valid" => is_valid);
+    Ok(is_valid)
+}
+
+/// The function generates and returns a random seed phrase.
+///
+/// A random, BIP-0039-compliant seed phrase is returned if the requested number of words is
+/// valid.
+///
+/// * `num_words` - The number of words in the seed phrase.
+/// * `word_list` - The word list to be used.
+pub fn generate_seed_phrase_for_word_list(
+    num_words: usize,
+    word_list: &(impl WordList + ?Sized),
+) -> SeedPhraseResult {
+    let word_list = word_list.get_words();
+    generate_seed_phrase_with_rng_for_word_list(num_words, word_list, &mut OsRng)
+}
+
+/// The function generates and returns a random seed phrase using the given random number
+/// generator.
+///
+/// A random, BIP-0039-compliant seed phrase is returned if the requested number of words is
+/// valid. The entropy is drawn from the given cryptographically secure random number generator
+/// instead of the operating system's random number generator.
+///
+/// * `num_words` - The number of words in the seed phrase.
+/// * `rng` - The cryptographically secure random number generator.
+pub fn generate_seed_phrase_with_rng<R: RngCore + CryptoRng>(
+    num_words: usize,
+    rng: &mut R,
+) -> SeedPhraseResult {
+    generate_seed_phrase_with_rng_for_word_list(num_words, DEFAULT_WORD_LIST, rng)
+}
+
+/// The function generates and returns a random seed phrase for the given word list using the
+/// given random number generator.
+///
+/// A random, BIP-0039-compliant seed phrase is returned if the requested number of words is
+/// valid. The entropy is drawn from the given cryptographically secure random number generator
+/// instead of the operating system's random number generator.
+///
+/// * `num_words` - The number of words in the seed phrase.
+/// * `word_list` - The word list to be used.
+/// * `rng` - The cryptographically secure random number generator.
+pub fn generate_seed_phrase_with_rng_for_word_list<R: RngCore + CryptoRng>(
+    num_words: usize,
+    word_list: &(impl WordList + ?Sized),
+    rng: &mut R,
+) -> SeedPhraseResult {
+    let word_list = word_list.get_words();
+    // Validate the word list.
+    validate_word_list(word_list)?;
+    get_random_seed_phrase(num_words, word_list, rng)
+}
+
+/// The function generates and returns a random seed phrase.
+///
+/// A random, BIP-0039-compliant seed phrase is returned if the requested number of words is
+/// valid.
+///
+/// * `num_words` - The number of words in the seed phrase.
+pub fn generate_seed_phrase(num_words: usize) -> SeedPhraseResult {
+    generate_seed_phrase_for_word_list(num_words, DEFAULT_WORD_LIST)
+}
+
+/// The function validates a given seed phrase using the standard word list.
+///
+/// The function checks BIP-0039 compliance for the given seed phrase. If the seed phrase is
+/// not compliant, the error message lists all detected problems, see
+/// [diagnose_seed_phrase](crate::diagnose_seed_phrase).
+///
+/// * `seed_phrase` - The given seed phrase.
+pub fn validate_seed_phrase(seed_phrase: &SeedPhrase) -> HarpoResult<()> {
+    validate_seed_phrase_for_word_list(seed_phrase, DEFAULT_WORD_LIST)
+}
+
+/// The function validates a given seed phrase.
+///
+/// The function checks BIP-0039 compliance for the given seed phrase. If the seed phrase is
+/// not compliant, the error message lists all detected problems, see
+/// [diagnose_seed_phrase_for_word_list](crate::diagnose_seed_phrase_for_word_list).
+///
+/// * `seed_phrase` - The given seed phrase.
+/// * `word_list` - The word list to be used.
+pub fn validate_seed_phrase_for_word_list(
+    seed_phrase: &SeedPhrase,
+    word_list: &(impl WordList + ?Sized),
+) -> HarpoResult<()> {
+    let word_list = word_list.get_words();
+    check_compliance(seed_phrase, word_list)
+}
+
+/// The function validates a given seed phrase according to the given standard.
+///
+/// For [PhraseStandard::Bip39](crate::seed_phrase::PhraseStandard::Bip39), the function behaves
+/// like [validate_seed_phrase](crate::validate_seed_phrase). For
+/// [PhraseStandard::Electrum](crate::seed_phrase::PhraseStandard::Electrum), the seed version
+/// of the seed phrase is checked instead of the BIP-0039 checksum.
+///
+/// * `seed_phrase` - The given seed phrase.
+/// * `standard` - The standard of the seed phrase.
+pub fn validate_seed_phrase_for_standard(
+    seed_phrase: &SeedPhrase,
+    standard: PhraseStandard,
+) -> HarpoResult<()> {
+    validate_seed_phrase_for_standard_for_word_list(seed_phrase, standard, DEFAULT_WORD_LIST)
+}
+
+/// The function validates a given seed phrase according to the given standard.
+///
+/// * `seed_phrase` - The given seed phrase.
+/// * `standard` - The standard of the seed phrase.
+/// * `word_list` - The word list to be used.
+pub fn validate_seed_phrase_for_standard_for_word_list(
+    seed_phrase: &SeedPhrase,
+    standard: PhraseStandard,
+    word_list: &(impl WordList + ?Sized),
+) -> HarpoResult<()> {
+    let word_list = word_list.get_words();
+    match standard {
+        PhraseStandard::Bip39 => check_compliance(seed_phrase, word_list),
+        PhraseStandard::Electrum => check_electrum_compliance(seed_phrase, word_list),
+    }
+}
+
+/// The function returns the detailed validation result of the given seed phrase using the
+/// standard word list.
+///
+/// The returned [SeedPhraseDiagnostics](crate::seed_phrase::SeedPhraseDiagnostics) list the
+/// words that are not in the word list together with their positions, an invalid number of
+/// words, and a checksum mismatch.
+///
+/// * `seed_phrase` - The given seed phrase.
+pub fn diagnose_seed_phrase(seed_phrase: &SeedPhrase) -> SeedPhraseDiagnostics {
+    seed_phrase::diagnose_seed_phrase(seed_phrase, DEFAULT_WORD_LIST)
+}
+
+/// The function returns the detailed validation result of the given seed phrase.
+///
+/// The returned [SeedPhraseDiagnostics](crate::seed_phrase::SeedPhraseDiagnostics) list the
+/// words that are not in the word list together with their positions, an invalid number of
+/// words, and a checksum mismatch.
+///
+/// * `seed_phrase` - The given seed phrase.
+/// * `word_list` - The word list to be used.
+pub fn diagnose_seed_phrase_for_word_list(
+    seed_phrase: &SeedPhrase,
+    word_list: &(impl WordList + ?Sized),
+) -> SeedPhraseDiagnostics {
+    let word_list = word_list.get_words();
+    seed_phrase::diagnose_seed_phrase(seed_phrase, word_list)
+}
+
+/// The function derives a child seed phrase from the given master seed phrase.
+///
+/// The child seed phrase is derived according to the BIP-0085 specification, using the
+/// English word list for the child seed phrase and no passphrase for the master seed phrase.
+/// The same master seed phrase, number of words, and index always result in the same child
+/// seed phrase.
+///
+/// * `seed_phrase` - The master seed phrase.
+/// * `num_words` - The number of words of the child seed phrase (12, 18, or 24).
+/// * `index` - The index of the child seed phrase.
+pub fn derive_child_seed_phrase(
+    seed_phrase: &SeedPhrase,
+    num_words: usize,
+    index: u32,
+) -> SeedPhraseResult {
+    derive_child_seed_phrase_for_word_list(seed_phrase, num_words, index, DEFAULT_WORD_LIST)
+}
+
+/// The function derives a child seed phrase from the given master seed phrase.
+///
+/// The child seed phrase is derived according to the BIP-0085 specification, using the
+/// English word list for the child seed phrase and no passphrase for the master seed phrase.
+/// The provided word list is the word list of the master seed phrase.
+///
+/// * `seed_phrase` - The master seed phrase.
+/// * `num_words` - The number of words of the child seed phrase (12, 18, or 24).
+/// * `index` - The index of the child seed phrase.
+/// * `word_list` - The word list of the master seed phrase.
+pub fn derive_child_seed_phrase_for_word_list(
+    seed_phrase: &SeedPhrase,
+    num_words: usize,
+    index: u32,
+    word_list: &(impl WordList + ?Sized),
+) -> SeedPhraseResult {
+    let word_list = word_list.get_words();
+    validate_word_list(word_list)?;
+    validate_seed_phrase_for_word_list(seed_phrase, word_list)?;
+    bip85::derive_child_seed_phrase(seed_phrase, "", num_words, index)
+}
+
+/// The function derives the BIP-0032 master key from the given seed phrase and passphrase.
+///
+/// The master key and its fingerprint, see
+/// [ExtendedPrivateKey::get_fingerprint](crate::bitcoin::ExtendedPrivateKey::get_fingerprint),
+/// can be compared with the values shown by the wallet to confirm that a reconstructed seed
+/// phrase belongs to the expected wallet. As required by BIP-0039, the seed phrase and the
+/// passphrase are NFKD-normalized before the seed is derived.
+/// The function is only available if the `bip32` feature is enabled.
+///
+/// * `seed_phrase` - The seed phrase.
+/// * `passphrase` - The BIP-0039 passphrase, which is empty if no passphrase is used.
+/// * `network` - The network of the extended private key.
+#[cfg(feature = "bip32")]
+pub fn derive_master_key(
+    seed_phrase: &SeedPhrase,
+    passphrase: &str,
+    network: Network,
+) -> HarpoResult<ExtendedPrivateKey> {
+    derive_master_key_for_word_list(seed_phrase, passphrase, network, DEFAULT_WORD_LIST)
+}
+
+/// The function derives the BIP-0032 master key from the given seed phrase and passphrase,
+/// see [derive_master_key](crate::derive_master_key).
+///
+/// * `seed_phrase` - The seed phrase.
+/// * `passphrase` - The BIP-0039 passphrase, which is empty if no passphrase is used.
+/// * `network` - The network of the extended private key.
+/// * `word_list` - The word list of the seed phrase.
+#[cfg(feature = "bip32")]
+pub fn derive_master_key_for_word_list(
+    seed_phrase: &SeedPhrase,
+    passphrase: &str,
+    network: Network,
+    word_list: &(impl WordList + ?Sized),
+) -> HarpoResult<ExtendedPrivateKey> {
+    let word_list = word_list.get_words();
+    validate_word_list(word_list)?;
+    validate_seed_phrase_for_word_list(seed_phrase, word_list)?;
+    bip32::derive_master_key(seed_phrase, passphrase, network)
+}
+
+/// The function parses a seed phrase from the given string using the given typo policy.
+///
+/// The function behaves like [parse_seed_phrase](crate::parse_seed_phrase) but handles words
+/// that are not in the default word list according to the typo policy, see
+/// [TypoPolicy](crate::seed_phrase::TypoPolicy). The returned report contains the seed phrase
+/// and all applied corrections.
+///
+/// * `input` - The seed phrase as a string.
+/// * `parse_mode` - The parse mode.
+/// * `typo_policy` - The typo policy.
+pub fn parse_seed_phrase_with_typo_policy(
+    input: &str,
+    parse_mode: ParseMode,
+    typo_policy: TypoPolicy,
+) -> HarpoResult<ParseReport> {
+    parse_seed_phrase_with_typo_policy_for_word_list(
+        input,
+        parse_mode,
+        typo_policy,
+        DEFAULT_WORD_LIST,
+    )
+}
+
+/// The function parses a seed phrase from the given string using the given typo policy and
+/// word list.
+///
+/// The function behaves like
+/// [parse_seed_phrase_for_word_list](crate::parse_seed_phrase_for_word_list) but handles words
+/// that are not in the word list according to the typo policy, see
+/// [TypoPolicy](crate::seed_phrase::TypoPolicy). The returned report contains the seed phrase
+/// and all applied corrections.
+///
+/// * `input` - The seed phrase as a string.
+/// * `parse_mode` - The parse mode.
+/// * `typo_policy` - The typo policy.
+/// * `word_list` - The word list.
+pub fn parse_seed_phrase_with_typo_policy_for_word_list(
+    input: &str,
+    parse_mode: ParseMode,
+    typo_policy: TypoPolicy,
+    word_list: &(impl WordList + ?Sized),
+) -> HarpoResult<ParseReport> {
+    let word_list = word_list.get_words();
+    validate_word_list(word_list)?;
+    seed_phrase::parse_seed_phrase_with_typo_policy(input, parse_mode, typo_policy, word_list)
+}
+
+/// The function checks whether a secret-shared seed phrase appears to be mistyped.
+///
+/// The function verifies all checksum information that is available for the index encoding of
+/// the secret-shared seed phrase. Note that seed phrases with 12 words and embedded indices do
+/// not have any checksum bits left, in which case only the words themselves are checked.
+///
+/// * `seed_phrase` - The secret-shared seed phrase.
+pub fn check_secret_shared_seed_phrase(seed_phrase: &SeedPhrase) -> HarpoResult<()> {
+    check_secret_shared_seed_phrase_for_word_list(seed_phrase, DEFAULT_WORD_LIST)
+}
+
+/// The function checks whether a secret-shared seed phrase appears to be mistyped using the
+/// given word list.
+///
+/// The function verifies all checksum information that is available for the index encoding of
+/// the secret-shared seed phrase. Note that seed phrases with 12 words and embedded indices do
+/// not have any checksum bits left, in which case only the words themselves are checked.
+///
+/// * `seed_phrase` - The secret-shared seed phrase.
+/// * `word_list` - The word list.
+pub fn check_secret_shared_seed_phrase_for_word_list(
+    seed_phrase: &SeedPhrase,
+    word_list: &(impl WordList + ?Sized),
+) -> HarpoResult<()> {
+    let word_list = word_list.get_words();
+    trace_span!("check_share");
+    validate_word_list(word_list)?;
+    let result = seed_phrase::check_secret_shared_seed_phrase(seed_phrase, word_list);
+    trace_event!("check_share.result", "valid" => result.is_ok());
+    result
+}
+
+/// The function is called to complete a secret-shared seed phrase with one unknown word.
+///
+/// The unknown word must be marked using the [WILDCARD_WORD](crate::seed_phrase::WILDCARD_WORD)
+/// `?`. The function returns all completions that pass the checksum verification of the
+/// secret-shared seed phrase. If other secret-shared seed phrases are provided and at least the
+/// threshold number of them is available, only the completion that is consistent with them is
+/// returned. An empty list is returned if no completion is found.
+///
+/// * `seed_phrase` - The secret-shared seed phrase with one unknown word.
+/// * `other_seed_phrases` - Other secret-shared seed phrases created for the same secret.
+pub fn complete_secret_shared_seed_phrase(
+    seed_phrase: &SeedPhrase,
+    other_seed_phrases: &[SeedPhrase],
+) -> HarpoResult<Vec<SeedPhrase>> {
+    complete_secret_shared_seed_phrase_for_word_list(
+        seed_phrase,
+        other_seed_phrases,
+        DEFAULT_WORD_LIST,
+    )
+}
+
+/// The function is called to complete a secret-shared seed phrase with one unknown word using
+/// the given word list.
+///
+/// The unknown word must be marked using the [WILDCARD_WORD](crate::seed_phrase::WILDCARD_WORD)
+/// `?`. The function returns all completions that pass the checksum verification of the
+/// secret-shared seed phrase. If other secret-shared seed phrases are provided and at least the
+/// threshold number of them is available, only the completion that is consistent with them is
+/// returned. An empty list is returned if no completion is found.
+///
+/// * `seed_phrase` - The secret-shared seed phrase with one unknown word.
+/// * `other_seed_phrases` - Other secret-shared seed phrases created for the same secret.
+/// * `word_list` - The word list.
+pub fn complete_secret_shared_seed_phrase_for_word_list(
+    seed_phrase: &SeedPhrase,
+    other_seed_phrases: &[SeedPhrase],
+    word_list: &(impl WordList + ?Sized),
+) -> HarpoResult<Vec<SeedPhrase>> {
+    let word_list = word_list.get_words();
+    validate_word_list(word_list)?;
+    let words = seed_phrase.get_words();
+    let wildcard_positions: Vec<usize> = (0..words.len())
+        .filter(|position| words[*position] == WILDCARD_WORD)
+        .collect();
+    if wildcard_positions.len() != 1 {
+        return Err(HarpoError::InvalidSeedPhrase(format!(
+            "Exactly one word must be marked as unknown using '{}'.",
+            WILDCARD_WORD
+        )));
+    }
+    if other_seed_phrases
+        .iter()
+        .any(|other| other.get_words().contains(&WILDCARD_WORD))
+    {
+        return Err(HarpoError::InvalidSeedPhrase(
+            "The other secret-shared seed phrases must not contain unknown words.".to_string(),
+        ));
+    }
+    // Collect all completions that pass the checksum verification.
+    let mut candidates = vec![];
+    for word in word_list {
+        let mut candidate_words: Vec<String> = words.iter().map(|word| word.to_string()).collect();
+        candidate_words[wildcard_positions[0]] = word.to_string();
+        let candidate = match seed_phrase.get_index() {
+            Some(index) => SeedPhrase::new_with_index(&candidate_words, index),
+            None => SeedPhrase::new(&candidate_words),
+        };
+        if seed_phrase::check_secret_shared_seed_phrase(&candidate, word_list).is_ok() {
+            candidates.push(candidate);
+        }
+    }
+    if other_seed_phrases.is_empty() {
+        return Ok(candidates);
+    }
+    // A completion is consistent with the other secret-shared seed phrases if it lies on the
+    // polynomial passing through them. This only holds for the correct completion if at least
+    // the threshold number of other secret-shared seed phrases is provided.
+    let polynomial = interpolate_polynomial(&get_secret_shares_for_seed_phrases(
+        other_seed_phrases,
+        word_list,
+    )?)?;
+    let mut consistent_candidates = vec![];
+    for candidate in &candidates {
+        if let Some(share) =
+            get_secret_shares_for_seed_phrases(std::slice::from_ref(candidate), word_list)?.pop()
+        {
+            let expected_shares = polynomial.get_secret_shares_for_indices(&[share.index]);
+            if expected_shares[0].element == share.element {
+                consistent_candidates.push(candidate.clone());
+            }
+        }
+    }
+    if consistent_candidates.is_empty() {
+        Ok(candidates)
+    } else {
+        Ok(consistent_candidates)
+    }
+}
+
+/// The function validates each of the given secret-shared seed phrases.
+///
+/// For each secret-shared seed phrase, the returned
+/// [ShareValidation](crate::seed_phrase::ShareValidation) contains the number of words, the
+/// detected index encoding and index, the result of the checksum verification, and a
+/// fingerprint, which can be used to detect duplicates.
+///
+/// * `seed_phrases` - The secret-shared seed phrases.
+pub fn validate_shares(seed_phrases: &[SeedPhrase]) -> Vec<ShareValidation> {
+    validate_shares_for_word_list(seed_phrases, DEFAULT_WORD_LIST)
+}
+
+/// The function validates each of the given secret-shared seed phrases using the given word
+/// list.
+///
+/// For each secret-shared seed phrase, the returned
+/// [ShareValidation](crate::seed_phrase::ShareValidation) contains the number of words, the
+/// detected index encoding and index, the result of the checksum verification, and a
+/// fingerprint, which can be used to detect duplicates.
+///
+/// * `seed_phrases` - The secret-shared seed phrases.
+/// * `word_list` - The word list.
+pub fn validate_shares_for_word_list(
+    seed_phrases: &[SeedPhrase],
+    word_list: &(impl WordList + ?Sized),
+) -> Vec<ShareValidation> {
+    let word_list = word_list.get_words();
+    let mut validations: Vec<ShareValidation> = seed_phrases
+        .iter()
+        .map(|seed_phrase| validate_share(seed_phrase, word_list))
+        .collect();
+    // Mark secret-shared seed phrases that are duplicates of earlier ones.
+    let mut positions: HashMap<String, usize> = HashMap::new();
+    for (position, validation) in validations.iter_mut().enumerate() {
+        if let Some(fingerprint) = validation.get_fingerprint() {
+            match positions.get(fingerprint) {
+                Some(earlier_position) => validation.set_duplicate_of(*earlier_position),
+                None => {
+                    positions.insert(fingerprint.to_string(), position);
+                }
+            }
+        }
+    }
+    validations
+}
+
+/// The function parses a seed phrase from the given string.
+///
+/// The parse mode determines how strictly the input is interpreted, see
+/// [ParseMode](crate::seed_phrase::ParseMode). An explicit index can be provided in the
+/// form `[INDEX]: [SEED PHRASE]`. All words must be in the default word list.
+///
+/// * `input` - The seed phrase as a string.
+/// * `parse_mode` - The parse mode.
+pub fn parse_seed_phrase(input: &str, parse_mode: ParseMode) -> SeedPhraseResult {
+    parse_seed_phrase_for_word_list(input, parse_mode, DEFAULT_WORD_LIST)
+}
+
+/// The function parses a seed phrase from the given string using the given word list.
+///
+/// The parse mode determines how strictly the input is interpreted, see
+/// [ParseMode](crate::seed_phrase::ParseMode). An explicit index can be provided in the
+/// form `[INDEX]: [SEED PHRASE]`. All words must be in the provided word list.
+///
+/// * `input` - The seed phrase as a string.
+/// * `parse_mode` - The parse mode.
+/// * `word_list` - The word list.
+pub fn parse_seed_phrase_for_word_list(
+    input: &str,
+    parse_mode: ParseMode,
+    word_list: &(impl WordList + ?Sized),
+) -> SeedPhraseResult {
+    let word_list = word_list.get_words();
+    validate_word_list(word_list)?;
+    seed_phrase::parse_seed_phrase(input, parse_mode, word_list)
+}
+
+/// The function splits words of the given seed phrase that consist of several concatenated
+/// words of the given word list.
+///
+/// Chinese and Japanese seed phrases are often written without spaces. Every word that is not in
+/// the word list and contains non-ASCII characters is split into words of the word list if this
+/// is possible in exactly one way. An error is returned if a word can be split in several ways.
+/// The index of the seed phrase, if any, is preserved.
+///
+/// * `seed_phrase` - The seed phrase.
+/// * `word_list` - The word list.
+pub fn segment_seed_phrase_for_word_list(
+    seed_phrase: &SeedPhrase,
+    word_list: &(impl WordList + ?Sized),
+) -> SeedPhraseResult {
+    let word_list = word_list.get_words();
+    validate_word_list(word_list)?;
+    let tokens = seed_phrase
+        .get_words()
+        .iter()
+        .map(|word| word.to_string())
+        .collect();
+    let words = segment_tokens(tokens, word_list)?;
+    match seed_phrase.get_index() {
+        Some(index) => Ok(SeedPhrase::new_with_index(&words, index)),
+        None => Ok(SeedPhrase::new(&words)),
+    }
+}
+
+/// The function returns the languages of all bundled word lists that contain the given word.
+///
+/// This function can be used to detect that a seed phrase was entered in a different language
+/// than expected.
+///
+/// * `word` - The word.
+pub fn get_bundled_languages_for_word(word: &str) -> Vec<&'static str> {
+    BUNDLED_WORD_LISTS
+        .iter()
+        .filter(|(_, word_list)| word_list.get_index(word).is_some())
+        .map(|(language, _)| *language)
+        .collect()
+}
+
+/// The function returns the bundled word list for the given language, if available.
+///
+/// * `language` - The language of the word list, e.g., "English".
+pub fn get_bundled_word_list(language: &str) -> Option<&'static [&'static str]> {
+    language
+        .parse::<Language>()
+        .ok()
+        .map(|language| language.get_word_list())
+}
+
+/// The function returns all words of the default word list that start with the given prefix.
+///
+/// The words are returned in the order of the word list.
+///
+/// * `prefix` - The prefix.
+pub fn get_word_completions(prefix: &str) -> Vec<&'static str> {
+    get_word_completions_for_word_list(prefix, DEFAULT_WORD_LIST)
+}
+
+/// The function returns all words of the given word list that start with the given prefix.
+///
+/// The words are returned in the order of the word list. The lookup uses a prefix tree, which is
+/// built when the word list is first used.
+///
+/// * `prefix` - The prefix.
+/// * `word_list` - The word list.
+pub fn get_word_completions_for_word_list<'a>(prefix: &str, word_list: &[&'a str]) -> Vec<&'a str> {
+    get_word_trie(word_list)
+        .get_completions(prefix)
+        .into_iter()
+        .map(|index| word_list[index])
+        .collect()
+}
+
+// ******************************** TESTS ********************************
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+    use std::str::FromStr;
+
+    /// The different number of seed phrase lengths is 5 (12, 15, 18, 21, 24).
+    const NUM_SEED_PHRASE_LENGTHS: usize = 5;
+
+    /// The number of test runs.
+    const NUM_TEST_RUNS: usize = 10;
+
+    /// The maximum number of secret-shared seed phrases in the test runs.
+    const MAX_NUM_SEED_PHRASES: usize = 64;
+
+    #[test]
+    /// The function provides basic tests for the create function.
+    fn test_create_secret_shared_seed_phrases() {
+        let words = [
+            "legal", "winner", "thank", "year", "wave", "sausage", "worth", "useful", "legal",
+            "winner", "thank", "yellow",
+        ];
+        let seed_phrase =
+            SeedPhrase::new(&words.iter().map(|s| s.to_string()).collect::<Vec<String>>());
+        let seed_phrases = create_secret_shared_seed_phrases(&seed_phrase, 2, 3, true);
+        // Assert that there are seed phrases.
+        assert!(seed_phrases.is_ok());
+        // Assert that the right number of seed phrases is returned.
+        assert_eq!(seed_phrases.unwrap().len(), 3);
+        // Change the last word, making it an invalid seed phrase.
+        let words = [
+            "legal", "winner", "thank", "year", "wave", "sausage", "worth", "useful", "legal",
+            "winner", "thank", "above",
+        ];
+        let seed_phrase =
+            SeedPhrase::new(&words.iter().map(|s| s.to_string()).collect::<Vec<String>>());
+        let seed_phrases = create_secret_shared_seed_phrases(&seed_phrase, 2, 3, true);
+        // Assert that an error is returned.
+        assert!(seed_phrases.is_err());
+    }
+
+    #[test]
+    /// The function provides basic tests for the reconstruct function.
+    fn test_reconstruct_seed_phrase() {
+        // Create two seed phrases.
+        let first_words = [
+            "coil", "reunion", "immune", "ignore", "custom", "gallery", "dutch", "trouble",
+            "snake", "ball", "wrong", "bike",
+        ];
+        let second_words = [
+            "stable", "biology", "key", "post", "fiction", "concert", "hill", "step", "vibrant",
+            "ocean", "punch", "car",
+        ];
+        let first_seed_phrase = SeedPhrase::new(
+            &first_words
+                .iter()
+                .map(|s| s.to_string())
+                .collect::<Vec<String>>(),
+        );
+        let second_seed_phrase = SeedPhrase::new(
+            &second_words
+                .iter()
+                .map(|s| s.to_string())
+                .collect::<Vec<String>>(),
+        );
+
+        let seed_phrases = [first_seed_phrase, second_seed_phrase];
+        // Reconstruct the seed phrase.
+        let seed_phrase = reconstruct_seed_phrase(&seed_phrases);
+        // Assert that a seed phrase is returned.
+        assert!(seed_phrase.is_ok());
+        // Assert that it matches the expected seed phrase.
+        let expected_words = [
+            "letter", "advice", "cage", "absurd", "amount", "doctor", "acoustic", "avoid",
+            "letter", "advice", "cage", "above",
+        ];
+        let expected_seed_phrase = SeedPhrase::new(
+            &expected_words
+                .iter()
+                .map(|s| s.to_string())
+                .collect::<Vec<String>>(),
+        );
+        assert_eq!(seed_phrase.unwrap(), expected_seed_phrase);
+    }
+
+    #[test]
+    /// The function tests the reconstruction of secret-shared seed phrases derived from
+    /// a randomly generated seed phrase.
+    fn test_random_seed_phrase_reconstruction() {
+        // The valid number of words.
+        let valid_num_words: [usize; NUM_SEED_PHRASE_LENGTHS] = [12, 15, 18, 21, 24];
+        let mut rng = rand::thread_rng();
+        for _test in 0..NUM_TEST_RUNS {
+            // Choose a random number of words.
+            let num_words = valid_num_words
+                .choose(&mut rng)
+                .expect("A valid random number of words should be chosen.");
+            // Generate a random seed phrase.
+            let seed_phrase = generate_seed_phrase(*num_words)
+                .expect("The generation of a seed phrase should work.");
+            // Determine randomly if indices should be embedded.
+            let embed_indices = rng.gen::<bool>();
+            // Get a random number of secret-shared seed phrases parameter.
+            let num_seed_phrases = match embed_indices {
+                true => rng.gen_range(2..MAX_EMBEDDED_SHARES),
+                false => rng.gen_range(2..MAX_NUM_SEED_PHRASES),
+            };
+            // Get the random threshold.
+            let threshold = rng.gen_range(2..num_seed_phrases + 1);
+            // Create the secret-shard seed phrases.
+            let seed_phrases = create_secret_shared_seed_phrases(
+                &seed_phrase,
+                threshold,
+                num_seed_phrases,
+                embed_indices,
+            )
+            .expect("The creation of secret-shared seed phrases should work.");
+            // Choose sufficiently many seed phrases.
+            let num_selected = rng.gen_range(threshold..num_seed_phrases + 1);
+            let selected_seed_phrases: Vec<SeedPhrase> = seed_phrases
+                .choose_multiple(&mut rng, num_selected)
+                .cloned()
+                .collect();
+            // Reconstruct the original seed phrase.
+            let reconstructed_seed_phrase = reconstruct_seed_phrase(&selected_seed_phrases)
+                .expect("The reconstruction of a seed-phrase should work.");
+            // Assert that the original and reconstructed seed phrases are identical.
+            assert_eq!(seed_phrase, reconstructed_seed_phrase);
+            // Choose a number of seed phrases below the threshold.
+            let num_selected = rng.gen_range(1..threshold);
+            let selected_seed_phrases: Vec<SeedPhrase> = seed_phrases
+                .choose_multiple(&mut rng, num_selected)
+                .cloned()
+                .collect();
+            // Assert that the reconstruction reports the missing seed phrases.
+            match reconstruct_seed_phrase(&selected_seed_phrases) {
+                Err(HarpoError::NotEnoughShares { provided, required }) => {
+                    assert_eq!(provided, num_selected);
+                    assert_eq!(required, threshold);
+                }
+                result => panic!("Unexpected result: {:?}", result),
+            }
+            // Without the recorded threshold, an incorrect seed phrase is reconstructed.
+            let stripped_seed_phrases: Vec<SeedPhrase> = selected_seed_phrases
+                .iter()
+                .map(|share| {
+                    let words: Vec<String> =
+                        share.get_words().into_iter().map(str::to_string).collect();
+                    match share.get_index() {
+                        Some(index) => SeedPhrase::new_with_index(&words, index),
+                        None => SeedPhrase::new(&words),
+                    }
+                })
+                .collect();
+            let reconstructed_seed_phrase = reconstruct_seed_phrase(&stripped_seed_phrases)
+                .expect("The reconstruction of a seed-phrase should work.");
+            assert_ne!(seed_phrase, reconstructed_seed_phrase);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "insecure-demo")]
+    /// The function tests the creation and reconstruction of the insecure demo seed phrases.
+    fn test_demo_seed_phrase_reconstruction() {
+        for num_words in [3, 6, 9] {
+            let seed_phrase = generate_seed_phrase(num_words).expect("The generation should work.");
+            assert!(validate_seed_phrase(&seed_phrase).is_ok());
+            // Embedding must be rejected because there are not enough spare bits.
+            assert!(create_secret_shared_seed_phrases(&seed_phrase, 2, 3, true).is_err());
+            let seed_phrases = create_secret_shared_seed_phrases(&seed_phrase, 2, 3, false)
+                .expect("The creation of secret-shared seed phrases should work.");
+            assert!(seed_phrases.iter().all(|phrase| phrase.len() == num_words));
+            let reconstructed_seed_phrase = reconstruct_seed_phrase(&seed_phrases[1..])
+                .expect("The reconstruction of a seed-phrase should work.");
+            assert_eq!(seed_phrase, reconstructed_seed_phrase);
+            // Seed phrases without explicit indices cannot be used.
+            let stripped_seed_phrases: Vec<SeedPhrase> = seed_phrases
+                .iter()
+                .map(|phrase| {
+                    let words: Vec<String> =
+                        phrase.get_words().iter().map(|s| s.to_string()).collect();
+                    SeedPhrase::new(&words)
+                })
+                .collect();
+            assert!(reconstruct_seed_phrase(&stripped_seed_phrases).is_err());
+        }
+    }
+
+    #[test]
+    /// The function tests the creation and reconstruction of seed phrases with index words.
+    fn test_index_word_reconstruction() {
+        let mut rng = rand::thread_rng();
+        for num_words in [12, 24] {
+            let seed_phrase = generate_seed_phrase(num_words).expect("The generation should work.");
+            // More shares than can be embedded are created.
+            let num_seed_phrases = MAX_INDEX_WORD_SHARES;
+            let threshold = rng.gen_range(2..MAX_EMBEDDED_SHARES);
+            let seed_phrases = create_secret_shared_seed_phrases_with_index_encoding(
+                &seed_phrase,
+                threshold,
+                num_seed_phrases,
+                IndexEncoding::IndexWord,
+            )
+            .expect("The creation of secret-shared seed phrases should work.");
+            assert_eq!(seed_phrases.len(), num_seed_phrases);
+            for share in &seed_phrases {
+                // There is an additional word and no explicit index.
+                assert_eq!(share.len(), num_words + 1);
+                assert!(share.get_index().is_none());
+                // The secret-bearing words are BIP-0039-compliant.
+                let words: Vec<String> = share.get_words()[..num_words]
+                    .iter()
+                    .map(|s| s.to_string())
+                    .collect();
+                assert!(validate_seed_phrase(&SeedPhrase::new(&words)).is_ok());
+            }
+            let selected_seed_phrases: Vec<SeedPhrase> = seed_phrases
+                .choose_multiple(&mut rng, threshold)
+                .cloned()
+                .collect();
+            let reconstructed_seed_phrase = reconstruct_seed_phrase(&selected_seed_phrases)
+                .expect("The reconstruction of a seed-phrase should work.");
+            assert_eq!(seed_phrase, reconstructed_seed_phrase);
+        }
+        // Too many shares are rejected.
+        let seed_phrase = generate_seed_phrase(12).expect("The generation should work.");
+        assert!(create_secret_shared_seed_phrases_with_index_encoding(
+            &seed_phrase,
+            2,
+            MAX_INDEX_WORD_SHARES + 1,
+            IndexEncoding::IndexWord,
+        )
+        .is_err());
+    }
+
+    #[test]
+    /// The function tests that a modified index word is detected.
+    fn test_index_word_checksum() {
+        let seed_phrases: Vec<SeedPhrase> = [
+            "act scissors zero label noble tomato swamp toe moral glass scissors silk abuse",
+            "anchor ensure yard kind thrive coil noble slab puppy country adjust fold again",
+        ]
+        .iter()
+        .map(|phrase| {
+            let words: Vec<String> = phrase.split_whitespace().map(str::to_string).collect();
+            SeedPhrase::new(&words)
+        })
+        .collect();
+        // Replace the index word with the next word in the list, which changes the checksum.
+        // The modified checksum matches neither the current nor any earlier checksum.
+        let mut first_words: Vec<String> = seed_phrases[0]
+            .get_words()
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let position = DEFAULT_WORD_LIST
+            .iter()
+            .position(|word| *word == first_words[12])
+            .expect("The index word should be in the word list.");
+        first_words[12] = DEFAULT_WORD_LIST[position ^ 1].to_string();
+        let tampered_seed_phrase = SeedPhrase::new(&first_words);
+        assert!(reconstruct_seed_phrase(&seed_phrases).is_ok());
+        let result = reconstruct_seed_phrase(&[tampered_seed_phrase, seed_phrases[1].clone()]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    /// The function tests that index words created by earlier versions of harpo are still
+    /// decoded.
+    fn test_index_word_compatibility() {
+        let seed_phrase = SeedPhrase::from_str(
+            "legal winner thank year wave sausage worth useful legal winner thank yellow",
+        )
+        .unwrap();
+        let fixtures = [
+            // The checksums of the first index words do not include a tag.
+            [
+                "vocal express magnet front pony smooth moment report lake emotion obtain \
+                favorite accuse",
+                "slot pumpkin hill rifle fitness super cable over keep oval eye process actor",
+                "drill apology spice bid blossom grocery provide eagle jewel tunnel antique \
+                account aim",
+            ],
+            // The secret-shared seed phrases were created using share format 1.
+            [
+                "act scissors zero label noble tomato swamp toe moral glass scissors silk abuse",
+                "lyrics mail tennis word cricket angry reveal struggle paddle size time mushroom \
+                actual",
+                "anchor ensure yard kind thrive coil noble slab puppy country adjust fold again",
+            ],
+            // The checksums include share format version 2 as the tag.
+            [
+                "similar gadget rely citizen fix silk crane term ramp ethics lecture inherit \
+                accuse",
+                "inch ride menu few return spike grit moment tone palm taxi trip adult",
+                "rookie bring gaze lend blue surround only lazy bike valve law frozen aerobic",
+            ],
+        ];
+        for fixture in fixtures {
+            let seed_phrases: Vec<SeedPhrase> = fixture
+                .iter()
+                .map(|phrase| {
+                    let words: Vec<String> =
+                        phrase.split_whitespace().map(str::to_string).collect();
+                    SeedPhrase::new(&words)
+                })
+                .collect();
+            for selected_seed_phrases in [&seed_phrases[..2], &seed_phrases[1..]] {
+                assert_eq!(
+                    reconstruct_seed_phrase(selected_seed_phrases)
+                        .expect("The reconstruction should work."),
+                    seed_phrase
+                );
+            }
+        }
+    }
+
+    #[test]
+    /// The function tests the limits on the number of secret-shared seed phrases and the
+    /// creation of a large number of secret-shared seed phrases.
+    fn test_num_shares_limits() {
+        assert_eq!(
+            get_max_num_shares(12, IndexEncoding::Embedded),
+            Some(MAX_EMBEDDED_SHARES)
+        );
+        assert_eq!(
+            get_max_num_shares(24, IndexEncoding::IndexWord),
+            Some(MAX_INDEX_WORD_SHARES)
+        );
+        assert_eq!(
+            get_max_num_shares(12, IndexEncoding::Explicit),
+            Some(u32::MAX as usize)
+        );
+        assert_eq!(get_max_num_shares(13, IndexEncoding::Explicit), None);
+        // For 3-word demo seed phrases, the limit is given by the modulus 2^32-5.
+        #[cfg(feature = "insecure-demo")]
+        assert_eq!(
+            get_max_num_shares(3, IndexEncoding::Explicit),
+            Some(u32::MAX as usize - 5)
+        );
+        let seed_phrase = generate_seed_phrase(12).expect("The generation should work.");
+        // The number of shares must not exceed the number of available indices.
+        if usize::MAX > u32::MAX as usize {
+            let result =
+                create_secret_shared_seed_phrases(&seed_phrase, 2, u32::MAX as usize + 1, false);
+            assert!(matches!(result, Err(HarpoError::InvalidParameter(_))));
+        }
+        // A large number of shares can be created and any subset of threshold size suffices.
+        let num_shares = 1000;
+        let threshold = 20;
+        let seed_phrases =
+            create_secret_shared_seed_phrases(&seed_phrase, threshold, num_shares, false)
+                .expect("The creation of secret-shared seed phrases should work.");
+        assert_eq!(seed_phrases.len(), num_shares);
+        let indices: HashSet<Option<u32>> = seed_phrases
+            .iter()
+            .map(|phrase| phrase.get_index())
+            .collect();
+        assert_eq!(indices.len(), num_shares);
+        let mut rng = rand::thread_rng();
+        let subset: Vec<SeedPhrase> = seed_phrases
+            .choose_multiple(&mut rng, threshold)
+            .cloned()
+            .collect();
+        assert_eq!(
+            reconstruct_seed_phrase(&subset).expect("The reconstruction should work."),
+            seed_phrase
+        );
+    }
+
+    #[test]
+    /// The function tests that mistyped secret-shared seed phrases are detected.
+    fn test_check_secret_shared_seed_phrase() {
+        // Flip the lowest-order bit of the last secret-bearing word, which is a checksum bit.
+        let tamper = |seed_phrase: &SeedPhrase, position: usize| {
+            let mut words: Vec<String> = seed_phrase
+                .get_words()
+                .iter()
+                .map(|s| s.to_string())
+                .collect();
+            let word_index = DEFAULT_WORD_LIST
+                .iter()
+                .position(|word| *word == words[position])
+                .expect("The word should be in the word list.");
+            words[position] = DEFAULT_WORD_LIST[word_index ^ 1].to_string();
+            match seed_phrase.get_index() {
+                Some(index) => SeedPhrase::new_with_index(&words, index),
+                None => SeedPhrase::new(&words),
+            }
+        };
+        for (num_words, index_encoding) in [
+            (24, IndexEncoding::Embedded),
+            (12, IndexEncoding::Explicit),
+            (15, IndexEncoding::IndexWord),
+        ] {
+            let seed_phrase = generate_seed_phrase(num_words).expect("The generation should work.");
+            let seed_phrases = create_secret_shared_seed_phrases_with_index_encoding(
+                &seed_phrase,
+                2,
+                3,
+                index_encoding,
+            )
+            .expect("The creation of secret-shared seed phrases should work.");
+            // The index word is skipped because a modified index word is tested in
+            // test_index_word_checksum.
+            for seed_phrase in &seed_phrases {
+                assert!(check_secret_shared_seed_phrase(seed_phrase).is_ok());
+                let tampered_seed_phrase = tamper(seed_phrase, num_words - 1);
+                assert!(check_secret_shared_seed_phrase(&tampered_seed_phrase).is_err());
+            }
+        }
+    }
+
+    #[test]
+    /// The function tests the validation of a stack of secret-shared seed phrases.
+    fn test_validate_shares() {
+        let seed_phrase = generate_seed_phrase(18).expect("The generation should work.");
+        let embedded_seed_phrases = create_secret_shared_seed_phrases(&seed_phrase, 2, 3, true)
+            .expect("The creation of secret-shared seed phrases should work.");
+        let explicit_seed_phrases = create_secret_shared_seed_phrases(&seed_phrase, 2, 3, false)
+            .expect("The creation of secret-shared seed phrases should work.");
+        let invalid_seed_phrase = SeedPhrase::new(&vec!["invalid".to_string(); 12]);
+        let seed_phrases = vec![
+            embedded_seed_phrases[0].clone(),
+            explicit_seed_phrases[1].clone(),
+            invalid_seed_phrase,
+            embedded_seed_phrases[0].clone(),
+        ];
+        let validations = validate_shares(&seed_phrases);
+        assert_eq!(validations.len(), 4);
+        assert!(validations[0].is_valid());
+        assert_eq!(validations[0].get_num_words(), 18);
+        assert_eq!(
+            validations[0].get_index_encoding(),
+            Some(IndexEncoding::Embedded)
+        );
+        assert_eq!(validations[0].get_index(), Some(1));
+        assert!(validations[1].is_valid());
+        assert_eq!(
+            validations[1].get_index_encoding(),
+            Some(IndexEncoding::Explicit)
+        );
+        assert_eq!(validations[1].get_index(), Some(2));
+        assert_ne!(
+            validations[0].get_fingerprint(),
+            validations[1].get_fingerprint()
+        );
+        assert!(!validations[2].is_valid());
+        assert_eq!(validations[2].get_index(), None);
+        assert_eq!(validations[2].get_fingerprint(), None);
+        assert_eq!(validations[3].get_duplicate_of(), Some(0));
+        assert_eq!(validations[0].get_duplicate_of(), None);
+    }
+
+    #[test]
+    /// The function tests the creation of secret-shared seed phrases using a split request.
+    fn test_split_request() {
+        let seed_phrase = generate_seed_phrase(12).expect("The generation should work.");
+        let seed_phrases = SplitRequest::new(&seed_phrase)
+            .threshold(2)
+            .shares(3)
+            .index_encoding(IndexEncoding::IndexWord)
+            .word_list(DEFAULT_WORD_LIST)
+            .create()
+            .expect("The creation of secret-shared seed phrases should work.");
+        assert_eq!(seed_phrases.len(), 3);
+        assert!(seed_phrases.iter().all(|share| share.len() == 13));
+        assert_eq!(
+            reconstruct_seed_phrase(&seed_phrases[1..]).expect("The reconstruction should work."),
+            seed_phrase
+        );
+        let seed_phrases = SplitRequest::new(&seed_phrase)
+            .threshold(2)
+            .shares(3)
+            .embed_indices(false)
+            .create()
+            .expect("The creation of secret-shared seed phrases should work.");
+        assert!(seed_phrases.iter().all(|share| share.get_index().is_some()));
+        // The threshold and the number of shares must be set.
+        assert!(SplitRequest::new(&seed_phrase).create().is_err());
+        assert!(SplitRequest::new(&seed_phrase).create_share_set().is_err());
+        assert!(SplitRequest::new(&seed_phrase)
+            .threshold(2)
+            .create()
+            .is_err());
+    }
+
+    #[test]
+    /// The function tests the secret sharing of Electrum seed phrases.
+    fn test_electrum_seed_phrases() {
+        let seed_phrase = SeedPhrase::from_str(
+            "wild father tree among universe such mobile favorite target dynamic credit identify",
+        )
+        .unwrap();
+        // The Electrum seed phrase is rejected as a BIP-0039 seed phrase.
+        assert!(validate_seed_phrase(&seed_phrase).is_err());
+        assert!(create_secret_shared_seed_phrases(&seed_phrase, 2, 3, true).is_err());
+        assert!(validate_seed_phrase_for_standard(&seed_phrase, PhraseStandard::Electrum).is_ok());
+        for index_encoding in [
+            IndexEncoding::Embedded,
+            IndexEncoding::Explicit,
+            IndexEncoding::IndexWord,
+        ] {
+            let seed_phrases = SplitRequest::new(&seed_phrase)
+                .threshold(2)
+                .shares(3)
+                .index_encoding(index_encoding)
+                .standard(PhraseStandard::Electrum)
+                .create()
+                .expect("The creation of secret-shared seed phrases should work.");
+            assert_eq!(
+                reconstruct_seed_phrase_for_standard(&seed_phrases[1..], PhraseStandard::Electrum)
+                    .expect("The reconstruction should work."),
+                seed_phrase
+            );
+            let mut reconstructor = Reconstructor::new(2).standard(PhraseStandard::Electrum);
+            reconstructor.add_share(seed_phrases[2].clone()).unwrap();
+            reconstructor.add_share(seed_phrases[0].clone()).unwrap();
+            assert_eq!(reconstructor.try_reconstruct().unwrap(), seed_phrase);
+        }
+        // BIP-0039 seed phrases are not Electrum seed phrases.
+        let bip39_seed_phrase = generate_seed_phrase(24).unwrap();
+        assert!(SplitRequest::new(&bip39_seed_phrase)
+            .threshold(2)
+            .shares(3)
+            .standard(PhraseStandard::Electrum)
+            .create()
+            .is_err());
+        let seed_phrases =
+            create_secret_shared_seed_phrases(&bip39_seed_phrase, 2, 3, true).unwrap();
+        assert!(
+            reconstruct_seed_phrase_for_standard(&seed_phrases, PhraseStandard::Electrum).is_err()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    /// The function tests that seed phrases and reports survive a serialization round trip.
+    fn test_serde_round_trip() {
+        let seed_phrase = generate_seed_phrase(12).expect("The generation should work.");
+        for embed_indices in [true, false] {
+            let seed_phrases = create_secret_shared_seed_phrases(&seed_phrase, 2, 3, embed_indices)
+                .expect("The creation of secret-shared seed phrases should work.");
+            let json = serde_json::to_string(&seed_phrases).expect("Serialization should work.");
+            let deserialized: Vec<SeedPhrase> =
+                serde_json::from_str(&json).expect("Deserialization should work.");
+            assert_eq!(deserialized, seed_phrases);
+            assert_eq!(deserialized[1].get_index(), seed_phrases[1].get_index());
+            let report = reconstruct_seed_phrase_with_report(&seed_phrases, &[])
+                .expect("The reconstruction should work.");
+            let json = serde_json::to_string(&report).expect("Serialization should work.");
+            let deserialized: ReconstructionReport =
+                serde_json::from_str(&json).expect("Deserialization should work.");
+            assert_eq!(deserialized, report);
+        }
+        // The index is optional in the serialized form.
+        let deserialized: SeedPhrase = serde_json::from_str(r#"{"words":["abandon","ability"]}"#)
+            .expect("Deserialization should work.");
+        assert_eq!(deserialized.get_index(), None);
+        let commitment = get_polynomial_commitment(&[seed_phrase])
+            .expect("The computation of the commitment should work.");
+        let json = serde_json::to_string(&commitment).expect("Serialization should work.");
+        assert_eq!(json, format!("\"{}\"", commitment));
+        let deserialized: PolynomialCommitment =
+            serde_json::from_str(&json).expect("Deserialization should work.");
+        assert_eq!(deserialized, commitment);
+    }
+
+    #[test]
+    /// The function tests the creation of secret-shared seed phrases for entropy.
+    fn test_create_secret_shared_seed_phrases_from_entropy() {
+        let mut rng = rand::thread_rng();
+        for num_bytes in [16, 20, 24, 28, 32] {
+            let entropy: Vec<u8> = (0..num_bytes).map(|_| rng.gen()).collect();
+            for embed_indices in [true, false] {
+                let seed_phrases =
+                    create_secret_shared_seed_phrases_from_entropy(&entropy, 3, 5, embed_indices)
+                        .expect("The creation of secret-shared seed phrases should work.");
+                assert_eq!(seed_phrases.len(), 5);
+                let seed_phrase = reconstruct_seed_phrase(&seed_phrases[1..4])
+                    .expect("The reconstruction should work.");
+                assert_eq!(
+                    seed_phrase,
+                    SeedPhrase::from_entropy(&entropy).expect("The entropy is valid.")
+                );
+                assert_eq!(seed_phrase.to_entropy().unwrap(), entropy);
+            }
+        }
+        assert!(create_secret_shared_seed_phrases_from_entropy(&[0; 18], 2, 3, true).is_err());
+        assert!(create_secret_shared_seed_phrases_from_entropy(&[0; 16], 4, 3, true).is_err());
+    }
+
+    #[test]
+    /// The function tests the secret sharing of strings.
+    fn test_split_and_reconstruct_string() {
+        for secret in [
+            "",
+            "correct horse battery staple",
+            "Pässwörd 密码 🔑",
+            "  spaces  ",
+        ] {
+            let byte_shares = split_string(secret, 2, 3).expect("The split should work.");
+            let reconstructed =
+                reconstruct_string(&byte_shares[1..]).expect("The reconstruction should work.");
+            assert_eq!(reconstructed, secret);
+        }
+        // Bytes that are not a valid UTF-8 encoding are rejected.
+        let byte_shares = split_bytes(&[0xff, 0xfe], 2, 3).expect("The split should work.");
+        assert!(reconstruct_string(&byte_shares).is_err());
+    }
+
+    #[test]
+    /// The function tests that share sets contain the creation parameters.
+    fn test_create_share_set() {
+        let seed_phrase = generate_seed_phrase(15).expect("The generation should work.");
+        let share_set =
+            create_share_set(&seed_phrase, 3, 5, false).expect("The creation should work.");
+        assert_eq!(share_set.get_threshold(), 3);
+        assert_eq!(share_set.get_num_shares(), 5);
+        assert_eq!(share_set.get_num_words(), 15);
+        assert_eq!(share_set.get_index_encoding(), IndexEncoding::Explicit);
+        assert_eq!(share_set.get_share_format_version(), SHARE_FORMAT_VERSION);
+        assert_eq!(share_set.get_shares().len(), 5);
+        assert!(verify_polynomial_commitment(
+            &share_set.get_shares()[2..],
+            &share_set.get_commitment()
+        )
+        .expect("The verification should work."));
+        let shares = share_set.into_shares();
+        assert_eq!(
+            reconstruct_seed_phrase(&shares[..3]).expect("The reconstruction should work."),
+            seed_phrase
+        );
+    }
+
+    #[test]
+    /// The function tests the incremental reconstruction of seed phrases.
+    fn test_reconstructor() {
+        let seed_phrase = generate_seed_phrase(12).expect("The generation should work.");
+        for embed_indices in [true, false] {
+            let shares = create_secret_shared_seed_phrases(&seed_phrase, 3, 5, embed_indices)
+                .expect("The creation of secret-shared seed phrases should work.");
+            let mut reconstructor = Reconstructor::new(3).word_list(DEFAULT_WORD_LIST);
+            assert_eq!(reconstructor.missing_count(), 3);
+            assert!(reconstructor.add_share(shares[4].clone()).unwrap());
+            // Duplicates are ignored.
+            assert!(!reconstructor.add_share(shares[4].clone()).unwrap());
+            assert!(reconstructor.add_share(shares[1].clone()).unwrap());
+            assert_eq!(reconstructor.get_num_shares(), 2);
+            assert_eq!(reconstructor.missing_count(), 1);
+            assert!(reconstructor.try_reconstruct().is_err());
+            assert!(reconstructor.add_share(shares[2].clone()).unwrap());
+            assert_eq!(reconstructor.missing_count(), 0);
+            assert_eq!(
+                reconstructor
+                    .try_reconstruct()
+                    .expect("The reconstruction should work."),
+                seed_phrase
+            );
+        }
+        // Secret-shared seed phrases with a different number of words are rejected.
+        let mut reconstructor = Reconstructor::new(2);
+        let shares = create_secret_shared_seed_phrases(&seed_phrase, 2, 2, false).unwrap();
+        reconstructor.add_share(shares[0].clone()).unwrap();
+        let other_seed_phrase = generate_seed_phrase(15).unwrap();
+        let other_shares =
+            create_secret_shared_seed_phrases(&other_seed_phrase, 2, 2, false).unwrap();
+        assert!(reconstructor.add_share(other_shares[1].clone()).is_err());
+        // A different secret-shared seed phrase with the same index is rejected.
+        let other_shares = create_secret_shared_seed_phrases(&seed_phrase, 2, 2, false).unwrap();
+        assert!(reconstructor.add_share(other_shares[0].clone()).is_err());
+    }
+
+    #[test]
+    /// The function tests the lazy creation of secret-shared seed phrases.
+    fn test_iter_secret_shared_seed_phrases() {
+        let seed_phrase = generate_seed_phrase(18).expect("The generation should work.");
+        for index_encoding in [
+            IndexEncoding::Embedded,
+            IndexEncoding::Explicit,
+            IndexEncoding::IndexWord,
+        ] {
+            let seed_phrases: Vec<SeedPhrase> = iter_secret_shared_seed_phrases(
+                &seed_phrase,
+                3,
+                MAX_EMBEDDED_SHARES,
+                index_encoding,
+            )
+            .expect("The parameters are valid.")
+            .skip(7)
+            .take(3)
+            .collect::<HarpoResult<Vec<SeedPhrase>>>()
+            .expect("The creation of secret-shared seed phrases should work.");
+            assert_eq!(seed_phrases.len(), 3);
+            assert_eq!(
+                reconstruct_seed_phrase(&seed_phrases).expect("The reconstruction should work."),
+                seed_phrase
+            );
+        }
+        // The parameters are checked before any secret-shared seed phrase is created.
+        assert!(
+            iter_secret_shared_seed_phrases(&seed_phrase, 4, 3, IndexEncoding::Explicit).is_err()
+        );
+        let invalid_seed_phrase = SeedPhrase::new(&vec!["abandon".to_string(); 12]);
+        assert!(iter_secret_shared_seed_phrases(
+            &invalid_seed_phrase,
+            2,
+            3,
+            IndexEncoding::Explicit
+        )
+        .is_err());
+    }
+
+    #[test]
+    /// The function tests that the secret sharing with a seeded random number generator is
+    /// deterministic.
+    fn test_create_secret_shared_seed_phrases_with_rng() {
+        let seed_phrase = generate_seed_phrase_with_rng(24, &mut StdRng::seed_from_u64(42))
+            .expect("The generation should work.");
+        assert_eq!(
+            generate_seed_phrase_with_rng(24, &mut StdRng::seed_from_u64(42)).unwrap(),
+            seed_phrase
+        );
+        let seed_phrases = create_secret_shared_seed_phrases_with_rng(
+            &seed_phrase,
+            3,
+            5,
+            IndexEncoding::Explicit,
+            &mut StdRng::seed_from_u64(7),
+        )
+        .expect("The creation of secret-shared seed phrases should work.");
+        assert_eq!(
+            create_secret_shared_seed_phrases_with_rng(
+                &seed_phrase,
+                3,
+                5,
+                IndexEncoding::Explicit,
+                &mut StdRng::seed_from_u64(7),
+            )
+            .unwrap(),
+            seed_phrases
+        );
+        assert_eq!(
+            reconstruct_seed_phrase(&seed_phrases[2..]).expect("The reconstruction should work."),
+            seed_phrase
+        );
+        // Invalid parameters are still rejected.
+        assert!(generate_seed_phrase_with_rng(13, &mut StdRng::seed_from_u64(42)).is_err());
+        assert!(create_secret_shared_seed_phrases_with_rng(
+            &seed_phrase,
+            6,
+            5,
+            IndexEncoding::Explicit,
+            &mut StdRng::seed_from_u64(7),
+        )
+        .is_err());
+    }
+
+    #[test]
+    /// The function tests that the secret-shared seed phrases derived from a salt can be
+    /// re-created.
+    fn test_create_secret_shared_seed_phrases_with_salt() {
+        let seed_phrase = generate_seed_phrase(12).expect("The generation should work.");
+        for index_encoding in [IndexEncoding::Embedded, IndexEncoding::Explicit] {
+            let seed_phrases = create_secret_shared_seed_phrases_with_salt(
+                &seed_phrase,
+                3,
+                5,
+                index_encoding,
+                b"salt",
+            )
+            .expect("The creation of secret-shared seed phrases should work.");
+            // The same salt results in the same secret-shared seed phrases.
+            let recreated_seed_phrases = create_secret_shared_seed_phrases_with_salt(
+                &seed_phrase,
+                3,
+                7,
+                index_encoding,
+                b"salt",
+            )
+            .unwrap();
+            assert_eq!(recreated_seed_phrases[..5], seed_phrases[..]);
+            // A different salt results in different secret-shared seed phrases.
+            let other_seed_phrases = create_secret_shared_seed_phrases_with_salt(
+                &seed_phrase,
+                3,
+                5,
+                index_encoding,
+                b"other salt",
+            )
+            .unwrap();
+            assert_ne!(other_seed_phrases, seed_phrases);
+            assert_eq!(
+                reconstruct_seed_phrase(&[
+                    seed_phrases[0].clone(),
+                    recreated_seed_phrases[6].clone(),
+                    seed_phrases[3].clone()
+                ])
+                .expect("The reconstruction should work."),
+                seed_phrase
+            );
+        }
+        assert!(create_secret_shared_seed_phrases_with_salt(
+            &seed_phrase,
+            6,
+            5,
+            IndexEncoding::Explicit,
+            b"salt"
+        )
+        .is_err());
+    }
+
+    #[test]
+    /// The function tests that the reconstruction report records the provenance of the shares.
+    fn test_reconstruction_report() {
+        let seed_phrase = generate_seed_phrase(18).expect("The generation should work.");
+        let seed_phrases = create_secret_shared_seed_phrases(&seed_phrase, 2, 3, true)
+            .expect("The creation of secret-shared seed phrases should work.");
+        let provided_seed_phrases = vec![
+            seed_phrases[0].clone(),
+            seed_phrases[2].clone(),
+            seed_phrases[0].clone(),
+        ];
+        let report = reconstruct_seed_phrase_with_report(
+            &provided_seed_phrases,
+            &["alice.txt", "bob.txt", "carol.txt"],
+        )
+        .expect("The reconstruction should work.");
+        assert_eq!(*report.get_seed_phrase(), seed_phrase);
+        let used_indices: Vec<Option<u32>> = report
+            .get_used_shares()
+            .iter()
+            .map(|share| share.get_index())
+            .collect();
+        assert_eq!(used_indices, vec![Some(1), Some(3)]);
+        assert_eq!(report.get_used_shares()[1].get_label(), Some("bob.txt"));
+        assert_eq!(report.get_ignored_shares().len(), 1);
+        assert_eq!(report.get_ignored_shares()[0].get_position(), 2);
+        assert_eq!(report.get_ignored_shares()[0].get_duplicate_of(), Some(0));
+        assert!(report.get_warnings().is_empty());
+        // The fingerprint does not depend on the used secret-shared seed phrases.
+        let other_report = reconstruct_seed_phrase_with_report(&seed_phrases[1..], &[])
+            .expect("The reconstruction should work.");
+        assert_eq!(report.get_fingerprint(), other_report.get_fingerprint());
+        assert_eq!(other_report.get_used_shares()[0].get_label(), None);
+        // The number of labels must match the number of secret-shared seed phrases.
+        assert!(reconstruct_seed_phrase_with_report(&seed_phrases, &["alice.txt"]).is_err());
+        // The consistency can only be checked if more shares than the threshold are used.
+        assert_eq!(report.is_consistent(), None);
+        let full_report = reconstruct_seed_phrase_with_report(&seed_phrases, &[])
+            .expect("The reconstruction should work.");
+        assert_eq!(full_report.is_consistent(), Some(true));
+        // A secret-shared seed phrase from a different split is detected as corrupted.
+        let other_seed_phrases = create_secret_shared_seed_phrases(&seed_phrase, 2, 3, true)
+            .expect("The creation of secret-shared seed phrases should work.");
+        let words: Vec<String> = other_seed_phrases[2]
+            .get_words()
+            .into_iter()
+            .map(str::to_string)
+            .collect();
+        let corrupted_seed_phrases = vec![
+            seed_phrases[0].clone(),
+            seed_phrases[1].clone(),
+            SeedPhrase::new(&words).with_threshold(2),
+        ];
+        let corrupted_report = reconstruct_seed_phrase_with_report(&corrupted_seed_phrases, &[])
+            .expect("The reconstruction should work.");
+        assert_eq!(corrupted_report.is_consistent(), Some(false));
+        assert_ne!(*corrupted_report.get_seed_phrase(), seed_phrase);
+        assert_eq!(corrupted_report.get_warnings().len(), 1);
+        assert!(reconstruct_seed_phrase(&corrupted_seed_phrases).is_err());
+        assert!(corrupted_report.get_corrupted_shares().is_empty());
+    }
+
+    #[test]
+    /// The function tests that corrupted secret-shared seed phrases are identified if there is
+    /// a consistent majority.
+    fn test_corrupted_share_identification() {
+        let seed_phrase = generate_seed_phrase(12).expect("The generation should work.");
+        let seed_phrases = create_secret_shared_seed_phrases(&seed_phrase, 2, 5, false)
+            .expect("The creation of secret-shared seed phrases should work.");
+        let other_seed_phrases = create_secret_shared_seed_phrases(&seed_phrase, 2, 5, false)
+            .expect("The creation of secret-shared seed phrases should work.");
+        let words: Vec<String> = other_seed_phrases[2]
+            .get_words()
+            .into_iter()
+            .map(str::to_string)
+            .collect();
+        let mut corrupted_seed_phrases = seed_phrases.clone();
+        corrupted_seed_phrases[2] = SeedPhrase::new_with_index(&words, 3).with_threshold(2);
+        // A single corrupted secret-shared seed phrase is tolerated among five.
+        assert_eq!(
+            reconstruct_seed_phrase(&corrupted_seed_phrases).unwrap(),
+            seed_phrase
+        );
+        // Two corrupted secret-shared seed phrases among five are only reported.
+        let words: Vec<String> = other_seed_phrases[3]
+            .get_words()
+            .into_iter()
+            .map(str::to_string)
+            .collect();
+        let mut twice_corrupted_seed_phrases = corrupted_seed_phrases.clone();
+        twice_corrupted_seed_phrases[3] = SeedPhrase::new_with_index(&words, 4).with_threshold(2);
+        let error = reconstruct_seed_phrase(&twice_corrupted_seed_phrases).unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("Secret-shared seed phrases 3, 4 appear to be corrupted."));
+        let report = reconstruct_seed_phrase_with_report(&corrupted_seed_phrases, &[])
+            .expect("The reconstruction should work.");
+        assert_eq!(report.is_consistent(), Some(false));
+        assert_eq!(*report.get_seed_phrase(), seed_phrase);
+        assert_eq!(report.get_used_shares().len(), 4);
+        assert_eq!(report.get_corrupted_shares().len(), 1);
+        assert_eq!(report.get_corrupted_shares()[0].get_position(), 2);
+        assert_eq!(report.get_warnings().len(), 1);
     }
-}
 
-// ******************************** TESTS ********************************
+    #[test]
+    /// The function tests that secret-shared seed phrases can be created for new indices.
+    fn test_reindex_shares() {
+        let seed_phrase = generate_seed_phrase(24).expect("The generation should work.");
+        let index_encodings = [
+            IndexEncoding::Embedded,
+            IndexEncoding::Explicit,
+            IndexEncoding::IndexWord,
+        ];
+        for index_encoding in index_encodings {
+            let shares = create_secret_shared_seed_phrases_with_index_encoding(
+                &seed_phrase,
+                3,
+                5,
+                index_encoding,
+            )
+            .unwrap();
+            // Re-index using the shares at indices 2, 4, and 5.
+            let quorum = [shares[1].clone(), shares[3].clone(), shares[4].clone()];
+            let new_shares = reindex_shares(&quorum, &[1, 6, 7]).unwrap();
+            // The share at index 1 is recreated.
+            assert_eq!(new_shares[0], shares[0]);
+            // The new shares can be combined with the old ones.
+            let mixed = [
+                new_shares[1].clone(),
+                new_shares[2].clone(),
+                shares[2].clone(),
+            ];
+            assert_eq!(reconstruct_seed_phrase(&mixed).unwrap(), seed_phrase);
+            // The new indices must be distinct and non-zero.
+            assert!(reindex_shares(&quorum, &[6, 6]).is_err());
+            assert!(reindex_shares(&quorum, &[0]).is_err());
+            assert!(reindex_shares(&quorum, &[]).is_err());
+        }
+        // Embedded indices cannot exceed the maximum number of embedded shares.
+        let shares = create_secret_shared_seed_phrases(&seed_phrase, 2, 2, true).unwrap();
+        assert!(reindex_shares(&shares, &[MAX_EMBEDDED_SHARES as u32 + 1]).is_err());
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use rand::{seq::SliceRandom, Rng};
+    #[test]
+    /// The function tests the secret sharing of Ethereum private keys.
+    fn test_ethereum_private_key_sharing() {
+        let private_key = "4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318"
+            .parse::<EthereumPrivateKey>()
+            .unwrap();
+        for index_encoding in [IndexEncoding::Embedded, IndexEncoding::IndexWord] {
+            let shares = split_ethereum_private_key(&private_key, 2, 3, index_encoding).unwrap();
+            assert!(shares
+                .iter()
+                .all(|share| check_secret_shared_seed_phrase(share).is_ok()));
+            let reconstructed = reconstruct_ethereum_private_key(&shares[1..]).unwrap();
+            assert_eq!(reconstructed, private_key);
+            assert_eq!(
+                reconstructed.get_address(),
+                "0x2c7536E3605D9C16a7a3D7b1898e529396a65c23"
+            );
+        }
+        // Seed phrases with a different number of words are rejected.
+        let seed_phrase = generate_seed_phrase(12).unwrap();
+        let shares = create_secret_shared_seed_phrases(&seed_phrase, 2, 2, true).unwrap();
+        assert!(reconstruct_ethereum_private_key(&shares).is_err());
+    }
 
-    /// The different number of seed phrase lengths is 5 (12, 15, 18, 21, 24).
-    const NUM_SEED_PHRASE_LENGTHS: usize = 5;
+    #[test]
+    /// The function tests the secret sharing of Bitcoin WIF and extended private keys.
+    fn test_bitcoin_private_key_sharing() {
+        let wif = "KwdMAjGmerYanjeui5SHS7JkmpZvVipYvB2LJGU1ZxJwYvP98617";
+        let private_key = wif.parse::<WifPrivateKey>().unwrap();
+        let shares = split_wif_private_key(&private_key, 2, 3, IndexEncoding::Embedded).unwrap();
+        let reconstructed = reconstruct_wif_private_key(&shares[..2], Network::Mainnet, true);
+        assert_eq!(reconstructed.unwrap().to_string(), wif);
+        // The key can be re-emitted for a different network.
+        let reconstructed =
+            reconstruct_wif_private_key(&shares[1..], Network::Testnet, true).unwrap();
+        assert_eq!(reconstructed.get_bytes(), private_key.get_bytes());
+        assert_ne!(reconstructed.to_string(), wif);
+        // The complete serialization preserves the network and the compression flag.
+        for wif in [
+            wif,
+            "5HueCGU8rMjxEXxiPuD5BDku4MkFqeZyd4dZ1jvhTVqvbTLvyTJ",
+            "cMahea7zqjxrtgAbB7LSGbcQUr1uX1ojuat9jZodMN87JcbXMTcA",
+        ] {
+            let private_key = wif.parse::<WifPrivateKey>().unwrap();
+            let byte_shares = split_serialized_wif_private_key(&private_key, 2, 3).unwrap();
+            let reconstructed = reconstruct_serialized_wif_private_key(&byte_shares[1..]);
+            assert_eq!(reconstructed.unwrap().to_string(), wif);
+        }
+        let xprv = "xprv9s21ZrQH143K3QTDL4LXw2F7HEK3wJUD2nW2nRk4stbPy6cq3jPPqjiChkVvvNKmPGJxWUtg6L\
+            nF5kejMRNNU3TGtRBeJgk33yuGBxrMPHi";
+        let private_key = xprv.parse::<ExtendedPrivateKey>().unwrap();
+        let shares =
+            split_extended_private_key(&private_key, 2, 3, IndexEncoding::IndexWord).unwrap();
+        assert!(shares.iter().all(|(chain_code_share, key_share)| {
+            check_secret_shared_seed_phrase(chain_code_share).is_ok()
+                && check_secret_shared_seed_phrase(key_share).is_ok()
+        }));
+        let metadata = private_key.get_metadata();
+        let reconstructed = reconstruct_extended_private_key(&shares[1..], metadata).unwrap();
+        assert_eq!(reconstructed.to_string(), xprv);
+        // The complete serialization can be secret-shared as well.
+        let byte_shares = split_serialized_extended_private_key(&private_key, 2, 3).unwrap();
+        let byte_shares: Vec<ByteShare> = byte_shares
+            .iter()
+            .map(|byte_share| byte_share.to_string().parse().unwrap())
+            .collect();
+        let reconstructed = reconstruct_serialized_extended_private_key(&byte_shares[..2]);
+        assert_eq!(reconstructed.unwrap().to_string(), xprv);
+        assert!(reconstruct_serialized_extended_private_key(&byte_shares[..1]).is_err());
+        let string_shares = split_string(xprv, 2, 3).unwrap();
+        assert!(reconstruct_serialized_extended_private_key(&string_shares).is_err());
+    }
 
-    /// The number of test runs.
-    const NUM_TEST_RUNS: usize = 10;
+    #[test]
+    /// The function tests the secret sharing of Solana private keys in both encodings.
+    fn test_solana_private_key_sharing() {
+        let secret_key = "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60";
+        let private_key = secret_key.parse::<SolanaPrivateKey>().unwrap();
+        let keypair = SolanaPrivateKey::new(
+            *private_key.get_secret_key(),
+            solana::SolanaKeyEncoding::Keypair,
+        )
+        .to_string();
+        for encoded_key in [secret_key, keypair.as_str()] {
+            let private_key = encoded_key.parse::<SolanaPrivateKey>().unwrap();
+            let byte_shares = split_solana_private_key(&private_key, 2, 3).unwrap();
+            let reconstructed = reconstruct_solana_private_key(&byte_shares[1..]).unwrap();
+            assert_eq!(reconstructed.to_string(), encoded_key);
+            assert_eq!(reconstructed.get_address(), private_key.get_address());
+        }
+        // Secret shares of other secrets are rejected.
+        let byte_shares = split_bytes(&[0x01; 16], 2, 3).unwrap();
+        assert!(reconstruct_solana_private_key(&byte_shares).is_err());
+    }
 
-    /// The maximum number of secret-shared seed phrases in the test runs.
-    const MAX_NUM_SEED_PHRASES: usize = 64;
+    #[test]
+    /// The function tests the creation and reconstruction of SLIP-0039 shares.
+    fn test_slip39_sharing() {
+        let seed_phrase = SeedPhrase::from_str(
+            "legal winner thank year wave sausage worth useful legal winner thank yellow",
+        )
+        .unwrap();
+        let shares = create_slip39_shares_for_seed_phrase(&seed_phrase, 1, &[(2, 3)]).unwrap();
+        assert_eq!(shares.len(), 3);
+        let shares: Vec<slip39::Slip39Share> = shares
+            .iter()
+            .map(|share| share.to_string().parse().unwrap())
+            .collect();
+        assert_eq!(
+            reconstruct_seed_phrase_from_slip39_shares(&shares[1..]).unwrap(),
+            seed_phrase
+        );
+        assert!(reconstruct_seed_phrase_from_slip39_shares(&shares[..1]).is_err());
+        // Master secrets of other lengths are supported but do not correspond to seed phrases.
+        let master_secret = [0x5a; 18];
+        let shares = create_slip39_shares(&master_secret, "TREZOR", 2, &[(1, 1), (1, 1)]).unwrap();
+        assert_eq!(
+            reconstruct_slip39_master_secret(&shares, "TREZOR").unwrap(),
+            master_secret
+        );
+        assert!(reconstruct_seed_phrase_from_slip39_shares(&shares).is_err());
+        assert!(create_slip39_shares(&master_secret, "TREZOR", 3, &[(1, 1), (1, 1)]).is_err());
+    }
 
     #[test]
-    /// The function provides basic tests for the create function.
-    fn test_create_secret_shared_seed_phrases() {
-        let words = [
-            "legal", "winner", "thank", "year", "wave", "sausage", "worth", "useful", "legal",
-            "winner", "thank", "yellow",
-        ];
-        let seed_phrase =
-            SeedPhrase::new(&words.iter().map(|s| s.to_string()).collect::<Vec<String>>());
-        let seed_phrases = create_secret_shared_seed_phrases(&seed_phrase, 2, 3, true);
-        // Assert that there are seed phrases.
-        assert!(seed_phrases.is_ok());
-        // Assert that the right number of seed phrases is returned.
-        assert_eq!(seed_phrases.unwrap().len(), 3);
-        // Change the last word, making it an invalid seed phrase.
-        let words = [
-            "legal", "winner", "thank", "year", "wave", "sausage", "worth", "useful", "legal",
-            "winner", "thank", "above",
-        ];
-        let seed_phrase =
-            SeedPhrase::new(&words.iter().map(|s| s.to_string()).collect::<Vec<String>>());
-        let seed_phrases = create_secret_shared_seed_phrases(&seed_phrase, 2, 3, true);
-        // Assert that an error is returned.
-        assert!(seed_phrases.is_err());
+    /// The function tests the creation and reconstruction of secret shares in the format of
+    /// `ssss`, including the migration of a seed phrase to secret-shared seed phrases.
+    fn test_ssss_sharing() {
+        let phrase = "legal winner thank year wave sausage worth useful legal winner thank yellow";
+        let shares = create_ssss_shares(phrase.as_bytes(), 2, 3, Some("wallet"), true).unwrap();
+        let shares: Vec<ssss::SsssShare> = shares
+            .iter()
+            .map(|share| share.to_string().parse().unwrap())
+            .collect();
+        assert_eq!(shares[1].get_token(), Some("wallet"));
+        let reconstructed = reconstruct_ssss_string(&shares[1..], 2, true).unwrap();
+        assert_eq!(reconstructed, phrase);
+        let seed_phrase = SeedPhrase::from_str(&reconstructed).unwrap();
+        let seed_phrases = create_secret_shared_seed_phrases(&seed_phrase, 2, 3, false).unwrap();
+        assert_eq!(
+            reconstruct_seed_phrase(&seed_phrases[..2]).unwrap(),
+            seed_phrase
+        );
+        // The raw secret retains leading zero bytes.
+        let secret = [0, 0, 1, 2, 3, 4, 5, 6, 7, 8];
+        let shares = create_ssss_shares(&secret, 3, 5, None, true).unwrap();
+        assert_eq!(reconstruct_ssss_secret(&shares, 3, true).unwrap(), secret);
+        assert!(reconstruct_ssss_secret(&shares[..2], 3, true).is_err());
     }
 
     #[test]
-    /// The function provides basic tests for the reconstruct function.
-    fn test_reconstruct_seed_phrase() {
-        // Create two seed phrases.
-        let first_words = [
-            "coil", "reunion", "immune", "ignore", "custom", "gallery", "dutch", "trouble",
-            "snake", "ball", "wrong", "bike",
-        ];
-        let second_words = [
-            "stable", "biology", "key", "post", "fiction", "concert", "hill", "step", "vibrant",
-            "ocean", "punch", "car",
-        ];
-        let first_seed_phrase = SeedPhrase::new(
-            &first_words
-                .iter()
-                .map(|s| s.to_string())
-                .collect::<Vec<String>>(),
+    /// The function tests the splitting into and the reconstruction from Seed XOR parts.
+    fn test_seed_xor() {
+        let seed_phrase = generate_seed_phrase_for_word_list(24, DEFAULT_WORD_LIST).unwrap();
+        let parts = create_seed_xor_parts(&seed_phrase, 3).unwrap();
+        assert!(parts.iter().all(|part| validate_seed_phrase(part).is_ok()));
+        assert_eq!(
+            reconstruct_seed_phrase_from_xor_parts(&parts).unwrap(),
+            seed_phrase
         );
-        let second_seed_phrase = SeedPhrase::new(
-            &second_words
-                .iter()
-                .map(|s| s.to_string())
-                .collect::<Vec<String>>(),
+        let parts =
+            create_seed_xor_parts_for_word_list(&seed_phrase, 2, DEFAULT_WORD_LIST).unwrap();
+        assert_eq!(
+            reconstruct_seed_phrase_from_xor_parts_for_word_list(&parts, DEFAULT_WORD_LIST)
+                .unwrap(),
+            seed_phrase
         );
+        assert!(reconstruct_seed_phrase_from_xor_parts(&parts[..1]).is_err());
+    }
 
-        let seed_phrases = [first_seed_phrase, second_seed_phrase];
-        // Reconstruct the seed phrase.
-        let seed_phrase = reconstruct_seed_phrase(&seed_phrases);
-        // Assert that a seed phrase is returned.
-        assert!(seed_phrase.is_ok());
-        // Assert that it matches the expected seed phrase.
-        let expected_words = [
-            "letter", "advice", "cage", "absurd", "amount", "doctor", "acoustic", "avoid",
-            "letter", "advice", "cage", "above",
-        ];
-        let expected_seed_phrase = SeedPhrase::new(
-            &expected_words
-                .iter()
-                .map(|s| s.to_string())
-                .collect::<Vec<String>>(),
+    #[test]
+    /// The function tests the completion of secret-shared seed phrases with an unknown word.
+    fn test_complete_secret_shared_seed_phrase() {
+        let seed_phrase = generate_seed_phrase(24).unwrap();
+        let shares = create_secret_shared_seed_phrases(&seed_phrase, 2, 3, true).unwrap();
+        let mut words: Vec<String> = shares[0]
+            .get_words()
+            .iter()
+            .map(|word| word.to_string())
+            .collect();
+        words[5] = WILDCARD_WORD.to_string();
+        let incomplete_share = SeedPhrase::new(&words);
+        // Without other secret-shared seed phrases, the checksum yields several candidates.
+        let candidates = complete_secret_shared_seed_phrase(&incomplete_share, &[]).unwrap();
+        assert!(candidates.contains(&shares[0]));
+        // The other secret-shared seed phrases determine the unique completion.
+        let candidates =
+            complete_secret_shared_seed_phrase(&incomplete_share, &shares[1..]).unwrap();
+        assert_eq!(candidates, vec![shares[0].clone()]);
+        // At most one unknown word is supported.
+        words[6] = WILDCARD_WORD.to_string();
+        assert!(
+            complete_secret_shared_seed_phrase(&SeedPhrase::new(&words), &shares[1..]).is_err()
         );
-        assert_eq!(seed_phrase.unwrap(), expected_seed_phrase);
     }
 
     #[test]
-    /// The function tests the reconstruction of secret-shared seed phrases derived from
-    /// a randomly generated seed phrase.
-    fn test_random_seed_phrase_reconstruction() {
-        // The valid number of words.
-        let valid_num_words: [usize; NUM_SEED_PHRASE_LENGTHS] = [12, 15, 18, 21, 24];
+    /// The function tests that the progress is reported when creating secret-shared seed
+    /// phrases.
+    fn test_progress_callback() {
+        let seed_phrase = generate_seed_phrase(15).expect("The generation should work.");
+        let mut updates = vec![];
+        let seed_phrases = create_secret_shared_seed_phrases_with_progress(
+            &seed_phrase,
+            3,
+            50,
+            IndexEncoding::Explicit,
+            &mut |completed, total| updates.push((completed, total)),
+        )
+        .expect("The creation of secret-shared seed phrases should work.");
+        assert_eq!(seed_phrases.len(), 50);
+        let expected_updates: Vec<(usize, usize)> = (0..=50).map(|i| (i, 50)).collect();
+        assert_eq!(updates, expected_updates);
+    }
+
+    #[test]
+    /// The function tests the creation and verification of polynomial commitments.
+    fn test_polynomial_commitment() {
         let mut rng = rand::thread_rng();
-        for _test in 0..NUM_TEST_RUNS {
-            // Choose a random number of words.
-            let num_words = valid_num_words
-                .choose(&mut rng)
-                .expect("A valid random number of words should be chosen.");
-            // Generate a random seed phrase.
-            let seed_phrase = generate_seed_phrase(*num_words)
-                .expect("The generation of a seed phrase should work.");
-            // Determine randomly if indices should be embedded.
-            let embed_indices = rng.gen::<bool>();
-            // Get a random number of secret-shared seed phrases parameter.
-            let num_seed_phrases = match embed_indices {
-                true => rng.gen_range(2..MAX_EMBEDDED_SHARES),
-                false => rng.gen_range(2..MAX_NUM_SEED_PHRASES),
-            };
-            // Get the random threshold.
-            let threshold = rng.gen_range(2..num_seed_phrases + 1);
-            // Create the secret-shard seed phrases.
-            let seed_phrases = create_secret_shared_seed_phrases(
-                &seed_phrase,
-                threshold,
-                num_seed_phrases,
-                embed_indices,
-            )
+        let seed_phrase = generate_seed_phrase(18).expect("The generation should work.");
+        let seed_phrases = create_secret_shared_seed_phrases(&seed_phrase, 3, 5, true)
             .expect("The creation of secret-shared seed phrases should work.");
-            // Choose sufficiently many seed phrases.
-            let num_selected = rng.gen_range(threshold..num_seed_phrases + 1);
-            let selected_seed_phrases: Vec<SeedPhrase> = seed_phrases
-                .choose_multiple(&mut rng, num_selected)
-                .cloned()
-                .collect();
-            // Reconstruct the original seed phrase.
-            let reconstructed_seed_phrase = reconstruct_seed_phrase(&selected_seed_phrases)
-                .expect("The reconstruction of a seed-phrase should work.");
-            // Assert that the original and reconstructed seed phrases are identical.
-            assert_eq!(seed_phrase, reconstructed_seed_phrase);
-            // Choose a number of seed phrases below the threshold.
-            let num_selected = rng.gen_range(1..threshold);
+        let commitment = get_polynomial_commitment(&seed_phrases[..3])
+            .expect("The commitment should be computed.");
+        // Any set of at least 3 seed phrases matches the commitment.
+        for num_selected in 3..=5 {
             let selected_seed_phrases: Vec<SeedPhrase> = seed_phrases
                 .choose_multiple(&mut rng, num_selected)
                 .cloned()
                 .collect();
-            // Attempt to reconstruct the original seed phrase.
-            let reconstructed_seed_phrase = reconstruct_seed_phrase(&selected_seed_phrases)
-                .expect("The reconstruction of a seed-phrase should work.");
-            // Assert that the original and reconstructed seed phrases are not identical.
-            assert_ne!(seed_phrase, reconstructed_seed_phrase);
+            assert!(verify_polynomial_commitment(&selected_seed_phrases, &commitment).unwrap());
+        }
+        // Too few seed phrases do not match the commitment.
+        assert!(!verify_polynomial_commitment(&seed_phrases[1..3], &commitment).unwrap());
+        // Seed phrases from a different split do not match the commitment.
+        let other_seed_phrases = create_secret_shared_seed_phrases(&seed_phrase, 3, 5, true)
+            .expect("The creation of secret-shared seed phrases should work.");
+        assert!(!verify_polynomial_commitment(&other_seed_phrases, &commitment).unwrap());
+    }
+
+    #[test]
+    /// The function tests the lookup of languages of bundled word lists.
+    fn test_bundled_languages() {
+        assert_eq!(get_bundled_languages_for_word("ability"), vec!["English"]);
+        assert!(get_bundled_languages_for_word("harpocrates").is_empty());
+        assert_eq!(
+            get_bundled_languages_for_word("\u{ff5a}oo"),
+            vec!["English"]
+        );
+        assert_eq!(get_bundled_word_list("english"), Some(DEFAULT_WORD_LIST));
+        assert!(get_bundled_word_list("Klingon").is_none());
+        #[cfg(feature = "japanese")]
+        {
+            assert_eq!(
+                get_bundled_languages_for_word("あいこくしん"),
+                vec!["Japanese"]
+            );
+            assert_eq!(
+                get_bundled_word_list("japanese"),
+                Some(word_list::JAPANESE_WORD_LIST)
+            );
         }
+        #[cfg(feature = "chinese-simplified")]
+        assert!(get_bundled_word_list("chinese-simplified").is_some());
     }
 
     #[test]
@@ -544,4 +5787,643 @@ mod tests {
         let seed_phrase = SeedPhrase::new(&words.map(String::from));
         assert!(validate_seed_phrase(&seed_phrase).is_err());
     }
+
+    #[test]
+    /// The function tests the error codes, the source chaining, and the attached context.
+    fn test_error_codes_and_context() {
+        use std::convert::TryFrom;
+        use std::error::Error;
+        let error = parse_seed_phrase("abandon ability xyzzy able", ParseMode::Strict)
+            .expect_err("The word 'xyzzy' is invalid.");
+        assert_eq!(error.get_code(), "unknown-word");
+        assert_eq!(error.get_word_position(), Some(2));
+        assert_eq!(error.get_file_path(), None);
+        assert!(error.to_string().contains("'xyzzy' at position 3"));
+        assert!(error.source().is_none());
+        let words = ["abandon", "xyzzy"];
+        let error = SeedPhrase::try_from(&words[..]).expect_err("The word 'xyzzy' is invalid.");
+        assert_eq!(error.get_word_position(), Some(1));
+        // Ambiguous abbreviations carry the word position as context.
+        let error = parse_seed_phrase("abandon ab", ParseMode::Lenient)
+            .expect_err("The abbreviation 'ab' is ambiguous.");
+        assert_eq!(error.get_code(), "invalid-seed-phrase");
+        assert_eq!(error.get_word_position(), Some(1));
+        assert!(error.to_string().ends_with("(word 2)"));
+        // The source of an I/O error is preserved when context is attached.
+        let io_error = std::io::Error::new(std::io::ErrorKind::NotFound, "missing");
+        let error = HarpoError::from(io_error)
+            .with_context(ErrorContext::File(PathBuf::from("shares.txt")))
+            .with_context(ErrorContext::WordPosition(4));
+        assert_eq!(error.get_code(), "io-error");
+        assert_eq!(error.get_file_path(), Some(Path::new("shares.txt")));
+        assert_eq!(error.get_word_position(), Some(4));
+        assert_eq!(error.get_context().len(), 2);
+        assert_eq!(error.source().unwrap().to_string(), "missing");
+        let error = HarpoError::from("x".parse::<u32>().unwrap_err());
+        assert_eq!(error.get_code(), "parse-int-error");
+        assert!(error.source().is_some());
+        assert_eq!(
+            HarpoError::InvalidParameter(String::new()).get_code(),
+            "invalid-parameter"
+        );
+    }
+
+    #[test]
+    /// The function tests the structured errors for invalid seed phrases.
+    fn test_structured_seed_phrase_errors() {
+        let mut words = vec!["abandon".to_string(); 11];
+        let error = validate_seed_phrase(&SeedPhrase::new(&words)).unwrap_err();
+        assert!(matches!(error, HarpoError::WrongWordCount { got: 11 }));
+        assert_eq!(error.get_code(), "wrong-word-count");
+        words.push("abandon".to_string());
+        let error = validate_seed_phrase(&SeedPhrase::new(&words)).unwrap_err();
+        assert!(matches!(error, HarpoError::ChecksumMismatch));
+        words[5] = "abandn".to_string();
+        match validate_seed_phrase(&SeedPhrase::new(&words)).unwrap_err() {
+            HarpoError::UnknownWord {
+                word,
+                position,
+                suggestions,
+            } => {
+                assert_eq!(word, "abandn");
+                assert_eq!(position, 5);
+                assert_eq!(suggestions, vec!["abandon".to_string()]);
+            }
+            error => panic!("Unexpected error: {}", error),
+        }
+        // Secret-shared seed phrases with different lengths cannot be combined.
+        let short_shares =
+            create_secret_shared_seed_phrases(&generate_seed_phrase(12).unwrap(), 2, 2, false)
+                .unwrap();
+        let long_shares =
+            create_secret_shared_seed_phrases(&generate_seed_phrase(15).unwrap(), 2, 2, false)
+                .unwrap();
+        let error = reconstruct_seed_phrase(&[short_shares[0].clone(), long_shares[1].clone()])
+            .unwrap_err();
+        assert!(matches!(error, HarpoError::LengthMismatchAcrossShares));
+    }
+
+    #[test]
+    /// The function tests that invalid and colliding share indices are rejected.
+    fn test_strict_share_index_validation() {
+        let seed_phrase = generate_seed_phrase(12).expect("The generation should work.");
+        let shares = create_secret_shared_seed_phrases(&seed_phrase, 2, 3, false).unwrap();
+        let get_words = |share: &SeedPhrase| -> Vec<String> {
+            share.get_words().into_iter().map(str::to_string).collect()
+        };
+        // An index of 0 is rejected.
+        let zero_share = SeedPhrase::new_with_index(&get_words(&shares[0]), 0);
+        let error = reconstruct_seed_phrase(&[zero_share, shares[1].clone()]).unwrap_err();
+        assert!(error.to_string().contains("invalid index 0"));
+        // Duplicate indices are reported together with the colliding seed phrases.
+        let error =
+            reconstruct_seed_phrase(&[shares[0].clone(), shares[1].clone(), shares[0].clone()])
+                .unwrap_err();
+        assert!(error.to_string().contains("1 and 3 have the same index 1"));
+        let relabeled_share =
+            SeedPhrase::new_with_index(&get_words(&shares[2]), shares[1].get_index().unwrap());
+        let error = reconstruct_seed_phrase(&[shares[1].clone(), relabeled_share]).unwrap_err();
+        assert!(error.to_string().contains("1 and 2 have the same index 2"));
+        // Embedded indices cannot be combined with explicit indices.
+        let embedded_shares = create_secret_shared_seed_phrases(&seed_phrase, 2, 3, true).unwrap();
+        assert!(reconstruct_seed_phrase(&[embedded_shares[0].clone(), shares[1].clone()]).is_err());
+        assert_eq!(
+            reconstruct_seed_phrase(&[embedded_shares[0].clone(), embedded_shares[2].clone()])
+                .unwrap(),
+            seed_phrase
+        );
+    }
+
+    #[test]
+    /// The function tests that secret-shared seed phrases carry a set identifier.
+    fn test_share_set_id() {
+        let seed_phrase = generate_seed_phrase(12).expect("The generation should work.");
+        let shares = create_secret_shared_seed_phrases(&seed_phrase, 2, 3, false).unwrap();
+        let set_id = shares[0]
+            .get_set_id()
+            .expect("The set ID should be present.");
+        assert!(shares
+            .iter()
+            .all(|share| share.get_set_id() == Some(set_id)));
+        // The set identifier survives a round trip through the string representation.
+        let share_string = shares[2].to_string();
+        assert!(share_string.starts_with(&format!("3/{:04x}/t2: ", set_id)));
+        let parsed_share = SeedPhrase::from_str(&share_string).unwrap();
+        assert_eq!(parsed_share.get_set_id(), Some(set_id));
+        assert_eq!(parsed_share.get_index(), Some(3));
+        // Shares from different splits of the same secret are rejected.
+        let other_shares = create_secret_shared_seed_phrases(&seed_phrase, 2, 3, false).unwrap();
+        assert_ne!(other_shares[0].get_set_id(), Some(set_id));
+        let error =
+            reconstruct_seed_phrase(&[shares[0].clone(), other_shares[1].clone()]).unwrap_err();
+        assert!(error.to_string().contains("different sets"));
+        let mut reconstructor = Reconstructor::new(2);
+        assert!(reconstructor.add_share(shares[0].clone()).unwrap());
+        assert!(reconstructor.add_share(other_shares[1].clone()).is_err());
+        assert_eq!(
+            reconstruct_seed_phrase(&[shares[0].clone(), shares[1].clone()]).unwrap(),
+            seed_phrase
+        );
+        // Malformed set identifiers are rejected.
+        let words = share_string.split_once(": ").unwrap().1;
+        assert!(SeedPhrase::from_str(&format!("3/12: {}", words)).is_err());
+        assert!(SeedPhrase::from_str(&format!("3/xyzw: {}", words)).is_err());
+    }
+
+    #[test]
+    /// The function tests that secret-shared seed phrases record the threshold.
+    fn test_share_threshold() {
+        let seed_phrase = generate_seed_phrase(12).expect("The generation should work.");
+        let shares = create_secret_shared_seed_phrases(&seed_phrase, 3, 5, false).unwrap();
+        assert!(shares.iter().all(|share| share.get_threshold() == Some(3)));
+        let parsed_share = SeedPhrase::from_str(&shares[0].to_string()).unwrap();
+        assert_eq!(parsed_share.get_threshold(), Some(3));
+        // Too few secret-shared seed phrases are reported instead of reconstructed.
+        let error = reconstruct_seed_phrase(&shares[..2]).unwrap_err();
+        assert_eq!(error.get_code(), "not-enough-shares");
+        assert!(error
+            .to_string()
+            .contains("2 secret-shared seed phrases were provided but 3 are required"));
+        assert!(reindex_shares(&shares[..2], &[6]).is_err());
+        assert_eq!(reconstruct_seed_phrase(&shares[1..4]).unwrap(), seed_phrase);
+        // The recorded threshold takes precedence over a lower threshold of the reconstructor.
+        let mut reconstructor = Reconstructor::new(2);
+        reconstructor.add_share(shares[0].clone()).unwrap();
+        reconstructor.add_share(shares[1].clone()).unwrap();
+        assert_eq!(reconstructor.missing_count(), 1);
+        assert!(reconstructor.try_reconstruct().is_err());
+        // Secret-shared seed phrases with different thresholds are rejected.
+        let words: Vec<String> = shares[1]
+            .get_words()
+            .into_iter()
+            .map(str::to_string)
+            .collect();
+        let relabeled_share = SeedPhrase::new_with_index(&words, 2).with_threshold(2);
+        let error = reconstruct_seed_phrase(&[shares[0].clone(), relabeled_share]).unwrap_err();
+        assert!(error.to_string().contains("different thresholds (3 and 2)"));
+        // Malformed thresholds are rejected.
+        let words = words.join(" ");
+        assert!(SeedPhrase::from_str(&format!("2/t3: {}", words)).is_ok());
+        assert!(SeedPhrase::from_str(&format!("2/t0: {}", words)).is_err());
+        assert!(SeedPhrase::from_str(&format!("2/tx: {}", words)).is_err());
+        assert!(SeedPhrase::from_str(&format!("2/t3/1a2b: {}", words)).is_err());
+        assert!(SeedPhrase::from_str(&format!("/: {}", words)).is_err());
+        // The threshold is recorded for embedded indices and index words as well.
+        for index_encoding in [IndexEncoding::Embedded, IndexEncoding::IndexWord] {
+            let shares = SplitRequest::new(&seed_phrase)
+                .threshold(3)
+                .shares(5)
+                .index_encoding(index_encoding)
+                .create()
+                .expect("The creation should work.");
+            let share_string = shares[0].to_string();
+            let set_id = shares[0].get_set_id().unwrap();
+            assert!(share_string.starts_with(&format!("/{:04x}/t3: ", set_id)));
+            let parsed_shares: Vec<SeedPhrase> = shares
+                .iter()
+                .map(|share| crate::seed_phrase::parse_phrase(&share.to_string()).unwrap())
+                .collect();
+            assert!(parsed_shares
+                .iter()
+                .all(|share| share.get_index().is_none() && share.get_threshold() == Some(3)));
+            let error = reconstruct_seed_phrase(&parsed_shares[..2]).unwrap_err();
+            assert_eq!(error.get_code(), "not-enough-shares");
+            assert_eq!(
+                reconstruct_seed_phrase(&parsed_shares[2..]).unwrap(),
+                seed_phrase
+            );
+        }
+    }
+
+    #[test]
+    /// The function tests that additional secret-shared seed phrases allow for error correction.
+    fn test_correctable_errors() {
+        let seed_phrase = generate_seed_phrase(24).expect("The generation should work.");
+        let share_set = SplitRequest::new(&seed_phrase)
+            .threshold(3)
+            .shares(4)
+            .correctable_errors(2)
+            .embed_indices(false)
+            .create_share_set()
+            .unwrap();
+        assert_eq!(share_set.get_num_shares(), 8);
+        let mut seed_phrases = share_set.get_shares().to_vec();
+        // A single transcription error per corrupted secret-shared seed phrase suffices.
+        for position in [0, 5] {
+            let mut words: Vec<String> = seed_phrases[position]
+                .get_words()
+                .into_iter()
+                .map(str::to_string)
+                .collect();
+            words[4] = if words[4] == "abandon" {
+                "ability".to_string()
+            } else {
+                "abandon".to_string()
+            };
+            seed_phrases[position] =
+                SeedPhrase::new_with_index(&words, position as u32 + 1).with_threshold(3);
+        }
+        assert_eq!(reconstruct_seed_phrase(&seed_phrases).unwrap(), seed_phrase);
+        let report = reconstruct_seed_phrase_with_report(&seed_phrases, &[]).unwrap();
+        let corrupted_positions: Vec<usize> = report
+            .get_corrupted_shares()
+            .iter()
+            .map(|share| share.get_position())
+            .collect();
+        assert_eq!(corrupted_positions, vec![0, 5]);
+        // Mistyped secret-shared seed phrases are only dropped if enough others remain.
+        assert_eq!(
+            reconstruct_seed_phrase(&seed_phrases[..4]).unwrap(),
+            seed_phrase
+        );
+        assert!(reconstruct_seed_phrase(&seed_phrases[..3]).is_err());
+    }
+
+    #[test]
+    /// The function tests the verification of secret-shared seed phrases using verification data.
+    fn test_verification_data() {
+        let seed_phrase = generate_seed_phrase(18).expect("The generation should work.");
+        for embed_indices in [true, false] {
+            let share_set = create_share_set(&seed_phrase, 3, 5, embed_indices)
+                .expect("The creation should work.");
+            let verification_data = share_set.get_verification_data();
+            assert_eq!(verification_data.get_threshold(), 3);
+            assert_eq!(verification_data.get_num_shares(), 5);
+            assert_eq!(verification_data.get_num_words(), 18);
+            assert_eq!(
+                verification_data.get_set_id(),
+                share_set.get_shares()[0].get_set_id().unwrap()
+            );
+            assert_eq!(
+                verification_data.get_commitment(),
+                share_set.get_commitment()
+            );
+            // The verification data can be stored and restored.
+            let verification_data: VerificationData = verification_data
+                .to_string()
+                .parse()
+                .expect("The parsing should work.");
+            for share in share_set.get_shares() {
+                assert!(verify_share(share, &verification_data).unwrap());
+            }
+            assert!(verify_set(&share_set.get_shares()[1..4], &verification_data).unwrap());
+            assert!(verify_set(&share_set.get_shares()[..2], &verification_data).is_err());
+            // Secret-shared seed phrases of a different set are rejected.
+            let other_set = create_share_set(&seed_phrase, 3, 5, embed_indices)
+                .expect("The creation should work.");
+            let other_share = &other_set.get_shares()[0];
+            assert!(!verify_share(other_share, &verification_data).unwrap());
+            let disguised_share = other_share
+                .clone()
+                .with_set_id(verification_data.get_set_id());
+            assert!(!verify_share(&disguised_share, &verification_data).unwrap());
+            let mut shares = share_set.get_shares()[..3].to_vec();
+            shares[1] = disguised_share;
+            assert!(!verify_set(&shares, &verification_data).unwrap());
+        }
+    }
+
+    #[test]
+    /// The function tests the creation of verification data for existing shares.
+    fn test_get_verification_data() {
+        let seed_phrase = generate_seed_phrase(12).expect("The generation should work.");
+        let shares = SplitRequest::new(&seed_phrase)
+            .threshold(2)
+            .shares(4)
+            .index_encoding(IndexEncoding::IndexWord)
+            .create()
+            .expect("The creation should work.");
+        let verification_data = get_verification_data(&shares, 2, IndexEncoding::IndexWord)
+            .expect("The creation of verification data should work.");
+        assert_eq!(verification_data.get_num_words(), 12);
+        assert_eq!(verification_data.get_num_shares(), 4);
+        assert!(shares
+            .iter()
+            .all(|share| verify_share(share, &verification_data).unwrap()));
+        // The secret-shared seed phrases must be complete and in order.
+        assert!(get_verification_data(&shares[1..], 2, IndexEncoding::IndexWord).is_err());
+        assert!(get_verification_data(&shares[..1], 2, IndexEncoding::IndexWord).is_err());
+    }
+
+    #[test]
+    /// The function tests that refreshed secret-shared seed phrases replace the old ones.
+    fn test_refresh_shares() {
+        let seed_phrase = generate_seed_phrase(24).expect("The generation should work.");
+        for index_encoding in [
+            IndexEncoding::Embedded,
+            IndexEncoding::Explicit,
+            IndexEncoding::IndexWord,
+        ] {
+            let old_shares = SplitRequest::new(&seed_phrase)
+                .threshold(3)
+                .shares(5)
+                .index_encoding(index_encoding)
+                .create()
+                .expect("The creation should work.");
+            let share_set = refresh_shares(&old_shares[1..4], 6).expect("The refresh should work.");
+            assert_eq!(share_set.get_threshold(), 3);
+            assert_eq!(share_set.get_num_shares(), 6);
+            assert_eq!(share_set.get_num_words(), 24);
+            assert_eq!(share_set.get_index_encoding(), index_encoding);
+            let new_shares = share_set.get_shares();
+            assert_ne!(new_shares[0], old_shares[0]);
+            assert_ne!(new_shares[0].get_set_id(), old_shares[0].get_set_id());
+            assert_eq!(
+                reconstruct_seed_phrase(&new_shares[3..]).expect("The reconstruction should work."),
+                seed_phrase
+            );
+            // Old and new secret-shared seed phrases cannot be combined.
+            let mixed_shares = vec![
+                old_shares[0].clone(),
+                new_shares[1].clone(),
+                new_shares[2].clone(),
+            ];
+            assert!(reconstruct_seed_phrase(&mixed_shares).is_err());
+            // The threshold number of secret-shared seed phrases is required.
+            assert!(refresh_shares(&old_shares[..2], 5).is_err());
+        }
+    }
+
+    #[test]
+    /// The function tests that secret-shared seed phrases can be re-shared with new parameters.
+    fn test_reshare_shares() {
+        let seed_phrase = generate_seed_phrase(15).expect("The generation should work.");
+        let old_shares = create_secret_shared_seed_phrases(&seed_phrase, 2, 3, true)
+            .expect("The creation should work.");
+        let share_set = reshare_shares(&old_shares[1..], 4, 7, IndexEncoding::IndexWord)
+            .expect("The re-sharing should work.");
+        assert_eq!(share_set.get_threshold(), 4);
+        assert_eq!(share_set.get_num_shares(), 7);
+        assert_eq!(share_set.get_num_words(), 15);
+        assert_eq!(share_set.get_index_encoding(), IndexEncoding::IndexWord);
+        let new_shares = share_set.get_shares();
+        assert_eq!(new_shares[0].len(), 16);
+        assert!(reconstruct_seed_phrase(&new_shares[..3]).is_err());
+        assert_eq!(
+            reconstruct_seed_phrase(&new_shares[3..]).expect("The reconstruction should work."),
+            seed_phrase
+        );
+        // The parameters are checked.
+        assert!(reshare_shares(&old_shares, 8, 7, IndexEncoding::Explicit).is_err());
+        assert!(reshare_shares(&old_shares[..1], 2, 3, IndexEncoding::Explicit).is_err());
+    }
+
+    #[test]
+    /// The function tests that an existing set of secret-shared seed phrases can be extended.
+    fn test_extend_shares() {
+        let seed_phrase = generate_seed_phrase(12).expect("The generation should work.");
+        for embed_indices in [true, false] {
+            let shares = create_secret_shared_seed_phrases(&seed_phrase, 3, 4, embed_indices)
+                .expect("The creation should work.");
+            let new_shares = extend_shares(&shares[1..], 4, 2).expect("The extension should work.");
+            assert_eq!(new_shares.len(), 2);
+            if !embed_indices {
+                assert_eq!(new_shares[0].get_index(), Some(5));
+                assert_eq!(new_shares[1].get_index(), Some(6));
+            }
+            // The new secret-shared seed phrases can be combined with the existing ones.
+            let combined = vec![
+                shares[0].clone(),
+                new_shares[0].clone(),
+                new_shares[1].clone(),
+            ];
+            assert_eq!(
+                reconstruct_seed_phrase(&combined).expect("The reconstruction should work."),
+                seed_phrase
+            );
+            // Indices of existing secret-shared seed phrases must not be reused.
+            assert!(extend_shares(&shares[1..], 3, 2).is_err());
+            assert!(extend_shares(&shares[..2], 4, 2).is_err());
+        }
+        // Secret-shared seed phrases created with a salt can be extended using the salt.
+        let shares = create_secret_shared_seed_phrases_with_salt(
+            &seed_phrase,
+            2,
+            3,
+            IndexEncoding::Explicit,
+            b"salt",
+        )
+        .expect("The creation should work.");
+        let more_shares = create_secret_shared_seed_phrases_with_salt(
+            &seed_phrase,
+            2,
+            5,
+            IndexEncoding::Explicit,
+            b"salt",
+        )
+        .expect("The creation should work.");
+        assert_eq!(&more_shares[..3], &shares[..]);
+        assert_eq!(
+            extend_shares(&shares[..2], 3, 2).expect("The extension should work."),
+            more_shares[3..].to_vec()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "bip32")]
+    /// The function tests that the master key of a reconstructed seed phrase matches the master
+    /// key of the original seed phrase.
+    fn test_derive_master_key() {
+        let seed_phrase = generate_seed_phrase(24).expect("The generation should work.");
+        let shares = create_secret_shared_seed_phrases(&seed_phrase, 2, 3, false)
+            .expect("The creation should work.");
+        let reconstructed_seed_phrase =
+            reconstruct_seed_phrase(&shares[1..]).expect("The reconstruction should work.");
+        let master_key = derive_master_key(&seed_phrase, "passphrase", Network::Mainnet)
+            .expect("The derivation should work.");
+        let reconstructed_master_key =
+            derive_master_key(&reconstructed_seed_phrase, "passphrase", Network::Mainnet)
+                .expect("The derivation should work.");
+        assert_eq!(
+            reconstructed_master_key.get_fingerprint(),
+            master_key.get_fingerprint()
+        );
+        assert_eq!(reconstructed_master_key.get_metadata().get_depth(), 0);
+        // Secret-shared seed phrases with an invalid checksum are rejected.
+        let invalid_seed_phrase = shares
+            .iter()
+            .find(|share| validate_seed_phrase(share).is_err());
+        if let Some(invalid_seed_phrase) = invalid_seed_phrase {
+            assert!(derive_master_key(invalid_seed_phrase, "", Network::Mainnet).is_err());
+        }
+    }
+
+    #[test]
+    /// The function tests that a lost secret-shared seed phrase can be re-created exactly.
+    fn test_regenerate_share() {
+        let seed_phrase = generate_seed_phrase(21).expect("The generation should work.");
+        for index_encoding in [
+            IndexEncoding::Embedded,
+            IndexEncoding::Explicit,
+            IndexEncoding::IndexWord,
+        ] {
+            let shares = SplitRequest::new(&seed_phrase)
+                .threshold(3)
+                .shares(5)
+                .index_encoding(index_encoding)
+                .create()
+                .expect("The creation should work.");
+            let surviving_shares = vec![shares[0].clone(), shares[3].clone(), shares[4].clone()];
+            let regenerated_share =
+                regenerate_share(&surviving_shares, 3).expect("The regeneration should work.");
+            assert_eq!(regenerated_share, shares[2]);
+            assert_eq!(regenerated_share.to_string(), shares[2].to_string());
+            assert!(regenerate_share(&surviving_shares, 0).is_err());
+            assert!(regenerate_share(&surviving_shares[..2], 3).is_err());
+        }
+    }
+
+    #[test]
+    /// The function tests the remote reconstruction from Lagrange-weighted partials.
+    fn test_lagrange_partials() {
+        let seed_phrase = generate_seed_phrase(24).expect("The generation should work.");
+        for index_encoding in [
+            IndexEncoding::Embedded,
+            IndexEncoding::Explicit,
+            IndexEncoding::IndexWord,
+        ] {
+            let shares = SplitRequest::new(&seed_phrase)
+                .threshold(3)
+                .shares(5)
+                .index_encoding(index_encoding)
+                .create()
+                .expect("The creation should work.");
+            let participating_indices = [1, 3, 4];
+            // The partials are exchanged as bytes.
+            let partials: Vec<LagrangePartial> = participating_indices
+                .iter()
+                .map(|index| {
+                    let partial = compute_lagrange_partial(
+                        &shares[*index as usize - 1],
+                        &participating_indices,
+                    )
+                    .expect("The computation should work.");
+                    // The value is not revealed in debug output.
+                    assert_eq!(format!("{:?}", partial), "LagrangePartial(256 bits)");
+                    LagrangePartial::from_bytes(&partial.to_bytes()).unwrap()
+                })
+                .collect();
+            assert_eq!(
+                reconstruct_seed_phrase_from_partials(&partials)
+                    .expect("The reconstruction should work."),
+                seed_phrase
+            );
+            assert!(compute_lagrange_partial(&shares[1], &participating_indices).is_err());
+            assert!(compute_lagrange_partial(&shares[0], &[1, 3]).is_err());
+        }
+        assert!(reconstruct_seed_phrase_from_partials(&[]).is_err());
+    }
+
+    #[test]
+    /// The function tests the two-level secret sharing among groups.
+    fn test_group_shares() {
+        let seed_phrase = generate_seed_phrase(24).expect("The generation should work.");
+        // Two out of three groups are required: a family (2 of 3), lawyers (1 of 1), and a safe
+        // deposit box (2 of 2).
+        let group_shares = create_group_shares(&seed_phrase, 2, &[(2, 3), (1, 1), (2, 2)])
+            .expect("The creation should work.");
+        assert_eq!(group_shares.len(), 6);
+        assert_eq!(group_shares[3].get_group_index(), 2);
+        assert_eq!(group_shares[3].get_member_index(), 1);
+        assert_eq!(group_shares[4].get_member_threshold(), Some(2));
+        // The group shares can be stored and restored.
+        let group_shares: Vec<GroupShare> = group_shares
+            .iter()
+            .map(|group_share| group_share.to_string().parse().unwrap())
+            .collect();
+        let family = &group_shares[..3];
+        let lawyer = &group_shares[3];
+        let safe_deposit_box = &group_shares[4..];
+        let mut shares = vec![family[0].clone(), family[2].clone(), lawyer.clone()];
+        assert_eq!(
+            reconstruct_seed_phrase_from_groups(&shares).expect("The reconstruction should work."),
+            seed_phrase
+        );
+        shares = safe_deposit_box.to_vec();
+        shares.push(lawyer.clone());
+        assert_eq!(
+            reconstruct_seed_phrase_from_groups(&shares).expect("The reconstruction should work."),
+            seed_phrase
+        );
+        // Incomplete groups do not count.
+        shares = vec![
+            family[1].clone(),
+            safe_deposit_box[0].clone(),
+            lawyer.clone(),
+        ];
+        assert!(reconstruct_seed_phrase_from_groups(&shares).is_err());
+        shares.push(family[0].clone());
+        assert_eq!(
+            reconstruct_seed_phrase_from_groups(&shares).expect("The reconstruction should work."),
+            seed_phrase
+        );
+        // The parameters are checked.
+        assert!(create_group_shares(&seed_phrase, 3, &[(2, 3), (1, 1)]).is_err());
+        assert!(create_group_shares(&seed_phrase, 1, &[(3, 2)]).is_err());
+        assert!(reconstruct_seed_phrase_from_groups(&[]).is_err());
+    }
+
+    #[test]
+    /// The function tests access policies with mandatory secret-shared seed phrases.
+    fn test_policy_shares() {
+        let seed_phrase = generate_seed_phrase(12).expect("The generation should work.");
+        // The executor's secret-shared seed phrase plus any 2 of 4 family ones are required.
+        let policy = AccessPolicy::new(2, 4).mandatory_shares(1);
+        assert_eq!(policy.get_groups(), vec![(1, 1), (2, 4)]);
+        let group_shares =
+            create_policy_shares(&seed_phrase, &policy).expect("The creation should work.");
+        assert_eq!(group_shares.len(), 5);
+        let executor = &group_shares[0];
+        let family = &group_shares[1..];
+        let shares = vec![executor.clone(), family[1].clone(), family[3].clone()];
+        assert_eq!(
+            reconstruct_seed_phrase_from_groups(&shares).expect("The reconstruction should work."),
+            seed_phrase
+        );
+        // The executor's secret-shared seed phrase cannot be replaced by family ones.
+        assert!(reconstruct_seed_phrase_from_groups(family).is_err());
+        assert!(reconstruct_seed_phrase_from_groups(&shares[..2]).is_err());
+        // Policies consisting of mandatory secret-shared seed phrases only are supported.
+        let policy = AccessPolicy::new(0, 0).mandatory_shares(2);
+        let group_shares =
+            create_policy_shares(&seed_phrase, &policy).expect("The creation should work.");
+        assert_eq!(
+            reconstruct_seed_phrase_from_groups(&group_shares)
+                .expect("The reconstruction should work."),
+            seed_phrase
+        );
+        assert!(reconstruct_seed_phrase_from_groups(&group_shares[1..]).is_err());
+        assert!(create_policy_shares(&seed_phrase, &AccessPolicy::new(0, 3)).is_err());
+    }
+
+    #[test]
+    /// The function tests weighted shares.
+    fn test_weighted_shares() {
+        let seed_phrase = generate_seed_phrase(18).expect("The generation should work.");
+        for embed_indices in [true, false] {
+            // The CFO alone reaches the threshold, whereas two managers are required otherwise.
+            let weighted_shares =
+                create_weighted_shares(&seed_phrase, 2, &[2, 1, 1], embed_indices)
+                    .expect("The creation should work.");
+            assert_eq!(weighted_shares.len(), 3);
+            assert_eq!(weighted_shares[0].get_weight(), 2);
+            let weighted_shares: Vec<WeightedShare> = weighted_shares
+                .iter()
+                .map(|weighted_share| weighted_share.to_string().parse().unwrap())
+                .collect();
+            for holders in [&weighted_shares[..1], &weighted_shares[1..]] {
+                assert_eq!(
+                    reconstruct_seed_phrase_from_weighted_shares(holders)
+                        .expect("The reconstruction should work."),
+                    seed_phrase
+                );
+            }
+            assert!(
+                reconstruct_seed_phrase_from_weighted_shares(&weighted_shares[2..])
+                    .map_or(true, |result| result != seed_phrase)
+            );
+        }
+        assert!(create_weighted_shares(&seed_phrase, 2, &[2, 0], false).is_err());
+        assert!(create_weighted_shares(&seed_phrase, 4, &[2, 1], false).is_err());
+    }
 }
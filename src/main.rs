@@ -4,15 +4,47 @@
 
 extern crate clap;
 use clap::{App, Arg, ArgMatches, SubCommand};
-use harpo::seed_phrase::SeedPhrase;
+use harpo::commitment::PolynomialCommitment;
+use harpo::ethereum::EthereumPrivateKey;
+use harpo::seed_phrase::{
+    can_embed_index, get_share_format_header, has_index_word, parse_phrase,
+    parse_phrases_from_reader, IndexEncoding, PhraseStandard, SeedPhrase, MAX_INDEX_WORD_SHARES,
+    SHARE_FORMAT_VERSION, VALID_NUM_WORDS, WILDCARD_WORD,
+};
+use harpo::verification::VerificationData;
+use harpo::word_list::{Language, WordList, DEFAULT_WORD_LIST};
 use harpo::{
-    create_secret_shared_seed_phrases, create_secret_shared_seed_phrases_for_word_list,
-    generate_seed_phrase, generate_seed_phrase_for_word_list, reconstruct_seed_phrase,
-    reconstruct_seed_phrase_for_word_list, validate_seed_phrase,
-    validate_seed_phrase_for_word_list, HarpoError, HarpoResult, SeedPhraseResult,
+    check_secret_shared_seed_phrase, check_secret_shared_seed_phrase_for_word_list,
+    check_word_list, complete_secret_shared_seed_phrase,
+    complete_secret_shared_seed_phrase_for_word_list, create_secret_shared_seed_phrases,
+    create_secret_shared_seed_phrases_for_word_list, create_seed_xor_parts,
+    create_seed_xor_parts_for_word_list, derive_child_seed_phrase,
+    derive_child_seed_phrase_for_word_list, diagnose_seed_phrase,
+    diagnose_seed_phrase_for_word_list, generate_seed_phrase, generate_seed_phrase_for_word_list,
+    generate_test_vectors, generate_test_vectors_for_word_list, get_bundled_languages_for_word,
+    get_bundled_word_list, get_polynomial_commitment, get_polynomial_commitment_for_word_list,
+    get_verification_data, get_verification_data_for_word_list, health_check,
+    reconstruct_ethereum_private_key, reconstruct_ethereum_private_key_for_word_list,
+    reconstruct_seed_phrase, reconstruct_seed_phrase_for_standard,
+    reconstruct_seed_phrase_for_standard_for_word_list, reconstruct_seed_phrase_for_word_list,
+    reconstruct_seed_phrase_from_xor_parts, reconstruct_seed_phrase_from_xor_parts_for_word_list,
+    reconstruct_seed_phrase_with_report, reconstruct_seed_phrase_with_report_for_word_list,
+    regenerate_share, regenerate_share_for_word_list, reshare_shares_with_progress_for_word_list,
+    segment_seed_phrase_for_word_list, split_ethereum_private_key,
+    split_ethereum_private_key_for_word_list, validate_seed_phrase_for_standard,
+    validate_seed_phrase_for_standard_for_word_list, validate_shares,
+    validate_shares_for_word_list, verify_polynomial_commitment,
+    verify_polynomial_commitment_for_word_list, verify_share, verify_share_for_word_list,
+    ErrorContext, HarpoError, HarpoResult, Progress, SeedPhraseResult, SplitRequest,
     MAX_EMBEDDED_SHARES,
 };
-use std::fs::read_to_string;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::fs::{read_to_string, File, OpenOptions};
+use std::io::{BufReader, Seek, SeekFrom, Write};
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
 
 /// The subcommand to create secret-shared seed phrases.
 const CREATE_SUBCOMMAND: &str = "create";
@@ -26,6 +58,113 @@ const GENERATE_SUBCOMMAND: &str = "generate";
 /// The subcommand to validate a seed phrase, i.e., check BIP-0039 compliance.
 const VALIDATE_SUBCOMMAND: &str = "validate";
 
+/// The subcommand to verify secret-shared seed phrases against public verification data.
+const VERIFY_SHARE_SUBCOMMAND: &str = "verify-share";
+
+/// The subcommand to re-create secret-shared seed phrases from existing ones.
+const UPGRADE_SHARES_SUBCOMMAND: &str = "upgrade-shares";
+
+/// The subcommand to re-create a lost secret-shared seed phrase from surviving ones.
+const REGENERATE_SHARE_SUBCOMMAND: &str = "regenerate-share";
+
+/// The subcommand to run the built-in known-answer tests.
+const SELFTEST_SUBCOMMAND: &str = "selftest";
+
+/// The subcommand to measure the performance of the creation and reconstruction.
+const BENCH_SUBCOMMAND: &str = "bench";
+
+/// The subcommand to create secret-shared seed phrases for a raw private key.
+const SPLIT_KEY_SUBCOMMAND: &str = "split-key";
+
+/// The subcommand to reconstruct a raw private key.
+const JOIN_KEY_SUBCOMMAND: &str = "join-key";
+
+/// The subcommand to derive a child seed phrase from a master seed phrase.
+const DERIVE_SUBCOMMAND: &str = "derive";
+
+/// The subcommand to run a guided demo with generated sample data.
+const DEMO_SUBCOMMAND: &str = "demo";
+
+/// The subcommand to generate reproducible test vectors.
+const GEN_TEST_VECTORS_SUBCOMMAND: &str = "gen-test-vectors";
+
+/// The subcommand to merge secret-shared seed phrases from multiple sources into a bundle.
+const MERGE_SUBCOMMAND: &str = "merge";
+
+/// The subcommand to split a seed phrase into Seed XOR parts.
+const XOR_SPLIT_SUBCOMMAND: &str = "xor-split";
+
+/// The subcommand to reconstruct a seed phrase from Seed XOR parts.
+const XOR_JOIN_SUBCOMMAND: &str = "xor-join";
+
+/// The description of secret-shared seed phrases used in prompts.
+const SHARE_DESCRIPTION: &str = "secret-shared seed phrase";
+
+/// The description of Seed XOR parts used in prompts.
+const XOR_PART_DESCRIPTION: &str = "Seed XOR part";
+
+/// The warning that is printed whenever Seed XOR parts are created or combined.
+///
+/// Since the XOR of any subset of the parts is a valid seed phrase, neither harpo nor the user
+/// can tell whether a part is missing.
+const XOR_MISSING_PART_WARNING: &str = "Warning: All Seed XOR parts are required. A missing \
+    part cannot be detected: Combining only some of the parts yields a different but valid seed \
+    phrase without any error.";
+
+/// The maximum number of shared prefixes of a word list that are listed in a warning.
+const MAX_LISTED_SHARED_PREFIXES: usize = 5;
+
+/// The file system types that are considered memory-backed.
+const MEMORY_BACKED_FILE_SYSTEMS: &[&str] = &["tmpfs", "ramfs"];
+
+/// The placeholder for masked words.
+const MASK_PLACEHOLDER: &str = "\u{25AE}\u{25AE}\u{25AE}";
+
+/// The minimum number of secret-shared seed phrases for which a progress bar is shown.
+const PROGRESS_MIN_SHARES: usize = 1000;
+
+/// The number of passes used to overwrite input files before removing them.
+const SHRED_NUM_PASSES: usize = 2;
+
+/// The size of the buffer used to overwrite input files.
+const SHRED_BUFFER_SIZE: usize = 4096;
+
+/// The step in percent at which progress is reported in the accessible output mode.
+const ACCESSIBLE_PROGRESS_STEP: usize = 25;
+
+/// The text that replaces masked words in the accessible output mode.
+const ACCESSIBLE_MASK_PLACEHOLDER: &str = "hidden";
+
+/// Flag indicating whether the accessible output mode is enabled.
+///
+/// The flag is set once at startup and determines how titles, secret output, and progress are
+/// presented throughout the program.
+static ACCESSIBLE_OUTPUT: AtomicBool = AtomicBool::new(false);
+
+/// The maximum number of candidate words that are shown for an unknown word.
+const MAX_DISPLAYED_CANDIDATES: usize = 20;
+
+/// The length of the progress bar in characters.
+const PROGRESS_BAR_LENGTH: usize = 40;
+
+/// The minimum number of benchmark iterations for which a progress bar is shown.
+const PROGRESS_MIN_ITERATIONS: usize = 10;
+
+/// The default number of words used in the benchmark.
+const BENCH_DEFAULT_LENGTH: &str = "24";
+
+/// The default number of iterations used in the benchmark.
+const BENCH_DEFAULT_ITERATIONS: &str = "10";
+
+/// The default number of words of a derived child seed phrase.
+const DERIVE_DEFAULT_WORDS: &str = "12";
+
+/// The default threshold used in the guided demo.
+const DEMO_DEFAULT_THRESHOLD: &str = "2";
+
+/// The default number of shares used in the guided demo.
+const DEMO_DEFAULT_NUM_SHARES: &str = "3";
+
 /// The function parses the command-line arguments.
 fn parse_command_line<'a>() -> ArgMatches<'a> {
     // Extract version and author from the Cargo.toml file.
@@ -41,37 +180,133 @@ fn parse_command_line<'a>() -> ArgMatches<'a> {
         .long("file")
         .help("Uses the data in the provided file as input");
 
+    // The argument --electrum is used to process Electrum seed phrases.
+    let electrum_argument = Arg::with_name("electrum")
+        .long("electrum")
+        .help("Processes an Electrum seed phrase instead of a BIP-0039 seed phrase")
+        .takes_value(false);
+
+    // The arguments of the create subcommand, which are also used by the upgrade-shares
+    // subcommand.
+    let create_arguments = vec![
+        file_argument.clone(),
+        Arg::with_name("no-embedding") // The embedding of share indices can be turned off.
+            .short("N")
+            .long("no-embedding")
+            .help("Stores share identifiers separately")
+            .takes_value(false),
+        Arg::with_name("commitment") // The polynomial commitment can be output.
+            .short("C")
+            .long("commitment")
+            .help("Outputs a commitment to the polynomial for later verification")
+            .takes_value(false),
+        Arg::with_name("index-word") // The share indices can be stored in an additional word.
+            .short("I")
+            .long("index-word")
+            .help("Stores share identifiers in an additional word")
+            .conflicts_with("no-embedding")
+            .takes_value(false),
+        Arg::with_name("num-shares") // The total number of shares.
+            .required(true)
+            .takes_value(true)
+            .short("n")
+            .long("num-shares")
+            .help("Sets the total number of shares to the given value"),
+        Arg::with_name("threshold") // The threshold for reconstruction.
+            .required(true)
+            .takes_value(true)
+            .short("t")
+            .long("threshold")
+            .help("Sets the threshold to the given value"),
+        Arg::with_name("correctable-errors") // Additional shares allow for error correction.
+            .takes_value(true)
+            .short("E")
+            .long("correctable-errors")
+            .help("Creates two additional shares per corrupted share that can be corrected"),
+    ];
+
     // The create subcommand.
     let create_subcommand = SubCommand::with_name(CREATE_SUBCOMMAND)
         .about("Creates secret-shared seed phrases")
+        .args(&create_arguments)
+        .arg(electrum_argument.clone())
+        .arg(
+            Arg::with_name("verification-data") // The public verification data can be stored.
+                .short("D")
+                .long("verification-data")
+                .help("Writes public data to verify the shares later to the provided file")
+                .takes_value(true),
+        );
+
+    // The upgrade-shares subcommand.
+    let upgrade_shares_subcommand = SubCommand::with_name(UPGRADE_SHARES_SUBCOMMAND)
+        .about("Re-creates secret-shared seed phrases from sufficiently many existing ones")
+        .args(&create_arguments);
+
+    // The regenerate-share subcommand.
+    let regenerate_share_subcommand = SubCommand::with_name(REGENERATE_SHARE_SUBCOMMAND)
+        .about("Re-creates a lost secret-shared seed phrase from sufficiently many surviving ones")
         .arg(file_argument.clone())
         .arg(
-            Arg::with_name("no-embedding") // The embedding of share indices can be turned off.
-                .short("N")
-                .long("no-embedding")
-                .help("Stores share identifiers separately")
+            Arg::with_name("index") // The index of the lost secret-shared seed phrase.
+                .required(true)
+                .takes_value(true)
+                .short("i")
+                .long("index")
+                .help("Re-creates the secret-shared seed phrase with the given index"),
+        );
+
+    // The split-key subcommand.
+    let split_key_subcommand = SubCommand::with_name(SPLIT_KEY_SUBCOMMAND)
+        .about("Creates secret-shared seed phrases for a hex-encoded private key")
+        .args(&create_arguments);
+
+    // The arguments of the reconstruct subcommand, which are also used by the join-key
+    // subcommand.
+    let reconstruct_arguments = vec![
+        file_argument.clone(),
+        Arg::with_name("commitment") // The polynomial commitment can be verified.
+            .short("C")
+            .long("commitment")
+            .help("Verifies that the seed phrases match the given polynomial commitment")
+            .takes_value(true),
+    ];
+
+    // The reconstruct subcommand.
+    let reconstruct_subcommand = SubCommand::with_name(RECONSTRUCT_SUBCOMMAND)
+        .about("Reconstructs a seed phrase")
+        .args(&reconstruct_arguments)
+        .arg(electrum_argument.clone().conflicts_with("report"))
+        .arg(
+            Arg::with_name("report") // The provenance of the shares can be reported.
+                .short("R")
+                .long("report")
+                .help("Reports the used and ignored shares and the fingerprint of the result")
                 .takes_value(false),
-        )
+        );
+
+    // The join-key subcommand.
+    let join_key_subcommand = SubCommand::with_name(JOIN_KEY_SUBCOMMAND)
+        .about("Reconstructs a hex-encoded private key")
+        .args(&reconstruct_arguments);
+
+    // The merge subcommand.
+    let merge_subcommand = SubCommand::with_name(MERGE_SUBCOMMAND)
+        .about("Merges secret-shared seed phrases from multiple files and directories")
         .arg(
-            Arg::with_name("num-shares") // The total number of shares.
+            Arg::with_name("sources") // The files and directories containing the shares.
                 .required(true)
+                .multiple(true)
                 .takes_value(true)
-                .short("n")
-                .long("num-shares")
-                .help("Sets the total number of shares to the given value"),
+                .help("Reads secret-shared seed phrases from the provided files and directories"),
         )
         .arg(
-            Arg::with_name("threshold") // The threshold for reconstruction.
-                .required(true)
+            Arg::with_name("threshold") // The threshold is used to report missing shares.
                 .takes_value(true)
                 .short("t")
                 .long("threshold")
-                .help("Sets the threshold to the given value"),
+                .help("Reports how many secret-shared seed phrases are missing for each set"),
         );
-    // The reconstruct subcommand.
-    let reconstruct_subcommand = SubCommand::with_name(RECONSTRUCT_SUBCOMMAND)
-        .about("Reconstructs a seed phrase")
-        .arg(file_argument.clone());
 
     // The generate subcommand.
     let generate_subcommand = SubCommand::with_name(GENERATE_SUBCOMMAND)
@@ -88,8 +323,148 @@ fn parse_command_line<'a>() -> ArgMatches<'a> {
     // The validate subcommand.
     let validate_subcommand = SubCommand::with_name(VALIDATE_SUBCOMMAND)
         .about("Validates a seed phrase")
+        .arg(file_argument.clone())
+        .arg(electrum_argument);
+
+    // The verify-share subcommand.
+    let verify_share_subcommand = SubCommand::with_name(VERIFY_SHARE_SUBCOMMAND)
+        .about("Verifies secret-shared seed phrases against public verification data")
+        .arg(file_argument.clone())
+        .arg(
+            Arg::with_name("verification-data") // The public verification data.
+                .required(true)
+                .short("D")
+                .long("verification-data")
+                .help("Reads the verification data from the provided file")
+                .takes_value(true),
+        );
+
+    // The selftest subcommand.
+    let selftest_subcommand = SubCommand::with_name(SELFTEST_SUBCOMMAND)
+        .about("Runs built-in known-answer tests to verify the binary");
+
+    // The bench subcommand.
+    let bench_subcommand = SubCommand::with_name(BENCH_SUBCOMMAND)
+        .about("Measures the time to create and reconstruct secret-shared seed phrases")
+        .arg(
+            Arg::with_name("length") // The number of words of the generated seed phrase.
+                .takes_value(true)
+                .short("l")
+                .long("length")
+                .default_value(BENCH_DEFAULT_LENGTH)
+                .help("Sets the number of words of the generated seed phrase"),
+        )
+        .arg(
+            Arg::with_name("num-shares") // The total number of shares.
+                .required(true)
+                .takes_value(true)
+                .short("n")
+                .long("num-shares")
+                .help("Sets the total number of shares to the given value"),
+        )
+        .arg(
+            Arg::with_name("threshold") // The threshold for reconstruction.
+                .required(true)
+                .takes_value(true)
+                .short("t")
+                .long("threshold")
+                .help("Sets the threshold to the given value"),
+        )
+        .arg(
+            Arg::with_name("iterations") // The number of measured iterations.
+                .takes_value(true)
+                .short("i")
+                .long("iterations")
+                .default_value(BENCH_DEFAULT_ITERATIONS)
+                .help("Sets the number of iterations to the given value"),
+        );
+
+    // The derive subcommand.
+    let derive_subcommand = SubCommand::with_name(DERIVE_SUBCOMMAND)
+        .about("Derives a child seed phrase from a master seed phrase (BIP-0085)")
+        .arg(file_argument.clone())
+        .arg(
+            Arg::with_name("from-shares") // The master seed phrase can be reconstructed first.
+                .short("S")
+                .long("from-shares")
+                .help("Reconstructs the master seed phrase from secret-shared seed phrases")
+                .takes_value(false),
+        )
+        .arg(
+            Arg::with_name("index") // The index of the child seed phrase.
+                .required(true)
+                .takes_value(true)
+                .short("i")
+                .long("index")
+                .help("Sets the index of the child seed phrase to the given value"),
+        )
+        .arg(
+            Arg::with_name("words") // The number of words of the child seed phrase.
+                .takes_value(true)
+                .long("words")
+                .default_value(DERIVE_DEFAULT_WORDS)
+                .possible_values(&["12", "18", "24"])
+                .help("Sets the number of words of the child seed phrase"),
+        );
+
+    // The xor-split subcommand.
+    let xor_split_subcommand = SubCommand::with_name(XOR_SPLIT_SUBCOMMAND)
+        .about("Splits a seed phrase into Seed XOR parts, all of which are required")
+        .arg(file_argument.clone())
+        .arg(
+            Arg::with_name("num-parts") // The number of parts.
+                .required(true)
+                .takes_value(true)
+                .short("n")
+                .long("num-parts")
+                .possible_values(&["2", "3"])
+                .help("Sets the number of parts to the given value"),
+        );
+
+    // The xor-join subcommand.
+    let xor_join_subcommand = SubCommand::with_name(XOR_JOIN_SUBCOMMAND)
+        .about("Reconstructs a seed phrase from all of its Seed XOR parts")
         .arg(file_argument);
 
+    // The gen-test-vectors subcommand.
+    let gen_test_vectors_subcommand = SubCommand::with_name(GEN_TEST_VECTORS_SUBCOMMAND)
+        .about("Generates reproducible test vectors in JSON format for other implementations")
+        .arg(
+            Arg::with_name("seed") // The seed from which the test vectors are derived.
+                .required(true)
+                .takes_value(true)
+                .short("s")
+                .long("seed")
+                .help("Derives all secrets and coefficients from the given hex-encoded seed"),
+        );
+
+    // The demo subcommand.
+    let demo_subcommand = SubCommand::with_name(DEMO_SUBCOMMAND)
+        .about("Runs a guided walkthrough using generated sample data")
+        .arg(
+            Arg::with_name("length") // The number of words of the sample seed phrase.
+                .takes_value(true)
+                .short("l")
+                .long("length")
+                .help("Sets the number of words of the sample seed phrase"),
+        )
+        .arg(
+            Arg::with_name("num-shares") // The total number of shares.
+                .takes_value(true)
+                .short("n")
+                .long("num-shares")
+                .default_value(DEMO_DEFAULT_NUM_SHARES)
+                .help("Sets the total number of shares to the given value"),
+        )
+        .arg(
+            Arg::with_name("threshold") // The threshold for reconstruction.
+                .takes_value(true)
+                .short("t")
+                .long("threshold")
+                .default_value(DEMO_DEFAULT_THRESHOLD)
+                .help("Sets the threshold to the given value"),
+        );
+
     // The application including the top-level arguments.
     App::new("harpo")
         .version(VERSION)
@@ -109,50 +484,82 @@ fn parse_command_line<'a>() -> ArgMatches<'a> {
                 .help("Reads the word list from the provided file")
                 .takes_value(true),
         )
+        .arg(
+            Arg::with_name("language") // A bundled word list can be selected.
+                .short("L")
+                .long("language")
+                .help("Uses the bundled word list for the provided language, e.g., English or Japanese")
+                .conflicts_with("word-list")
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("output") // Secrets can be written to a file.
+                .short("o")
+                .long("output")
+                .help(
+                    "Writes the secret output to the provided file on a memory-backed file system",
+                )
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("output-fd") // Secrets can be written to a file descriptor.
+                .long("output-fd")
+                .help("Writes the secret output to the provided file descriptor")
+                .conflicts_with("output")
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("force") // Secrets can be written to any file system when forced.
+                .long("force")
+                .help("Writes the secret output even if the file system is not memory-backed")
+                .takes_value(false),
+        )
+        .arg(
+            Arg::with_name("shred") // Consumed input files can be overwritten and removed.
+                .long("shred")
+                .help("Overwrites and removes the input file after successful processing")
+                .takes_value(false),
+        )
+        .arg(
+            Arg::with_name("accessible") // Output can be optimized for screen readers.
+                .long("accessible")
+                .help("Prints labeled line-by-line output without decorations or progress bars")
+                .takes_value(false),
+        )
+        .arg(
+            Arg::with_name("mask") // Secrets can be partially masked on standard output.
+                .long("mask")
+                .help("Shows only the words at even or odd positions of the secret output")
+                .possible_values(&["even", "odd"])
+                .takes_value(true),
+        )
         .subcommand(create_subcommand) // Add the create subcommand.
         .subcommand(reconstruct_subcommand) // Add the reconstruct subcommand.
         .subcommand(generate_subcommand) // Add the generate subcommand.
         .subcommand(validate_subcommand) // Add the validate subcommand.
+        .subcommand(verify_share_subcommand) // Add the verify-share subcommand.
+        .subcommand(upgrade_shares_subcommand) // Add the upgrade-shares subcommand.
+        .subcommand(regenerate_share_subcommand) // Add the regenerate-share subcommand.
+        .subcommand(selftest_subcommand) // Add the selftest subcommand.
+        .subcommand(bench_subcommand) // Add the bench subcommand.
+        .subcommand(split_key_subcommand) // Add the split-key subcommand.
+        .subcommand(join_key_subcommand) // Add the join-key subcommand.
+        .subcommand(derive_subcommand) // Add the derive subcommand.
+        .subcommand(demo_subcommand) // Add the demo subcommand.
+        .subcommand(gen_test_vectors_subcommand) // Add the gen-test-vectors subcommand.
+        .subcommand(merge_subcommand) // Add the merge subcommand.
+        .subcommand(xor_split_subcommand) // Add the xor-split subcommand.
+        .subcommand(xor_join_subcommand) // Add the xor-join subcommand.
         .get_matches()
 }
 
-/// The function converts the given string into a seed phrase.
-///
-/// The function takes a space-delimited seed phrase in the form of a string (slice) as its
-/// argument and returns a seed phrase if the string can
-/// be split into sufficiently many words.
-/// Note that the function does not verify the validity of the provided words.
-///
-/// * `input` - The input seed phrase as a space-delimited string.
-fn convert_string_to_seed_phrase(input: &str) -> SeedPhraseResult {
-    // Break the input into words.
-    let mut words: Vec<String> = input
-        .replace(':', ": ") // If there is an index, ensure that it is a separate word.
-        .to_lowercase() // No upper-case words are allowed.
-        .trim() // Remove white spaces in the beginning and at the end.
-        .split(' ') // Split the string.
-        .filter(|word| !word.is_empty()) // Keep only words with a positive length.
-        .map(str::to_string) // Map the string slices to strings.
-        .collect(); // Collect the vector.
-    if words.is_empty() {
-        // Make sure that there are sufficiently many words.
-        return Err(HarpoError::InvalidSeedPhrase(
-            "No seed phrase provided.".to_string(),
-        ));
-    }
-    // If there is an explicit index, extract it from the list of words.
-    if words[0].contains(':') {
-        let index_string = words.remove(0);
-        match index_string.replace(":", "").parse::<u32>() {
-            Ok(index) => Ok(SeedPhrase::new_with_index(&words, index)),
-            Err(_) => Err(HarpoError::InvalidSeedPhrase(
-                "Could not parse index of seed phrase.".to_string(),
-            )),
-        }
-    } else {
-        // Otherwise, create a seed phrase without an index.
-        Ok(SeedPhrase::new(&words))
-    }
+/// The function runs the given function that reads from the file with the given path and
+/// attaches the file path to the error, if any.
+///
+/// * `file_path` - The path to the file.
+/// * `read` - The function that reads from the file.
+fn with_file_context<T>(file_path: &str, read: impl FnOnce() -> HarpoResult<T>) -> HarpoResult<T> {
+    read().map_err(|error| error.with_context(ErrorContext::File(file_path.into())))
 }
 
 /// The function reads a seed phrase from the given file.
@@ -162,48 +569,207 @@ fn convert_string_to_seed_phrase(input: &str) -> SeedPhraseResult {
 ///
 /// * `file_path` - The path to the file containing the seed phrase.
 fn read_seed_phrase_from_file(file_path: &str) -> SeedPhraseResult {
-    // Read the file content.
-    let file_content = read_to_string(file_path)?;
-    // Find a line that might encode a seed phrase.
-    let seed_phrase_string = file_content
-        .lines()
-        .find(|line| !line.starts_with('#') && !line.is_empty());
-    // If a seed phrase is found, turn the string into a SeedPhrase struct and return it.
-    match seed_phrase_string {
-        Some(seed_phrase_string) => convert_string_to_seed_phrase(seed_phrase_string),
-        None => Err(HarpoError::InvalidSeedPhrase(format!(
-            "Could not read the seed phrase from the file {}.",
-            file_path
-        ))),
-    }
+    with_file_context(file_path, || {
+        // Read the file content.
+        let file_content = read_to_string(file_path)?;
+        // Find a line that might encode a seed phrase.
+        let seed_phrase_string = file_content
+            .lines()
+            .find(|line| !line.starts_with('#') && !line.is_empty());
+        // If a seed phrase is found, turn the string into a SeedPhrase struct and return it.
+        match seed_phrase_string {
+            Some(seed_phrase_string) => parse_phrase(seed_phrase_string),
+            None => Err(HarpoError::InvalidSeedPhrase(
+                "Could not read the seed phrase from the file.".to_string(),
+            )),
+        }
+    })
 }
 
 /// The function reads a seed phrase from standard input.
 ///
 /// The function reads a line from standard input and returns it as a
 /// seed phrase if possible.
-fn read_seed_phrase_interactively() -> SeedPhraseResult {
+///
+/// * `word_list` - The word list, if provided.
+fn read_seed_phrase_interactively(word_list: &Option<Vec<String>>) -> SeedPhraseResult {
     let mut seed_phrase_string = String::new();
     println!("Please enter your seed phrase (12, 15, 18, 21, or 24 space-delimited words):");
     // Read from standard input.
     let _ = std::io::stdin().read_line(&mut seed_phrase_string)?;
     // If the input can be converted to a seed phrase, return the seed phrase.
-    convert_string_to_seed_phrase(&seed_phrase_string)
+    offer_word_suggestions(parse_phrase(&seed_phrase_string)?, word_list)
 }
 
-/// The function handles the creation of secret-shared seed phrases.
+/// The function offers corrections for the words of the given seed phrase that are not in the
+/// word list.
 ///
-/// The input to the function is the command-line arguments. If processing succeeds,
-/// the function returns the secret-shared seed phrases.
+/// For each such word, the words that differ by a single typo are listed, and the user can
+/// either select one of them or keep the word.
+///
+/// * `seed_phrase` - The seed phrase.
+/// * `word_list` - The word list, if provided.
+fn offer_word_suggestions(
+    seed_phrase: SeedPhrase,
+    word_list: &Option<Vec<String>>,
+) -> SeedPhraseResult {
+    let diagnostics = match word_list {
+        Some(list) => {
+            let slice_list: Vec<&str> = list.iter().map(|s| s.as_str()).collect();
+            diagnose_seed_phrase_for_word_list(&seed_phrase, &slice_list)
+        }
+        None => diagnose_seed_phrase(&seed_phrase),
+    };
+    let mut words: Vec<String> = seed_phrase
+        .get_words()
+        .into_iter()
+        .map(str::to_string)
+        .collect();
+    for unknown_word in diagnostics.get_unknown_words() {
+        let suggestions = unknown_word.get_suggestions();
+        if suggestions.is_empty() {
+            continue;
+        }
+        println!(
+            "Word {} ('{}') is not in the word list. Did you mean:",
+            unknown_word.get_position() + 1,
+            unknown_word.get_word()
+        );
+        for (number, suggestion) in suggestions.iter().enumerate() {
+            println!("  {}) {}", number + 1, suggestion);
+        }
+        println!("Enter the number of the correct word or press enter to keep the word:");
+        let mut answer = String::new();
+        let _ = std::io::stdin().read_line(&mut answer)?;
+        if let Some(suggestion) = answer
+            .trim()
+            .parse::<usize>()
+            .ok()
+            .and_then(|number| number.checked_sub(1))
+            .and_then(|position| suggestions.get(position))
+        {
+            words[unknown_word.get_position()] = suggestion.clone();
+        }
+    }
+    Ok(match seed_phrase.get_index() {
+        Some(index) => SeedPhrase::new_with_index(&words, index),
+        None => SeedPhrase::new(&words),
+    })
+}
+
+/// The function splits concatenated words of the given seed phrase, e.g., of a Chinese seed
+/// phrase written without spaces, using the provided word list.
+///
+/// The seed phrase is returned unchanged if no word list is provided because the words of the
+/// default word list are always separated by spaces.
+///
+/// * `seed_phrase` - The seed phrase.
+/// * `word_list` - The word list, if provided.
+fn segment_seed_phrase(
+    seed_phrase: SeedPhrase,
+    word_list: &Option<Vec<String>>,
+) -> SeedPhraseResult {
+    match word_list {
+        Some(list) => {
+            let slice_list: Vec<&str> = list.iter().map(|s| s.as_str()).collect();
+            segment_seed_phrase_for_word_list(&seed_phrase, &slice_list)
+        }
+        None => Ok(seed_phrase),
+    }
+}
+
+/// The function splits concatenated words of all given seed phrases using the provided word
+/// list, see [segment_seed_phrase].
+///
+/// * `seed_phrases` - The seed phrases.
+/// * `word_list` - The word list, if provided.
+fn segment_seed_phrases(
+    seed_phrases: Vec<SeedPhrase>,
+    word_list: &Option<Vec<String>>,
+) -> HarpoResult<Vec<SeedPhrase>> {
+    seed_phrases
+        .into_iter()
+        .map(|seed_phrase| segment_seed_phrase(seed_phrase, word_list))
+        .collect()
+}
+
+/// The function checks that the words of the given seed phrases are in the active word list.
+///
+/// If a word is not in the active word list but in a bundled word list, the language of the
+/// bundled word list is reported. When reading input interactively, the user is offered to
+/// switch to the bundled word list.
+/// The function returns the word list to be used.
+///
+/// * `seed_phrases` - The seed phrases.
+/// * `word_list` - The active word list, if provided.
+/// * `interactive` - Flag indicating whether the user can be asked to switch the word list.
+fn resolve_word_list(
+    seed_phrases: &[SeedPhrase],
+    word_list: Option<Vec<String>>,
+    interactive: bool,
+) -> HarpoResult<Option<Vec<String>>> {
+    // Find the first word that is not in the active word list.
+    let active_list: Vec<&str> = match &word_list {
+        Some(list) => list.iter().map(|word| word.as_str()).collect(),
+        None => Language::default().get_word_list().to_vec(),
+    };
+    let unknown_word = seed_phrases
+        .iter()
+        .flat_map(|seed_phrase| seed_phrase.get_words())
+        .find(|word| active_list.get_index(word).is_none());
+    let unknown_word = match unknown_word {
+        Some(word) => word.to_string(),
+        None => return Ok(word_list),
+    };
+    // Find a bundled word list that contains all words of the seed phrases.
+    let candidate = get_bundled_languages_for_word(&unknown_word)
+        .into_iter()
+        .find(|language| {
+            get_bundled_word_list(language).is_some_and(|list| {
+                seed_phrases
+                    .iter()
+                    .flat_map(|seed_phrase| seed_phrase.get_words())
+                    .all(|word| list.get_index(word).is_some())
+            })
+        });
+    let language = match candidate {
+        Some(language) => language,
+        None => return Ok(word_list),
+    };
+    if !interactive {
+        return Err(HarpoError::InvalidSeedPhrase(format!(
+            "The word '{}' is not in the active word list, but the seed phrase matches the \
+            {} word list.",
+            unknown_word, language
+        )));
+    }
+    println!();
+    println!(
+        "The word '{}' is not in the active word list, but the seed phrase matches the {} word list.",
+        unknown_word, language
+    );
+    println!("Switch to the {} word list? [y/N]", language);
+    let mut answer = String::new();
+    let _ = std::io::stdin().read_line(&mut answer)?;
+    if answer.trim().eq_ignore_ascii_case("y") || answer.trim().eq_ignore_ascii_case("yes") {
+        // The unwrap() call is okay because the language was found among the bundled lists.
+        let list = get_bundled_word_list(language).unwrap();
+        Ok(Some(list.iter().map(|word| word.to_string()).collect()))
+    } else {
+        Ok(word_list)
+    }
+}
+
+/// The function parses and checks the parameters for the creation of secret-shared seed phrases.
+///
+/// If the parameters are valid, the function returns the number of shares, the threshold, and
+/// the index encoding. The number of shares includes two additional shares per correctable
+/// error.
 ///
 /// * `command_line` - The command-line arguments.
-/// * `verbose` - Flag indicating if verbose output should be generated.
-/// * `word_list` - The word list to be used, if provided.
-fn handle_create(
+fn get_create_parameters(
     command_line: &clap::ArgMatches,
-    verbose: bool,
-    word_list: Option<Vec<String>>,
-) -> HarpoResult<Vec<SeedPhrase>> {
+) -> HarpoResult<(usize, usize, IndexEncoding)> {
     // The unwrap() call is okay because --num-shares must be provided.
     let num_shares = command_line
         .value_of("num-shares")
@@ -214,7 +780,19 @@ fn handle_create(
         .value_of("threshold")
         .unwrap()
         .parse::<usize>()?;
-    let embed_indices = !command_line.is_present("no-embedding");
+    let num_correctable_errors = match command_line.value_of("correctable-errors") {
+        Some(num_errors) => num_errors.parse::<usize>()?,
+        None => 0,
+    };
+    let num_shares = num_shares.saturating_add(num_correctable_errors.saturating_mul(2));
+    let index_encoding = if command_line.is_present("no-embedding") {
+        IndexEncoding::Explicit
+    } else if command_line.is_present("index-word") {
+        IndexEncoding::IndexWord
+    } else {
+        IndexEncoding::Embedded
+    };
+    let embed_indices = index_encoding == IndexEncoding::Embedded;
     // Check early whether the parameters are valid.
     if threshold < 1 {
         return Err(HarpoError::InvalidParameter(
@@ -232,6 +810,12 @@ fn handle_create(
             MAX_EMBEDDED_SHARES
         )));
     }
+    if num_shares > MAX_INDEX_WORD_SHARES && index_encoding == IndexEncoding::IndexWord {
+        return Err(HarpoError::InvalidParameter(format!(
+            "Index words cannot be used (--index-word) when creating more than {} shares.",
+            MAX_INDEX_WORD_SHARES
+        )));
+    }
 
     if threshold > num_shares
         || threshold < 1
@@ -241,6 +825,36 @@ fn handle_create(
             "The provided parameters are invalid.".to_string(),
         ));
     }
+    Ok((num_shares, threshold, index_encoding))
+}
+
+/// The function returns the standard of the seed phrase given on the command line.
+///
+/// * `command_line` - The command-line arguments.
+fn get_phrase_standard(command_line: &clap::ArgMatches) -> PhraseStandard {
+    if command_line.is_present("electrum") {
+        PhraseStandard::Electrum
+    } else {
+        PhraseStandard::Bip39
+    }
+}
+
+/// The function handles the creation of secret-shared seed phrases.
+///
+/// The input to the function is the command-line arguments. If processing succeeds,
+/// the function returns the secret-shared seed phrases and, if requested, the polynomial
+/// commitment.
+///
+/// * `command_line` - The command-line arguments.
+/// * `verbose` - Flag indicating if verbose output should be generated.
+/// * `word_list` - The word list to be used, if provided.
+fn handle_create(
+    command_line: &clap::ArgMatches,
+    verbose: bool,
+    word_list: Option<Vec<String>>,
+) -> HarpoResult<(Vec<SeedPhrase>, Option<PolynomialCommitment>)> {
+    let (num_shares, threshold, index_encoding) = get_create_parameters(command_line)?;
+    let standard = get_phrase_standard(command_line);
     // Print verbose output if the flag --verbose is set.
     if verbose {
         println!(
@@ -258,8 +872,14 @@ fn handle_create(
         read_seed_phrase_from_file(file_path)?
     } else {
         // The seed phrase must be entered interactively.
-        read_seed_phrase_interactively()?
+        read_seed_phrase_interactively(&word_list)?
     };
+    let seed_phrase = segment_seed_phrase(seed_phrase, &word_list)?;
+    let word_list = resolve_word_list(
+        std::slice::from_ref(&seed_phrase),
+        word_list,
+        !command_line.is_present("file"),
+    )?;
     if verbose {
         println!();
         println!(
@@ -267,21 +887,206 @@ fn handle_create(
             seed_phrase
         );
     }
-    // Create the shares and return them.
-    match word_list {
-        Some(list) => {
-            let slice_list: Vec<&str> = list.iter().map(|s| s.as_str()).collect();
-            create_secret_shared_seed_phrases_for_word_list(
-                &seed_phrase,
-                threshold,
-                num_shares,
-                embed_indices,
-                &slice_list,
-            )
+    let verification_word_list = word_list.clone();
+    let (seed_phrases, commitment) = create_shares(
+        &seed_phrase,
+        threshold,
+        num_shares,
+        index_encoding,
+        standard,
+        command_line.is_present("commitment"),
+        word_list,
+    )?;
+    if let Some(file_path) = command_line.value_of("verification-data") {
+        let verification_data = match verification_word_list {
+            Some(list) => {
+                let slice_list: Vec<&str> = list.iter().map(|s| s.as_str()).collect();
+                get_verification_data_for_word_list(
+                    &seed_phrases,
+                    threshold,
+                    index_encoding,
+                    &slice_list,
+                )?
+            }
+            None => get_verification_data(&seed_phrases, threshold, index_encoding)?,
+        };
+        with_file_context(file_path, || {
+            Ok(std::fs::write(file_path, verification_data.to_string())?)
+        })?;
+        if verbose {
+            println!("Wrote the verification data to {}.", file_path);
         }
-        None => {
-            create_secret_shared_seed_phrases(&seed_phrase, threshold, num_shares, embed_indices)
+    }
+    Ok((seed_phrases, commitment))
+}
+
+/// The function creates secret-shared seed phrases and, if requested, the polynomial commitment.
+///
+/// * `seed_phrase` - The seed phrase to be secret-shared.
+/// * `threshold` - The threshold.
+/// * `num_shares` - The number of secret-shared seed phrases.
+/// * `index_encoding` - The encoding of the share indices.
+/// * `standard` - The standard of the seed phrase.
+/// * `with_commitment` - Flag indicating if the polynomial commitment should be computed.
+/// * `word_list` - The word list to be used, if provided.
+fn create_shares(
+    seed_phrase: &SeedPhrase,
+    threshold: usize,
+    num_shares: usize,
+    index_encoding: IndexEncoding,
+    standard: PhraseStandard,
+    with_commitment: bool,
+    word_list: Option<Vec<String>>,
+) -> HarpoResult<(Vec<SeedPhrase>, Option<PolynomialCommitment>)> {
+    // Create the shares.
+    let slice_list: Option<Vec<&str>> = word_list
+        .as_ref()
+        .map(|list| list.iter().map(|s| s.as_str()).collect());
+    // A progress bar is shown for large numbers of shares.
+    let mut show_progress = ProgressBar::new("Creating shares", PROGRESS_MIN_SHARES);
+    let mut request = SplitRequest::new(seed_phrase)
+        .threshold(threshold)
+        .shares(num_shares)
+        .index_encoding(index_encoding)
+        .standard(standard);
+    if let Some(list) = &slice_list {
+        request = request.word_list(list);
+    }
+    let seed_phrases = request.create_with_progress(&mut show_progress)?;
+    let commitment = if with_commitment {
+        Some(compute_commitment(
+            &seed_phrases,
+            threshold,
+            slice_list.as_deref(),
+        )?)
+    } else {
+        None
+    };
+    Ok((seed_phrases, commitment))
+}
+
+/// The function computes the polynomial commitment for newly created secret-shared seed phrases.
+///
+/// * `seed_phrases` - The secret-shared seed phrases.
+/// * `threshold` - The threshold.
+/// * `word_list` - The word list to be used, if provided.
+fn compute_commitment(
+    seed_phrases: &[SeedPhrase],
+    threshold: usize,
+    word_list: Option<&[&str]>,
+) -> HarpoResult<PolynomialCommitment> {
+    // The first `threshold` shares suffice.
+    let quorum = &seed_phrases[..threshold];
+    match word_list {
+        Some(list) => get_polynomial_commitment_for_word_list(quorum, list),
+        None => get_polynomial_commitment(quorum),
+    }
+}
+
+/// The function reads a hex-encoded private key from the given file.
+///
+/// The first line that is not empty and not a comment is used.
+///
+/// * `file_path` - The path to the file containing the private key.
+fn read_private_key_from_file(file_path: &str) -> HarpoResult<EthereumPrivateKey> {
+    with_file_context(file_path, || {
+        let file_content = read_to_string(file_path)?;
+        match file_content
+            .lines()
+            .find(|line| !line.starts_with('#') && !line.trim().is_empty())
+        {
+            Some(line) => line.parse::<EthereumPrivateKey>(),
+            None => Err(HarpoError::InvalidParameter(
+                "Could not read the private key from the file.".to_string(),
+            )),
+        }
+    })
+}
+
+/// The function reads a hex-encoded private key from standard input.
+fn read_private_key_interactively() -> HarpoResult<EthereumPrivateKey> {
+    let mut private_key_string = String::new();
+    println!("Please enter your private key (64 hex characters):");
+    let _ = std::io::stdin().read_line(&mut private_key_string)?;
+    private_key_string.parse::<EthereumPrivateKey>()
+}
+
+/// The function handles the creation of secret-shared seed phrases for a raw private key.
+///
+/// The input to the function is the command-line arguments. If processing succeeds,
+/// the function returns the secret-shared seed phrases and, if requested, the polynomial
+/// commitment.
+///
+/// * `command_line` - The command-line arguments.
+/// * `verbose` - Flag indicating if verbose output should be generated.
+/// * `word_list` - The word list to be used, if provided.
+fn handle_split_key(
+    command_line: &clap::ArgMatches,
+    verbose: bool,
+    word_list: Option<Vec<String>>,
+) -> HarpoResult<(Vec<SeedPhrase>, Option<PolynomialCommitment>)> {
+    let (num_shares, threshold, index_encoding) = get_create_parameters(command_line)?;
+    // Read the input from a file or interactively.
+    let private_key = if let Some(file_path) = command_line.value_of("file") {
+        if verbose {
+            println!("Reading the private key from {}...", file_path);
+        }
+        read_private_key_from_file(file_path)?
+    } else {
+        read_private_key_interactively()?
+    };
+    if verbose {
+        println!();
+        println!(
+            "Creating secret-shared seed phrases for the private key of address {}...",
+            private_key.get_address()
+        );
+    }
+    let slice_list: Option<Vec<&str>> = word_list
+        .as_ref()
+        .map(|list| list.iter().map(|s| s.as_str()).collect());
+    let seed_phrases = match &slice_list {
+        Some(list) => split_ethereum_private_key_for_word_list(
+            &private_key,
+            threshold,
+            num_shares,
+            index_encoding,
+            list,
+        )?,
+        None => split_ethereum_private_key(&private_key, threshold, num_shares, index_encoding)?,
+    };
+    let commitment = if command_line.is_present("commitment") {
+        Some(compute_commitment(
+            &seed_phrases,
+            threshold,
+            slice_list.as_deref(),
+        )?)
+    } else {
+        None
+    };
+    Ok((seed_phrases, commitment))
+}
+
+/// The function handles the reconstruction of a raw private key.
+///
+/// The input to the function is the command-line arguments. If processing succeeds,
+/// the function returns the reconstructed private key.
+///
+/// * `command_line` - The command-line arguments.
+/// * `verbose` - Flag indicating if verbose output should be generated.
+/// * `word_list` - The word list to be used, if provided.
+fn handle_join_key(
+    command_line: &clap::ArgMatches,
+    verbose: bool,
+    word_list: Option<Vec<String>>,
+) -> HarpoResult<EthereumPrivateKey> {
+    let (seed_phrases, word_list) = read_checked_shares(command_line, verbose, word_list)?;
+    match word_list {
+        Some(list) => {
+            let slice_list: Vec<&str> = list.iter().map(|s| s.as_str()).collect();
+            reconstruct_ethereum_private_key_for_word_list(&seed_phrases, &slice_list)
         }
+        None => reconstruct_ethereum_private_key(&seed_phrases),
     }
 }
 
@@ -292,52 +1097,50 @@ fn handle_create(
 ///
 /// * `file_path` - The path to the file containing the seed phrases.
 fn read_seed_phrases_from_file(file_path: &str) -> HarpoResult<Vec<SeedPhrase>> {
-    // Read the file content.
-    let file_content = read_to_string(file_path)?;
-    // Get all potential seed phrases.
-    let seed_phrase_options: Vec<SeedPhraseResult> = file_content
-        .lines()
-        .filter(|line| !line.starts_with('#') && !line.is_empty())
-        .map(convert_string_to_seed_phrase)
-        .collect();
-    // If there is a 'None' entry, return an error.
-    if seed_phrase_options.iter().any(|option| option.is_err()) {
-        Err(HarpoError::InvalidSeedPhrase(
-            "Encountered an invalid seed phrase in the file.".to_string(),
-        ))
-    } else {
-        // Otherwise, remove the 'None' entries and return the seed phrases.
-        Ok(seed_phrase_options
-            .into_iter()
-            .flatten()
-            .collect::<Vec<SeedPhrase>>())
-    }
+    with_file_context(file_path, || {
+        parse_phrases_from_reader(BufReader::new(File::open(file_path)?))
+    })
 }
 
 /// The function reads multiple seed phrases interactively.
 ///
 /// The function reads lines from standard input and, if processing succeeds, returns all
 /// collected seed phrases.
-fn read_seed_phrases_interactively() -> HarpoResult<Vec<SeedPhrase>> {
+///
+/// * `description` - The description of the seed phrases used in the prompts.
+/// * `word_list` - The word list, if provided.
+fn read_seed_phrases_interactively(
+    description: &str,
+    word_list: &Option<Vec<String>>,
+) -> HarpoResult<Vec<SeedPhrase>> {
     let mut seed_phrases = vec![];
     let mut seed_phrase_string = String::new();
     // Read the first seed phrase from standard input.
-    println!("Please enter the first secret-shared seed phrase (12, 15, 18, 21, or 24 space-delimited words):");
+    println!(
+        "Please enter the first {} (12, 15, 18, 21, or 24 space-delimited words):",
+        description
+    );
     let _ = std::io::stdin().read_line(&mut seed_phrase_string)?;
-    match convert_string_to_seed_phrase(&seed_phrase_string) {
-        Ok(seed_phrase) => seed_phrases.push(seed_phrase),
+    match parse_phrase(&seed_phrase_string) {
+        Ok(seed_phrase) => seed_phrases.push(offer_word_suggestions(seed_phrase, word_list)?),
         Err(e) => return Err(e),
     }
     seed_phrase_string.clear();
     // Read the next seed phrase from standard input.
     println!();
-    println!("Please enter the next secret-shared seed phrase (press enter when done):");
+    println!(
+        "Please enter the next {} (press enter when done):",
+        description
+    );
     let _ = std::io::stdin().read_line(&mut seed_phrase_string)?;
-    while let Ok(seed_phrase) = convert_string_to_seed_phrase(&seed_phrase_string) {
-        seed_phrases.push(seed_phrase);
+    while let Ok(seed_phrase) = parse_phrase(&seed_phrase_string) {
+        seed_phrases.push(offer_word_suggestions(seed_phrase, word_list)?);
         seed_phrase_string.clear();
         println!();
-        println!("Please enter the next secret-shared seed phrase (press enter when done):");
+        println!(
+            "Please enter the next {} (press enter when done):",
+            description
+        );
         let _ = std::io::stdin().read_line(&mut seed_phrase_string)?;
     }
     Ok(seed_phrases)
@@ -346,7 +1149,8 @@ fn read_seed_phrases_interactively() -> HarpoResult<Vec<SeedPhrase>> {
 /// The function handles the reconstruction of a seed phrase.
 ///
 /// The input to the function is the command-line arguments. If processing succeeds,
-/// the function returns the reconstructed seed phrase.
+/// the function returns the reconstructed seed phrase and, if requested, a report about the
+/// provenance of the secret-shared seed phrases.
 ///
 /// * `command_line` - The command-line arguments.
 /// * `verbose` - Flag indicating if verbose output should be generated.
@@ -355,7 +1159,99 @@ fn handle_reconstruct(
     command_line: &clap::ArgMatches,
     verbose: bool,
     word_list: Option<Vec<String>>,
-) -> SeedPhraseResult {
+) -> HarpoResult<(SeedPhrase, Option<Vec<String>>)> {
+    let (seed_phrases, word_list) = read_checked_shares(command_line, verbose, word_list)?;
+    let slice_list: Option<Vec<&str>> = word_list
+        .as_ref()
+        .map(|list| list.iter().map(|s| s.as_str()).collect());
+    if !command_line.is_present("report") {
+        // Reconstruct the seed phrase.
+        let standard = get_phrase_standard(command_line);
+        let seed_phrase = match &slice_list {
+            Some(list) => {
+                reconstruct_seed_phrase_for_standard_for_word_list(&seed_phrases, standard, list)?
+            }
+            None => reconstruct_seed_phrase_for_standard(&seed_phrases, standard)?,
+        };
+        return Ok((seed_phrase, None));
+    }
+    // The secret-shared seed phrases are labeled with their source, if there is a file.
+    let labels: Vec<String> = match command_line.value_of("file") {
+        Some(file_path) => (1..=seed_phrases.len())
+            .map(|position| format!("{}, seed phrase {}", file_path, position))
+            .collect(),
+        None => vec![],
+    };
+    let labels: Vec<&str> = labels.iter().map(|label| label.as_str()).collect();
+    let reconstruction_report = match &slice_list {
+        Some(list) => {
+            reconstruct_seed_phrase_with_report_for_word_list(&seed_phrases, &labels, list)?
+        }
+        None => reconstruct_seed_phrase_with_report(&seed_phrases, &labels)?,
+    };
+    let mut report = vec![format!(
+        "Fingerprint of the reconstructed seed phrase: {}",
+        reconstruction_report.get_fingerprint()
+    )];
+    report.extend(
+        reconstruction_report
+            .get_used_shares()
+            .iter()
+            .map(|share| format!("Used {}", share)),
+    );
+    report.extend(
+        reconstruction_report
+            .get_ignored_shares()
+            .iter()
+            .map(|share| match share.get_duplicate_of() {
+                Some(position) => {
+                    format!("Ignored {} (duplicate of share {})", share, position + 1)
+                }
+                None => format!(
+                    "Ignored {} (superseded by a share with the same index)",
+                    share
+                ),
+            }),
+    );
+    report.extend(
+        reconstruction_report
+            .get_corrupted_shares()
+            .iter()
+            .map(|share| format!("Ignored {} (corrupted)", share)),
+    );
+    report.push(match reconstruction_report.is_consistent() {
+        Some(true) => "Consistency of the secret-shared seed phrases: verified".to_string(),
+        Some(false) => "Consistency of the secret-shared seed phrases: failed".to_string(),
+        None => "Consistency of the secret-shared seed phrases: not checked (more shares than \
+            the threshold are required)"
+            .to_string(),
+    });
+    report.extend(
+        reconstruction_report
+            .get_warnings()
+            .iter()
+            .map(|warning| format!("Warning: {}", warning)),
+    );
+    Ok((
+        reconstruction_report.get_seed_phrase().clone(),
+        Some(report),
+    ))
+}
+
+/// The function reads and checks secret-shared seed phrases for reconstruction.
+///
+/// Each secret-shared seed phrase is checked individually and, if a polynomial commitment is
+/// provided, the secret-shared seed phrases are verified against it. If processing succeeds,
+/// the function returns the secret-shared seed phrases and the word list to be used.
+///
+/// * `command_line` - The command-line arguments.
+/// * `verbose` - Flag indicating if verbose output should be generated.
+/// * `word_list` - The word list to be used, if provided.
+fn read_checked_shares(
+    command_line: &clap::ArgMatches,
+    verbose: bool,
+    word_list: Option<Vec<String>>,
+) -> HarpoResult<(Vec<SeedPhrase>, Option<Vec<String>>)> {
     // Read the input from a file or interactively.
     let seed_phrases = if let Some(file_path) = command_line.value_of("file") {
         // Print verbose output if the flag --verbose is set.
@@ -366,8 +1262,10 @@ fn handle_reconstruct(
         read_seed_phrases_from_file(file_path)?
     } else {
         // The seed phrases must be entered interactively.
-        read_seed_phrases_interactively()?
+        read_seed_phrases_interactively(SHARE_DESCRIPTION, &word_list)?
     };
+    let seed_phrases = segment_seed_phrases(seed_phrases, &word_list)?;
+    let word_list = resolve_word_list(&seed_phrases, word_list, !command_line.is_present("file"))?;
     if verbose {
         let length = seed_phrases.len();
         if length > 1 {
@@ -383,134 +1281,1650 @@ fn handle_reconstruct(
             println!("{}", seed_phrase);
         }
     }
-    // Reconstruct the seed phrase.
-    match word_list {
-        Some(list) => {
-            let slice_list: Vec<&str> = list.iter().map(|s| s.as_str()).collect();
-            reconstruct_seed_phrase_for_word_list(&seed_phrases, &slice_list)
+    let slice_list: Option<Vec<&str>> = word_list
+        .as_ref()
+        .map(|list| list.iter().map(|s| s.as_str()).collect());
+    let seed_phrases = complete_seed_phrases(seed_phrases, slice_list.as_deref())?;
+    // Check each secret-shared seed phrase before combining them.
+    check_secret_shared_seed_phrases(&seed_phrases, slice_list.as_deref(), verbose)?;
+    // Verify the polynomial commitment if one is provided.
+    if let Some(commitment_string) = command_line.value_of("commitment") {
+        let commitment = commitment_string.parse::<PolynomialCommitment>()?;
+        let is_match = match &slice_list {
+            Some(list) => {
+                verify_polynomial_commitment_for_word_list(&seed_phrases, &commitment, list)?
+            }
+            None => verify_polynomial_commitment(&seed_phrases, &commitment)?,
+        };
+        if !is_match {
+            return Err(HarpoError::InvalidSeedPhrase(
+                "The seed phrases do not match the polynomial commitment.".to_string(),
+            ));
         }
-        None => reconstruct_seed_phrase(&seed_phrases),
+        println!();
+        println!("The seed phrases match the polynomial commitment.");
     }
+    Ok((seed_phrases, word_list))
 }
 
-/// The function attempts to read a word list from the provided file path.
+/// The function handles the upgrade of secret-shared seed phrases.
 ///
-/// The function simply assumes that there is one word per line and builds a vector
-/// of strings accordingly. There is no verification that a proper word list is processed.
+/// The seed phrase is reconstructed internally from the provided secret-shared seed phrases
+/// and secret-shared again using the current share format and the provided parameters.
+/// The reconstructed seed phrase is never output. If processing succeeds, the function returns
+/// the new secret-shared seed phrases, the polynomial commitment if requested, and a report.
 ///
-/// * `file_path` - The path to the file containing the word list.
-fn read_word_list_from_file(file_path: &str) -> HarpoResult<Vec<String>> {
-    // Read the file content.
-    let file_content = read_to_string(file_path)?;
-    // Read the words, one per line.
-    let word_list: Vec<String> = file_content.lines().map(str::to_string).collect();
-    Ok(word_list)
+/// * `command_line` - The command-line arguments.
+/// * `verbose` - Flag indicating if verbose output should be generated.
+/// * `word_list` - The word list to be used, if provided.
+fn handle_upgrade_shares(
+    command_line: &clap::ArgMatches,
+    verbose: bool,
+    word_list: Option<Vec<String>>,
+) -> HarpoResult<(Vec<SeedPhrase>, Option<PolynomialCommitment>, Vec<String>)> {
+    // Check the parameters before reading any secret-shared seed phrases.
+    let (num_shares, threshold, index_encoding) = get_create_parameters(command_line)?;
+    // Read the input from a file or interactively.
+    let old_seed_phrases = if let Some(file_path) = command_line.value_of("file") {
+        if verbose {
+            println!("Reading seed phrases from {}...", file_path);
+            println!();
+        }
+        read_seed_phrases_from_file(file_path)?
+    } else {
+        // The seed phrases must be entered interactively.
+        read_seed_phrases_interactively(SHARE_DESCRIPTION, &word_list)?
+    };
+    let old_seed_phrases = segment_seed_phrases(old_seed_phrases, &word_list)?;
+    let word_list = resolve_word_list(
+        &old_seed_phrases,
+        word_list,
+        !command_line.is_present("file"),
+    )?;
+    let slice_list: Option<Vec<&str>> = word_list
+        .as_ref()
+        .map(|list| list.iter().map(|s| s.as_str()).collect());
+    let old_seed_phrases = complete_seed_phrases(old_seed_phrases, slice_list.as_deref())?;
+    // Check each secret-shared seed phrase before combining them.
+    check_secret_shared_seed_phrases(&old_seed_phrases, slice_list.as_deref(), verbose)?;
+    // Re-share the seed phrase without reconstructing it.
+    let mut show_progress = ProgressBar::new("Creating shares", PROGRESS_MIN_SHARES);
+    let share_set = reshare_shares_with_progress_for_word_list(
+        &old_seed_phrases,
+        threshold,
+        num_shares,
+        index_encoding,
+        slice_list.as_deref().unwrap_or(DEFAULT_WORD_LIST),
+        &mut show_progress,
+    )?;
+    // Determine the index encoding of the provided seed phrases for the report.
+    let old_index_encoding = match old_seed_phrases.first() {
+        Some(seed_phrase) if seed_phrase.get_index().is_some() => "explicit",
+        Some(seed_phrase) if has_index_word(seed_phrase.len()) => "index word",
+        _ => "embedded",
+    };
+    let new_index_encoding = match index_encoding {
+        IndexEncoding::Embedded => "embedded",
+        IndexEncoding::Explicit => "explicit",
+        IndexEncoding::IndexWord => "index word",
+    };
+    let report = vec![
+        format!(
+            "Provided secret-shared seed phrases: {} ({} indices)",
+            old_seed_phrases.len(),
+            old_index_encoding
+        ),
+        format!(
+            "Created secret-shared seed phrases: {} ({} indices, share format {})",
+            num_shares, new_index_encoding, SHARE_FORMAT_VERSION
+        ),
+        format!("Threshold for reconstruction: {}", threshold),
+        "The provided secret-shared seed phrases should be destroyed once the new ones are \
+        distributed."
+            .to_string(),
+    ];
+    let commitment = if command_line.is_present("commitment") {
+        Some(share_set.get_commitment())
+    } else {
+        None
+    };
+    Ok((share_set.into_shares(), commitment, report))
 }
 
-/// The function handles the generation of a seed phrase.
+/// The function handles the re-creation of a lost secret-shared seed phrase.
 ///
-/// The function generates a new seed phrase with the number of words provided on the command line.
+/// The input to the function is the command-line arguments. If processing succeeds, the
+/// function returns the re-created secret-shared seed phrase, which is identical to the lost one
+/// if sufficiently many surviving secret-shared seed phrases are provided.
 ///
 /// * `command_line` - The command-line arguments.
 /// * `verbose` - Flag indicating if verbose output should be generated.
 /// * `word_list` - The word list to be used, if provided.
-fn handle_generate(
+fn handle_regenerate_share(
     command_line: &clap::ArgMatches,
     verbose: bool,
     word_list: Option<Vec<String>>,
 ) -> SeedPhraseResult {
-    // Get the length of the word list. The unwrap() call is okay because --length must be provided.
-    let length = command_line.value_of("length").unwrap().parse::<usize>()?;
+    // Check the index before reading any secret-shared seed phrases.
+    // The unwrap() call is okay because --index must be provided.
+    let index = command_line.value_of("index").unwrap().parse::<u32>()?;
+    // Read the input from a file or interactively.
+    let seed_phrases = if let Some(file_path) = command_line.value_of("file") {
+        if verbose {
+            println!("Reading seed phrases from {}...", file_path);
+            println!();
+        }
+        read_seed_phrases_from_file(file_path)?
+    } else {
+        // The seed phrases must be entered interactively.
+        read_seed_phrases_interactively(SHARE_DESCRIPTION, &word_list)?
+    };
+    let seed_phrases = segment_seed_phrases(seed_phrases, &word_list)?;
+    let word_list = resolve_word_list(&seed_phrases, word_list, !command_line.is_present("file"))?;
+    let slice_list: Option<Vec<&str>> = word_list
+        .as_ref()
+        .map(|list| list.iter().map(|s| s.as_str()).collect());
+    let seed_phrases = complete_seed_phrases(seed_phrases, slice_list.as_deref())?;
+    // Check each secret-shared seed phrase before combining them.
+    check_secret_shared_seed_phrases(&seed_phrases, slice_list.as_deref(), verbose)?;
     if verbose {
-        println!("Length of seed phrase: {}", length);
+        println!(
+            "Re-creating the secret-shared seed phrase with index {}...",
+            index
+        );
     }
-    // Generate the seed phrase.
-    match word_list {
-        Some(list) => {
-            let slice_list: Vec<&str> = list.iter().map(|s| s.as_str()).collect();
-            generate_seed_phrase_for_word_list(length, &slice_list)
+    match &slice_list {
+        Some(list) => regenerate_share_for_word_list(&seed_phrases, index, list),
+        None => regenerate_share(&seed_phrases, index),
+    }
+}
+
+/// This struct holds the secret-shared seed phrases of one set collected by the merge command.
+///
+/// Share files do not record a set identifier, so secret-shared seed phrases are grouped by
+/// their number of words and their index encoding.
+struct ShareSet {
+    /// The number of words of the secret-shared seed phrases.
+    num_words: usize,
+    /// The index encoding of the secret-shared seed phrases.
+    index_encoding: Option<IndexEncoding>,
+    /// The secret-shared seed phrases together with their indices.
+    shares: Vec<(u32, SeedPhrase)>,
+    /// The number of secret-shared seed phrases that conflict with an index in the set.
+    num_conflicts: usize,
+}
+
+/// The function returns the name of the given index encoding for reports.
+///
+/// * `index_encoding` - The index encoding, if it can be determined.
+fn get_index_encoding_name(index_encoding: Option<IndexEncoding>) -> &'static str {
+    match index_encoding {
+        Some(IndexEncoding::Embedded) => "embedded",
+        Some(IndexEncoding::Explicit) => "explicit",
+        Some(IndexEncoding::IndexWord) => "index word",
+        None => "unknown",
+    }
+}
+
+/// The function collects the paths of the share files in the given sources.
+///
+/// Files are used directly. For directories, all regular files in the directory are used in
+/// alphabetical order. Subdirectories are not searched.
+///
+/// * `sources` - The paths of the files and directories.
+fn collect_share_files(sources: &[&str]) -> HarpoResult<Vec<String>> {
+    let mut file_paths = vec![];
+    for source in sources {
+        if Path::new(source).is_dir() {
+            let mut directory_paths = vec![];
+            for entry in std::fs::read_dir(source)? {
+                let path = entry?.path();
+                if path.is_file() {
+                    directory_paths.push(path.to_string_lossy().to_string());
+                }
+            }
+            directory_paths.sort();
+            file_paths.extend(directory_paths);
+        } else {
+            file_paths.push(source.to_string());
         }
-        None => generate_seed_phrase(length),
     }
+    Ok(file_paths)
 }
 
-/// The function handles the validation of a seed phrase.
+/// The function handles the merging of secret-shared seed phrases from multiple sources.
 ///
-/// The input to the function is the command-line arguments.
-/// The function verifies BIP-0039 compliance of the given seed phrase.
+/// The secret-shared seed phrases are read from all share files in the provided sources,
+/// validated, and deduplicated. The remaining secret-shared seed phrases are grouped into sets
+/// and sorted by index. If processing succeeds, the function returns the lines of the canonical
+/// bundle and a report.
 ///
 /// * `command_line` - The command-line arguments.
 /// * `verbose` - Flag indicating if verbose output should be generated.
 /// * `word_list` - The word list to be used, if provided.
-fn handle_validate(
+fn handle_merge(
     command_line: &clap::ArgMatches,
     verbose: bool,
     word_list: Option<Vec<String>>,
-) -> HarpoResult<()> {
-    // Read the input from a file or interactively.
-    let seed_phrase = if let Some(file_path) = command_line.value_of("file") {
-        // Print verbose output if the flag --verbose is set.
+) -> HarpoResult<(Vec<String>, Vec<String>)> {
+    let threshold = match command_line.value_of("threshold") {
+        Some(threshold) => Some(threshold.parse::<usize>()?),
+        None => None,
+    };
+    if threshold == Some(0) {
+        return Err(HarpoError::InvalidParameter(
+            "The threshold must be at least 1.".to_string(),
+        ));
+    }
+    // The unwrap() call is okay because at least one source must be provided.
+    let sources: Vec<&str> = command_line.values_of("sources").unwrap().collect();
+    let file_paths = collect_share_files(&sources)?;
+    // Read all secret-shared seed phrases, remembering the file of each one.
+    let mut seed_phrases = vec![];
+    let mut origins = vec![];
+    let mut num_files = 0;
+    for file_path in &file_paths {
         if verbose {
-            println!("Reading the seed phrase from {}...", file_path);
+            println!("Reading seed phrases from {}...", file_path);
+        }
+        let file_seed_phrases = match read_seed_phrases_from_file(file_path)
+            .and_then(|file_seed_phrases| segment_seed_phrases(file_seed_phrases, &word_list))
+        {
+            Ok(file_seed_phrases) => file_seed_phrases,
+            Err(error) => {
+                eprintln!("Warning: Skipping {}: {}", file_path, error);
+                continue;
+            }
+        };
+        num_files += 1;
+        for (line, seed_phrase) in file_seed_phrases.into_iter().enumerate() {
+            seed_phrases.push(seed_phrase);
+            origins.push(format!("{} (seed phrase {})", file_path, line + 1));
         }
-        read_seed_phrase_from_file(file_path)?
-    } else {
-        // The seed phrases must be entered interactively.
-        read_seed_phrase_interactively()?
-    };
-    if verbose {
-        println!();
-        println!("Validating the seed phrase '{}'...", seed_phrase);
     }
-    // Validate the seed phrase.
-    match word_list {
+    if seed_phrases.is_empty() {
+        return Err(HarpoError::InvalidSeedPhrase(
+            "No secret-shared seed phrases found in the provided sources.".to_string(),
+        ));
+    }
+    let word_list = resolve_word_list(&seed_phrases, word_list, false)?;
+    let validations = match &word_list {
         Some(list) => {
             let slice_list: Vec<&str> = list.iter().map(|s| s.as_str()).collect();
-            validate_seed_phrase_for_word_list(&seed_phrase, &slice_list)
+            validate_shares_for_word_list(&seed_phrases, &slice_list)
         }
-        None => validate_seed_phrase(&seed_phrase),
+        None => validate_shares(&seed_phrases),
+    };
+    // Group the valid secret-shared seed phrases into sets, skipping duplicates.
+    let mut share_sets: Vec<ShareSet> = vec![];
+    let mut num_invalid = 0;
+    let mut num_duplicates = 0;
+    for (position, (seed_phrase, validation)) in
+        seed_phrases.into_iter().zip(validations).enumerate()
+    {
+        let index = match (validation.get_error(), validation.get_index()) {
+            (None, Some(index)) => index,
+            (error, _) => {
+                num_invalid += 1;
+                eprintln!(
+                    "Warning: Skipping invalid secret-shared seed phrase in {}: {}",
+                    origins[position],
+                    error.unwrap_or("The index cannot be determined.")
+                );
+                continue;
+            }
+        };
+        if let Some(earlier_position) = validation.get_duplicate_of() {
+            num_duplicates += 1;
+            if verbose {
+                println!(
+                    "Skipping {}, which duplicates {}.",
+                    origins[position], origins[earlier_position]
+                );
+            }
+            continue;
+        }
+        let num_words = validation.get_num_words();
+        let index_encoding = validation.get_index_encoding();
+        let share_set = match share_sets.iter().position(|share_set| {
+            share_set.num_words == num_words && share_set.index_encoding == index_encoding
+        }) {
+            Some(set_position) => &mut share_sets[set_position],
+            None => {
+                share_sets.push(ShareSet {
+                    num_words,
+                    index_encoding,
+                    shares: vec![],
+                    num_conflicts: 0,
+                });
+                // The unwrap() call is okay because a set was just added.
+                share_sets.last_mut().unwrap()
+            }
+        };
+        // A different secret-shared seed phrase with the same index belongs to another set.
+        if share_set
+            .shares
+            .iter()
+            .any(|(share_index, _)| *share_index == index)
+        {
+            share_set.num_conflicts += 1;
+            eprintln!(
+                "Warning: Skipping {} because another secret-shared seed phrase has index {}.",
+                origins[position], index
+            );
+            continue;
+        }
+        share_set.shares.push((index, seed_phrase));
+    }
+    if share_sets.is_empty() {
+        return Err(HarpoError::InvalidSeedPhrase(
+            "No valid secret-shared seed phrases found in the provided sources.".to_string(),
+        ));
+    }
+    let mut lines = vec![];
+    let mut report = vec![
+        format!(
+            "Read secret-shared seed phrases: {} from {} files",
+            origins.len(),
+            num_files
+        ),
+        format!(
+            "Skipped invalid secret-shared seed phrases: {}",
+            num_invalid
+        ),
+        format!(
+            "Removed duplicate secret-shared seed phrases: {}",
+            num_duplicates
+        ),
+    ];
+    for (set_number, share_set) in share_sets.iter_mut().enumerate() {
+        share_set.shares.sort_by_key(|(index, _)| *index);
+        let indices: Vec<String> = share_set
+            .shares
+            .iter()
+            .map(|(index, _)| index.to_string())
+            .collect();
+        let description = format!(
+            "Set {} ({} words, {} indices): {} secret-shared seed phrases with indices {}",
+            set_number + 1,
+            share_set.num_words,
+            get_index_encoding_name(share_set.index_encoding),
+            share_set.shares.len(),
+            indices.join(", ")
+        );
+        lines.push(format!("# {}", description));
+        lines.extend(
+            share_set
+                .shares
+                .iter()
+                .map(|(_, seed_phrase)| seed_phrase.to_string()),
+        );
+        report.push(description);
+        if let Some(threshold) = threshold {
+            if share_set.shares.len() >= threshold {
+                report.push(format!("Set {} is complete.", set_number + 1));
+            } else {
+                report.push(format!(
+                    "Set {} is missing {} secret-shared seed phrases.",
+                    set_number + 1,
+                    threshold - share_set.shares.len()
+                ));
+            }
+        }
+        if share_set.num_conflicts > 0 {
+            report.push(format!(
+                "Set {} had {} conflicting secret-shared seed phrases, so shares of several \
+                sets may be mixed.",
+                set_number + 1,
+                share_set.num_conflicts
+            ));
+        }
+    }
+    if share_sets.len() > 1 {
+        report.push(
+            "The bundle contains several sets, which must be reconstructed separately.".to_string(),
+        );
     }
+    Ok((lines, report))
 }
 
-/// The main function uses the command-line arguments to trigger the right command execution.
+/// This struct holds the state of a progress bar shown on standard error.
 ///
-/// Given the command-line arguments, the main function triggers the processing of the
-/// provided subcommand.
-fn main() {
-    let command_line = parse_command_line();
-    let verbose = command_line.is_present("verbose");
-    // If a path to a word-list file is provided, try to load it.
-    let word_list = match command_line.value_of("word-list") {
-        Some(file_path) => {
-            if verbose {
-                println!("Word list file: {}", file_path);
+/// The progress bar is driven by the [Progress](harpo::Progress) callbacks of the library and
+/// is only shown for operations with many steps.
+struct ProgressBar {
+    /// The label shown in front of the progress bar.
+    label: &'static str,
+    /// The minimum total number of steps for which the progress bar is shown.
+    min_total: usize,
+    /// The last shown percentage, if any.
+    last_percentage: Option<usize>,
+}
+
+impl ProgressBar {
+    /// The function creates a progress bar with the given label.
+    ///
+    /// * `label` - The label shown in front of the progress bar.
+    /// * `min_total` - The minimum total number of steps for which the progress bar is shown.
+    fn new(label: &'static str, min_total: usize) -> Self {
+        ProgressBar {
+            label,
+            min_total,
+            last_percentage: None,
+        }
+    }
+}
+
+impl Progress for ProgressBar {
+    /// The progress bar is redrawn whenever the percentage changes.
+    ///
+    /// * `completed` - The number of completed steps.
+    /// * `total` - The total number of steps.
+    fn update(&mut self, completed: usize, total: usize) {
+        if total < self.min_total.max(1) {
+            return;
+        }
+        let percentage = completed * 100 / total;
+        if is_accessible_output() {
+            // Progress is reported in coarse steps on separate lines.
+            let step = percentage / ACCESSIBLE_PROGRESS_STEP * ACCESSIBLE_PROGRESS_STEP;
+            if self.last_percentage != Some(step) {
+                self.last_percentage = Some(step);
+                eprintln!("{}: {} percent complete.", self.label, step);
             }
-            match read_word_list_from_file(file_path) {
-                Ok(list) => Some(list),
-                Err(error) => {
-                    eprintln!("{}", error);
-                    return;
-                }
+            return;
+        }
+        if self.last_percentage != Some(percentage) {
+            self.last_percentage = Some(percentage);
+            let bar_length = percentage * PROGRESS_BAR_LENGTH / 100;
+            eprint!(
+                "\r{} [{}{}] {:3}%",
+                self.label,
+                "#".repeat(bar_length),
+                " ".repeat(PROGRESS_BAR_LENGTH - bar_length),
+                percentage
+            );
+            if completed == total {
+                eprintln!();
             }
         }
-        None => None,
+    }
+}
+
+/// The function overwrites and removes the input file of the given subcommand if the flag
+/// `--shred` is set.
+///
+/// Overwriting is best effort only, which is pointed out in a warning.
+///
+/// * `command_line` - The command-line arguments.
+/// * `subcommand` - The subcommand that consumed the input file.
+fn shred_input_file(command_line: &ArgMatches, subcommand: &str) {
+    if !command_line.is_present("shred") {
+        return;
+    }
+    let file_path = match command_line
+        .subcommand_matches(subcommand)
+        .and_then(|matches| matches.value_of("file"))
+    {
+        Some(file_path) => file_path,
+        None => {
+            eprintln!("Warning: The flag --shred has no effect without an input file.");
+            return;
+        }
     };
-    // Trigger the right function based on the provided subcommand.
-    match command_line.subcommand_name() {
-        Some(CREATE_SUBCOMMAND) => {
-            match handle_create(
-                command_line
-                    .subcommand_matches(CREATE_SUBCOMMAND)
-                    .expect("The 'create' command must be specified."),
+    match shred_file(file_path) {
+        Ok(()) => {
+            println!();
+            println!("The input file {} was overwritten and removed.", file_path);
+            eprintln!(
+                "Warning: Overwriting files is best effort only. On SSDs, flash memory, and \
+                journaling or copy-on-write file systems, copies of the data may remain."
+            );
+        }
+        Err(err) => eprintln!(
+            "Warning: The input file {} could not be shredded: {}",
+            file_path, err
+        ),
+    }
+}
+
+/// The function overwrites the given file with random bytes and then with zeros before removing
+/// it.
+///
+/// * `file_path` - The path to the file.
+fn shred_file(file_path: &str) -> HarpoResult<()> {
+    // Symbolic links and special files are not overwritten.
+    if !std::fs::symlink_metadata(file_path)?.file_type().is_file() {
+        return Err(HarpoError::InvalidParameter(
+            "Only regular files can be shredded.".to_string(),
+        ));
+    }
+    let length = std::fs::metadata(file_path)?.len() as usize;
+    let mut file = OpenOptions::new().write(true).open(file_path)?;
+    let mut buffer = [0u8; SHRED_BUFFER_SIZE];
+    for pass in 0..SHRED_NUM_PASSES {
+        file.seek(SeekFrom::Start(0))?;
+        let mut remaining = length;
+        while remaining > 0 {
+            let chunk_length = remaining.min(SHRED_BUFFER_SIZE);
+            // The last pass writes zeros, all other passes write random bytes.
+            if pass + 1 < SHRED_NUM_PASSES {
+                rand::thread_rng().fill(&mut buffer[..chunk_length]);
+            } else {
+                buffer[..chunk_length].fill(0);
+            }
+            file.write_all(&buffer[..chunk_length])?;
+            remaining -= chunk_length;
+        }
+        file.sync_all()?;
+    }
+    drop(file);
+    std::fs::remove_file(file_path)?;
+    Ok(())
+}
+
+/// The function completes the secret-shared seed phrases that contain an unknown word.
+///
+/// An unknown word is marked using `?`. The secret-shared seed phrases without unknown words
+/// are used to determine the completion. If there is no unique completion, the candidate words
+/// are reported and an error is returned.
+///
+/// * `seed_phrases` - The secret-shared seed phrases.
+/// * `word_list` - The word list to be used, if provided.
+fn complete_seed_phrases(
+    seed_phrases: Vec<SeedPhrase>,
+    word_list: Option<&[&str]>,
+) -> HarpoResult<Vec<SeedPhrase>> {
+    let complete_seed_phrases: Vec<SeedPhrase> = seed_phrases
+        .iter()
+        .filter(|seed_phrase| !seed_phrase.get_words().contains(&WILDCARD_WORD))
+        .cloned()
+        .collect();
+    let mut result = vec![];
+    for (position, seed_phrase) in seed_phrases.into_iter().enumerate() {
+        let words = seed_phrase.get_words();
+        let wildcard_position = match words.iter().position(|word| *word == WILDCARD_WORD) {
+            Some(wildcard_position) => wildcard_position,
+            None => {
+                result.push(seed_phrase);
+                continue;
+            }
+        };
+        let candidates = match word_list {
+            Some(list) => complete_secret_shared_seed_phrase_for_word_list(
+                &seed_phrase,
+                &complete_seed_phrases,
+                list,
+            )?,
+            None => complete_secret_shared_seed_phrase(&seed_phrase, &complete_seed_phrases)?,
+        };
+        match candidates.len() {
+            0 => {
+                return Err(HarpoError::InvalidSeedPhrase(format!(
+                    "No completion found for the unknown word of secret-shared seed phrase {}.",
+                    position + 1
+                )))
+            }
+            1 => {
+                println!(
+                    "The unknown word {} of secret-shared seed phrase {} was completed.",
+                    wildcard_position + 1,
+                    position + 1
+                );
+                result.extend(candidates);
+            }
+            num_candidates => {
+                let candidate_words: Vec<&str> = candidates
+                    .iter()
+                    .take(MAX_DISPLAYED_CANDIDATES)
+                    .map(|candidate| candidate.get_words()[wildcard_position])
+                    .collect();
+                let ellipsis = if num_candidates > MAX_DISPLAYED_CANDIDATES {
+                    ", ..."
+                } else {
+                    ""
+                };
+                return Err(HarpoError::InvalidSeedPhrase(format!(
+                    "The unknown word {} of secret-shared seed phrase {} has {} candidates: {}{}\n\
+                    Provide more secret-shared seed phrases to determine the unique completion.",
+                    wildcard_position + 1,
+                    position + 1,
+                    num_candidates,
+                    candidate_words.join(", "),
+                    ellipsis
+                )));
+            }
+        }
+    }
+    Ok(result)
+}
+
+/// The function checks each secret-shared seed phrase and reports the ones that appear to be
+/// mistyped.
+///
+/// An error is returned if at least one secret-shared seed phrase appears to be mistyped.
+///
+/// * `seed_phrases` - The secret-shared seed phrases.
+/// * `word_list` - The word list to be used, if provided.
+/// * `verbose` - Flag indicating if verbose output should be generated.
+fn check_secret_shared_seed_phrases(
+    seed_phrases: &[SeedPhrase],
+    word_list: Option<&[&str]>,
+    verbose: bool,
+) -> HarpoResult<()> {
+    let mut num_mistyped = 0;
+    // A progress bar is shown when auditing large numbers of shares.
+    let mut show_progress = ProgressBar::new("Checking shares", PROGRESS_MIN_SHARES);
+    show_progress.update(0, seed_phrases.len());
+    for (position, seed_phrase) in seed_phrases.iter().enumerate() {
+        let result = match word_list {
+            Some(list) => check_secret_shared_seed_phrase_for_word_list(seed_phrase, list),
+            None => check_secret_shared_seed_phrase(seed_phrase),
+        };
+        match result {
+            Ok(()) => {
+                if verbose {
+                    println!("Secret-shared seed phrase {}: OK", position + 1);
+                }
+            }
+            Err(error) => {
+                num_mistyped += 1;
+                eprintln!(
+                    "Secret-shared seed phrase {} appears to be mistyped: {}",
+                    position + 1,
+                    error
+                );
+            }
+        }
+        show_progress.update(position + 1, seed_phrases.len());
+    }
+    if num_mistyped > 0 {
+        Err(HarpoError::InvalidSeedPhrase(format!(
+            "{} of the {} secret-shared seed phrases appear to be mistyped.",
+            num_mistyped,
+            seed_phrases.len()
+        )))
+    } else {
+        Ok(())
+    }
+}
+
+/// The function attempts to read a word list from the provided file path.
+///
+/// The function assumes that there is one word per line and builds a vector of strings
+/// accordingly. An error is returned if the words do not form a valid word list. A warning is
+/// printed if the first four characters do not identify each word uniquely.
+///
+/// * `file_path` - The path to the file containing the word list.
+fn read_word_list_from_file(file_path: &str) -> HarpoResult<Vec<String>> {
+    with_file_context(file_path, || {
+        // Read the file content.
+        let file_content = read_to_string(file_path)?;
+        // Read the words, one per line.
+        let word_list: Vec<String> = file_content.lines().map(str::to_string).collect();
+        // Check the word list.
+        let slice_list: Vec<&str> = word_list.iter().map(|s| s.as_str()).collect();
+        let shared_prefixes = check_word_list(&slice_list)?;
+        if !shared_prefixes.is_empty() {
+            eprintln!(
+                "Warning: {} prefixes of the word list are shared by several words.",
+                shared_prefixes.len()
+            );
+            for shared_prefix in shared_prefixes.iter().take(MAX_LISTED_SHARED_PREFIXES) {
+                eprintln!("  {}", shared_prefix);
+            }
+        }
+        Ok(word_list)
+    })
+}
+
+/// The function handles the generation of a seed phrase.
+///
+/// The function generates a new seed phrase with the number of words provided on the command line.
+///
+/// * `command_line` - The command-line arguments.
+/// * `verbose` - Flag indicating if verbose output should be generated.
+/// * `word_list` - The word list to be used, if provided.
+fn handle_generate(
+    command_line: &clap::ArgMatches,
+    verbose: bool,
+    word_list: Option<Vec<String>>,
+) -> SeedPhraseResult {
+    // Get the length of the word list. The unwrap() call is okay because --length must be provided.
+    let length = command_line.value_of("length").unwrap().parse::<usize>()?;
+    if verbose {
+        println!("Length of seed phrase: {}", length);
+    }
+    // Generate the seed phrase.
+    match word_list {
+        Some(list) => {
+            let slice_list: Vec<&str> = list.iter().map(|s| s.as_str()).collect();
+            generate_seed_phrase_for_word_list(length, &slice_list)
+        }
+        None => generate_seed_phrase(length),
+    }
+}
+
+/// The function handles the validation of a seed phrase.
+///
+/// The input to the function is the command-line arguments.
+/// The function verifies BIP-0039 compliance of the given seed phrase.
+///
+/// * `command_line` - The command-line arguments.
+/// * `verbose` - Flag indicating if verbose output should be generated.
+/// * `word_list` - The word list to be used, if provided.
+fn handle_validate(
+    command_line: &clap::ArgMatches,
+    verbose: bool,
+    word_list: Option<Vec<String>>,
+) -> HarpoResult<()> {
+    // Read the input from a file or interactively.
+    let seed_phrase = if let Some(file_path) = command_line.value_of("file") {
+        // Print verbose output if the flag --verbose is set.
+        if verbose {
+            println!("Reading the seed phrase from {}...", file_path);
+        }
+        read_seed_phrase_from_file(file_path)?
+    } else {
+        // The seed phrases must be entered interactively.
+        read_seed_phrase_interactively(&word_list)?
+    };
+    let seed_phrase = segment_seed_phrase(seed_phrase, &word_list)?;
+    let word_list = resolve_word_list(
+        std::slice::from_ref(&seed_phrase),
+        word_list,
+        !command_line.is_present("file"),
+    )?;
+    if verbose {
+        println!();
+        println!("Validating the seed phrase '{}'...", seed_phrase);
+    }
+    // Validate the seed phrase.
+    let standard = get_phrase_standard(command_line);
+    match word_list {
+        Some(list) => {
+            let slice_list: Vec<&str> = list.iter().map(|s| s.as_str()).collect();
+            validate_seed_phrase_for_standard_for_word_list(&seed_phrase, standard, &slice_list)
+        }
+        None => validate_seed_phrase_for_standard(&seed_phrase, standard),
+    }
+}
+
+/// The function handles the verification of secret-shared seed phrases.
+///
+/// The secret-shared seed phrases are checked individually against the public verification data,
+/// i.e., no other secret-shared seed phrases are required. If processing succeeds, the function
+/// returns one line per secret-shared seed phrase and a flag indicating whether all of them match
+/// the verification data.
+///
+/// * `command_line` - The command-line arguments.
+/// * `verbose` - Flag indicating if verbose output should be generated.
+/// * `word_list` - The word list to be used, if provided.
+fn handle_verify_share(
+    command_line: &clap::ArgMatches,
+    verbose: bool,
+    word_list: Option<Vec<String>>,
+) -> HarpoResult<(Vec<String>, bool)> {
+    // The unwrap() call is okay because --verification-data must be provided.
+    let data_path = command_line.value_of("verification-data").unwrap();
+    if verbose {
+        println!("Reading the verification data from {}...", data_path);
+    }
+    let verification_data: VerificationData =
+        with_file_context(data_path, || read_to_string(data_path)?.parse())?;
+    // Read the input from a file or interactively.
+    let seed_phrases = if let Some(file_path) = command_line.value_of("file") {
+        if verbose {
+            println!(
+                "Reading the secret-shared seed phrases from {}...",
+                file_path
+            );
+        }
+        read_seed_phrases_from_file(file_path)?
+    } else {
+        // A single secret-shared seed phrase must be entered interactively.
+        vec![read_seed_phrase_interactively(&word_list)?]
+    };
+    let word_list = resolve_word_list(&seed_phrases, word_list, !command_line.is_present("file"))?;
+    let slice_list: Option<Vec<&str>> = word_list
+        .as_ref()
+        .map(|list| list.iter().map(|s| s.as_str()).collect());
+    let mut lines = vec![];
+    let mut all_match = true;
+    for (position, seed_phrase) in seed_phrases.iter().enumerate() {
+        let is_match = match &slice_list {
+            Some(list) => verify_share_for_word_list(seed_phrase, &verification_data, list)?,
+            None => verify_share(seed_phrase, &verification_data)?,
+        };
+        all_match &= is_match;
+        lines.push(format!(
+            "Secret-shared seed phrase {}: {}",
+            position + 1,
+            if is_match {
+                "matches the verification data"
+            } else {
+                "does NOT match the verification data"
+            }
+        ));
+    }
+    Ok((lines, all_match))
+}
+
+/// The function handles the derivation of a child seed phrase.
+///
+/// The master seed phrase is either provided directly or reconstructed from the provided
+/// secret-shared seed phrases. The master seed phrase is never output. If processing succeeds,
+/// the function returns the child seed phrase.
+///
+/// * `command_line` - The command-line arguments.
+/// * `verbose` - Flag indicating if verbose output should be generated.
+/// * `word_list` - The word list to be used, if provided.
+fn handle_derive(
+    command_line: &clap::ArgMatches,
+    verbose: bool,
+    word_list: Option<Vec<String>>,
+) -> SeedPhraseResult {
+    // Check the parameters before reading any seed phrases.
+    // The unwrap() call is okay because --index must be provided.
+    let index = command_line.value_of("index").unwrap().parse::<u32>()?;
+    // The unwrap() call is okay because --words has a default value.
+    let num_words = command_line.value_of("words").unwrap().parse::<usize>()?;
+    let interactive = !command_line.is_present("file");
+    let (seed_phrase, word_list) = if command_line.is_present("from-shares") {
+        let seed_phrases = match command_line.value_of("file") {
+            Some(file_path) => read_seed_phrases_from_file(file_path)?,
+            None => read_seed_phrases_interactively(SHARE_DESCRIPTION, &word_list)?,
+        };
+        let seed_phrases = segment_seed_phrases(seed_phrases, &word_list)?;
+        let word_list = resolve_word_list(&seed_phrases, word_list, interactive)?;
+        let slice_list: Option<Vec<&str>> = word_list
+            .as_ref()
+            .map(|list| list.iter().map(|s| s.as_str()).collect());
+        let seed_phrases = complete_seed_phrases(seed_phrases, slice_list.as_deref())?;
+        // Check each secret-shared seed phrase before combining them.
+        check_secret_shared_seed_phrases(&seed_phrases, slice_list.as_deref(), verbose)?;
+        let seed_phrase = match &slice_list {
+            Some(list) => reconstruct_seed_phrase_for_word_list(&seed_phrases, list)?,
+            None => reconstruct_seed_phrase(&seed_phrases)?,
+        };
+        (seed_phrase, word_list)
+    } else {
+        let seed_phrase = match command_line.value_of("file") {
+            Some(file_path) => read_seed_phrase_from_file(file_path)?,
+            None => read_seed_phrase_interactively(&word_list)?,
+        };
+        let seed_phrase = segment_seed_phrase(seed_phrase, &word_list)?;
+        let word_list =
+            resolve_word_list(std::slice::from_ref(&seed_phrase), word_list, interactive)?;
+        (seed_phrase, word_list)
+    };
+    if verbose {
+        println!();
+        println!(
+            "Deriving the child seed phrase at m/83696968'/39'/0'/{}'/{}'...",
+            num_words, index
+        );
+    }
+    match word_list {
+        Some(list) => {
+            let slice_list: Vec<&str> = list.iter().map(|s| s.as_str()).collect();
+            derive_child_seed_phrase_for_word_list(&seed_phrase, num_words, index, &slice_list)
+        }
+        None => derive_child_seed_phrase(&seed_phrase, num_words, index),
+    }
+}
+
+/// The function handles the splitting of a seed phrase into Seed XOR parts.
+///
+/// The seed phrase is provided in the same way as for the validate subcommand. If processing
+/// succeeds, the function returns the parts.
+///
+/// * `command_line` - The command-line arguments.
+/// * `verbose` - Flag indicating if verbose output should be generated.
+/// * `word_list` - The word list to be used, if provided.
+fn handle_xor_split(
+    command_line: &clap::ArgMatches,
+    verbose: bool,
+    word_list: Option<Vec<String>>,
+) -> HarpoResult<Vec<SeedPhrase>> {
+    // The unwrap() call is okay because --num-parts must be provided.
+    let num_parts = command_line
+        .value_of("num-parts")
+        .unwrap()
+        .parse::<usize>()?;
+    let seed_phrase = match command_line.value_of("file") {
+        Some(file_path) => read_seed_phrase_from_file(file_path)?,
+        None => read_seed_phrase_interactively(&word_list)?,
+    };
+    let seed_phrase = segment_seed_phrase(seed_phrase, &word_list)?;
+    let word_list = resolve_word_list(
+        std::slice::from_ref(&seed_phrase),
+        word_list,
+        !command_line.is_present("file"),
+    )?;
+    if verbose {
+        println!();
+        println!(
+            "Splitting the seed phrase into {} Seed XOR parts...",
+            num_parts
+        );
+    }
+    match word_list {
+        Some(list) => {
+            let slice_list: Vec<&str> = list.iter().map(|s| s.as_str()).collect();
+            create_seed_xor_parts_for_word_list(&seed_phrase, num_parts, &slice_list)
+        }
+        None => create_seed_xor_parts(&seed_phrase, num_parts),
+    }
+}
+
+/// The function handles the reconstruction of a seed phrase from Seed XOR parts.
+///
+/// The parts are provided in the same way as secret-shared seed phrases for the reconstruct
+/// subcommand. If processing succeeds, the function returns the reconstructed seed phrase.
+///
+/// * `command_line` - The command-line arguments.
+/// * `verbose` - Flag indicating if verbose output should be generated.
+/// * `word_list` - The word list to be used, if provided.
+fn handle_xor_join(
+    command_line: &clap::ArgMatches,
+    verbose: bool,
+    word_list: Option<Vec<String>>,
+) -> SeedPhraseResult {
+    let parts = match command_line.value_of("file") {
+        Some(file_path) => read_seed_phrases_from_file(file_path)?,
+        None => read_seed_phrases_interactively(XOR_PART_DESCRIPTION, &word_list)?,
+    };
+    let parts = segment_seed_phrases(parts, &word_list)?;
+    let word_list = resolve_word_list(&parts, word_list, !command_line.is_present("file"))?;
+    if verbose {
+        println!();
+        println!("Combining {} Seed XOR parts...", parts.len());
+    }
+    match word_list {
+        Some(list) => {
+            let slice_list: Vec<&str> = list.iter().map(|s| s.as_str()).collect();
+            reconstruct_seed_phrase_from_xor_parts_for_word_list(&parts, &slice_list)
+        }
+        None => reconstruct_seed_phrase_from_xor_parts(&parts),
+    }
+}
+
+/// The function handles the benchmark.
+///
+/// The function repeatedly creates secret-shared seed phrases for a generated seed phrase and
+/// reconstructs the seed phrase from `threshold` secret-shared seed phrases, measuring the
+/// elapsed time. Only generated seed phrases are used and no secrets are printed.
+///
+/// * `command_line` - The command-line arguments.
+/// * `word_list` - The word list to be used, if provided.
+fn handle_bench(
+    command_line: &clap::ArgMatches,
+    word_list: Option<Vec<String>>,
+) -> HarpoResult<()> {
+    // The unwrap() calls are okay because there are default values or the values are required.
+    let length = command_line.value_of("length").unwrap().parse::<usize>()?;
+    let num_shares = command_line
+        .value_of("num-shares")
+        .unwrap()
+        .parse::<usize>()?;
+    let threshold = command_line
+        .value_of("threshold")
+        .unwrap()
+        .parse::<usize>()?;
+    let iterations = command_line
+        .value_of("iterations")
+        .unwrap()
+        .parse::<u32>()?;
+    if iterations < 1 {
+        return Err(HarpoError::InvalidParameter(
+            "The number of iterations must be at least 1.".to_string(),
+        ));
+    }
+    // Indices are embedded whenever possible.
+    let embed_indices = num_shares <= MAX_EMBEDDED_SHARES && can_embed_index(length);
+    let slice_list: Option<Vec<&str>> = word_list
+        .as_ref()
+        .map(|list| list.iter().map(|s| s.as_str()).collect());
+    println!(
+        "Benchmarking {} iterations with {}-word seed phrases, {} shares, and threshold {}...",
+        iterations, length, num_shares, threshold
+    );
+    let mut create_time = Duration::ZERO;
+    let mut reconstruct_time = Duration::ZERO;
+    let mut show_progress = ProgressBar::new("Benchmarking", PROGRESS_MIN_ITERATIONS);
+    show_progress.update(0, iterations as usize);
+    for iteration in 0..iterations {
+        let seed_phrase = match &slice_list {
+            Some(list) => generate_seed_phrase_for_word_list(length, list)?,
+            None => generate_seed_phrase(length)?,
+        };
+        let start = Instant::now();
+        let seed_phrases = match &slice_list {
+            Some(list) => create_secret_shared_seed_phrases_for_word_list(
+                &seed_phrase,
+                threshold,
+                num_shares,
+                embed_indices,
+                list,
+            )?,
+            None => create_secret_shared_seed_phrases(
+                &seed_phrase,
+                threshold,
+                num_shares,
+                embed_indices,
+            )?,
+        };
+        create_time += start.elapsed();
+        let quorum = &seed_phrases[num_shares - threshold..];
+        let start = Instant::now();
+        let reconstructed_seed_phrase = match &slice_list {
+            Some(list) => reconstruct_seed_phrase_for_word_list(quorum, list)?,
+            None => reconstruct_seed_phrase(quorum)?,
+        };
+        reconstruct_time += start.elapsed();
+        if reconstructed_seed_phrase != seed_phrase {
+            return Err(HarpoError::InvalidSeedPhrase(
+                "The reconstructed seed phrase does not match the generated seed phrase."
+                    .to_string(),
+            ));
+        }
+        show_progress.update(iteration as usize + 1, iterations as usize);
+    }
+    println!();
+    println!("Results (average per iteration):");
+    print_rule(32);
+    println!(
+        "Creation of {} secret-shared seed phrases: {:.3} ms",
+        num_shares,
+        create_time.as_secs_f64() * 1000.0 / iterations as f64
+    );
+    println!(
+        "Reconstruction from {} secret-shared seed phrases: {:.3} ms",
+        threshold,
+        reconstruct_time.as_secs_f64() * 1000.0 / iterations as f64
+    );
+    Ok(())
+}
+
+/// The function handles the generation of test vectors.
+///
+/// The test vectors are derived from the hex-encoded seed and returned as a JSON document.
+///
+/// * `command_line` - The command-line arguments.
+/// * `word_list` - The word list to be used, if provided.
+fn handle_gen_test_vectors(
+    command_line: &clap::ArgMatches,
+    word_list: Option<Vec<String>>,
+) -> HarpoResult<String> {
+    // The unwrap() call is okay because --seed must be provided.
+    let seed_string = command_line.value_of("seed").unwrap().trim();
+    let seed_string = seed_string.strip_prefix("0x").unwrap_or(seed_string);
+    if !seed_string.len().is_multiple_of(2) || !seed_string.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(HarpoError::InvalidParameter(
+            "The seed must consist of an even number of hex characters.".to_string(),
+        ));
+    }
+    let seed = (0..seed_string.len())
+        .step_by(2)
+        .map(|index| u8::from_str_radix(&seed_string[index..index + 2], 16))
+        .collect::<Result<Vec<u8>, _>>()?;
+    let suite = match word_list {
+        Some(list) => {
+            let slice_list: Vec<&str> = list.iter().map(|s| s.as_str()).collect();
+            generate_test_vectors_for_word_list(&seed, &slice_list)?
+        }
+        None => generate_test_vectors(&seed)?,
+    };
+    Ok(suite.to_string())
+}
+
+/// The function runs the guided demo.
+///
+/// The demo walks through the creation, distribution, and reconstruction of secret-shared
+/// seed phrases. It only ever uses a randomly generated sample seed phrase, never user input.
+///
+/// * `command_line` - The command-line arguments.
+/// * `word_list` - The word list to be used, if provided.
+fn handle_demo(command_line: &clap::ArgMatches, word_list: Option<Vec<String>>) -> HarpoResult<()> {
+    // The smallest permissible number of words is used by default.
+    let length = match command_line.value_of("length") {
+        Some(length) => length.parse::<usize>()?,
+        None => VALID_NUM_WORDS[0],
+    };
+    // The unwrap() calls are okay because there are default values.
+    let num_shares = command_line
+        .value_of("num-shares")
+        .unwrap()
+        .parse::<usize>()?;
+    let threshold = command_line
+        .value_of("threshold")
+        .unwrap()
+        .parse::<usize>()?;
+    // Indices are embedded whenever possible.
+    let embed_indices = num_shares <= MAX_EMBEDDED_SHARES && can_embed_index(length);
+    let slice_list: Option<Vec<&str>> = word_list
+        .as_ref()
+        .map(|list| list.iter().map(|s| s.as_str()).collect());
+
+    println!("Welcome to the harpo demo!");
+    println!();
+    println!("This walkthrough only uses generated sample data.");
+    println!("Do NOT enter or use your real seed phrase during the demo.");
+    println!();
+
+    // Step 1: Generate the sample seed phrase.
+    println!(
+        "Step 1: A sample seed phrase with {} words is generated.",
+        length
+    );
+    print_rule(54);
+    let seed_phrase = match &slice_list {
+        Some(list) => generate_seed_phrase_for_word_list(length, list)?,
+        None => generate_seed_phrase(length)?,
+    };
+    println!("{}", seed_phrase);
+    println!();
+
+    // Step 2: Create the secret-shared seed phrases.
+    println!(
+        "Step 2: The seed phrase is split into {} secret-shared seed phrases, any {} of which",
+        num_shares, threshold
+    );
+    println!("can be used to reconstruct the sample seed phrase.");
+    print_rule(79);
+    let seed_phrases = match &slice_list {
+        Some(list) => create_secret_shared_seed_phrases_for_word_list(
+            &seed_phrase,
+            threshold,
+            num_shares,
+            embed_indices,
+            list,
+        )?,
+        None => {
+            create_secret_shared_seed_phrases(&seed_phrase, threshold, num_shares, embed_indices)?
+        }
+    };
+    if embed_indices {
+        println!("The index of each secret-shared seed phrase is embedded in its words.");
+    } else {
+        println!("The index of each secret-shared seed phrase must be stored alongside the words.");
+    }
+    println!();
+
+    // Step 3: Distribute the secret-shared seed phrases.
+    println!("Step 3: Each custodian receives exactly one secret-shared seed phrase.");
+    print_rule(70);
+    for (custodian, share) in seed_phrases.iter().enumerate() {
+        println!("Custodian {}: {}", custodian + 1, share);
+    }
+    println!();
+
+    // Step 4: Reconstruct the seed phrase using a random quorum.
+    let mut rng = rand::thread_rng();
+    let quorum: Vec<SeedPhrase> = seed_phrases
+        .choose_multiple(&mut rng, threshold)
+        .cloned()
+        .collect();
+    println!(
+        "Step 4: {} custodians meet and combine their secret-shared seed phrases.",
+        threshold
+    );
+    print_rule(71);
+    for share in &quorum {
+        println!("{}", share);
+    }
+    let reconstructed_seed_phrase = match &slice_list {
+        Some(list) => reconstruct_seed_phrase_for_word_list(&quorum, list)?,
+        None => reconstruct_seed_phrase(&quorum)?,
+    };
+    println!();
+    println!("Reconstructed seed phrase: {}", reconstructed_seed_phrase);
+    if reconstructed_seed_phrase == seed_phrase {
+        println!("The reconstructed seed phrase matches the sample seed phrase.");
+    } else {
+        return Err(HarpoError::InvalidSeedPhrase(
+            "The reconstructed seed phrase does not match the sample seed phrase.".to_string(),
+        ));
+    }
+    println!();
+
+    // Step 5: Show that fewer custodians cannot reconstruct the seed phrase.
+    if threshold > 1 {
+        let insufficient_quorum = &quorum[..threshold - 1];
+        let result = match &slice_list {
+            Some(list) => reconstruct_seed_phrase_for_word_list(insufficient_quorum, list),
+            None => reconstruct_seed_phrase(insufficient_quorum),
+        };
+        println!(
+            "Step 5: {} custodian(s) try to reconstruct the seed phrase without the others.",
+            threshold - 1
+        );
+        print_rule(79);
+        match result {
+            // The secret-shared seed phrases record the threshold, so too few of them are
+            // rejected.
+            Err(HarpoError::NotEnoughShares { .. }) => {
+                println!("The reconstruction fails because too few secret-shared seed phrases");
+                println!(
+                    "are provided. Since each secret-shared seed phrase records the threshold,"
+                );
+                println!("harpo reports this instead of returning a random seed phrase, which is");
+                println!(
+                    "all that fewer than {} custodians could compute.",
+                    threshold
+                );
+            }
+            Err(err) => return Err(err),
+            Ok(wrong_seed_phrase) => {
+                println!("The result is a random seed phrase: {}", wrong_seed_phrase);
+            }
+        }
+        println!();
+    }
+    println!("The demo is complete. You are now ready for the real ceremony.");
+    Ok(())
+}
+
+/// The function returns the type of the file system holding the given path.
+///
+/// The type is determined by finding the longest mount point in `/proc/mounts` that is a
+/// prefix of the path.
+///
+/// * `path` - The path, which must exist.
+#[cfg(target_os = "linux")]
+fn get_file_system_type(path: &Path) -> HarpoResult<String> {
+    let path = path.canonicalize()?;
+    let mounts = read_to_string("/proc/mounts")?;
+    let mut file_system_type = None;
+    let mut longest_match = 0;
+    for line in mounts.lines() {
+        let fields: Vec<&str> = line.split(' ').collect();
+        if fields.len() < 3 {
+            continue;
+        }
+        // Spaces in mount points are escaped.
+        let mount_point = fields[1].replace("\\040", " ");
+        if path.starts_with(&mount_point) && mount_point.len() >= longest_match {
+            longest_match = mount_point.len();
+            file_system_type = Some(fields[2].to_string());
+        }
+    }
+    file_system_type.ok_or_else(|| {
+        HarpoError::InvalidParameter(format!(
+            "Could not determine the file system of {}.",
+            path.display()
+        ))
+    })
+}
+
+/// The function returns the type of the file system holding the given path.
+///
+/// The file system type can only be determined on Linux.
+///
+/// * `path` - The path, which must exist.
+#[cfg(not(target_os = "linux"))]
+fn get_file_system_type(path: &Path) -> HarpoResult<String> {
+    Err(HarpoError::InvalidParameter(format!(
+        "Could not determine the file system of {}.",
+        path.display()
+    )))
+}
+
+/// The function returns true if swap space is enabled.
+///
+/// On systems other than Linux, the function conservatively returns true.
+fn is_swap_enabled() -> bool {
+    match read_to_string("/proc/swaps") {
+        // The first line is the header.
+        Ok(swaps) => swaps.lines().count() > 1,
+        Err(_) => true,
+    }
+}
+
+/// The function checks whether secrets may be written to the given file path.
+///
+/// Secrets may only be written to files on memory-backed file systems unless the `force` flag
+/// is set, in which case a warning is printed. A warning is also printed if swap space is
+/// enabled because the contents of a tmpfs file system may then be written to disk.
+/// Symbolic links are never accepted as output files.
+///
+/// * `file_path` - The path of the output file.
+/// * `force` - Flag indicating whether any file system is accepted.
+fn check_output_path(file_path: &str, force: bool) -> HarpoResult<()> {
+    let path = Path::new(file_path);
+    // If the file exists, its own file system is checked. Otherwise, the file system of the
+    // parent directory is checked.
+    let checked_path = match path.symlink_metadata() {
+        Ok(metadata) if metadata.file_type().is_symlink() => {
+            return Err(HarpoError::InvalidParameter(format!(
+                "The file {} is a symbolic link, which is not accepted as secret output.",
+                file_path
+            )));
+        }
+        Ok(_) => path,
+        Err(_) => match path.parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => parent,
+            _ => Path::new("."),
+        },
+    };
+    let problem = match get_file_system_type(checked_path) {
+        Ok(file_system_type) if MEMORY_BACKED_FILE_SYSTEMS.contains(&file_system_type.as_str()) => {
+            if file_system_type == "tmpfs" && is_swap_enabled() {
+                eprintln!(
+                    "Warning: Swap space is enabled, so the content of {} may be written to disk.",
+                    file_path
+                );
+            }
+            return Ok(());
+        }
+        Ok(file_system_type) => format!(
+            "The file {} is on a file system of type {}, which is not memory-backed.",
+            file_path, file_system_type
+        ),
+        Err(error) => format!("{}", error),
+    };
+    if force {
+        eprintln!("Warning: {}", problem);
+        Ok(())
+    } else {
+        Err(HarpoError::InvalidParameter(format!(
+            "{} Use --force to write the secret output anyway.",
+            problem
+        )))
+    }
+}
+
+/// The function opens the given file descriptor for writing.
+///
+/// * `file_descriptor` - The file descriptor.
+#[cfg(unix)]
+fn open_file_descriptor(file_descriptor: i32) -> HarpoResult<Box<dyn Write>> {
+    use std::os::unix::io::FromRawFd;
+    if file_descriptor < 0 {
+        return Err(HarpoError::InvalidParameter(
+            "The file descriptor must not be negative.".to_string(),
+        ));
+    }
+    // SAFETY: The file descriptor is provided explicitly by the caller, who hands it over to
+    // this process for writing. Invalid descriptors cause an I/O error when writing.
+    Ok(Box::new(unsafe { File::from_raw_fd(file_descriptor) }))
+}
+
+/// The function opens the given file descriptor for writing.
+///
+/// File descriptors are only supported on Unix systems.
+///
+/// * `file_descriptor` - The file descriptor.
+#[cfg(not(unix))]
+fn open_file_descriptor(_file_descriptor: i32) -> HarpoResult<Box<dyn Write>> {
+    Err(HarpoError::InvalidParameter(
+        "File descriptors are only supported on Unix systems.".to_string(),
+    ))
+}
+
+/// The function opens the destination of the secret output, if any.
+///
+/// If neither `--output` nor `--output-fd` is provided, the function returns `None`, indicating
+/// that the secret output is written to standard output.
+///
+/// * `command_line` - The command-line arguments.
+fn open_secret_output(command_line: &clap::ArgMatches) -> HarpoResult<Option<Box<dyn Write>>> {
+    if let Some(file_descriptor) = command_line.value_of("output-fd") {
+        return Ok(Some(open_file_descriptor(file_descriptor.parse::<i32>()?)?));
+    }
+    if let Some(file_path) = command_line.value_of("output") {
+        check_output_path(file_path, command_line.is_present("force"))?;
+        let mut options = OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            // The file is only accessible by the owner and a symbolic link is not followed, even
+            // if it is created after the path was checked.
+            options.mode(0o600).custom_flags(libc::O_NOFOLLOW);
+        }
+        let file = options.open(file_path)?;
+        // The permissions of an existing file are restricted as well.
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            file.set_permissions(std::fs::Permissions::from_mode(0o600))?;
+        }
+        return Ok(Some(Box::new(file)));
+    }
+    Ok(None)
+}
+
+/// The function writes the given secret lines.
+///
+/// If there is no secret output, the lines are written to standard output below the given title.
+/// Otherwise, only the lines are written to the secret output.
+///
+/// * `secret_output` - The secret output, if any.
+/// * `mask` - The words that are shown on standard output ("even" or "odd"), if masking is used.
+/// * `title` - The title.
+/// * `header` - The header line written to the secret output, if any.
+/// * `lines` - The secret lines.
+fn write_secret_lines(
+    secret_output: &mut Option<Box<dyn Write>>,
+    mask: Option<&str>,
+    title: &str,
+    header: Option<String>,
+    lines: &[String],
+) -> HarpoResult<()> {
+    match secret_output {
+        Some(output) => {
+            if let Some(header) = header {
+                writeln!(output, "{}", header)?;
+            }
+            for line in lines {
+                writeln!(output, "{}", line)?;
+            }
+            output.flush()?;
+            println!();
+            println!(
+                "{} ({}) written to the provided output.",
+                title,
+                lines.len()
+            );
+        }
+        None if is_accessible_output() => {
+            println!();
+            println!("{}:", title);
+            print_accessible_lines(mask, lines);
+        }
+        None => {
+            println!();
+            println!("{}:", title);
+            print_rule(title.len() + 1);
+            for line in lines {
+                match mask {
+                    // Comment lines do not contain secrets and are never masked.
+                    Some(shown_words) if !line.starts_with('#') => {
+                        println!("{}", mask_line(line, shown_words == "even"))
+                    }
+                    _ => println!("{}", line),
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// The function returns true if the accessible output mode is enabled.
+fn is_accessible_output() -> bool {
+    ACCESSIBLE_OUTPUT.load(Ordering::Relaxed)
+}
+
+/// The function prints a horizontal rule of the given length below a title.
+///
+/// No rule is printed in the accessible output mode because screen readers read it out.
+///
+/// * `length` - The length of the rule.
+fn print_rule(length: usize) {
+    if !is_accessible_output() {
+        println!("{}", "-".repeat(length));
+    }
+}
+
+/// The function prints the given secret lines in the accessible output mode.
+///
+/// Each seed phrase is introduced by a label and its words are printed on separate, numbered
+/// lines. Comment lines are printed without the comment marker.
+///
+/// * `mask` - The words that are shown ("even" or "odd"), if masking is used.
+/// * `lines` - The secret lines.
+fn print_accessible_lines(mask: Option<&str>, lines: &[String]) {
+    let total = lines.iter().filter(|line| !line.starts_with('#')).count();
+    let mut number = 0;
+    for line in lines {
+        if let Some(comment) = line.strip_prefix('#') {
+            println!();
+            println!("{}", comment.trim());
+            continue;
+        }
+        number += 1;
+        // An explicit index or a prefix without an index is reported in the label.
+        let (index, words) = match line.split_once(": ") {
+            Some((index, words)) => (Some(index), words),
+            None => (None, line.as_str()),
+        };
+        let words: Vec<&str> = words.split(' ').collect();
+        println!();
+        if words.len() == 1 {
+            println!("Value {} of {}: {}", number, total, words[0]);
+            continue;
+        }
+        match index {
+            Some(prefix) if prefix.starts_with('/') => {
+                println!("Seed phrase {} of {}, prefix {}:", number, total, prefix)
+            }
+            Some(index) => println!("Seed phrase {} of {}, index {}:", number, total, index),
+            None => println!("Seed phrase {} of {}:", number, total),
+        }
+        for (position, word) in words.iter().enumerate() {
+            let is_shown = match mask {
+                Some(shown_words) => ((position + 1) % 2 == 0) == (shown_words == "even"),
+                None => true,
+            };
+            if is_shown {
+                println!("Word {}: {}", position + 1, word);
+            } else {
+                println!("Word {}: {}", position + 1, ACCESSIBLE_MASK_PLACEHOLDER);
+            }
+        }
+    }
+}
+
+/// The function masks every other word of the given seed phrase line.
+///
+/// An explicit index or a prefix at the beginning of the line is not masked.
+///
+/// * `line` - The seed phrase line.
+/// * `show_even` - Flag indicating if the words at even positions (2, 4, 6, ...) are shown.
+fn mask_line(line: &str, show_even: bool) -> String {
+    let mut tokens: Vec<&str> = line.split(' ').collect();
+    let mut position = 0;
+    for token in tokens.iter_mut() {
+        // Skip the explicit index.
+        if token.ends_with(':') {
+            continue;
+        }
+        position += 1;
+        if (position % 2 == 0) != show_even {
+            *token = MASK_PLACEHOLDER;
+        }
+    }
+    tokens.join(" ")
+}
+
+/// The main function uses the command-line arguments to trigger the right command execution.
+///
+/// Given the command-line arguments, the main function triggers the processing of the
+/// provided subcommand.
+fn main() {
+    let command_line = parse_command_line();
+    let verbose = command_line.is_present("verbose");
+    ACCESSIBLE_OUTPUT.store(command_line.is_present("accessible"), Ordering::Relaxed);
+    // If a path to a word-list file is provided, try to load it.
+    let word_list = match command_line.value_of("word-list") {
+        Some(file_path) => {
+            if verbose {
+                println!("Word list file: {}", file_path);
+            }
+            match read_word_list_from_file(file_path) {
+                Ok(list) => Some(list),
+                Err(error) => {
+                    eprintln!("{}", error);
+                    return;
+                }
+            }
+        }
+        None => match command_line.value_of("language") {
+            Some(language) => match language.parse::<Language>() {
+                Ok(language) => {
+                    if verbose {
+                        println!("Language: {}", language);
+                    }
+                    Some(
+                        language
+                            .get_word_list()
+                            .iter()
+                            .map(|word| word.to_string())
+                            .collect(),
+                    )
+                }
+                Err(error) => {
+                    eprintln!("{}", error);
+                    return;
+                }
+            },
+            None => None,
+        },
+    };
+    // Open the secret output, if provided.
+    let mut secret_output = match open_secret_output(&command_line) {
+        Ok(secret_output) => secret_output,
+        Err(error) => {
+            eprintln!("{}", error);
+            return;
+        }
+    };
+    // The words that are shown if the secret output is masked.
+    let mask = command_line.value_of("mask");
+    // Trigger the right function based on the provided subcommand.
+    match command_line.subcommand_name() {
+        Some(CREATE_SUBCOMMAND) => {
+            match handle_create(
+                command_line
+                    .subcommand_matches(CREATE_SUBCOMMAND)
+                    .expect("The 'create' command must be specified."),
                 verbose,
                 word_list,
             ) {
-                Ok(seed_phrases) => {
-                    println!();
-                    println!("Created secret-shared seed phrases:");
-                    println!("-----------------------------------");
-                    for seed_phrase in seed_phrases {
-                        println!("{}", seed_phrase);
+                Ok((seed_phrases, commitment)) => {
+                    let lines: Vec<String> = seed_phrases
+                        .iter()
+                        .map(|phrase| phrase.to_string())
+                        .collect();
+                    let is_written = write_secret_lines(
+                        &mut secret_output,
+                        mask,
+                        "Created secret-shared seed phrases",
+                        Some(get_share_format_header()),
+                        &lines,
+                    )
+                    .map_err(|err| eprintln!("{}", err))
+                    .is_ok();
+                    if let Some(commitment) = commitment {
+                        println!();
+                        println!("Polynomial commitment:");
+                        print_rule(22);
+                        println!("{}", commitment);
+                    }
+                    if is_written {
+                        shred_input_file(&command_line, CREATE_SUBCOMMAND);
                     }
                 }
                 Err(err) => {
@@ -527,11 +2941,27 @@ fn main() {
                 verbose,
                 word_list,
             ) {
-                Ok(seed_phrase) => {
-                    println!();
-                    println!("Reconstructed seed phrase:");
-                    println!("--------------------------");
-                    println!("{}", seed_phrase)
+                Ok((seed_phrase, report)) => {
+                    let is_written = write_secret_lines(
+                        &mut secret_output,
+                        mask,
+                        "Reconstructed seed phrase",
+                        None,
+                        &[seed_phrase.to_string()],
+                    )
+                    .map_err(|err| eprintln!("{}", err))
+                    .is_ok();
+                    if let Some(report) = report {
+                        println!();
+                        println!("Reconstruction report:");
+                        print_rule(22);
+                        for line in report {
+                            println!("{}", line);
+                        }
+                    }
+                    if is_written {
+                        shred_input_file(&command_line, RECONSTRUCT_SUBCOMMAND);
+                    }
                 }
                 Err(err) => {
                     println!();
@@ -548,10 +2978,15 @@ fn main() {
                 word_list,
             ) {
                 Ok(seed_phrase) => {
-                    println!();
-                    println!("Generated seed phrase:");
-                    println!("----------------------");
-                    println!("{}", seed_phrase)
+                    if let Err(err) = write_secret_lines(
+                        &mut secret_output,
+                        mask,
+                        "Generated seed phrase",
+                        None,
+                        &[seed_phrase.to_string()],
+                    ) {
+                        eprintln!("{}", err);
+                    }
                 }
                 Err(err) => {
                     println!();
@@ -571,12 +3006,338 @@ fn main() {
                     println!();
                     println!("The seed phrase is valid.");
                 }
-                Err(_) => {
+                Err(err) => {
                     println!();
                     println!("The seed phrase is NOT valid!");
+                    eprintln!("{}", err);
+                }
+            }
+        }
+        Some(VERIFY_SHARE_SUBCOMMAND) => {
+            match handle_verify_share(
+                command_line
+                    .subcommand_matches(VERIFY_SHARE_SUBCOMMAND)
+                    .expect("Error: The 'verify-share' command must be specified."),
+                verbose,
+                word_list,
+            ) {
+                Ok((lines, all_match)) => {
+                    println!();
+                    for line in lines {
+                        println!("{}", line);
+                    }
+                    if !all_match {
+                        std::process::exit(1);
+                    }
+                }
+                Err(err) => {
+                    println!();
+                    eprintln!("{}", err);
+                }
+            }
+        }
+        Some(UPGRADE_SHARES_SUBCOMMAND) => {
+            match handle_upgrade_shares(
+                command_line
+                    .subcommand_matches(UPGRADE_SHARES_SUBCOMMAND)
+                    .expect("Error: The 'upgrade-shares' command must be specified."),
+                verbose,
+                word_list,
+            ) {
+                Ok((seed_phrases, commitment, report)) => {
+                    let lines: Vec<String> = seed_phrases
+                        .iter()
+                        .map(|phrase| phrase.to_string())
+                        .collect();
+                    let is_written = write_secret_lines(
+                        &mut secret_output,
+                        mask,
+                        "Upgraded secret-shared seed phrases",
+                        Some(get_share_format_header()),
+                        &lines,
+                    )
+                    .map_err(|err| eprintln!("{}", err))
+                    .is_ok();
+                    if let Some(commitment) = commitment {
+                        println!();
+                        println!("Polynomial commitment:");
+                        print_rule(22);
+                        println!("{}", commitment);
+                    }
+                    println!();
+                    println!("Upgrade report:");
+                    print_rule(15);
+                    for line in report {
+                        println!("{}", line);
+                    }
+                    if is_written {
+                        shred_input_file(&command_line, UPGRADE_SHARES_SUBCOMMAND);
+                    }
+                }
+                Err(err) => {
+                    println!();
+                    eprintln!("{}", err);
+                }
+            };
+        }
+        Some(REGENERATE_SHARE_SUBCOMMAND) => {
+            match handle_regenerate_share(
+                command_line
+                    .subcommand_matches(REGENERATE_SHARE_SUBCOMMAND)
+                    .expect("Error: The 'regenerate-share' command must be specified."),
+                verbose,
+                word_list,
+            ) {
+                Ok(seed_phrase) => {
+                    let is_written = write_secret_lines(
+                        &mut secret_output,
+                        mask,
+                        "Re-created secret-shared seed phrase",
+                        Some(get_share_format_header()),
+                        &[seed_phrase.to_string()],
+                    )
+                    .map_err(|err| eprintln!("{}", err))
+                    .is_ok();
+                    if is_written {
+                        shred_input_file(&command_line, REGENERATE_SHARE_SUBCOMMAND);
+                    }
+                }
+                Err(err) => {
+                    println!();
+                    eprintln!("{}", err);
+                }
+            };
+        }
+        Some(SELFTEST_SUBCOMMAND) => {
+            let report = health_check();
+            println!("{}", report);
+            println!();
+            if report.is_healthy() {
+                println!("All known-answer tests passed.");
+            } else {
+                eprintln!("At least one known-answer test failed! Do not use this binary.");
+                std::process::exit(1);
+            }
+        }
+        Some(BENCH_SUBCOMMAND) => {
+            if let Err(err) = handle_bench(
+                command_line
+                    .subcommand_matches(BENCH_SUBCOMMAND)
+                    .expect("Error: The 'bench' command must be specified."),
+                word_list,
+            ) {
+                println!();
+                eprintln!("{}", err);
+            }
+        }
+        Some(SPLIT_KEY_SUBCOMMAND) => {
+            match handle_split_key(
+                command_line
+                    .subcommand_matches(SPLIT_KEY_SUBCOMMAND)
+                    .expect("Error: The 'split-key' command must be specified."),
+                verbose,
+                word_list,
+            ) {
+                Ok((seed_phrases, commitment)) => {
+                    let lines: Vec<String> = seed_phrases
+                        .iter()
+                        .map(|phrase| phrase.to_string())
+                        .collect();
+                    let is_written = write_secret_lines(
+                        &mut secret_output,
+                        mask,
+                        "Created secret-shared seed phrases",
+                        Some(get_share_format_header()),
+                        &lines,
+                    )
+                    .map_err(|err| eprintln!("{}", err))
+                    .is_ok();
+                    if let Some(commitment) = commitment {
+                        println!();
+                        println!("Polynomial commitment:");
+                        print_rule(22);
+                        println!("{}", commitment);
+                    }
+                    if is_written {
+                        shred_input_file(&command_line, SPLIT_KEY_SUBCOMMAND);
+                    }
+                }
+                Err(err) => {
+                    println!();
+                    eprintln!("{}", err);
+                }
+            };
+        }
+        Some(JOIN_KEY_SUBCOMMAND) => {
+            match handle_join_key(
+                command_line
+                    .subcommand_matches(JOIN_KEY_SUBCOMMAND)
+                    .expect("Error: The 'join-key' command must be specified."),
+                verbose,
+                word_list,
+            ) {
+                Ok(private_key) => {
+                    let is_written = write_secret_lines(
+                        &mut secret_output,
+                        mask,
+                        "Reconstructed private key",
+                        None,
+                        &[private_key.to_string()],
+                    )
+                    .map_err(|err| eprintln!("{}", err))
+                    .is_ok();
+                    // The address is not secret and allows confirming the reconstructed key.
+                    println!();
+                    println!("Address: {}", private_key.get_address());
+                    if is_written {
+                        shred_input_file(&command_line, JOIN_KEY_SUBCOMMAND);
+                    }
+                }
+                Err(err) => {
+                    println!();
+                    eprintln!("{}", err);
                 }
+            };
+        }
+        Some(DERIVE_SUBCOMMAND) => {
+            match handle_derive(
+                command_line
+                    .subcommand_matches(DERIVE_SUBCOMMAND)
+                    .expect("Error: The 'derive' command must be specified."),
+                verbose,
+                word_list,
+            ) {
+                Ok(seed_phrase) => {
+                    if let Err(err) = write_secret_lines(
+                        &mut secret_output,
+                        mask,
+                        "Derived child seed phrase",
+                        None,
+                        &[seed_phrase.to_string()],
+                    ) {
+                        eprintln!("{}", err);
+                    }
+                }
+                Err(err) => {
+                    println!();
+                    eprintln!("{}", err);
+                }
+            };
+        }
+        Some(DEMO_SUBCOMMAND) => {
+            if let Err(err) = handle_demo(
+                command_line
+                    .subcommand_matches(DEMO_SUBCOMMAND)
+                    .expect("Error: The 'demo' command must be specified."),
+                word_list,
+            ) {
+                println!();
+                eprintln!("{}", err);
+            }
+        }
+        Some(GEN_TEST_VECTORS_SUBCOMMAND) => {
+            match handle_gen_test_vectors(
+                command_line
+                    .subcommand_matches(GEN_TEST_VECTORS_SUBCOMMAND)
+                    .expect("Error: The 'gen-test-vectors' command must be specified."),
+                word_list,
+            ) {
+                Ok(json) => println!("{}", json),
+                Err(err) => eprintln!("{}", err),
             }
         }
+        Some(MERGE_SUBCOMMAND) => {
+            match handle_merge(
+                command_line
+                    .subcommand_matches(MERGE_SUBCOMMAND)
+                    .expect("Error: The 'merge' command must be specified."),
+                verbose,
+                word_list,
+            ) {
+                Ok((lines, report)) => {
+                    if let Err(err) = write_secret_lines(
+                        &mut secret_output,
+                        mask,
+                        "Merged secret-shared seed phrases",
+                        Some(get_share_format_header()),
+                        &lines,
+                    ) {
+                        eprintln!("{}", err);
+                    }
+                    println!();
+                    println!("Merge report:");
+                    print_rule(13);
+                    for line in report {
+                        println!("{}", line);
+                    }
+                }
+                Err(err) => {
+                    println!();
+                    eprintln!("{}", err);
+                }
+            };
+        }
+        Some(XOR_SPLIT_SUBCOMMAND) => {
+            match handle_xor_split(
+                command_line
+                    .subcommand_matches(XOR_SPLIT_SUBCOMMAND)
+                    .expect("Error: The 'xor-split' command must be specified."),
+                verbose,
+                word_list,
+            ) {
+                Ok(parts) => {
+                    let lines: Vec<String> = parts.iter().map(|part| part.to_string()).collect();
+                    let is_written = write_secret_lines(
+                        &mut secret_output,
+                        mask,
+                        "Created Seed XOR parts",
+                        None,
+                        &lines,
+                    )
+                    .map_err(|err| eprintln!("{}", err))
+                    .is_ok();
+                    println!();
+                    eprintln!("{}", XOR_MISSING_PART_WARNING);
+                    if is_written {
+                        shred_input_file(&command_line, XOR_SPLIT_SUBCOMMAND);
+                    }
+                }
+                Err(err) => {
+                    println!();
+                    eprintln!("{}", err);
+                }
+            };
+        }
+        Some(XOR_JOIN_SUBCOMMAND) => {
+            match handle_xor_join(
+                command_line
+                    .subcommand_matches(XOR_JOIN_SUBCOMMAND)
+                    .expect("Error: The 'xor-join' command must be specified."),
+                verbose,
+                word_list,
+            ) {
+                Ok(seed_phrase) => {
+                    let is_written = write_secret_lines(
+                        &mut secret_output,
+                        mask,
+                        "Reconstructed seed phrase",
+                        None,
+                        &[seed_phrase.to_string()],
+                    )
+                    .map_err(|err| eprintln!("{}", err))
+                    .is_ok();
+                    println!();
+                    eprintln!("{}", XOR_MISSING_PART_WARNING);
+                    if is_written {
+                        shred_input_file(&command_line, XOR_JOIN_SUBCOMMAND);
+                    }
+                }
+                Err(err) => {
+                    println!();
+                    eprintln!("{}", err);
+                }
+            };
+        }
         _ => eprintln!("Error: A subcommand must be provided. Use --help to view options."),
     };
 }
@@ -1,104 +1,724 @@
 //! The `math` module provides all required finite field operations.
 //!
+//! Finite field elements are stored in fixed-size arrays of 32-bit limbs, i.e., the arithmetic
+//! does not allocate memory. It runs in constant time, i.e., the sequence of operations does not
+//! depend on the secret values, which protects against timing side channels.
+//!
 
-use num::Integer;
-use num_bigint::{BigInt, BigUint, ToBigInt};
-use num_traits::{One, Zero};
-use rand::{distributions::Standard, rngs::OsRng, Rng};
+use crate::secret_sharing::get_field_modulus_for_bits;
+use crate::{HarpoError, HarpoResult};
+use num_bigint::BigUint;
+use rand::{CryptoRng, RngCore};
+use sha2::{Digest, Sha256};
 use std::cmp::Ordering;
+use std::fmt;
 use std::ops::{Add, Div, Mul, Sub};
+use zeroize::{Zeroize, Zeroizing};
 
-/// The function returns a random finite field element with the given number of bits.
+/// The function returns a random number with the given number of bits modulo the modulus.
 ///
 /// The function first generates sufficiently many random bits and then applies the
 /// provided modulus.
 ///
 /// * `bits` - The size of the random number in bits.
-/// * `bits` - The modulus.
-pub(crate) fn get_random_number(bits: usize, modulus: &BigUint) -> BigUint {
-    // Determine the required number of 32-byte integers.
-    let num_elements = ((bits + 31) / 32) as usize;
-    // Get the random numbers.
-    let random_bytes: Vec<u32> = OsRng.sample_iter(Standard).take(num_elements).collect();
-    // Construct a big unsigned integer and apply the modulus.
-    BigUint::from_slice(&random_bytes).mod_floor(modulus)
+/// * `modulus` - The modulus.
+#[cfg(test)]
+pub(crate) fn get_random_number(bits: usize, modulus: &Modulus) -> Limbs {
+    get_random_number_with_rng(bits, modulus, &mut rand::rngs::OsRng)
+}
+
+/// The function returns a random number with the given number of bits modulo the modulus
+/// using the given random number generator.
+///
+/// The number of bits must not exceed twice the number of bits of the modulus.
+///
+/// * `bits` - The size of the random number in bits.
+/// * `modulus` - The modulus.
+/// * `rng` - The cryptographically secure random number generator.
+pub(crate) fn get_random_number_with_rng<R: RngCore + CryptoRng>(
+    bits: usize,
+    modulus: &Modulus,
+    rng: &mut R,
+) -> Limbs {
+    // Determine the required number of 32-bit limbs.
+    let num_limbs = bits.div_ceil(32);
+    assert!(
+        num_limbs <= 2 * modulus.num_limbs,
+        "The number of random bits exceeds twice the size of the modulus."
+    );
+    let mut wide_limbs = Zeroizing::new([0; 2 * MAX_NUM_LIMBS]);
+    for limb in wide_limbs.iter_mut().take(num_limbs) {
+        *limb = rng.next_u32();
+    }
+    modulus.reduce_wide(&wide_limbs)
+}
+
+/// The domain separator for deterministically derived numbers.
+const DETERMINISTIC_DOMAIN_SEPARATOR: &[u8] = b"harpo-deterministic-v1";
+
+/// The function returns a deterministic number with the given number of bits modulo the
+/// modulus.
+///
+/// The bytes are obtained by concatenating the SHA-256 hashes of the domain separator, the seed,
+/// the label, and a 32-bit big-endian counter, starting at 0. The first `bits / 8` bytes
+/// (rounded up) are interpreted as a big-endian integer, and the provided modulus is applied.
+/// The numbers must only be used for reproducible test vectors, never to protect real secrets.
+///
+/// * `seed` - The seed.
+/// * `label` - The label that distinguishes the numbers derived from the same seed.
+/// * `bits` - The size of the number in bits.
+/// * `modulus` - The modulus.
+pub(crate) fn get_deterministic_number(
+    seed: &[u8],
+    label: &str,
+    bits: usize,
+    modulus: &Modulus,
+) -> Limbs {
+    get_number_from_hash(
+        DETERMINISTIC_DOMAIN_SEPARATOR,
+        &[seed, label.as_bytes()],
+        bits,
+        modulus,
+    )
 }
 
-/// Given a number and a modulus, the function returns the modular inverse.
+/// The domain separator for numbers derived from a secret and a salt.
+const DERIVED_DOMAIN_SEPARATOR: &[u8] = b"harpo-derived-v1";
+
+/// The function returns a number with the given number of bits modulo the modulus that is
+/// derived from a secret and a salt.
 ///
-/// * `number` - The number that is to be inverted.
+/// The bytes are obtained by concatenating the SHA-256 hashes of the domain separator, the
+/// secret, the length of the salt as a 64-bit big-endian number, the salt, the label, and a
+/// 32-bit big-endian counter, starting at 0. The number is unpredictable as long as the secret
+/// is unknown.
+///
+/// * `secret` - The secret.
+/// * `salt` - The salt.
+/// * `label` - The label that distinguishes the numbers derived from the same secret and salt.
+/// * `bits` - The size of the number in bits.
 /// * `modulus` - The modulus.
-fn modular_inverse(number: &BigUint, modulus: &BigUint) -> BigUint {
-    // If the modulus is 1, return 1.
-    if modulus == &One::one() {
-        return One::one();
-    }
-    let mut number = number
-        .to_bigint()
-        .expect("Conversion to big integer failed.");
-    let mut modulus = modulus
-        .to_bigint()
-        .expect("Conversion to big integer failed.");
-    // Use the extended Euclidean algorithm to compute the inverse.
-    let original_modulus = modulus.clone();
-    let mut x: BigInt = Zero::zero();
-    let mut inverse: BigInt = One::one();
-    while number > One::one() {
-        let (dividend, remainder) = number.div_rem(&modulus);
-        inverse -= dividend * &x;
-        number = remainder;
-        std::mem::swap(&mut number, &mut modulus);
-        std::mem::swap(&mut x, &mut inverse)
-    }
-    // If the inverse is negative, add the modulus.
-    if inverse < Zero::zero() {
-        inverse += original_modulus
-    }
-    // Convert the inverse to an unsigned big integer.
-    inverse
-        .to_biguint()
-        .expect("Conversion to unsigned big integer failed.")
+pub(crate) fn get_derived_number(
+    secret: &[u8],
+    salt: &[u8],
+    label: &str,
+    bits: usize,
+    modulus: &Modulus,
+) -> Limbs {
+    get_number_from_hash(
+        DERIVED_DOMAIN_SEPARATOR,
+        &[
+            secret,
+            &(salt.len() as u64).to_be_bytes(),
+            salt,
+            label.as_bytes(),
+        ],
+        bits,
+        modulus,
+    )
+}
+
+/// The function hashes the domain separator and the given inputs together with a counter until
+/// sufficiently many bytes are available, and it returns the resulting number modulo the
+/// provided modulus.
+///
+/// The number of bits must not exceed twice the number of bits of the modulus.
+///
+/// * `domain_separator` - The domain separator.
+/// * `inputs` - The inputs that are hashed after the domain separator.
+/// * `bits` - The size of the number in bits.
+/// * `modulus` - The modulus.
+fn get_number_from_hash(
+    domain_separator: &[u8],
+    inputs: &[&[u8]],
+    bits: usize,
+    modulus: &Modulus,
+) -> Limbs {
+    let num_bytes = bits.div_ceil(8);
+    assert!(
+        num_bytes <= 8 * modulus.num_limbs,
+        "The number of bits exceeds twice the size of the modulus."
+    );
+    let mut bytes = Zeroizing::new(vec![]);
+    let mut counter: u32 = 0;
+    while bytes.len() < num_bytes {
+        let mut hasher = Sha256::new();
+        hasher.update(domain_separator);
+        for input in inputs {
+            hasher.update(input);
+        }
+        hasher.update(counter.to_be_bytes());
+        bytes.extend_from_slice(&hasher.finalize());
+        counter += 1;
+    }
+    bytes.truncate(num_bytes);
+    // The bytes are interpreted as a big-endian integer.
+    bytes.reverse();
+    let mut wide_limbs = Zeroizing::new([0; 2 * MAX_NUM_LIMBS]);
+    set_limbs_from_bytes(&mut wide_limbs[..], &bytes);
+    modulus.reduce_wide(&wide_limbs)
+}
+
+/// The function sets the given limbs to the integer defined by the given bytes in
+/// little-endian format.
+///
+/// The limbs must be able to hold all bytes.
+///
+/// * `limbs` - The limbs.
+/// * `bytes` - The bytes in little-endian format.
+fn set_limbs_from_bytes(limbs: &mut [u32], bytes: &[u8]) {
+    for (position, byte) in bytes.iter().enumerate() {
+        limbs[position >> 2] |= (*byte as u32) << ((position & 3) << 3);
+    }
+}
+
+/// The maximum number of 32-bit limbs of a finite field element, which supports moduli with up
+/// to 512 bits.
+pub(crate) const MAX_NUM_LIMBS: usize = 16;
+
+/// The type of the little-endian 32-bit limbs of a finite field element.
+///
+/// Only the limbs up to the number of limbs of the modulus are used, the remaining limbs are
+/// zero.
+pub(crate) type Limbs = [u32; MAX_NUM_LIMBS];
+
+/// The function returns a mask whose bits are all set if the given bit is 1 and unset if it
+/// is 0.
+///
+/// The mask is passed through an optimization barrier so that the compiler does not turn the
+/// selection based on the mask into a branch.
+///
+/// * `bit` - The bit, which must be 0 or 1.
+fn get_mask(bit: u32) -> u32 {
+    std::hint::black_box(0u32.wrapping_sub(bit))
+}
+
+/// The function returns the first limbs if all bits of the mask are set and the second limbs
+/// if no bits are set.
+///
+/// * `mask` - The mask.
+/// * `first` - The first limbs.
+/// * `second` - The second limbs.
+fn select(mask: u32, first: &Limbs, second: &Limbs) -> Limbs {
+    let mut result = [0; MAX_NUM_LIMBS];
+    for ((limb, first_limb), second_limb) in result.iter_mut().zip(first).zip(second) {
+        *limb = (first_limb & mask) | (second_limb & !mask);
+    }
+    result
+}
+
+/// This struct holds a prime modulus of the form `2^(32n) - c`, where `n` is at most
+/// [MAX_NUM_LIMBS](crate::math::MAX_NUM_LIMBS) and `c` fits into 32 bits, and it provides
+/// constant-time arithmetic modulo this prime.
+///
+/// All moduli defined in the [secret_sharing](crate::secret_sharing) module have this form.
+/// The sequence of operations depends only on the modulus, never on the values, i.e., there are
+/// no branches or memory accesses that depend on secret values.
+/// All arithmetic functions expect reduced inputs and return reduced outputs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) struct Modulus {
+    /// The limbs of the modulus.
+    limbs: Limbs,
+    /// The number of limbs of the modulus.
+    num_limbs: usize,
+    /// The difference `c` between `2^(32n)` and the modulus.
+    offset: u32,
+}
+
+impl Modulus {
+    /// The function creates the modulus with the given little-endian 32-bit limbs.
+    ///
+    /// The function is evaluated at compile time for the supported moduli, see
+    /// [get_field_modulus_for_bits](crate::secret_sharing::get_field_modulus_for_bits). It
+    /// panics if the modulus does not have the required form.
+    ///
+    /// * `limbs` - The limbs of the modulus.
+    pub(crate) const fn new(limbs: &[u32]) -> Self {
+        assert!(
+            !limbs.is_empty() && limbs.len() <= MAX_NUM_LIMBS && limbs[0] != 0,
+            "The modulus must have the form 2^(32n) - c for a 32-bit offset c."
+        );
+        let mut modulus_limbs = [0; MAX_NUM_LIMBS];
+        let mut index = 0;
+        while index < limbs.len() {
+            assert!(
+                index == 0 || limbs[index] == u32::MAX,
+                "The modulus must have the form 2^(32n) - c for a 32-bit offset c."
+            );
+            modulus_limbs[index] = limbs[index];
+            index += 1;
+        }
+        Modulus {
+            limbs: modulus_limbs,
+            num_limbs: limbs.len(),
+            offset: limbs[0].wrapping_neg(),
+        }
+    }
+
+    /// The function returns the number of bits of the modulus.
+    pub(crate) fn get_num_bits(&self) -> usize {
+        self.num_limbs << 5
+    }
+
+    /// The function returns the modulus in the form of a big unsigned integer.
+    pub(crate) fn to_big_uint(self) -> BigUint {
+        BigUint::from_slice(&self.limbs[..self.num_limbs])
+    }
+
+    /// The function returns the given limbs reduced modulo the modulus.
+    ///
+    /// The limbs must not exceed the number of limbs of the modulus.
+    ///
+    /// * `limbs` - The limbs.
+    fn reduce(&self, limbs: &Limbs) -> Limbs {
+        // The value is smaller than 2^(32n), which is smaller than twice the modulus.
+        self.reduce_once(limbs, 0)
+    }
+
+    /// The function subtracts the modulus from the value `carry * 2^(32n) + limbs` if the value
+    /// is at least the modulus.
+    ///
+    /// The value must be smaller than twice the modulus.
+    ///
+    /// * `limbs` - The limbs of the value.
+    /// * `carry` - The carry, which must be 0 or 1.
+    fn reduce_once(&self, limbs: &Limbs, carry: u32) -> Limbs {
+        let mut difference = [0; MAX_NUM_LIMBS];
+        let mut borrow = 0;
+        for index in 0..self.num_limbs {
+            let (partial, first_borrow) = limbs[index].overflowing_sub(self.limbs[index]);
+            let (partial, second_borrow) = partial.overflowing_sub(borrow);
+            difference[index] = partial;
+            borrow = (first_borrow | second_borrow) as u32;
+        }
+        // The difference is used if there is a carry or if the subtraction does not borrow.
+        select(get_mask(carry | (borrow ^ 1)), &difference, limbs)
+    }
+
+    /// The function returns the value defined by the given wide limbs modulo the modulus.
+    ///
+    /// The value must be smaller than `2^(64n)`, i.e., only the first `2n` limbs may be used.
+    ///
+    /// * `wide_limbs` - The limbs of the value.
+    fn reduce_wide(&self, wide_limbs: &[u32; 2 * MAX_NUM_LIMBS]) -> Limbs {
+        let num_limbs = self.num_limbs;
+        // Since 2^(32n) is congruent to c, high * 2^(32n) + low is congruent to high * c + low.
+        let offset = self.offset as u64;
+        let mut result = [0; MAX_NUM_LIMBS];
+        let mut carry = 0;
+        for index in 0..num_limbs {
+            let partial =
+                wide_limbs[index] as u64 + wide_limbs[index + num_limbs] as u64 * offset + carry;
+            result[index] = partial as u32;
+            carry = partial >> 32;
+        }
+        // The carry is at most c. It is folded back three times, after which it is guaranteed
+        // to be zero.
+        for _ in 0..3 {
+            let mut summand = carry * offset;
+            carry = 0;
+            for limb in result.iter_mut().take(num_limbs) {
+                let partial = *limb as u64 + (summand & u32::MAX as u64) + carry;
+                *limb = partial as u32;
+                carry = partial >> 32;
+                summand >>= 32;
+            }
+            carry += summand;
+        }
+        let reduced_result = self.reduce(&result);
+        result.zeroize();
+        reduced_result
+    }
+
+    /// The function returns the sum of the given values modulo the modulus.
+    ///
+    /// * `first` - The first summand.
+    /// * `second` - The second summand.
+    fn add(&self, first: &Limbs, second: &Limbs) -> Limbs {
+        let mut sum = [0; MAX_NUM_LIMBS];
+        let mut carry = 0;
+        for index in 0..self.num_limbs {
+            let partial = first[index] as u64 + second[index] as u64 + carry;
+            sum[index] = partial as u32;
+            carry = partial >> 32;
+        }
+        let result = self.reduce_once(&sum, carry as u32);
+        sum.zeroize();
+        result
+    }
+
+    /// The function returns the difference of the given values modulo the modulus.
+    ///
+    /// * `first` - The minuend.
+    /// * `second` - The subtrahend.
+    fn sub(&self, first: &Limbs, second: &Limbs) -> Limbs {
+        let mut difference = [0; MAX_NUM_LIMBS];
+        let mut borrow = 0;
+        for index in 0..self.num_limbs {
+            let (partial, first_borrow) = first[index].overflowing_sub(second[index]);
+            let (partial, second_borrow) = partial.overflowing_sub(borrow);
+            difference[index] = partial;
+            borrow = (first_borrow | second_borrow) as u32;
+        }
+        // The modulus is added back if the subtraction borrows.
+        let mask = get_mask(borrow);
+        let mut carry = 0;
+        for (limb, modulus_limb) in difference.iter_mut().zip(&self.limbs).take(self.num_limbs) {
+            let partial = *limb as u64 + (modulus_limb & mask) as u64 + carry;
+            *limb = partial as u32;
+            carry = partial >> 32;
+        }
+        difference
+    }
+
+    /// The function returns the product of the given values modulo the modulus.
+    ///
+    /// * `first` - The first factor.
+    /// * `second` - The second factor.
+    fn mul(&self, first: &Limbs, second: &Limbs) -> Limbs {
+        let num_limbs = self.num_limbs;
+        let mut product = Zeroizing::new([0; 2 * MAX_NUM_LIMBS]);
+        for first_index in 0..num_limbs {
+            let mut carry = 0;
+            for second_index in 0..num_limbs {
+                let partial = product[first_index + second_index] as u64
+                    + first[first_index] as u64 * second[second_index] as u64
+                    + carry;
+                product[first_index + second_index] = partial as u32;
+                carry = partial >> 32;
+            }
+            product[first_index + num_limbs] = carry as u32;
+        }
+        self.reduce_wide(&product)
+    }
+
+    /// The function returns the product of the given value and the given 32-bit integer modulo
+    /// the modulus.
+    ///
+    /// Since the product has at most one more limb than the modulus, it is reduced by folding
+    /// the top limb using the offset, which is considerably faster than a full multiplication.
+    ///
+    /// * `value` - The value.
+    /// * `factor` - The 32-bit integer.
+    fn mul_integer(&self, value: &Limbs, factor: u32) -> Limbs {
+        let num_limbs = self.num_limbs;
+        let mut product = Zeroizing::new([0; 2 * MAX_NUM_LIMBS]);
+        let mut carry = 0;
+        for index in 0..num_limbs {
+            let partial = value[index] as u64 * factor as u64 + carry;
+            product[index] = partial as u32;
+            carry = partial >> 32;
+        }
+        product[num_limbs] = carry as u32;
+        self.reduce_wide(&product)
+    }
+
+    /// The function returns the inverse of the given value modulo the modulus.
+    ///
+    /// The inverse is computed as `a^(p-2)` using Fermat's little theorem. Since the exponent
+    /// is public, the sequence of operations does not depend on the value.
+    /// The inverse of zero is zero.
+    ///
+    /// * `value` - The value that is to be inverted.
+    fn inverse(&self, value: &Limbs) -> Limbs {
+        let mut two = [0; MAX_NUM_LIMBS];
+        two[0] = 2;
+        let exponent = self.sub(&self.limbs, &two);
+        let mut result = [0; MAX_NUM_LIMBS];
+        result[0] = 1;
+        for index in (0..self.num_limbs).rev() {
+            for bit in (0..32).rev() {
+                result = self.mul(&result, &result);
+                if (exponent[index] >> bit) & 1 == 1 {
+                    result = self.mul(&result, value);
+                }
+            }
+        }
+        result
+    }
+}
+
+/// The number of random bases used by the Miller-Rabin primality test.
+const NUM_PRIMALITY_TEST_ROUNDS: usize = 64;
+
+/// The function returns true if the given number is prime with overwhelming probability.
+///
+/// The function runs the Miller-Rabin primality test with random bases. It is only applied to
+/// public values such as moduli and therefore need not run in constant time.
+///
+/// * `number` - The number that is tested.
+pub(crate) fn is_probable_prime(number: &BigUint) -> bool {
+    let one = BigUint::from(1u32);
+    let two = BigUint::from(2u32);
+    if *number < two {
+        return false;
+    }
+    if *number == two || *number == BigUint::from(3u32) {
+        return true;
+    }
+    if !number.bit(0) {
+        return false;
+    }
+    // Write number - 1 = d * 2^s for an odd number d.
+    let number_minus_one = number - &one;
+    let num_trailing_zeros = number_minus_one.trailing_zeros().unwrap_or(0);
+    let odd_part = &number_minus_one >> num_trailing_zeros;
+    let num_bytes = number.to_bytes_le().len();
+    let mut rng = rand::rngs::OsRng;
+    for _ in 0..NUM_PRIMALITY_TEST_ROUNDS {
+        // The base is chosen uniformly at random in the range [2, number - 2].
+        let mut bytes = vec![0; num_bytes + 8];
+        rng.fill_bytes(&mut bytes);
+        let base = BigUint::from_bytes_le(&bytes) % (number - 3u32) + &two;
+        let mut value = base.modpow(&odd_part, number);
+        if value == one || value == number_minus_one {
+            continue;
+        }
+        let mut is_witness = true;
+        for _ in 1..num_trailing_zeros {
+            value = value.modpow(&two, number);
+            if value == number_minus_one {
+                is_witness = false;
+                break;
+            }
+        }
+        if is_witness {
+            return false;
+        }
+    }
+    true
+}
+
+/// The prime number 2^128-159, which is the modulus for 128-bit inputs.
+///
+/// Arithmetic modulo this prime is supported natively using `u128` values, see
+/// [Field128](crate::math::Field128).
+pub(crate) const MODULUS_128: u128 = u128::MAX - 158;
+
+/// The difference between 2^128 and [MODULUS_128](crate::math::MODULUS_128), i.e., 2^128 is
+/// congruent to this value modulo the prime.
+const MODULUS_128_OFFSET: u128 = 159;
+
+/// This struct provides arithmetic modulo 2^128-159 using `u128` values.
+///
+/// The 256-bit intermediate results of multiplications are represented by two `u128` values.
+/// All functions expect reduced inputs and return reduced outputs. As for
+/// [Modulus](crate::math::Modulus), the sequence of operations does not depend on the
+/// values.
+pub(crate) struct Field128;
+
+impl Field128 {
+    /// The function returns the sum of the given values modulo the prime.
+    ///
+    /// * `a` - The first summand.
+    /// * `b` - The second summand.
+    pub fn add(a: u128, b: u128) -> u128 {
+        let (sum, overflow) = a.overflowing_add(b);
+        let (difference, borrow) = sum.overflowing_sub(MODULUS_128);
+        // If the sum overflows, it is 2^128 + sum, which is congruent to sum + 159.
+        let overflow_sum = sum.wrapping_add(MODULUS_128_OFFSET);
+        let reduced_sum = Self::select(borrow, sum, difference);
+        Self::select(overflow, overflow_sum, reduced_sum)
+    }
+
+    /// The function returns the difference of the given values modulo the prime.
+    ///
+    /// * `a` - The minuend.
+    /// * `b` - The subtrahend.
+    pub fn sub(a: u128, b: u128) -> u128 {
+        let (difference, borrow) = a.overflowing_sub(b);
+        // If the subtraction borrows, the modulus is added back.
+        difference.wrapping_add(MODULUS_128 & Self::get_mask(borrow))
+    }
+
+    /// The function returns a mask whose bits are all set if the given condition holds and
+    /// unset otherwise.
+    ///
+    /// * `condition` - The condition.
+    fn get_mask(condition: bool) -> u128 {
+        std::hint::black_box(0u128.wrapping_sub(condition as u128))
+    }
+
+    /// The function returns the first value if the condition holds and the second value
+    /// otherwise, without branching on the condition.
+    ///
+    /// * `condition` - The condition.
+    /// * `first` - The first value.
+    /// * `second` - The second value.
+    fn select(condition: bool, first: u128, second: u128) -> u128 {
+        let mask = Self::get_mask(condition);
+        (first & mask) | (second & !mask)
+    }
+
+    /// The function returns the full 256-bit product of the given values as a pair
+    /// `(high, low)` of 128-bit values.
+    ///
+    /// * `a` - The first factor.
+    /// * `b` - The second factor.
+    fn mul_wide(a: u128, b: u128) -> (u128, u128) {
+        const MASK: u128 = u64::MAX as u128;
+        let (a_high, a_low) = (a >> 64, a & MASK);
+        let (b_high, b_low) = (b >> 64, b & MASK);
+        let low_low = a_low * b_low;
+        let low_high = a_low * b_high;
+        let high_low = a_high * b_low;
+        let high_high = a_high * b_high;
+        // Add the middle terms, keeping track of the carries.
+        let middle = (low_low >> 64) + (low_high & MASK) + (high_low & MASK);
+        let low = (middle << 64) | (low_low & MASK);
+        let high = high_high + (low_high >> 64) + (high_low >> 64) + (middle >> 64);
+        (high, low)
+    }
+
+    /// The function returns the product of the given values modulo the prime.
+    ///
+    /// * `a` - The first factor.
+    /// * `b` - The second factor.
+    pub fn mul(a: u128, b: u128) -> u128 {
+        let (mut high, mut low) = Self::mul_wide(a, b);
+        // Since 2^128 is congruent to 159, high * 2^128 + low is congruent to high * 159 + low.
+        // The high part shrinks by at least 120 bits in every iteration until it is at most 1,
+        // so it is guaranteed to be zero after four iterations.
+        for _ in 0..4 {
+            let (product_high, product_low) = Self::mul_wide(high, MODULUS_128_OFFSET);
+            let (sum, overflow) = low.overflowing_add(product_low);
+            low = sum;
+            high = product_high + overflow as u128;
+        }
+        let (difference, borrow) = low.overflowing_sub(MODULUS_128);
+        Self::select(borrow, low, difference)
+    }
+
+    /// The function returns the inverse of the given non-zero value modulo the prime.
+    ///
+    /// The inverse is computed as `a^(p-2)` using Fermat's little theorem.
+    ///
+    /// * `a` - The value that is to be inverted.
+    pub fn inverse(a: u128) -> u128 {
+        let mut exponent = MODULUS_128 - 2;
+        let mut base = a;
+        let mut result = 1;
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = Self::mul(result, base);
+            }
+            base = Self::mul(base, base);
+            exponent >>= 1;
+        }
+        result
+    }
 }
 
-#[derive(Debug, Clone, Eq)]
+#[derive(Clone, Eq)]
 /// The struct holds a finite field element.
-pub(crate) struct FiniteFieldElement {
-    /// The value in the form of a big unsigned integer.
-    pub value: BigUint,
-    /// The modulus in the form of a big unsigned integer.
-    pub modulus: BigUint,
+///
+/// The modulus is one of the prime moduli defined in the
+/// [secret_sharing](crate::secret_sharing) module.
+pub struct FiniteFieldElement {
+    /// The value in the form of little-endian 32-bit limbs.
+    value: Limbs,
+    /// The modulus, which is shared by all elements of the same finite field.
+    pub(crate) modulus: &'static Modulus,
+}
+
+impl Drop for FiniteFieldElement {
+    /// The value is wiped from memory when the finite field element is dropped.
+    fn drop(&mut self) {
+        self.value.zeroize();
+    }
 }
 
 impl FiniteFieldElement {
     /// The function creates a finite field element.
     ///
+    /// The bytes are interpreted as an integer in little-endian format. All bytes are used,
+    /// i.e., the number of bytes need not be a multiple of four, but the number of bytes must
+    /// not exceed the number of bytes of the modulus.
+    ///
     /// * `bytes` - The bytes that define the value of the finite field element.
     /// * `modulus` - The modulus.
-    pub fn new(bytes: &[u8], modulus: &BigUint) -> Self {
-        let mut integers: Vec<u32> = vec![0; bytes.len() >> 2];
-        // Since a big unsigned integer is composed of 32-bit integers, the provided bytes
-        // are converted into an array of 32-bit integers first.
-        for index in 0..(bytes.len() >> 2) {
-            integers[index] = (bytes[4 * index] as u32)
-                + ((bytes[4 * index + 1] as u32) << 8)
-                + ((bytes[4 * index + 2] as u32) << 16)
-                + ((bytes[4 * index + 3] as u32) << 24)
-        }
-        // Return the finite field element.
-        FiniteFieldElement {
-            value: BigUint::from_slice(&integers),
-            modulus: modulus.clone(),
-        }
+    pub(crate) fn new(bytes: &[u8], modulus: &'static Modulus) -> Self {
+        assert!(
+            bytes.len() <= modulus.num_limbs << 2,
+            "The number of bytes exceeds the size of the modulus."
+        );
+        let mut value = [0; MAX_NUM_LIMBS];
+        set_limbs_from_bytes(&mut value, bytes);
+        FiniteFieldElement { value, modulus }
     }
 
     /// The function creates a random finite field element.
     ///
     /// * `num_bits` - The number of random bits used to construct the finite field element.
     /// * `modulus` - The modulus.
-    pub fn new_random(num_bits: usize, modulus: &BigUint) -> Self {
+    #[cfg(test)]
+    pub(crate) fn new_random(num_bits: usize, modulus: &'static Modulus) -> Self {
         FiniteFieldElement {
             value: get_random_number(num_bits, modulus),
-            modulus: modulus.clone(),
+            modulus,
+        }
+    }
+
+    /// The function creates a random finite field element using the given random number
+    /// generator.
+    ///
+    /// * `num_bits` - The number of random bits used to construct the finite field element.
+    /// * `modulus` - The modulus.
+    /// * `rng` - The cryptographically secure random number generator.
+    pub(crate) fn new_random_with_rng<R: RngCore + CryptoRng>(
+        num_bits: usize,
+        modulus: &'static Modulus,
+        rng: &mut R,
+    ) -> Self {
+        FiniteFieldElement {
+            value: get_random_number_with_rng(num_bits, modulus, rng),
+            modulus,
+        }
+    }
+
+    /// The function creates a deterministic finite field element for reproducible test vectors.
+    ///
+    /// * `seed` - The seed.
+    /// * `label` - The label that distinguishes the elements derived from the same seed.
+    /// * `num_bits` - The number of bits used to construct the finite field element.
+    /// * `modulus` - The modulus.
+    pub(crate) fn new_deterministic(
+        seed: &[u8],
+        label: &str,
+        num_bits: usize,
+        modulus: &'static Modulus,
+    ) -> Self {
+        FiniteFieldElement {
+            value: get_deterministic_number(seed, label, num_bits, modulus),
+            modulus,
+        }
+    }
+
+    /// The function creates a finite field element that is derived from the given secret and
+    /// salt.
+    ///
+    /// * `secret` - The secret from which the element is derived.
+    /// * `salt` - The salt.
+    /// * `label` - The label that distinguishes the elements derived from the same secret and
+    ///   salt.
+    /// * `num_bits` - The number of bits used to construct the finite field element.
+    /// * `modulus` - The modulus.
+    pub(crate) fn new_derived(
+        secret: &FiniteFieldElement,
+        salt: &[u8],
+        label: &str,
+        num_bits: usize,
+        modulus: &'static Modulus,
+    ) -> Self {
+        FiniteFieldElement {
+            value: get_derived_number(
+                &Zeroizing::new(secret.get_bytes()),
+                salt,
+                label,
+                num_bits,
+                modulus,
+            ),
+            modulus,
         }
     }
 
@@ -106,21 +726,136 @@ impl FiniteFieldElement {
     ///
     /// * `number` - The 32-bit number.
     /// * `modulus` - The modulus.
-    pub fn new_integer(number: u32, modulus: &BigUint) -> Self {
+    pub(crate) fn new_integer(number: u32, modulus: &'static Modulus) -> Self {
+        let mut value = [0; MAX_NUM_LIMBS];
+        value[0] = number;
         FiniteFieldElement {
-            value: BigUint::from_slice(&[number]),
-            modulus: modulus.clone(),
+            value: modulus.reduce(&value),
+            modulus,
+        }
+    }
+
+    /// The function returns the value as a `u128` value if the modulus is
+    /// [MODULUS_128](crate::math::MODULUS_128).
+    pub(crate) fn get_value_128(&self) -> Option<u128> {
+        if self.modulus.num_limbs == 4 && self.modulus.offset as u128 == MODULUS_128_OFFSET {
+            Some(
+                self.value[..4]
+                    .iter()
+                    .rev()
+                    .fold(0, |value, limb| (value << 32) | *limb as u128),
+            )
+        } else {
+            None
         }
     }
 
+    /// The function creates a finite field element with modulus
+    /// [MODULUS_128](crate::math::MODULUS_128) from the given `u128` value.
+    ///
+    /// * `value` - The value.
+    pub(crate) fn new_128(value: u128) -> Self {
+        let modulus = get_field_modulus_for_bits(128).expect("The 128-bit modulus must exist.");
+        FiniteFieldElement::new(&value.to_le_bytes(), modulus)
+    }
+
+    /// The function creates a finite field element from the given bytes.
+    ///
+    /// The bytes are interpreted as an integer in little-endian format. The number of bytes
+    /// determines the modulus, see
+    /// [get_modulus_for_bits](crate::secret_sharing::get_modulus_for_bits), and the integer must
+    /// be smaller than the modulus.
+    ///
+    /// * `bytes` - The bytes that define the value of the finite field element.
+    pub fn from_bytes(bytes: &[u8]) -> HarpoResult<Self> {
+        let modulus = get_field_modulus_for_bits(bytes.len() << 3).ok_or_else(|| {
+            HarpoError::InvalidParameter(format!(
+                "There is no finite field for elements with {} bytes.",
+                bytes.len()
+            ))
+        })?;
+        let element = FiniteFieldElement::new(bytes, modulus);
+        if modulus.reduce(&element.value) != element.value {
+            return Err(HarpoError::InvalidParameter(
+                "The bytes exceed the modulus of the finite field.".to_string(),
+            ));
+        }
+        Ok(element)
+    }
+
+    /// The function returns the product of the finite field element and the given 32-bit
+    /// integer.
+    ///
+    /// The function is faster than a multiplication with the corresponding finite field element,
+    /// which makes it suitable for evaluating polynomials at the indices of secret shares.
+    ///
+    /// * `number` - The 32-bit integer.
+    pub(crate) fn mul_integer(&self, number: u32) -> Self {
+        let modulus = self.modulus;
+        let value = Zeroizing::new(modulus.reduce(&self.value));
+        FiniteFieldElement {
+            value: modulus.mul_integer(&value, number),
+            modulus,
+        }
+    }
+
+    /// The function returns the number of bits of the modulus.
+    pub fn get_num_bits(&self) -> usize {
+        self.modulus.get_num_bits()
+    }
+
     /// The function returns the bytes corresponding to the finite field element.
+    ///
+    /// The bytes are returned in little-endian format and the number of bytes is determined by
+    /// the modulus.
     pub fn get_bytes(&self) -> Vec<u8> {
-        // The length of the array is given by the number of bits needed to represent the modulus.
-        let mut bytes: Vec<u8> = vec![0; (self.modulus.bits() >> 3) as usize];
-        // Get the bytes in little-endian format.
-        let value_bytes = self.value.to_bytes_le();
-        bytes[..value_bytes.len()].clone_from_slice(&value_bytes[..]);
-        bytes
+        self.value[..self.modulus.num_limbs]
+            .iter()
+            .flat_map(|limb| limb.to_le_bytes())
+            .collect()
+    }
+
+    /// The function returns true if the finite field element is zero.
+    pub(crate) fn is_zero(&self) -> bool {
+        *self == FiniteFieldElement::new_integer(0, self.modulus)
+    }
+
+    /// The function returns the value in the form of a big unsigned integer.
+    pub(crate) fn to_big_uint(&self) -> BigUint {
+        BigUint::from_slice(&self.value[..self.modulus.num_limbs])
+    }
+
+    /// The function applies the given constant-time operation to the values of this and the
+    /// other finite field element.
+    ///
+    /// The modulus of this element defines the modulus of the operation.
+    ///
+    /// * `other` - The other finite field element.
+    /// * `operation` - The operation on the limbs of the values.
+    fn apply(&self, other: &Self, operation: impl Fn(&Modulus, &Limbs, &Limbs) -> Limbs) -> Self {
+        // The values are reduced first because elements created from bytes need not be reduced.
+        let modulus = self.modulus;
+        let value = Zeroizing::new(modulus.reduce(&self.value));
+        let other_value = Zeroizing::new(modulus.reduce(&other.value));
+        FiniteFieldElement {
+            value: operation(modulus, &value, &other_value),
+            modulus,
+        }
+    }
+}
+
+impl fmt::Debug for FiniteFieldElement {
+    /// The value of the finite field element is not revealed in debug output.
+    /// The value can be obtained explicitly using
+    /// [get_bytes](crate::secret_sharing::FiniteFieldElement::get_bytes).
+    ///
+    /// * `formatter` - The formatter.
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "FiniteFieldElement({} bits)",
+            self.get_num_bits()
+        )
     }
 }
 
@@ -138,16 +873,25 @@ impl Ord for FiniteFieldElement {
     ///
     /// `other`- The other finite field element.
     fn cmp(&self, other: &Self) -> Ordering {
-        self.value.cmp(&other.value)
+        self.value.iter().rev().cmp(other.value.iter().rev())
     }
 }
 
 impl PartialEq for FiniteFieldElement {
     /// The function defines equality for finite field elements.
     ///
+    /// The values are compared in constant time.
+    ///
     /// `other`- The other finite field element.
     fn eq(&self, other: &Self) -> bool {
-        self.value == other.value
+        let difference = self
+            .value
+            .iter()
+            .zip(&other.value)
+            .fold(0, |difference, (limb, other_limb)| {
+                difference | (limb ^ other_limb)
+            });
+        std::hint::black_box(difference) == 0
     }
 }
 
@@ -158,10 +902,7 @@ impl Add for FiniteFieldElement {
     ///
     /// `other`- The other finite field element.
     fn add(self, other: Self) -> Self {
-        Self {
-            value: (self.value + other.value).mod_floor(&self.modulus),
-            modulus: self.modulus.clone(),
-        }
+        self.apply(&other, Modulus::add)
     }
 }
 
@@ -172,15 +913,7 @@ impl Sub for FiniteFieldElement {
     ///
     /// `other`- The other finite field element.
     fn sub(self, other: FiniteFieldElement) -> Self {
-        let value = if self.value > other.value {
-            self.value - other.value
-        } else {
-            self.value + self.modulus.clone() - other.value
-        };
-        Self {
-            value,
-            modulus: self.modulus,
-        }
+        self.apply(&other, Modulus::sub)
     }
 }
 
@@ -191,10 +924,7 @@ impl Mul for FiniteFieldElement {
     ///
     /// `other`- The other finite field element.
     fn mul(self, other: Self) -> Self {
-        Self {
-            value: (self.value * other.value).mod_floor(&self.modulus),
-            modulus: self.modulus.clone(),
-        }
+        self.apply(&other, Modulus::mul)
     }
 }
 
@@ -207,14 +937,13 @@ impl Div for FiniteFieldElement {
     ///
     /// `other`- The other finite field element.
     fn div(self, other: Self) -> Self {
-        // Get the modular inverse of the other element's value.
-        // The modulus of "self" is used because the first term defines the modulus of the
-        // operation.
-        let inverse_value = modular_inverse(&other.value, &self.modulus);
-        Self {
-            value: (self.value * inverse_value).mod_floor(&self.modulus),
-            modulus: self.modulus.clone(),
-        }
+        // The inverse of the other element's value is multiplied with this element's value.
+        self.apply(&other, |modulus, first, second| {
+            let mut inverse = modulus.inverse(second);
+            let product = modulus.mul(first, &inverse);
+            inverse.zeroize();
+            product
+        })
     }
 }
 
@@ -223,97 +952,241 @@ impl Div for FiniteFieldElement {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::secret_sharing::MODULUS_ARRAY_256;
+    use num_traits::{One, Zero};
+    use rand::Rng;
 
     // Every random test is repeated this many times.
     const NUM_TEST_RUNS: u32 = 100;
 
     #[test]
-    /// The function generates random inputs for the mod_inverse() function and verifies
+    /// The function generates random inputs for the inverse() function and verifies
     /// that the product with the inverse always yields 1.
     fn test_modular_inverse() {
-        let modulus = BigUint::from_slice(&MODULUS_ARRAY_256);
+        let modulus = get_field_modulus_for_bits(256).unwrap();
+        let mut one = [0; MAX_NUM_LIMBS];
+        one[0] = 1;
         for _i in 0..NUM_TEST_RUNS {
-            let num = get_random_number(256, &modulus);
-            let inverse = modular_inverse(&num, &modulus);
-            assert_eq!((num * inverse).mod_floor(&modulus), One::one());
+            let num = get_random_number(256, modulus);
+            let inverse = modulus.inverse(&num);
+            assert_eq!(modulus.mul(&num, &inverse), one);
         }
     }
 
     #[test]
     /// The function tests the addition operation over finite field elements.
     fn test_finite_field_addition() {
-        let modulus = BigUint::from_slice(&MODULUS_ARRAY_256);
+        let modulus = get_field_modulus_for_bits(256).unwrap();
+        let big_modulus = modulus.to_big_uint();
         for _i in 0..NUM_TEST_RUNS {
-            let element_1 = FiniteFieldElement::new_random(256, &modulus);
-            let element_2 = FiniteFieldElement::new_random(256, &modulus);
-            let mut sum = element_1.value.clone() + element_2.value.clone();
-            if sum >= modulus {
-                sum -= modulus.clone();
+            let element_1 = FiniteFieldElement::new_random(256, modulus);
+            let element_2 = FiniteFieldElement::new_random(256, modulus);
+            let mut sum = element_1.to_big_uint() + element_2.to_big_uint();
+            if sum >= big_modulus {
+                sum -= big_modulus.clone();
             }
-            assert_eq!((element_1 + element_2).value, sum);
+            assert_eq!((element_1 + element_2).to_big_uint(), sum);
         }
     }
 
     #[test]
     /// The function tests the subtraction operation over finite field elements.
     fn test_finite_field_subtraction() {
-        let modulus = BigUint::from_slice(&MODULUS_ARRAY_256);
+        let modulus = get_field_modulus_for_bits(256).unwrap();
+        let big_modulus = modulus.to_big_uint();
         for _i in 0..NUM_TEST_RUNS {
-            let element_1 = FiniteFieldElement::new_random(256, &modulus);
-            let element_2 = FiniteFieldElement::new_random(256, &modulus);
+            let element_1 = FiniteFieldElement::new_random(256, modulus);
+            let element_2 = FiniteFieldElement::new_random(256, modulus);
             let difference = if element_1 >= element_2 {
-                element_1.value.clone() - element_2.value.clone()
+                element_1.to_big_uint() - element_2.to_big_uint()
             } else {
-                element_1.value.clone() + modulus.clone() - element_2.value.clone()
+                element_1.to_big_uint() + big_modulus.clone() - element_2.to_big_uint()
             };
-            assert_eq!((element_1 - element_2).value, difference);
+            assert_eq!((element_1 - element_2).to_big_uint(), difference);
         }
+        // Subtracting an element from itself must result in zero.
+        let element = FiniteFieldElement::new_random(256, modulus);
+        assert!((element.clone() - element).is_zero());
     }
 
     #[test]
     /// The function tests the multiplication operation over finite field elements.
     fn test_finite_field_multiplication() {
-        let modulus = BigUint::from_slice(&MODULUS_ARRAY_256);
+        let modulus = get_field_modulus_for_bits(256).unwrap();
+        let big_modulus = modulus.to_big_uint();
         for _i in 0..NUM_TEST_RUNS {
-            let element_1 = FiniteFieldElement::new_random(256, &modulus);
-            let element_2 = FiniteFieldElement::new_random(256, &modulus);
-            let product = element_1.value.clone() * element_2.value.clone();
-            assert_eq!((element_1 * element_2).value, product.mod_floor(&modulus));
+            let element_1 = FiniteFieldElement::new_random(256, modulus);
+            let element_2 = FiniteFieldElement::new_random(256, modulus);
+            let product = element_1.to_big_uint() * element_2.to_big_uint();
+            assert_eq!(
+                (element_1 * element_2).to_big_uint(),
+                product % &big_modulus
+            );
         }
     }
 
     #[test]
     /// The function tests the division operation over finite field elements.
     fn test_finite_field_division() {
-        let modulus = BigUint::from_slice(&MODULUS_ARRAY_256);
+        let modulus = get_field_modulus_for_bits(256).unwrap();
+        let big_modulus = modulus.to_big_uint();
         for _i in 0..NUM_TEST_RUNS {
-            let element_1 = FiniteFieldElement::new_random(256, &modulus);
-            let element_2 = FiniteFieldElement::new_random(256, &modulus);
-            let element_3 = FiniteFieldElement::new_random(256, &modulus);
-            let term = (element_1.value.clone()
-                * element_2.value.clone()
-                * modular_inverse(&element_3.value, &modulus))
-            .mod_floor(&modulus);
+            let element_1 = FiniteFieldElement::new_random(256, modulus);
+            let element_2 = FiniteFieldElement::new_random(256, modulus);
+            let element_3 = FiniteFieldElement::new_random(256, modulus);
+            let inverse = element_3
+                .to_big_uint()
+                .modpow(&(&big_modulus - 2u32), &big_modulus);
+            let term = (element_1.to_big_uint() * element_2.to_big_uint() * inverse) % &big_modulus;
             assert_eq!(
-                (element_1.clone() * element_2.clone() / element_3.clone()).value,
+                (element_1.clone() * element_2.clone() / element_3.clone()).to_big_uint(),
                 term
             );
-            assert_eq!((element_1 / element_3 * element_2).value, term);
+            assert_eq!((element_1 / element_3 * element_2).to_big_uint(), term);
         }
     }
 
+    #[test]
+    /// The function ensures that all bytes are used when creating a finite field element,
+    /// including trailing bytes if the number of bytes is not a multiple of four.
+    fn test_element_from_unaligned_bytes() {
+        let modulus = get_field_modulus_for_bits(256).unwrap();
+        let mut rng = rand::thread_rng();
+        for length in 0..=32 {
+            let bytes: Vec<u8> = (0..length).map(|_| rng.gen::<u8>()).collect();
+            let element = FiniteFieldElement::new(&bytes, modulus);
+            assert_eq!(element.to_big_uint(), BigUint::from_bytes_le(&bytes));
+            // Since the modulus has 256 bits, the bytes must be recovered with zero padding.
+            let mut expected_bytes = bytes.clone();
+            expected_bytes.resize(32, 0);
+            assert_eq!(element.get_bytes(), expected_bytes);
+        }
+        // A regression test for a 5-byte input whose last byte used to be dropped.
+        let element = FiniteFieldElement::new(&[1, 0, 0, 0, 1], modulus);
+        assert_eq!(element.to_big_uint(), BigUint::from(1u64 + (1u64 << 32)));
+    }
+
+    #[test]
+    /// The function tests the native arithmetic modulo 2^128-159 against the generic arithmetic.
+    fn test_field_128_arithmetic() {
+        let modulus = get_field_modulus_for_bits(128).unwrap();
+        assert_eq!(modulus.to_big_uint(), BigUint::from(MODULUS_128));
+        let mut values: Vec<u128> = vec![0, 1, 2, 158, 159, MODULUS_128 - 2, MODULUS_128 - 1];
+        let mut rng = rand::thread_rng();
+        for _i in 0..NUM_TEST_RUNS {
+            values.push(rng.gen_range(0..MODULUS_128));
+        }
+        for a in &values {
+            for b in &values {
+                let element_a = FiniteFieldElement::new_128(*a);
+                let element_b = FiniteFieldElement::new_128(*b);
+                assert_eq!(
+                    Field128::add(*a, *b),
+                    (element_a.clone() + element_b.clone())
+                        .get_value_128()
+                        .unwrap()
+                );
+                assert_eq!(
+                    Field128::sub(*a, *b),
+                    (element_a.clone() - element_b.clone())
+                        .get_value_128()
+                        .unwrap()
+                );
+                assert_eq!(
+                    Field128::mul(*a, *b),
+                    (element_a.clone() * element_b.clone())
+                        .get_value_128()
+                        .unwrap()
+                );
+                if *b != 0 {
+                    assert_eq!(
+                        Field128::mul(*a, Field128::inverse(*b)),
+                        (element_a / element_b).get_value_128().unwrap()
+                    );
+                }
+            }
+        }
+        // Elements with a different modulus have no u128 value.
+        let element = FiniteFieldElement::new_integer(1, get_field_modulus_for_bits(256).unwrap());
+        assert_eq!(element.get_value_128(), None);
+    }
+
     #[test]
     /// The function ensures that the finite field element is always encoded using
     /// the correct number of bytes.
     fn test_correct_byte_length() {
-        let modulus = BigUint::from_slice(&MODULUS_ARRAY_256);
+        let modulus = get_field_modulus_for_bits(256).unwrap();
         let mut rng = rand::thread_rng();
         for _i in 0..NUM_TEST_RUNS {
             let length = rng.gen_range(10..256);
-            let element = FiniteFieldElement::new_random(length, &modulus);
+            let element = FiniteFieldElement::new_random(length, modulus);
             // Since a 256-bit modulus is used, 256/8 = 32 bytes should always be used.
             assert_eq!(element.get_bytes().len(), 256 >> 3);
         }
     }
+
+    #[test]
+    /// The function tests the constant-time limb arithmetic against the generic arithmetic for
+    /// all supported moduli, including the edge cases 0, 1, and the largest values.
+    fn test_limb_arithmetic() {
+        for modulus in
+            (1..=MAX_NUM_LIMBS).filter_map(|num_limbs| get_field_modulus_for_bits(32 * num_limbs))
+        {
+            let big_modulus = modulus.to_big_uint();
+            let get_limbs = |value: &BigUint| {
+                let mut limbs = [0; MAX_NUM_LIMBS];
+                for (limb, digit) in limbs.iter_mut().zip(value.iter_u32_digits()) {
+                    *limb = digit;
+                }
+                limbs
+            };
+            let get_value = |limbs: &Limbs| BigUint::from_slice(limbs);
+            let mut values: Vec<BigUint> = vec![
+                BigUint::zero(),
+                BigUint::one(),
+                BigUint::from(modulus.offset),
+                &big_modulus - 2u32,
+                &big_modulus - 1u32,
+            ];
+            for _i in 0..10 {
+                values.push(get_value(&get_random_number(
+                    modulus.get_num_bits(),
+                    modulus,
+                )));
+            }
+            for a in &values {
+                for b in &values {
+                    let sum = modulus.add(&get_limbs(a), &get_limbs(b));
+                    assert_eq!(get_value(&sum), (a + b) % &big_modulus);
+                    let difference = modulus.sub(&get_limbs(a), &get_limbs(b));
+                    assert_eq!(
+                        get_value(&difference),
+                        (a + &big_modulus - b) % &big_modulus
+                    );
+                    let product = modulus.mul(&get_limbs(a), &get_limbs(b));
+                    assert_eq!(get_value(&product), (a * b) % &big_modulus);
+                }
+                for factor in [0, 1, 2, modulus.offset, u32::MAX] {
+                    let product = modulus.mul_integer(&get_limbs(a), factor);
+                    assert_eq!(get_value(&product), (a * factor) % &big_modulus);
+                }
+                let inverse = modulus.inverse(&get_limbs(a));
+                assert_eq!(
+                    get_value(&inverse),
+                    a.modpow(&(&big_modulus - 2u32), &big_modulus)
+                );
+            }
+            // Values between the modulus and 2^(32n) are reduced.
+            let value = get_limbs(&(&big_modulus + 1u32));
+            assert_eq!(get_value(&modulus.reduce(&value)), BigUint::one());
+            // Random numbers with up to twice the number of bits are reduced.
+            let mut rng = rand::thread_rng();
+            let wide_value: Vec<u32> = (0..2 * modulus.num_limbs).map(|_| rng.gen()).collect();
+            let mut wide_limbs = [0; 2 * MAX_NUM_LIMBS];
+            wide_limbs[..wide_value.len()].copy_from_slice(&wide_value);
+            assert_eq!(
+                get_value(&modulus.reduce_wide(&wide_limbs)),
+                BigUint::from_slice(&wide_value) % &big_modulus
+            );
+        }
+    }
 }
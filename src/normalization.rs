@@ -0,0 +1,50 @@
+//! The `normalization` module provides the NFKD normalization of words.
+//!
+//! BIP-0039 requires that words, seed phrases, and passphrases are compared and hashed after NFKD
+//! normalization. The normalization is delegated to the `unicode-normalization` crate, which
+//! covers the complete Unicode Character Database, including the canonical reordering of
+//! combining marks.
+//!
+
+use std::borrow::Cow;
+use unicode_normalization::{is_nfkd_quick, IsNormalized, UnicodeNormalization};
+
+/// The function returns the NFKD normalization of the given input.
+///
+/// The input is returned unchanged if it is already normalized, which is always the case for
+/// ASCII input.
+///
+/// * `input` - The input.
+pub(crate) fn normalize(input: &str) -> Cow<'_, str> {
+    if input.is_ascii() || is_nfkd_quick(input.chars()) == IsNormalized::Yes {
+        return Cow::Borrowed(input);
+    }
+    Cow::Owned(input.nfkd().collect())
+}
+
+// ******************************** TESTS ********************************
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// The function tests the NFKD normalization of composed and decomposed characters.
+    fn test_normalize() {
+        assert!(matches!(normalize("abandon"), Cow::Borrowed(_)));
+        assert_eq!(normalize("\u{e9}l\u{e8}ve"), "e\u{301}le\u{300}ve");
+        assert!(matches!(normalize("e\u{301}le\u{300}ve"), Cow::Borrowed(_)));
+        assert_eq!(normalize("\u{f1}and\u{fa}"), "n\u{303}andu\u{301}");
+        // Hangul syllables are decomposed into jamo.
+        assert_eq!(
+            normalize("\u{ac00}\u{d7a3}"),
+            "\u{1100}\u{1161}\u{1112}\u{1175}\u{11c2}"
+        );
+        // Kana with voiced sound marks and full-width letters are decomposed as well.
+        assert_eq!(normalize("\u{304c}"), "\u{304b}\u{3099}");
+        assert_eq!(normalize("\u{ff41}\u{ff42}"), "ab");
+        // Combining marks are reordered canonically, i.e., by their combining class.
+        assert_eq!(normalize("a\u{301}\u{316}"), "a\u{316}\u{301}");
+        assert_eq!(normalize("\u{e1}\u{316}"), "a\u{316}\u{301}");
+    }
+}
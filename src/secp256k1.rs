@@ -0,0 +1,147 @@
+//! The `secp256k1` module provides the elliptic curve operations on the secp256k1 curve that are
+//! required to work with Bitcoin and Ethereum keys.
+//!
+//! The operations are implemented by the [k256](https://docs.rs/k256) crate, which is
+//! constant-time and wipes secret keys from memory when they are dropped.
+//!
+
+use k256::elliptic_curve::sec1::ToEncodedPoint;
+use k256::elliptic_curve::PrimeField;
+use k256::{Scalar, SecretKey};
+
+/// The number of bytes of a private key and of each coordinate of a public key.
+pub(crate) const KEY_LENGTH: usize = 32;
+
+/// The function returns true if the given big-endian bytes encode a valid private key, i.e., a
+/// non-zero number less than the group order.
+///
+/// * `private_key` - The private key.
+pub(crate) fn is_valid_private_key(private_key: &[u8]) -> bool {
+    private_key.len() == KEY_LENGTH && SecretKey::from_slice(private_key).is_ok()
+}
+
+/// The function returns the uncompressed public key for the given private key.
+///
+/// The public key is returned as the big-endian x- and y-coordinates of the point obtained by
+/// multiplying the generator with the private key. The private key must be valid, see
+/// [is_valid_private_key](crate::secp256k1::is_valid_private_key).
+///
+/// * `private_key` - The private key in big-endian format.
+pub(crate) fn get_public_key(
+    private_key: &[u8; KEY_LENGTH],
+) -> ([u8; KEY_LENGTH], [u8; KEY_LENGTH]) {
+    // The unwrap() call is okay because the private key must be valid.
+    let secret_key = SecretKey::from_slice(private_key).unwrap();
+    let point = secret_key.public_key().to_encoded_point(false);
+    let mut x = [0; KEY_LENGTH];
+    let mut y = [0; KEY_LENGTH];
+    // The unwrap() calls are okay because an uncompressed point has both coordinates.
+    x.copy_from_slice(point.x().unwrap());
+    y.copy_from_slice(point.y().unwrap());
+    (x, y)
+}
+
+/// The function adds the given tweak to the given private key modulo the group order.
+///
+/// The function returns `None` if the tweak is not less than the group order or if the sum is
+/// zero, in which case BIP-0032 requires to skip the derivation index.
+///
+/// * `private_key` - The private key in big-endian format, which must be valid.
+/// * `tweak` - The tweak in big-endian format.
+pub(crate) fn add_tweak(
+    private_key: &[u8; KEY_LENGTH],
+    tweak: &[u8; KEY_LENGTH],
+) -> Option<[u8; KEY_LENGTH]> {
+    let key = Option::<Scalar>::from(Scalar::from_repr((*private_key).into()))?;
+    let tweak = Option::<Scalar>::from(Scalar::from_repr((*tweak).into()))?;
+    let sum = key + tweak;
+    if bool::from(sum.is_zero()) {
+        return None;
+    }
+    Some(sum.to_bytes().into())
+}
+
+// ******************************** TESTS ********************************
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The order of the secp256k1 group in big-endian format.
+    const GROUP_ORDER: [u8; KEY_LENGTH] = [
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xfe, 0xba, 0xae, 0xdc, 0xe6, 0xaf, 0x48, 0xa0, 0x3b, 0xbf, 0xd2, 0x5e, 0x8c, 0xd0, 0x36,
+        0x41, 0x41,
+    ];
+
+    /// The function returns the private key for the given small number.
+    ///
+    /// * `number` - The number.
+    fn get_small_key(number: u8) -> [u8; KEY_LENGTH] {
+        let mut key = [0; KEY_LENGTH];
+        key[KEY_LENGTH - 1] = number;
+        key
+    }
+
+    /// The function encodes the given bytes as an upper-case hex string.
+    ///
+    /// * `bytes` - The bytes.
+    fn encode_hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|byte| format!("{:02X}", byte)).collect()
+    }
+
+    #[test]
+    /// The function tests the public key derivation for small private keys.
+    fn test_public_key() {
+        let (x, y) = get_public_key(&get_small_key(1));
+        assert_eq!(
+            encode_hex(&x),
+            "79BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798"
+        );
+        assert_eq!(
+            encode_hex(&y),
+            "483ADA7726A3C4655DA4FBFC0E1108A8FD17B448A68554199C47D08FFB10D4B8"
+        );
+        // The x-coordinate of 2G.
+        let (x, _) = get_public_key(&get_small_key(2));
+        assert_eq!(
+            encode_hex(&x),
+            "C6047F9441ED7D6D3045406E95C07CD85C778E4B8CEF3CA7ABAC09B95C709EE5"
+        );
+        // The point (n-1)G is the negation of G.
+        let mut order_minus_one = GROUP_ORDER;
+        order_minus_one[KEY_LENGTH - 1] -= 1;
+        let (x, y) = get_public_key(&order_minus_one);
+        assert_eq!(
+            encode_hex(&x),
+            "79BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798"
+        );
+        assert_eq!(
+            encode_hex(&y),
+            "B7C52588D95C3B9AA25B0403F1EEF75702E84BB7597AABE663B82F6F04EF2777"
+        );
+        assert!(!is_valid_private_key(&[0; KEY_LENGTH]));
+        assert!(!is_valid_private_key(&GROUP_ORDER));
+        assert!(!is_valid_private_key(&[1]));
+    }
+
+    #[test]
+    /// The function tests the addition of tweaks to private keys.
+    fn test_add_tweak() {
+        assert_eq!(
+            add_tweak(&get_small_key(1), &get_small_key(2)),
+            Some(get_small_key(3))
+        );
+        let mut order_minus_one = GROUP_ORDER;
+        order_minus_one[KEY_LENGTH - 1] -= 1;
+        // The sum wraps around the group order.
+        assert_eq!(
+            add_tweak(&get_small_key(2), &order_minus_one),
+            Some(get_small_key(1))
+        );
+        // The sum must not be zero.
+        assert_eq!(add_tweak(&get_small_key(1), &order_minus_one), None);
+        // The tweak must be less than the group order.
+        assert_eq!(add_tweak(&get_small_key(1), &GROUP_ORDER), None);
+    }
+}
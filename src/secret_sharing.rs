@@ -5,10 +5,42 @@
 //! For each supported bit length, the used modulus is defined as the largest prime number with the
 //! given number of bits.
 //! These prime numbers can be found here: <https://primes.utm.edu/lists/2small/200bit.html>
+//! Prime moduli for additional security levels of up to 512 bits can be registered at runtime
+//! using [register_modulus].
+//!
+//! The module can be used to secret-share values that are not seed phrases: A
+//! [FiniteFieldElement] is created from the secret bytes, a [SecretPolynomial] embedding the
+//! secret yields the [SecretShare]s, and [reconstruct_secret] recovers the secret from
+//! sufficiently many secret shares.
+
+pub use crate::math::FiniteFieldElement;
 
-use crate::math::FiniteFieldElement;
+use crate::math::{is_probable_prime, Field128, Modulus, MAX_NUM_LIMBS};
+use crate::{HarpoError, HarpoResult};
 use num_bigint::BigUint;
+use rand::{rngs::OsRng, CryptoRng, RngCore};
+use sha2::{Digest, Sha256};
+use std::collections::{HashMap, HashSet};
 use std::fmt;
+use std::sync::Mutex;
+
+/// The prime number
+/// 2^32-5 = 4294967291
+/// is used as the modulus for 32-bit demo inputs.
+#[cfg(feature = "insecure-demo")]
+pub const MODULUS_ARRAY_32: [u32; 1] = [u32::MAX - 4];
+
+/// The prime number
+/// 2^64-59 = 18446744073709551557
+/// is used as the modulus for 64-bit demo inputs.
+#[cfg(feature = "insecure-demo")]
+pub const MODULUS_ARRAY_64: [u32; 2] = [u32::MAX - 58, u32::MAX];
+
+/// The prime number
+/// 2^96-17 = 79228162514264337593543950319
+/// is used as the modulus for 96-bit demo inputs.
+#[cfg(feature = "insecure-demo")]
+pub const MODULUS_ARRAY_96: [u32; 3] = [u32::MAX - 16, u32::MAX, u32::MAX];
 
 /// The prime number
 /// 2^128-159 = 340282366920938463463374607431768211297
@@ -61,47 +93,169 @@ pub const MODULUS_ARRAY_256: [u32; 8] = [
 
 /// The function returns the modulus for the given security level.
 ///
-/// * `num_bits`: The security level (128, 160, 192, 224, or 256).
-pub(crate) fn get_modulus_for_bits(num_bits: usize) -> Option<BigUint> {
-    match num_bits {
-        128 => Some(BigUint::from_slice(&MODULUS_ARRAY_128)),
-        160 => Some(BigUint::from_slice(&MODULUS_ARRAY_160)),
-        192 => Some(BigUint::from_slice(&MODULUS_ARRAY_192)),
-        224 => Some(BigUint::from_slice(&MODULUS_ARRAY_224)),
-        256 => Some(BigUint::from_slice(&MODULUS_ARRAY_256)),
-        _ => None,
+/// If the `insecure-demo` feature is enabled, the demo levels 32, 64, and 96 are supported as well.
+/// Moduli for additional security levels can be added using [register_modulus].
+///
+/// * `num_bits`: The security level (128, 160, 192, 224, 256, or a registered level).
+pub fn get_modulus_for_bits(num_bits: usize) -> Option<BigUint> {
+    get_field_modulus_for_bits(num_bits).map(|modulus| modulus.to_big_uint())
+}
+
+/// The fixed-width moduli, which are computed at compile time and shared by all finite field
+/// elements.
+#[cfg(feature = "insecure-demo")]
+static FIELD_MODULUS_32: Modulus = Modulus::new(&MODULUS_ARRAY_32);
+#[cfg(feature = "insecure-demo")]
+static FIELD_MODULUS_64: Modulus = Modulus::new(&MODULUS_ARRAY_64);
+#[cfg(feature = "insecure-demo")]
+static FIELD_MODULUS_96: Modulus = Modulus::new(&MODULUS_ARRAY_96);
+static FIELD_MODULUS_128: Modulus = Modulus::new(&MODULUS_ARRAY_128);
+static FIELD_MODULUS_160: Modulus = Modulus::new(&MODULUS_ARRAY_160);
+static FIELD_MODULUS_192: Modulus = Modulus::new(&MODULUS_ARRAY_192);
+static FIELD_MODULUS_224: Modulus = Modulus::new(&MODULUS_ARRAY_224);
+static FIELD_MODULUS_256: Modulus = Modulus::new(&MODULUS_ARRAY_256);
+
+/// The built-in moduli, which cannot be replaced.
+static BUILT_IN_FIELD_MODULI: &[&Modulus] = &[
+    #[cfg(feature = "insecure-demo")]
+    &FIELD_MODULUS_32,
+    #[cfg(feature = "insecure-demo")]
+    &FIELD_MODULUS_64,
+    #[cfg(feature = "insecure-demo")]
+    &FIELD_MODULUS_96,
+    &FIELD_MODULUS_128,
+    &FIELD_MODULUS_160,
+    &FIELD_MODULUS_192,
+    &FIELD_MODULUS_224,
+    &FIELD_MODULUS_256,
+];
+
+/// The moduli registered at runtime, indexed by their number of bits.
+///
+/// Registered moduli are never removed, which allows finite field elements to hold a static
+/// reference to their modulus.
+static REGISTERED_FIELD_MODULI: Mutex<Option<HashMap<usize, &'static Modulus>>> = Mutex::new(None);
+
+/// The function registers the prime modulus `2^num_bits - offset` for the given security level.
+///
+/// Once registered, the modulus is used for all finite field elements with `num_bits` bits,
+/// e.g., by [FiniteFieldElement::from_bytes] and [SecretPolynomial::from_secret], which allows
+/// larger secrets to be secret-shared. The number of bits must be a multiple of 32 and at most
+/// 512, the offset must be smaller than `2^32`, and the modulus must be prime.
+/// The built-in moduli cannot be replaced, and a security level can only be registered once.
+/// Registering the same modulus again succeeds.
+///
+/// * `num_bits` - The security level in bits.
+/// * `offset` - The difference between `2^num_bits` and the modulus.
+pub fn register_modulus(num_bits: usize, offset: u32) -> HarpoResult<()> {
+    if num_bits == 0 || !num_bits.is_multiple_of(32) || num_bits > MAX_NUM_LIMBS * 32 {
+        return Err(HarpoError::InvalidParameter(format!(
+            "The number of bits must be a positive multiple of 32 of at most {}.",
+            MAX_NUM_LIMBS * 32
+        )));
     }
+    if offset == 0 {
+        return Err(HarpoError::InvalidParameter(
+            "The offset must be positive.".to_string(),
+        ));
+    }
+    let mut limbs = vec![u32::MAX; num_bits / 32];
+    limbs[0] = offset.wrapping_neg();
+    let modulus = Modulus::new(&limbs);
+    if let Some(existing_modulus) = get_field_modulus_for_bits(num_bits) {
+        if *existing_modulus == modulus {
+            return Ok(());
+        }
+        return Err(HarpoError::InvalidParameter(format!(
+            "A different modulus is already defined for {} bits.",
+            num_bits
+        )));
+    }
+    if !is_probable_prime(&modulus.to_big_uint()) {
+        return Err(HarpoError::InvalidParameter(
+            "The modulus must be prime.".to_string(),
+        ));
+    }
+    let mut registry = REGISTERED_FIELD_MODULI
+        .lock()
+        .unwrap_or_else(|error| error.into_inner());
+    let registry = registry.get_or_insert_with(HashMap::new);
+    // The modulus may have been registered concurrently.
+    let registered_modulus = *registry
+        .entry(num_bits)
+        .or_insert_with(|| Box::leak(Box::new(modulus)));
+    if *registered_modulus != modulus {
+        return Err(HarpoError::InvalidParameter(format!(
+            "A different modulus is already defined for {} bits.",
+            num_bits
+        )));
+    }
+    Ok(())
+}
+
+/// The function returns the shared fixed-width modulus for the given security level, which is
+/// used for the finite field arithmetic.
+///
+/// The built-in moduli take precedence over the registered moduli.
+///
+/// * `num_bits`: The security level (128, 160, 192, 224, 256, or a registered level).
+pub(crate) fn get_field_modulus_for_bits(num_bits: usize) -> Option<&'static Modulus> {
+    if let Some(modulus) = BUILT_IN_FIELD_MODULI
+        .iter()
+        .find(|modulus| modulus.get_num_bits() == num_bits)
+    {
+        return Some(*modulus);
+    }
+    // A poisoned lock is recovered because registered moduli are never modified.
+    REGISTERED_FIELD_MODULI
+        .lock()
+        .unwrap_or_else(|error| error.into_inner())
+        .as_ref()
+        .and_then(|registry| registry.get(&num_bits).copied())
 }
 
 /// The function returns the modulus for the given number of words.
 ///
 /// The number of words correlates with the security level, starting with 12 words
 /// for 128-bit security up to 24 words for 256-bit security.
+/// If the `insecure-demo` feature is enabled, 3, 6, and 9 words are supported as well.
+///
 /// * `num_words`: The number of words (12, 15, 18, 21, or 24).
-pub(crate) fn get_modulus_for_words(num_words: usize) -> Option<BigUint> {
+pub(crate) fn get_modulus_for_words(num_words: usize) -> Option<&'static Modulus> {
     match num_words {
-        12 => Some(BigUint::from_slice(&MODULUS_ARRAY_128)),
-        15 => Some(BigUint::from_slice(&MODULUS_ARRAY_160)),
-        18 => Some(BigUint::from_slice(&MODULUS_ARRAY_192)),
-        21 => Some(BigUint::from_slice(&MODULUS_ARRAY_224)),
-        24 => Some(BigUint::from_slice(&MODULUS_ARRAY_256)),
+        #[cfg(feature = "insecure-demo")]
+        3 | 6 | 9 => get_field_modulus_for_bits(num_words / 3 * 32),
+        12 | 15 | 18 | 21 | 24 => get_field_modulus_for_bits(num_words / 3 * 32),
         _ => None,
     }
 }
 
+/// The domain separator used when computing polynomial commitments.
+const COMMITMENT_DOMAIN_SEPARATOR: &[u8] = b"harpo polynomial commitment";
+
+/// The domain separator used when deriving the identifier of a set of secret shares.
+const SET_ID_DOMAIN_SEPARATOR: &[u8] = b"harpo share set id";
+
+/// The domain separator used when computing commitments to secret shares.
+const SHARE_COMMITMENT_DOMAIN_SEPARATOR: &[u8] = b"harpo share commitment";
+
+/// The maximum number of subsets of secret shares that are interpolated when identifying
+/// inconsistent secret shares.
+const MAX_CONSISTENCY_SUBSETS: usize = 10_000;
+
 /// The struct used to represent polynomials encapsulating a secret.
-pub(crate) struct SecretPolynomial {
+pub struct SecretPolynomial {
     /// The vector of coefficients.
     coefficients: Vec<FiniteFieldElement>,
 }
 
 /// The struct used to represent a secret share.
-pub(crate) struct SecretShare {
+pub struct SecretShare {
     /// The index of the secret share.
-    pub index: u32,
+    pub(crate) index: u32,
     /// The value of the secret share, which is obtained by evaluating the underlying
     /// polynomial at `index`.
-    pub element: FiniteFieldElement,
+    pub(crate) element: FiniteFieldElement,
 }
 
 impl SecretShare {
@@ -115,6 +269,28 @@ impl SecretShare {
             element: element.clone(),
         }
     }
+
+    /// The function returns the index of the secret share.
+    pub fn get_index(&self) -> u32 {
+        self.index
+    }
+
+    /// The function returns the value of the secret share.
+    pub fn get_element(&self) -> &FiniteFieldElement {
+        &self.element
+    }
+
+    /// The function returns the hash commitment of the secret share.
+    ///
+    /// The commitment is the SHA-256 hash of a domain separator, the index, and the bytes of
+    /// the value. Since the value is uniformly random, the commitment does not reveal it.
+    pub(crate) fn get_commitment(&self) -> [u8; 32] {
+        let mut hasher = Sha256::new();
+        hasher.update(SHARE_COMMITMENT_DOMAIN_SEPARATOR);
+        hasher.update(self.index.to_be_bytes());
+        hasher.update(self.element.get_bytes());
+        hasher.finalize().into()
+    }
 }
 
 impl Clone for SecretShare {
@@ -127,6 +303,21 @@ impl Clone for SecretShare {
     }
 }
 
+impl fmt::Debug for SecretShare {
+    /// The value of the secret share is not revealed in debug output.
+    /// The value can be obtained explicitly using [get_element](SecretShare::get_element).
+    ///
+    /// * `formatter` - The formatter.
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "SecretShare(index {}, {} bits)",
+            self.index,
+            self.element.get_num_bits()
+        )
+    }
+}
+
 impl fmt::Display for SecretShare {
     /// The function defines how a secret share is printed.
     ///
@@ -135,7 +326,56 @@ impl fmt::Display for SecretShare {
     ///
     /// * `formatter` - The formatter.
     fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(formatter, "[{}, {}]", self.index, self.element.value)
+        write!(
+            formatter,
+            "[{}, {}]",
+            self.index,
+            self.element.to_big_uint()
+        )
+    }
+}
+
+/// The struct used to represent the Lagrange-weighted contribution of a secret share to the
+/// secret.
+///
+/// A partial is exactly as sensitive as the secret share itself. Its value is therefore not
+/// revealed in debug output and can only be obtained explicitly using
+/// [to_bytes](LagrangePartial::to_bytes).
+#[derive(Clone, PartialEq, Eq)]
+pub struct LagrangePartial {
+    /// The Lagrange-weighted value of the secret share.
+    element: FiniteFieldElement,
+}
+
+impl LagrangePartial {
+    /// The function creates a partial from the given bytes.
+    ///
+    /// The bytes must have been obtained using [to_bytes](LagrangePartial::to_bytes).
+    ///
+    /// * `bytes` - The bytes in little-endian format.
+    pub fn from_bytes(bytes: &[u8]) -> HarpoResult<Self> {
+        Ok(LagrangePartial {
+            element: FiniteFieldElement::from_bytes(bytes)?,
+        })
+    }
+
+    /// The function returns the bytes of the partial in little-endian format.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.element.get_bytes()
+    }
+
+    /// The function returns the number of bits of the finite field of the partial.
+    pub fn get_num_bits(&self) -> usize {
+        self.element.get_num_bits()
+    }
+}
+
+impl fmt::Debug for LagrangePartial {
+    /// The value of the partial is not revealed in debug output.
+    ///
+    /// * `formatter` - The formatter.
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "LagrangePartial({} bits)", self.get_num_bits())
     }
 }
 
@@ -150,11 +390,32 @@ impl SecretPolynomial {
     /// * `num_bits` - The security level in bits.
     ///  * `degree` - The degree of the constructed polynomial.
     pub(crate) fn new(secret: &FiniteFieldElement, num_bits: usize, degree: usize) -> Option<Self> {
-        match get_modulus_for_bits(num_bits) {
+        SecretPolynomial::new_with_rng(secret, num_bits, degree, &mut OsRng)
+    }
+
+    /// The function creates a random polynomial that embeds the provided secret using the given
+    /// random number generator.
+    ///
+    /// The function behaves like [SecretPolynomial::new] but draws the coefficients from the
+    /// given random number generator.
+    ///
+    /// * `secret` - The secret embedded in the polynomial.
+    /// * `num_bits` - The security level in bits.
+    /// * `degree` - The degree of the constructed polynomial.
+    /// * `rng` - The cryptographically secure random number generator.
+    pub(crate) fn new_with_rng<R: RngCore + CryptoRng>(
+        secret: &FiniteFieldElement,
+        num_bits: usize,
+        degree: usize,
+        rng: &mut R,
+    ) -> Option<Self> {
+        match get_field_modulus_for_bits(num_bits) {
             Some(modulus) => {
                 let mut coefficients = vec![secret.clone()];
                 for _in in 1..=degree {
-                    coefficients.push(FiniteFieldElement::new_random(num_bits, &modulus));
+                    coefficients.push(FiniteFieldElement::new_random_with_rng(
+                        num_bits, modulus, rng,
+                    ));
                 }
                 Some(SecretPolynomial { coefficients })
             }
@@ -162,35 +423,206 @@ impl SecretPolynomial {
         }
     }
 
+    /// The function creates a random polynomial of the given degree that embeds the provided
+    /// secret.
+    ///
+    /// The secret is the constant coefficient and all other coefficients are chosen uniformly
+    /// at random from the finite field of the secret. At least `degree + 1` secret shares are
+    /// required to reconstruct the secret.
+    ///
+    /// * `secret` - The secret embedded in the polynomial.
+    /// * `degree` - The degree of the constructed polynomial.
+    pub fn from_secret(secret: &FiniteFieldElement, degree: usize) -> HarpoResult<Self> {
+        let num_bits = secret.get_num_bits();
+        if get_field_modulus_for_bits(num_bits).as_ref() != Some(&secret.modulus) {
+            return Err(HarpoError::InvalidParameter(
+                "The secret is not defined over a supported finite field.".to_string(),
+            ));
+        }
+        SecretPolynomial::new(secret, num_bits, degree).ok_or_else(|| {
+            HarpoError::InvalidParameter(
+                "Could not instantiate the required secret polynomial.".to_string(),
+            )
+        })
+    }
+
+    /// The function creates a polynomial whose coefficients are derived from the provided secret
+    /// and salt.
+    ///
+    /// The coefficient at position `i > 0` is derived with the label `coefficient-i-of-d`, where
+    /// `d` is the degree, so that the same secret, salt, and degree always result in the same
+    /// polynomial.
+    /// A polynomial is only returned if there is a modulus defined for the given number of bits.
+    ///
+    /// * `secret` - The secret embedded in the polynomial.
+    /// * `num_bits` - The security level in bits.
+    /// * `degree` - The degree of the constructed polynomial.
+    /// * `salt` - The salt.
+    pub(crate) fn new_derived(
+        secret: &FiniteFieldElement,
+        num_bits: usize,
+        degree: usize,
+        salt: &[u8],
+    ) -> Option<Self> {
+        let modulus = get_field_modulus_for_bits(num_bits)?;
+        let mut coefficients = vec![secret.clone()];
+        for position in 1..=degree {
+            coefficients.push(FiniteFieldElement::new_derived(
+                secret,
+                salt,
+                &format!("coefficient-{}-of-{}", position, degree),
+                num_bits,
+                modulus,
+            ));
+        }
+        Some(SecretPolynomial { coefficients })
+    }
+
+    /// The function creates a polynomial with deterministic coefficients that embeds the
+    /// provided secret.
+    ///
+    /// The coefficient at position `i > 0` is derived from the seed with the label
+    /// `coefficient-i`. The polynomial must only be used for reproducible test vectors, never to
+    /// protect real secrets.
+    /// A polynomial is only returned if there is a modulus defined for the given number of bits.
+    ///
+    /// * `secret` - The secret embedded in the polynomial.
+    /// * `num_bits` - The security level in bits.
+    /// * `degree` - The degree of the constructed polynomial.
+    /// * `seed` - The seed from which the coefficients are derived.
+    pub(crate) fn new_deterministic(
+        secret: &FiniteFieldElement,
+        num_bits: usize,
+        degree: usize,
+        seed: &[u8],
+    ) -> Option<Self> {
+        let modulus = get_field_modulus_for_bits(num_bits)?;
+        let mut coefficients = vec![secret.clone()];
+        for position in 1..=degree {
+            coefficients.push(FiniteFieldElement::new_deterministic(
+                seed,
+                &format!("coefficient-{}", position),
+                num_bits,
+                modulus,
+            ));
+        }
+        Some(SecretPolynomial { coefficients })
+    }
+
+    /// The function creates a polynomial of the given degree with the same secret and fresh
+    /// random coefficients.
+    ///
+    /// * `degree` - The degree of the new polynomial.
+    pub(crate) fn refresh(&self, degree: usize) -> HarpoResult<Self> {
+        let secret = &self.coefficients[0];
+        SecretPolynomial::new(secret, secret.get_num_bits(), degree).ok_or_else(|| {
+            HarpoError::InvalidParameter(
+                "Could not instantiate the required secret polynomial.".to_string(),
+            )
+        })
+    }
+
+    /// The function returns the coefficients, starting with the constant coefficient.
+    pub(crate) fn get_coefficients(&self) -> &[FiniteFieldElement] {
+        &self.coefficients
+    }
+
+    /// The function creates a polynomial with the given coefficients.
+    ///
+    /// * `coefficients` - The coefficients, starting with the constant coefficient.
+    pub(crate) fn from_coefficients(coefficients: Vec<FiniteFieldElement>) -> Self {
+        SecretPolynomial { coefficients }
+    }
+
     /// The function evaluates the polynomial at the given value.
     ///
     /// * `value` - The value for which the polynomial is evaluated.
     fn evaluate(&self, value: u32) -> FiniteFieldElement {
         let degree = self.coefficients.len() - 1;
         let mut result = self.coefficients[degree].clone();
-        // Convert the value to a finite field element.
-        let finite_field_value = FiniteFieldElement::new_integer(value, &result.modulus);
         // Iterate over the coefficients in reverse order.
         for index in (0..degree).rev() {
-            result = (result * finite_field_value.clone()) + self.coefficients[index].clone();
+            result = result.mul_integer(value) + self.coefficients[index].clone();
         }
         result
     }
 
+    /// The function returns the hash commitment of the polynomial.
+    ///
+    /// The commitment is the SHA-256 hash of a domain separator, the size of the coefficients
+    /// in bytes, and the bytes of all coefficients, starting with the constant coefficient.
+    pub(crate) fn get_commitment(&self) -> [u8; 32] {
+        let mut hasher = Sha256::new();
+        hasher.update(COMMITMENT_DOMAIN_SEPARATOR);
+        let num_bytes = self.coefficients[0].get_bytes().len() as u16;
+        hasher.update(num_bytes.to_be_bytes());
+        for coefficient in &self.coefficients {
+            hasher.update(coefficient.get_bytes());
+        }
+        hasher.finalize().into()
+    }
+
+    /// The function returns the number of secret shares required to interpolate the
+    /// polynomial, which is 1 higher than the degree.
+    pub(crate) fn get_threshold(&self) -> usize {
+        self.coefficients.len()
+    }
+
+    /// The function returns the identifier of the set of secret shares of the polynomial.
+    ///
+    /// The identifier consists of the first two bytes of the hash of a domain separator and the
+    /// commitment of the polynomial. Since the polynomial has random coefficients, secret shares
+    /// of different polynomials have different identifiers with high probability.
+    pub(crate) fn get_set_id(&self) -> u16 {
+        let mut hasher = Sha256::new();
+        hasher.update(SET_ID_DOMAIN_SEPARATOR);
+        hasher.update(self.get_commitment());
+        let hash = hasher.finalize();
+        u16::from_be_bytes([hash[0], hash[1]])
+    }
+
+    /// The function returns the coefficients as `u128` values if the polynomial is defined over
+    /// the 128-bit field.
+    fn get_coefficients_128(&self) -> Option<Vec<u128>> {
+        self.coefficients
+            .iter()
+            .map(|coefficient| coefficient.get_value_128())
+            .collect()
+    }
+
+    /// The function returns the secret shares for the given indices.
+    ///
+    /// * `indices` - The indices of the requested secret shares.
+    pub(crate) fn get_secret_shares_for_indices(&self, indices: &[u32]) -> Vec<SecretShare> {
+        // Use native arithmetic if the polynomial is defined over the 128-bit field.
+        if let Some(coefficients) = self.get_coefficients_128() {
+            return indices
+                .iter()
+                .map(|index| SecretShare {
+                    index: *index,
+                    element: FiniteFieldElement::new_128(evaluate_128(&coefficients, *index)),
+                })
+                .collect();
+        }
+        indices
+            .iter()
+            .map(|index| SecretShare {
+                index: *index,
+                element: self.evaluate(*index),
+            })
+            .collect()
+    }
+
     /// The function returns the requested number of secret shares.
     ///
+    /// The secret shares have the indices `1, 2, ..., number`.
+    ///
     /// * `number` - The number of requested secret shares.
-    pub(crate) fn get_secret_shares(&self, number: u32) -> Vec<SecretShare> {
+    pub fn get_secret_shares(&self, number: u32) -> Vec<SecretShare> {
         // The shares correspond to the polynomial points
         // `f(1), f(2), ..., f(number)`.
-        let mut secret_shares = vec![];
-        for index in 1..=number {
-            secret_shares.push(SecretShare {
-                index,
-                element: self.evaluate(index),
-            });
-        }
-        secret_shares
+        let indices: Vec<u32> = (1..=number).collect();
+        self.get_secret_shares_for_indices(&indices)
     }
 }
 
@@ -199,37 +631,575 @@ impl SecretPolynomial {
 /// The function assumes that the degree of the polynomial is one less than the number of
 /// provided secret shares. If any secret share is wrong or an insufficient number of
 /// secret shares is provided, the function will essentially return a random value.
+/// An error is returned if no secret share is provided, if an index is 0 or occurs more than
+/// once, or if the secret shares are not defined over the same finite field.
+///
+/// * `secret_shares` - The provided secret shares.
+pub fn reconstruct_secret(secret_shares: &[SecretShare]) -> HarpoResult<FiniteFieldElement> {
+    check_secret_shares(secret_shares)?;
+    // Use native arithmetic if the secret shares are defined over the 128-bit field.
+    let values: Option<Vec<u128>> = secret_shares
+        .iter()
+        .map(|share| share.element.get_value_128())
+        .collect();
+    match values {
+        Some(values) => Ok(FiniteFieldElement::new_128(reconstruct_secret_128(
+            secret_shares,
+            &values,
+        ))),
+        None => Ok(reconstruct_secret_generic(secret_shares)),
+    }
+}
+
+/// The function computes the Lagrange-weighted contribution of a secret share to the secret.
+///
+/// The secret is the sum of the partials of all participating secret shares, see
+/// [combine_lagrange_partials](crate::secret_sharing::combine_lagrange_partials).
+/// Note that a partial is exactly as sensitive as the secret share itself: The Lagrange
+/// coefficient only depends on the public indices, so the secret share is recovered by dividing
+/// the partial by it.
+/// An error is returned if an index is 0 or occurs more than once, or if the index of the
+/// secret share is not among the participating indices.
+///
+/// * `secret_share` - The secret share.
+/// * `participating_indices` - The indices of all participating secret shares.
+pub fn compute_lagrange_partial(
+    secret_share: &SecretShare,
+    participating_indices: &[u32],
+) -> HarpoResult<LagrangePartial> {
+    let mut indices = HashSet::new();
+    for index in participating_indices {
+        if *index == 0 || !indices.insert(*index) {
+            return Err(HarpoError::InvalidParameter(format!(
+                "Invalid or duplicate participating index: {}",
+                index
+            )));
+        }
+    }
+    let position = participating_indices
+        .iter()
+        .position(|index| *index == secret_share.index)
+        .ok_or_else(|| {
+            HarpoError::InvalidParameter(format!(
+                "The index {} of the secret share is not among the participating indices.",
+                secret_share.index
+            ))
+        })?;
+    let modulus = &secret_share.element.modulus;
+    let inverse =
+        get_inverse_lagrange_denominators(participating_indices, modulus).swap_remove(position);
+    // The numerator is the product of the terms (0 - index) for all other indices.
+    let zero = FiniteFieldElement::new_integer(0, modulus);
+    let mut numerator = FiniteFieldElement::new_integer(1, modulus);
+    for index in participating_indices
+        .iter()
+        .filter(|index| **index != secret_share.index)
+    {
+        numerator = zero.clone() - numerator.mul_integer(*index);
+    }
+    Ok(LagrangePartial {
+        element: secret_share.element.clone() * numerator * inverse,
+    })
+}
+
+/// The function combines the Lagrange-weighted contributions of the participating secret shares
+/// into the secret.
+///
+/// An error is returned if no partial is provided or if the partials are not defined over the
+/// same finite field.
+///
+/// * `partials` - The partials computed using
+///   [compute_lagrange_partial](crate::secret_sharing::compute_lagrange_partial).
+pub fn combine_lagrange_partials(partials: &[LagrangePartial]) -> HarpoResult<FiniteFieldElement> {
+    let (first_partial, other_partials) = partials.split_first().ok_or_else(|| {
+        HarpoError::InvalidParameter("At least one partial is required.".to_string())
+    })?;
+    if other_partials
+        .iter()
+        .any(|partial| partial.element.modulus != first_partial.element.modulus)
+    {
+        return Err(HarpoError::InvalidParameter(
+            "The partials are not defined over the same finite field.".to_string(),
+        ));
+    }
+    Ok(other_partials
+        .iter()
+        .fold(first_partial.element.clone(), |secret, partial| {
+            secret + partial.element.clone()
+        }))
+}
+
+/// The function checks that the given secret shares can be used for a reconstruction.
+///
+/// * `secret_shares` - The provided secret shares.
+fn check_secret_shares(secret_shares: &[SecretShare]) -> HarpoResult<()> {
+    let first_share = secret_shares.first().ok_or_else(|| {
+        HarpoError::InvalidParameter("At least one secret share is required.".to_string())
+    })?;
+    let mut indices = HashSet::new();
+    for secret_share in secret_shares {
+        if secret_share.index == 0 || !indices.insert(secret_share.index) {
+            return Err(HarpoError::InvalidParameter(format!(
+                "Invalid or duplicate index of a secret share: {}",
+                secret_share.index
+            )));
+        }
+        if secret_share.element.modulus != first_share.element.modulus {
+            return Err(HarpoError::InvalidParameter(
+                "The secret shares are not defined over the same finite field.".to_string(),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// The function evaluates the polynomial with the given coefficients at the given value in the
+/// 128-bit field.
+///
+/// * `coefficients` - The coefficients, starting with the constant coefficient.
+/// * `value` - The value for which the polynomial is evaluated.
+fn evaluate_128(coefficients: &[u128], value: u32) -> u128 {
+    let value = value as u128;
+    coefficients.iter().rev().fold(0, |result, coefficient| {
+        Field128::add(Field128::mul(result, value), *coefficient)
+    })
+}
+
+/// The function reconstructs the secret in the 128-bit field.
+///
+/// The function computes the same Lagrange interpolation at 0 as
+/// [reconstruct_secret_generic](crate::secret_sharing::reconstruct_secret_generic) using native
+/// arithmetic. The inverted Lagrange denominators are taken from the cache.
 ///
 /// * `secret_shares` - The provided secret shares.
-pub(crate) fn reconstruct_secret(secret_shares: &[SecretShare]) -> FiniteFieldElement {
+/// * `values` - The values of the secret shares as `u128` values.
+fn reconstruct_secret_128(secret_shares: &[SecretShare], values: &[u128]) -> u128 {
+    let indices: Vec<u32> = secret_shares.iter().map(|share| share.index).collect();
+    let modulus = &secret_shares[0].element.modulus;
+    let inverses = get_inverse_lagrange_denominators(&indices, modulus);
+    let mut secret = 0;
+    for ((secret_share, value), inverse) in secret_shares.iter().zip(values).zip(inverses) {
+        // The numerator is the product of the terms (0 - index) for all other indices.
+        let mut numerator = 1;
+        for index in indices.iter().filter(|index| **index != secret_share.index) {
+            numerator = Field128::mul(numerator, Field128::sub(0, *index as u128));
+        }
+        // The unwrap() call is okay because the inverse is defined over the same field.
+        let inverse = inverse.get_value_128().unwrap();
+        secret = Field128::add(
+            secret,
+            Field128::mul(Field128::mul(*value, numerator), inverse),
+        );
+    }
+    secret
+}
+
+/// The function reconstructs the secret based on the provided secret shares using generic
+/// arithmetic.
+///
+/// * `secret_shares` - The provided secret shares.
+fn reconstruct_secret_generic(secret_shares: &[SecretShare]) -> FiniteFieldElement {
     // Get the modulus from the finite field element of the first share.
     let modulus = &secret_shares[0].element.modulus;
     // Create the list of indices.
     let indices: Vec<u32> = secret_shares.iter().map(|share| share.index).collect();
-    let mut secret = FiniteFieldElement::new_integer(0, modulus);
+    let inverses = get_inverse_lagrange_denominators(&indices, modulus);
+    let zero = FiniteFieldElement::new_integer(0, modulus);
+    let mut secret = zero.clone();
     // Process each share.
-    for secret_share in secret_shares {
-        let term = secret_share.element.clone();
-        let mut multiply_term = FiniteFieldElement::new_integer(1, modulus);
-        let mut divide_term = FiniteFieldElement::new_integer(1, modulus);
-        let other_indices: Vec<u32> = indices
-            .iter()
-            .copied()
-            .filter(|index| *index != secret_share.index)
-            .collect();
-        for index in other_indices {
-            let index_element = FiniteFieldElement::new_integer(index, modulus);
-            let secret_share_index_element =
-                FiniteFieldElement::new_integer(secret_share.index, modulus);
-            multiply_term = multiply_term * index_element.clone();
-            divide_term = divide_term * (index_element - secret_share_index_element.clone());
+    for (secret_share, inverse) in secret_shares.iter().zip(inverses) {
+        // The numerator is the product of the terms (0 - index) for all other indices.
+        let mut numerator = FiniteFieldElement::new_integer(1, modulus);
+        for index in indices.iter().filter(|index| **index != secret_share.index) {
+            numerator = zero.clone() - numerator.mul_integer(*index);
         }
         // Update the secret:
-        secret = secret + (term * multiply_term / divide_term);
+        secret = secret + (secret_share.element.clone() * numerator * inverse);
     }
     secret
 }
 
+/// The maximum number of index sets for which the inverted Lagrange denominators are cached.
+const MAX_CACHED_INDEX_SETS: usize = 64;
+
+/// The type of the cache that maps a modulus and a list of indices to the inverted Lagrange
+/// denominators.
+type LagrangeCache = HashMap<(Modulus, Vec<u32>), Vec<FiniteFieldElement>>;
+
+/// The inverted Lagrange denominators of recently used index sets.
+///
+/// The denominators only depend on the public indices and the modulus. Caching them allows
+/// repeated reconstructions with the same indices, e.g., in audit loops, to skip the inversions.
+static LAGRANGE_CACHE: Mutex<Option<LagrangeCache>> = Mutex::new(None);
+
+/// The function returns the inverted Lagrange denominators for the given indices.
+///
+/// The denominator at position `i` is the product of the terms `indices[i] - indices[j]` for all
+/// other indices. The inverted denominators are cached per modulus and list of indices.
+///
+/// * `indices` - The indices of the secret shares.
+/// * `modulus` - The modulus.
+fn get_inverse_lagrange_denominators(
+    indices: &[u32],
+    modulus: &'static Modulus,
+) -> Vec<FiniteFieldElement> {
+    let key = (*modulus, indices.to_vec());
+    // A poisoned lock is recovered because the cache only holds derived public values.
+    let cached_inverses = LAGRANGE_CACHE
+        .lock()
+        .unwrap_or_else(|error| error.into_inner())
+        .as_ref()
+        .and_then(|cache| cache.get(&key).cloned());
+    if let Some(inverses) = cached_inverses {
+        return inverses;
+    }
+    let inverses = compute_inverse_lagrange_denominators(indices, modulus);
+    let mut cache = LAGRANGE_CACHE
+        .lock()
+        .unwrap_or_else(|error| error.into_inner());
+    let cache = cache.get_or_insert_with(HashMap::new);
+    // The cache is cleared when it is full in order to bound its size.
+    if cache.len() >= MAX_CACHED_INDEX_SETS {
+        cache.clear();
+    }
+    cache.insert(key, inverses.clone());
+    inverses
+}
+
+/// The function computes the inverted Lagrange denominators for the given indices.
+///
+/// All denominators are inverted using a single inversion (Montgomery's trick).
+///
+/// * `indices` - The indices of the secret shares.
+/// * `modulus` - The modulus.
+fn compute_inverse_lagrange_denominators(
+    indices: &[u32],
+    modulus: &'static Modulus,
+) -> Vec<FiniteFieldElement> {
+    let one = FiniteFieldElement::new_integer(1, modulus);
+    let denominators: Vec<FiniteFieldElement> = indices
+        .iter()
+        .map(|index| {
+            let index_element = FiniteFieldElement::new_integer(*index, modulus);
+            indices
+                .iter()
+                .filter(|other_index| *other_index != index)
+                .fold(one.clone(), |product, other_index| {
+                    product
+                        * (index_element.clone()
+                            - FiniteFieldElement::new_integer(*other_index, modulus))
+                })
+        })
+        .collect();
+    // Compute the prefix products and invert the product of all denominators.
+    let mut prefix_products = Vec::with_capacity(denominators.len());
+    let mut product = one.clone();
+    for denominator in &denominators {
+        prefix_products.push(product.clone());
+        product = product * denominator.clone();
+    }
+    // Native arithmetic is used for the inversion in the 128-bit field.
+    let mut inverse = match product.get_value_128() {
+        Some(value) => FiniteFieldElement::new_128(Field128::inverse(value)),
+        None => one / product,
+    };
+    // Extract the inverses in reverse order.
+    let mut inverses = prefix_products;
+    for (position, denominator) in denominators.iter().enumerate().rev() {
+        inverses[position] = inverse.clone() * inverses[position].clone();
+        inverse = inverse * denominator.clone();
+    }
+    inverses
+}
+
+/// The function interpolates the polynomial passing through the given secret shares.
+///
+/// The degree of the returned polynomial is at most one less than the number of provided secret
+/// shares. Leading zero coefficients are removed so that the same polynomial is returned for
+/// any set of at least `degree+1` secret shares of a polynomial of degree `degree`.
+/// An error is returned if no secret shares are provided, if the indices are not distinct and
+/// positive, or if the secret shares are not defined over the same finite field.
+///
+/// * `secret_shares` - The provided secret shares.
+pub(crate) fn interpolate_polynomial(
+    secret_shares: &[SecretShare],
+) -> HarpoResult<SecretPolynomial> {
+    check_secret_shares(secret_shares)?;
+    // Get the modulus from the finite field element of the first share.
+    let modulus = &secret_shares[0].element.modulus;
+    let zero = FiniteFieldElement::new_integer(0, modulus);
+    let mut coefficients = vec![zero.clone(); secret_shares.len()];
+    let indices: Vec<u32> = secret_shares.iter().map(|share| share.index).collect();
+    let inverses = get_inverse_lagrange_denominators(&indices, modulus);
+    // Process each share.
+    for (secret_share, inverse) in secret_shares.iter().zip(inverses) {
+        // The basis polynomial is the product of the terms (x - index) for all other indices.
+        let mut basis = vec![FiniteFieldElement::new_integer(1, modulus)];
+        for other_share in secret_shares {
+            if other_share.index == secret_share.index {
+                continue;
+            }
+            let index_element = FiniteFieldElement::new_integer(other_share.index, modulus);
+            // Multiply the basis polynomial by (x - index).
+            let mut product = vec![zero.clone(); basis.len() + 1];
+            for (position, coefficient) in basis.iter().enumerate() {
+                product[position + 1] = product[position + 1].clone() + coefficient.clone();
+                product[position] =
+                    product[position].clone() - (coefficient.clone() * index_element.clone());
+            }
+            basis = product;
+        }
+        // Add the scaled basis polynomial.
+        let factor = secret_share.element.clone() * inverse;
+        for (position, coefficient) in basis.into_iter().enumerate() {
+            coefficients[position] =
+                coefficients[position].clone() + (coefficient * factor.clone());
+        }
+    }
+    // Remove leading zero coefficients, keeping at least the constant coefficient.
+    while coefficients.len() > 1 && coefficients[coefficients.len() - 1].is_zero() {
+        coefficients.pop();
+    }
+    Ok(SecretPolynomial { coefficients })
+}
+
+/// The function decodes the polynomial of the given threshold from the given secret shares,
+/// tolerating up to `(n - threshold) / 2` corrupted secret shares for `n` secret shares.
+///
+/// Since the secret shares form a Reed-Solomon codeword, the Berlekamp-Welch algorithm is
+/// used: it determines an error-locator polynomial `E` of degree `e = (n - threshold) / 2` and
+/// a polynomial `Q` such that `Q(index) = E(index) * element` for all secret shares, which
+/// yields the polynomial `Q / E`. `None` is returned if there are too many corrupted secret
+/// shares.
+///
+/// * `secret_shares` - The secret shares, which must have distinct indices.
+/// * `threshold` - The threshold.
+pub(crate) fn decode_polynomial(
+    secret_shares: &[SecretShare],
+    threshold: usize,
+) -> HarpoResult<Option<SecretPolynomial>> {
+    check_secret_shares(secret_shares)?;
+    let num_shares = secret_shares.len();
+    if threshold == 0 || num_shares < threshold {
+        return Ok(None);
+    }
+    let modulus = secret_shares[0].element.modulus;
+    let zero = FiniteFieldElement::new_integer(0, modulus);
+    let num_errors = (num_shares - threshold) / 2;
+    // The unknowns are the coefficients of `Q` followed by the non-leading coefficients of the
+    // monic polynomial `E`.
+    let num_unknowns = num_errors + threshold + num_errors;
+    let mut rows: Vec<Vec<FiniteFieldElement>> = secret_shares
+        .iter()
+        .map(|share| {
+            let index = FiniteFieldElement::new_integer(share.index, modulus);
+            let mut powers = vec![FiniteFieldElement::new_integer(1, modulus)];
+            for _ in 1..=(num_errors + threshold) {
+                let power = powers[powers.len() - 1].clone() * index.clone();
+                powers.push(power);
+            }
+            let mut row = powers[..(num_errors + threshold)].to_vec();
+            for power in &powers[..num_errors] {
+                row.push(zero.clone() - share.element.clone() * power.clone());
+            }
+            row.push(share.element.clone() * powers[num_errors].clone());
+            row
+        })
+        .collect();
+    let solution = match solve_linear_system(&mut rows, num_unknowns, &zero) {
+        Some(solution) => solution,
+        None => return Ok(None),
+    };
+    let mut error_locator = solution[(num_errors + threshold)..].to_vec();
+    error_locator.push(FiniteFieldElement::new_integer(1, modulus));
+    let mut remainder = solution[..(num_errors + threshold)].to_vec();
+    // Divide `Q` by `E` using polynomial long division.
+    let mut quotient = vec![zero.clone(); threshold];
+    for position in (0..threshold).rev() {
+        let factor = remainder[position + num_errors].clone();
+        for (offset, coefficient) in error_locator.iter().enumerate() {
+            remainder[position + offset] =
+                remainder[position + offset].clone() - factor.clone() * coefficient.clone();
+        }
+        quotient[position] = factor;
+    }
+    if remainder.iter().any(|coefficient| !coefficient.is_zero()) {
+        return Ok(None);
+    }
+    while quotient.len() > 1 && quotient[quotient.len() - 1].is_zero() {
+        quotient.pop();
+    }
+    let polynomial = SecretPolynomial {
+        coefficients: quotient,
+    };
+    // The polynomial is only unique if it passes through all but at most `num_errors` secret
+    // shares.
+    let indices: Vec<u32> = secret_shares.iter().map(|share| share.index).collect();
+    let num_matches = polynomial
+        .get_secret_shares_for_indices(&indices)
+        .iter()
+        .zip(secret_shares)
+        .filter(|(expected, actual)| expected.element == actual.element)
+        .count();
+    if num_matches + num_errors < num_shares {
+        return Ok(None);
+    }
+    Ok(Some(polynomial))
+}
+
+/// The function solves the given system of linear equations using Gaussian elimination.
+///
+/// Each row holds the coefficients of the unknowns followed by the constant term. Free
+/// unknowns are set to zero. `None` is returned if the system has no solution.
+///
+/// * `rows` - The rows of the augmented matrix, which are modified in place.
+/// * `num_unknowns` - The number of unknowns.
+/// * `zero` - The zero element of the finite field.
+fn solve_linear_system(
+    rows: &mut [Vec<FiniteFieldElement>],
+    num_unknowns: usize,
+    zero: &FiniteFieldElement,
+) -> Option<Vec<FiniteFieldElement>> {
+    let mut pivot_columns = vec![];
+    let mut pivot_row = 0;
+    for column in 0..num_unknowns {
+        let row = match (pivot_row..rows.len()).find(|row| !rows[*row][column].is_zero()) {
+            Some(row) => row,
+            None => continue,
+        };
+        rows.swap(pivot_row, row);
+        let inverse =
+            FiniteFieldElement::new_integer(1, zero.modulus) / rows[pivot_row][column].clone();
+        for entry in rows[pivot_row].iter_mut() {
+            *entry = entry.clone() * inverse.clone();
+        }
+        let pivot_entries = rows[pivot_row].clone();
+        for (other_row, entries) in rows.iter_mut().enumerate() {
+            if other_row == pivot_row || entries[column].is_zero() {
+                continue;
+            }
+            let factor = entries[column].clone();
+            for (entry, pivot_entry) in entries.iter_mut().zip(&pivot_entries).skip(column) {
+                *entry = entry.clone() - factor.clone() * pivot_entry.clone();
+            }
+        }
+        pivot_columns.push(column);
+        pivot_row += 1;
+    }
+    // The system has no solution if a row without pivot has a non-zero constant term.
+    if rows[pivot_row..]
+        .iter()
+        .any(|row| !row[num_unknowns].is_zero())
+    {
+        return None;
+    }
+    let mut solution = vec![zero.clone(); num_unknowns];
+    for (row, column) in pivot_columns.into_iter().enumerate() {
+        solution[column] = rows[row][num_unknowns].clone();
+    }
+    Some(solution)
+}
+
+/// The function identifies the secret shares that do not lie on the polynomial of the given
+/// threshold that passes through the most secret shares.
+///
+/// The polynomial is decoded using [decode_polynomial] first. If there are too many corrupted
+/// secret shares for the decoding, the function interpolates subsets of `threshold` secret
+/// shares and counts the secret shares that lie on each resulting polynomial. The positions of
+/// the remaining secret shares are returned if a single polynomial passes through more than
+/// `threshold` secret shares and through more secret shares than any other polynomial.
+/// Otherwise, or if there are too many subsets to try, `None` is returned.
+///
+/// * `secret_shares` - The secret shares, which must have distinct indices.
+/// * `threshold` - The threshold.
+pub(crate) fn find_inconsistent_shares(
+    secret_shares: &[SecretShare],
+    threshold: usize,
+) -> HarpoResult<Option<Vec<usize>>> {
+    check_secret_shares(secret_shares)?;
+    let num_shares = secret_shares.len();
+    if threshold == 0 || num_shares <= threshold {
+        return Ok(None);
+    }
+    let indices: Vec<u32> = secret_shares.iter().map(|share| share.index).collect();
+    if let Some(polynomial) = decode_polynomial(secret_shares, threshold)? {
+        return Ok(Some(
+            polynomial
+                .get_secret_shares_for_indices(&indices)
+                .iter()
+                .zip(secret_shares)
+                .enumerate()
+                .filter(|(_, (expected, actual))| expected.element != actual.element)
+                .map(|(position, _)| position)
+                .collect(),
+        ));
+    }
+    // Two different polynomials of degree `threshold - 1` agree on at most `threshold - 1`
+    // secret shares, so a polynomial passing through at least `unique_count` secret shares is
+    // the only one with that many secret shares.
+    let unique_count = (num_shares + threshold - 1) / 2 + 1;
+    let mut best_positions: Vec<usize> = vec![];
+    let mut is_ambiguous = false;
+    let mut is_decided = false;
+    let mut subset: Vec<usize> = (0..threshold).collect();
+    for _ in 0..MAX_CONSISTENCY_SUBSETS {
+        let subset_shares: Vec<SecretShare> = subset
+            .iter()
+            .map(|position| secret_shares[*position].clone())
+            .collect();
+        let polynomial = interpolate_polynomial(&subset_shares)?;
+        let positions: Vec<usize> = polynomial
+            .get_secret_shares_for_indices(&indices)
+            .iter()
+            .zip(secret_shares)
+            .enumerate()
+            .filter(|(_, (expected, actual))| expected.element == actual.element)
+            .map(|(position, _)| position)
+            .collect();
+        if positions.len() > best_positions.len() {
+            best_positions = positions;
+            is_ambiguous = false;
+        } else if positions.len() == best_positions.len() && positions != best_positions {
+            is_ambiguous = true;
+        }
+        if best_positions.len() >= unique_count {
+            is_decided = true;
+            break;
+        }
+        if !advance_subset(&mut subset, num_shares) {
+            is_decided = true;
+            break;
+        }
+    }
+    if !is_decided || is_ambiguous || best_positions.len() <= threshold {
+        return Ok(None);
+    }
+    Ok(Some(
+        (0..num_shares)
+            .filter(|position| !best_positions.contains(position))
+            .collect(),
+    ))
+}
+
+/// The function advances the given subset of positions to the next subset in lexicographic
+/// order and returns false if there is no next subset.
+///
+/// * `subset` - The sorted positions of the subset.
+/// * `num_elements` - The number of elements from which the subset is chosen.
+fn advance_subset(subset: &mut [usize], num_elements: usize) -> bool {
+    let size = subset.len();
+    for position in (0..size).rev() {
+        if subset[position] < num_elements - size + position {
+            subset[position] += 1;
+            for next_position in (position + 1)..size {
+                subset[next_position] = subset[next_position - 1] + 1;
+            }
+            return true;
+        }
+    }
+    false
+}
+
 // ******************************** TESTS ********************************
 
 #[cfg(test)]
@@ -244,17 +1214,17 @@ mod tests {
     /// The function tests the evaluation of a random secret embedded in a secret polynomial
     /// at value 0 (extracting the secret) and 1 (returning the sum of the coefficients).
     fn test_polynomial_evaluation() {
-        let modulus = get_modulus_for_bits(128).unwrap();
+        let modulus = get_field_modulus_for_bits(128).unwrap();
         let mut rng = rand::thread_rng();
         for _test in 0..NUM_TEST_RUNS {
-            let secret = FiniteFieldElement::new_random(128, &modulus);
+            let secret = FiniteFieldElement::new_random(128, modulus);
             let degree = rng.gen_range(2..20);
             let polynomial = SecretPolynomial::new(&secret, 128, degree).unwrap();
             // Evaluate the secret polynomial at 0.
             assert_eq!(polynomial.evaluate(0), secret);
             // Evaluate the secret polynomial at 1 (which should be the sum of coefficients).
             let mut coefficient_sum: FiniteFieldElement =
-                FiniteFieldElement::new_integer(0, &modulus);
+                FiniteFieldElement::new_integer(0, modulus);
             for coefficient in &polynomial.coefficients {
                 coefficient_sum = coefficient_sum + coefficient.clone();
             }
@@ -267,7 +1237,8 @@ mod tests {
     fn test_working_secret_reconstruction() {
         let mut rng = rand::thread_rng();
         for _test in 0..NUM_TEST_RUNS {
-            let secret = FiniteFieldElement::new_random(256, &get_modulus_for_bits(256).unwrap());
+            let secret =
+                FiniteFieldElement::new_random(256, get_field_modulus_for_bits(256).unwrap());
             let degree = rng.gen_range(2..20);
             let polynomial = SecretPolynomial::new(&secret, 256, degree).unwrap();
             // Construct a large number of shares.
@@ -279,17 +1250,118 @@ mod tests {
                 .cloned()
                 .collect();
             // Reconstruct the secret.
-            let reconstructed_secret = reconstruct_secret(&random_shares);
+            let reconstructed_secret = reconstruct_secret(&random_shares).unwrap();
             // Assert that the secret was reconstructed correctly.
             assert_eq!(secret, reconstructed_secret);
         }
     }
 
+    #[test]
+    /// The function tests that the native 128-bit arithmetic yields the same secret shares and
+    /// reconstructed secrets as the generic arithmetic.
+    fn test_field_128_fast_path() {
+        let modulus = get_field_modulus_for_bits(128).unwrap();
+        let mut rng = rand::thread_rng();
+        for _test in 0..NUM_TEST_RUNS {
+            let secret = FiniteFieldElement::new_random(128, modulus);
+            let degree = rng.gen_range(0..20);
+            let polynomial = SecretPolynomial::new(&secret, 128, degree).unwrap();
+            let shares = polynomial.get_secret_shares((degree * 2 + 1) as u32);
+            for share in &shares {
+                assert_eq!(share.element, polynomial.evaluate(share.index));
+            }
+            let random_shares: Vec<SecretShare> = shares
+                .choose_multiple(&mut rng, degree + 1)
+                .cloned()
+                .collect();
+            let values: Vec<u128> = random_shares
+                .iter()
+                .map(|share| share.element.get_value_128().unwrap())
+                .collect();
+            let reconstructed_secret = reconstruct_secret_128(&random_shares, &values);
+            assert_eq!(
+                FiniteFieldElement::new_128(reconstructed_secret),
+                reconstruct_secret_generic(&random_shares)
+            );
+            assert_eq!(reconstruct_secret(&random_shares).unwrap(), secret);
+        }
+    }
+
+    #[test]
+    /// The function tests that the cached inverted Lagrange denominators are correct and that
+    /// repeated reconstructions with the same indices yield the same secret.
+    fn test_cached_lagrange_denominators() {
+        let modulus = get_field_modulus_for_bits(256).unwrap();
+        let indices = [3, 1, 7, 4];
+        let inverses = get_inverse_lagrange_denominators(&indices, modulus);
+        assert_eq!(
+            inverses,
+            get_inverse_lagrange_denominators(&indices, modulus)
+        );
+        let one = FiniteFieldElement::new_integer(1, modulus);
+        for (index, inverse) in indices.iter().zip(&inverses) {
+            let mut denominator = one.clone();
+            for other_index in indices.iter().filter(|other_index| *other_index != index) {
+                denominator = denominator
+                    * (FiniteFieldElement::new_integer(*index, modulus)
+                        - FiniteFieldElement::new_integer(*other_index, modulus));
+            }
+            assert_eq!(denominator * inverse.clone(), one);
+        }
+        for num_bits in [128, 256] {
+            let modulus = get_field_modulus_for_bits(num_bits).unwrap();
+            let secret = FiniteFieldElement::new_random(num_bits, modulus);
+            let polynomial = SecretPolynomial::new(&secret, num_bits, 3).unwrap();
+            let shares = polynomial.get_secret_shares_for_indices(&indices);
+            for _test in 0..NUM_TEST_RUNS {
+                assert_eq!(reconstruct_secret(&shares).unwrap(), secret);
+            }
+        }
+    }
+
+    #[test]
+    /// The function tests that the interpolated polynomial matches the original polynomial
+    /// if sufficiently many secret shares are used.
+    fn test_polynomial_interpolation() {
+        let modulus = get_field_modulus_for_bits(128).unwrap();
+        let mut rng = rand::thread_rng();
+        for _test in 0..NUM_TEST_RUNS {
+            let secret = FiniteFieldElement::new_random(128, modulus);
+            let degree = rng.gen_range(0..10);
+            let polynomial = SecretPolynomial::new(&secret, 128, degree).unwrap();
+            let shares = polynomial.get_secret_shares((degree * 2 + 1) as u32);
+            // Any set of at least `degree+1` shares yields the same polynomial.
+            let num_shares = rng.gen_range(degree + 1..=degree * 2 + 1);
+            let random_shares: Vec<SecretShare> = shares
+                .choose_multiple(&mut rng, num_shares)
+                .cloned()
+                .collect();
+            let interpolated_polynomial = interpolate_polynomial(&random_shares).unwrap();
+            assert_eq!(
+                interpolated_polynomial.coefficients,
+                polynomial.coefficients
+            );
+            assert_eq!(
+                interpolated_polynomial.get_commitment(),
+                polynomial.get_commitment()
+            );
+            // Fewer shares result in a different polynomial.
+            if degree > 0 {
+                let interpolated_polynomial =
+                    interpolate_polynomial(&random_shares[..degree]).unwrap();
+                assert_ne!(
+                    interpolated_polynomial.get_commitment(),
+                    polynomial.get_commitment()
+                );
+            }
+        }
+    }
+
     #[test]
     /// The function ensures that secret cannot be reconstructed when fewer than `degree+1`
     // shares are combined.
     fn test_failing_secret_reconstruction() {
-        let modulus = &get_modulus_for_bits(256).unwrap();
+        let modulus = &get_field_modulus_for_bits(256).unwrap();
         let mut rng = rand::thread_rng();
         for _test in 0..NUM_TEST_RUNS {
             let secret = FiniteFieldElement::new_random(256, modulus);
@@ -304,9 +1376,221 @@ mod tests {
                 .cloned()
                 .collect();
             // Attempt to reconstruct the secret.
-            let reconstructed_secret = reconstruct_secret(&random_shares);
+            let reconstructed_secret = reconstruct_secret(&random_shares).unwrap();
             // Assert that the secret was not reconstructed.
             assert_ne!(secret, reconstructed_secret);
         }
     }
+
+    #[test]
+    /// The function tests the public secret-sharing API for secrets that are not seed phrases.
+    fn test_public_secret_sharing() {
+        let mut rng = rand::thread_rng();
+        for num_bytes in [16, 20, 24, 28, 32] {
+            let mut bytes: Vec<u8> = (0..num_bytes).map(|_| rng.gen()).collect();
+            // Ensure that the value is smaller than the modulus.
+            bytes[num_bytes - 1] = 0;
+            let secret = FiniteFieldElement::from_bytes(&bytes).expect("The bytes are valid.");
+            assert_eq!(secret.get_num_bits(), num_bytes << 3);
+            let polynomial =
+                SecretPolynomial::from_secret(&secret, 2).expect("The secret is valid.");
+            let shares = polynomial.get_secret_shares(5);
+            assert_eq!(shares[4].get_index(), 5);
+            // The values are not revealed in debug output.
+            assert_eq!(
+                format!("{:?}", shares[4]),
+                format!("SecretShare(index 5, {} bits)", num_bytes << 3)
+            );
+            assert_eq!(
+                format!("{:?}", secret),
+                format!("FiniteFieldElement({} bits)", num_bytes << 3)
+            );
+            let reconstructed =
+                reconstruct_secret(&shares[2..5]).expect("The reconstruction should work.");
+            assert_eq!(reconstructed.get_bytes(), bytes);
+            // Duplicate indices and missing shares are rejected.
+            let duplicates = [shares[0].clone(), shares[1].clone(), shares[0].clone()];
+            assert!(reconstruct_secret(&duplicates).is_err());
+            assert!(reconstruct_secret(&[]).is_err());
+            assert!(interpolate_polynomial(&duplicates).is_err());
+            assert!(interpolate_polynomial(&[]).is_err());
+            assert!(reconstruct_secret(&[SecretShare::new(shares[0].get_element(), 0)]).is_err());
+        }
+        // The bytes must correspond to a supported field and be smaller than the modulus.
+        assert!(FiniteFieldElement::from_bytes(&[0; 17]).is_err());
+        assert!(FiniteFieldElement::from_bytes(&[0xff; 16]).is_err());
+        // Secret shares over different fields cannot be combined.
+        let first = FiniteFieldElement::from_bytes(&[1; 16]).unwrap();
+        let second = FiniteFieldElement::from_bytes(&[1; 20]).unwrap();
+        let shares = [SecretShare::new(&first, 1), SecretShare::new(&second, 2)];
+        assert!(reconstruct_secret(&shares).is_err());
+        assert!(interpolate_polynomial(&shares).is_err());
+    }
+
+    #[test]
+    /// The function tests the reconstruction of the secret from Lagrange-weighted partials.
+    fn test_lagrange_partials() {
+        for num_bits in [128, 256] {
+            let modulus = get_field_modulus_for_bits(num_bits).unwrap();
+            let secret = FiniteFieldElement::new_random(num_bits, modulus);
+            let polynomial = SecretPolynomial::new(&secret, num_bits, 2).unwrap();
+            let shares = polynomial.get_secret_shares(5);
+            let participating_indices = [2, 4, 5];
+            let partials: Vec<LagrangePartial> = participating_indices
+                .iter()
+                .map(|index| {
+                    compute_lagrange_partial(&shares[*index as usize - 1], &participating_indices)
+                        .expect("The computation should work.")
+                })
+                .collect();
+            assert_eq!(
+                combine_lagrange_partials(&partials).expect("The combination should work."),
+                secret
+            );
+            // The partials must be combined completely.
+            assert_ne!(combine_lagrange_partials(&partials[..2]).unwrap(), secret);
+            // Invalid participating indices are rejected.
+            assert!(compute_lagrange_partial(&shares[0], &participating_indices).is_err());
+            assert!(compute_lagrange_partial(&shares[1], &[2, 4, 4]).is_err());
+            assert!(compute_lagrange_partial(&shares[1], &[0, 2, 4]).is_err());
+        }
+        assert!(combine_lagrange_partials(&[]).is_err());
+        let partials = [
+            LagrangePartial::from_bytes(&[1; 16]).unwrap(),
+            LagrangePartial::from_bytes(&[1; 20]).unwrap(),
+        ];
+        assert!(combine_lagrange_partials(&partials).is_err());
+    }
+
+    #[test]
+    /// The function tests the registration of moduli for additional security levels.
+    fn test_register_modulus() {
+        for prime in [2u32, 3, 5, 7, 4294967291] {
+            assert!(is_probable_prime(&BigUint::from(prime)));
+        }
+        for composite in [0u32, 1, 4, 9, 561, 4294967295] {
+            assert!(!is_probable_prime(&BigUint::from(composite)));
+        }
+        // The largest primes with 384 and 512 bits are 2^384-317 and 2^512-569.
+        assert!(get_modulus_for_bits(384).is_none());
+        assert!(register_modulus(384, 317).is_ok());
+        assert!(register_modulus(512, 569).is_ok());
+        assert!(register_modulus(512, 569).is_ok());
+        assert_eq!(
+            get_modulus_for_bits(384),
+            Some((BigUint::from(1u32) << 384) - 317u32)
+        );
+        let mut rng = rand::thread_rng();
+        for num_bytes in [48, 64] {
+            let mut bytes: Vec<u8> = (0..num_bytes).map(|_| rng.gen()).collect();
+            bytes[num_bytes - 1] = 0;
+            let secret = FiniteFieldElement::from_bytes(&bytes).expect("The bytes are valid.");
+            assert_eq!(secret.get_num_bits(), num_bytes << 3);
+            let polynomial =
+                SecretPolynomial::from_secret(&secret, 3).expect("The secret is valid.");
+            let mut shares = polynomial.get_secret_shares(6);
+            shares.shuffle(&mut rng);
+            let reconstructed =
+                reconstruct_secret(&shares[..4]).expect("The reconstruction should work.");
+            assert_eq!(reconstructed.get_bytes(), bytes);
+            assert_ne!(reconstruct_secret(&shares[..3]).unwrap().get_bytes(), bytes);
+        }
+        // Invalid security levels, offsets, and composite moduli are rejected.
+        assert!(register_modulus(0, 1).is_err());
+        assert!(register_modulus(100, 3).is_err());
+        assert!(register_modulus(544, 3).is_err());
+        assert!(register_modulus(320, 0).is_err());
+        assert!(register_modulus(320, 1).is_err());
+        assert!(get_modulus_for_bits(320).is_none());
+        // Existing moduli cannot be replaced.
+        assert!(register_modulus(128, 159).is_ok());
+        assert!(register_modulus(128, 173).is_err());
+        assert!(register_modulus(384, 319).is_err());
+    }
+
+    #[test]
+    /// The function tests the identification of inconsistent secret shares.
+    fn test_find_inconsistent_shares() {
+        let modulus = get_field_modulus_for_bits(128).unwrap();
+        let secret = FiniteFieldElement::new_random(128, modulus);
+        let polynomial = SecretPolynomial::new(&secret, 128, 2).unwrap();
+        let mut secret_shares = polynomial.get_secret_shares(7);
+        assert_eq!(
+            find_inconsistent_shares(&secret_shares, 3).unwrap(),
+            Some(vec![])
+        );
+        // Two corrupted secret shares are identified among seven secret shares.
+        for position in [1, 4] {
+            secret_shares[position] = SecretShare::new(
+                &FiniteFieldElement::new_random(128, modulus),
+                secret_shares[position].index,
+            );
+        }
+        assert_eq!(
+            find_inconsistent_shares(&secret_shares, 3).unwrap(),
+            Some(vec![1, 4])
+        );
+        assert_eq!(
+            reconstruct_secret(&[
+                secret_shares[0].clone(),
+                secret_shares[2].clone(),
+                secret_shares[3].clone()
+            ])
+            .unwrap(),
+            secret
+        );
+        // Without a majority, the corrupted secret shares cannot be identified.
+        assert_eq!(
+            find_inconsistent_shares(&secret_shares[..4], 3).unwrap(),
+            None
+        );
+        assert_eq!(
+            find_inconsistent_shares(&secret_shares[..3], 3).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    /// The function tests the decoding of polynomials from partially corrupted secret shares.
+    fn test_decode_polynomial() {
+        let mut rng = rand::thread_rng();
+        for num_bits in [128, 256] {
+            let modulus = get_field_modulus_for_bits(num_bits).unwrap();
+            for _test in 0..NUM_TEST_RUNS {
+                let threshold = rng.gen_range(1..6);
+                let num_shares = threshold + rng.gen_range(0..8);
+                let secret = FiniteFieldElement::new_random(num_bits, modulus);
+                let polynomial = SecretPolynomial::new(&secret, num_bits, threshold - 1).unwrap();
+                let mut secret_shares = polynomial.get_secret_shares(num_shares as u32);
+                let max_num_errors = (num_shares - threshold) / 2;
+                let mut positions: Vec<usize> = (0..num_shares).collect();
+                positions.shuffle(&mut rng);
+                for position in &positions[..rng.gen_range(0..=max_num_errors)] {
+                    secret_shares[*position] = SecretShare::new(
+                        &FiniteFieldElement::new_random(num_bits, modulus),
+                        secret_shares[*position].index,
+                    );
+                }
+                let decoded_polynomial = decode_polynomial(&secret_shares, threshold)
+                    .unwrap()
+                    .expect("The decoding should work.");
+                assert_eq!(
+                    decoded_polynomial.get_commitment(),
+                    polynomial.get_commitment()
+                );
+            }
+        }
+        // Too many corrupted secret shares cannot be decoded.
+        let modulus = get_field_modulus_for_bits(128).unwrap();
+        let secret = FiniteFieldElement::new_random(128, modulus);
+        let polynomial = SecretPolynomial::new(&secret, 128, 1).unwrap();
+        let mut secret_shares = polynomial.get_secret_shares(5);
+        for position in [0, 1] {
+            secret_shares[position] = SecretShare::new(
+                &FiniteFieldElement::new_random(128, modulus),
+                secret_shares[position].index,
+            );
+        }
+        assert!(decode_polynomial(&secret_shares, 2).unwrap().is_none());
+    }
 }
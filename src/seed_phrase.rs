@@ -3,12 +3,18 @@
 //!
 
 use crate::math::FiniteFieldElement;
+use crate::normalization::normalize;
 use crate::secret_sharing::get_modulus_for_words;
-use crate::word_list::DEFAULT_WORD_LIST;
-use crate::{HarpoError, HarpoResult, SeedPhraseResult};
+use crate::word_list::{get_word_trie, WordList, WordTrie, DEFAULT_WORD_LIST};
+use crate::{ErrorContext, HarpoError, HarpoResult, SeedPhraseResult};
+use rand::{CryptoRng, RngCore};
 use sha2::{Digest, Sha256};
-use std::cmp;
+use std::convert::TryFrom;
 use std::fmt;
+use std::io::BufRead;
+use std::str::FromStr;
+use std::sync::Arc;
+use zeroize::{Zeroize, Zeroizing};
 
 /// The number of bits that each word represents.
 const NUM_BITS_PER_WORD: usize = 11;
@@ -17,15 +23,82 @@ pub const NUM_BITS_FOR_INDEX: usize = 4;
 /// The increase in the number of bits from one security level to the next.
 const ENTROPY_INCREMENT: usize = 32;
 
+/// The version of the share format produced by this version of `harpo`.
+///
+/// The version is increased whenever the encoding of secret-shared seed phrases changes.
+/// Version 1 corresponds to the embedded and explicit index encodings of the first releases.
+/// Version 2 adds the optional set identifier and threshold to explicit indices.
+pub const SHARE_FORMAT_VERSION: u32 = 2;
+/// The prefix of the header line that records the share format version in share files.
+const SHARE_FORMAT_HEADER_PREFIX: &str = "# harpo share format";
+
+/// The number of bits of the index word that encode the index.
+const NUM_INDEX_BITS_IN_INDEX_WORD: usize = 7;
+/// The number of bits of the index word that encode the checksum.
+const NUM_CHECKSUM_BITS_IN_INDEX_WORD: usize = NUM_BITS_PER_WORD - NUM_INDEX_BITS_IN_INDEX_WORD;
+/// The maximum number of shares whose indices can be encoded in an index word.
+pub const MAX_INDEX_WORD_SHARES: usize = 1 << NUM_INDEX_BITS_IN_INDEX_WORD;
+/// The tag that is bound to the checksum of index words.
+///
+/// The tag is fixed so that index words remain valid when the share format version is raised.
+/// It equals the share format version in which the tag was introduced.
+const INDEX_WORD_CHECKSUM_TAG: u32 = 1;
+/// The tags that earlier versions of `harpo` bound to the checksum of index words.
+///
+/// The first index words were created without a tag and, for a short time, the tag followed
+/// share format version 2. Index words with these checksums are still decoded.
+const LEGACY_INDEX_WORD_CHECKSUM_TAGS: [Option<u32>; 2] = [None, Some(2)];
+
+/// The word that marks an unknown word of a secret-shared seed phrase, see
+/// [complete_secret_shared_seed_phrase](crate::complete_secret_shared_seed_phrase).
+pub const WILDCARD_WORD: &str = "?";
+
+/// The maximum number of words that are suggested for a word that is not in the word list.
+pub const MAX_WORD_SUGGESTIONS: usize = 3;
+
+/// The maximum number of candidate words that are listed for an ambiguous abbreviation.
+const MAX_LISTED_COMPLETIONS: usize = 5;
+
+/// The permissible numbers of words in a seed phrase.
+#[cfg(not(feature = "insecure-demo"))]
+pub const VALID_NUM_WORDS: &[usize] = &[12, 15, 18, 21, 24];
+
+/// The permissible numbers of words in a seed phrase.
+/// The insecure demo phrases with 3, 6, and 9 words are included.
+#[cfg(feature = "insecure-demo")]
+pub const VALID_NUM_WORDS: &[usize] = &[3, 6, 9, 12, 15, 18, 21, 24];
+
+/// The error message returned when a seed phrase has an invalid number of words.
+#[cfg(not(feature = "insecure-demo"))]
+pub(crate) const INVALID_NUM_WORDS_MESSAGE: &str =
+    "The number of words must be 12, 15, 18, 21, or 24.";
+
+/// The error message returned when a seed phrase has an invalid number of words.
+#[cfg(feature = "insecure-demo")]
+pub(crate) const INVALID_NUM_WORDS_MESSAGE: &str =
+    "The number of words must be 3, 6, 9 (insecure demo), 12, 15, 18, 21, or 24.";
+
 /// This struct represents a seed phrase.
 /// A seed phrase consists of a series of words and, optionally, an index.
 /// The index is used to reconstruct secret-shared seed phrases.
-#[derive(Eq, Debug)]
+/// The words are stored in shared, immutable storage so that cloning a seed phrase is cheap.
+/// The words are wiped from memory when the last clone of the seed phrase is dropped.
+/// The words are not revealed in debug output unless [reveal](SeedPhrase::reveal) is called.
+#[derive(Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(from = "SerializedSeedPhrase", into = "SerializedSeedPhrase")
+)]
 pub struct SeedPhrase {
     /// The words.
-    words: Vec<String>,
+    words: Arc<[Box<str>]>,
     /// The optional index.
     index: Option<u32>,
+    /// The optional identifier of the set of secret-shared seed phrases.
+    set_id: Option<u16>,
+    /// The optional number of secret-shared seed phrases required for the reconstruction.
+    threshold: Option<usize>,
 }
 
 impl SeedPhrase {
@@ -37,10 +110,11 @@ impl SeedPhrase {
     ///
     /// * `words` - The words that make up the seed phrase.
     pub fn new(words: &[String]) -> Self {
-        let internal_words: Vec<String> = words.to_vec();
         SeedPhrase {
-            words: internal_words,
+            words: get_shared_words(words),
             index: None,
+            set_id: None,
+            threshold: None,
         }
     }
 
@@ -53,139 +127,1655 @@ impl SeedPhrase {
     /// * `words` - The words that make up the seed phrase.
     /// * `index` - The index of the seed phrase.
     pub fn new_with_index(words: &[String], index: u32) -> Self {
-        let internal_words: Vec<String> = words.to_vec();
         SeedPhrase {
-            words: internal_words,
+            words: get_shared_words(words),
             index: Some(index),
+            set_id: None,
+            threshold: None,
+        }
+    }
+
+    /// The function returns the number of words that make up the seed phrase.
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    /// The function returns true if the seed phrase is empty.
+    pub fn is_empty(&self) -> bool {
+        self.words.len() == 0
+    }
+
+    /// The function returns the words that make up the seed phrase.
+    pub fn get_words(&self) -> Vec<&str> {
+        self.words.iter().map(|s| s.as_ref()).collect()
+    }
+
+    /// The function returns the index of the seed phrase, if any.
+    pub fn get_index(&self) -> Option<u32> {
+        self.index
+    }
+
+    /// The function returns the identifier of the set of secret-shared seed phrases that the
+    /// seed phrase belongs to, if any.
+    pub fn get_set_id(&self) -> Option<u16> {
+        self.set_id
+    }
+
+    /// The function returns the seed phrase with the given set identifier.
+    ///
+    /// All secret-shared seed phrases created together share the same set identifier, which
+    /// allows the detection of secret-shared seed phrases from different sets.
+    ///
+    /// * `set_id` - The set identifier.
+    pub fn with_set_id(mut self, set_id: u16) -> Self {
+        self.set_id = Some(set_id);
+        self
+    }
+
+    /// The function returns the number of secret-shared seed phrases required to reconstruct
+    /// the seed phrase, if known.
+    pub fn get_threshold(&self) -> Option<usize> {
+        self.threshold
+    }
+
+    /// The function returns the seed phrase with the given threshold.
+    ///
+    /// The threshold allows the reconstruction to report missing secret-shared seed phrases
+    /// instead of producing an incorrect seed phrase.
+    ///
+    /// * `threshold` - The threshold.
+    pub fn with_threshold(mut self, threshold: usize) -> Self {
+        self.threshold = Some(threshold);
+        self
+    }
+
+    /// The function returns a wrapper whose debug output reveals the words of the seed phrase.
+    ///
+    /// The regular debug output of a seed phrase only contains the number of words, the index,
+    /// and a short fingerprint so that seed phrases do not end up in logs by accident.
+    pub fn reveal(&self) -> RevealedSeedPhrase<'_> {
+        RevealedSeedPhrase(self)
+    }
+
+    /// The function returns the security level of the seed phrase in bits.
+    pub fn get_num_bits(&self) -> usize {
+        // The number of security bits is the total number of bits rounded down to the
+        // nearest multiple of 'ENTROPY_INCREMENT'.
+        ((self.words.len() * NUM_BITS_PER_WORD) / ENTROPY_INCREMENT) * ENTROPY_INCREMENT
+    }
+
+    /// The function creates a BIP-0039-compliant seed phrase for the given entropy.
+    ///
+    /// The checksum bits are computed automatically. The entropy must consist of 16, 20, 24, 28,
+    /// or 32 bytes, resulting in a seed phrase with 12, 15, 18, 21, or 24 words, respectively.
+    /// The words are taken from the default word list.
+    ///
+    /// * `entropy` - The entropy.
+    pub fn from_entropy(entropy: &[u8]) -> SeedPhraseResult {
+        SeedPhrase::from_entropy_for_word_list(entropy, DEFAULT_WORD_LIST)
+    }
+
+    /// The function creates a BIP-0039-compliant seed phrase for the given entropy using the
+    /// given word list.
+    ///
+    /// The checksum bits are computed automatically. The entropy must consist of 16, 20, 24, 28,
+    /// or 32 bytes, resulting in a seed phrase with 12, 15, 18, 21, or 24 words, respectively.
+    ///
+    /// * `entropy` - The entropy.
+    /// * `word_list` - The word list.
+    pub fn from_entropy_for_word_list(
+        entropy: &[u8],
+        word_list: &(impl WordList + ?Sized),
+    ) -> SeedPhraseResult {
+        let word_list = word_list.get_words();
+        crate::validate_word_list(word_list)?;
+        // Every 4 bytes of entropy are encoded using 3 words.
+        if !(entropy.len().is_multiple_of(4) && is_valid_num_words(entropy.len() * 3 / 4)) {
+            return Err(HarpoError::InvalidParameter(format!(
+                "The entropy length of {} bytes is not supported.",
+                entropy.len()
+            )));
+        }
+        get_seed_phrase_for_entropy(entropy, word_list)
+    }
+
+    /// The function returns the entropy encoded in the seed phrase.
+    ///
+    /// The seed phrase must be BIP-0039 compliant for the default word list. The checksum bits
+    /// are verified and not part of the returned entropy. The index, if any, is ignored.
+    pub fn to_entropy(&self) -> HarpoResult<Vec<u8>> {
+        self.to_entropy_for_word_list(DEFAULT_WORD_LIST)
+    }
+
+    /// The function returns the entropy encoded in the seed phrase using the given word list.
+    ///
+    /// The seed phrase must be BIP-0039 compliant for the given word list. The checksum bits
+    /// are verified and not part of the returned entropy. The index, if any, is ignored.
+    ///
+    /// * `word_list` - The word list.
+    pub fn to_entropy_for_word_list(
+        &self,
+        word_list: &(impl WordList + ?Sized),
+    ) -> HarpoResult<Vec<u8>> {
+        let word_list = word_list.get_words();
+        crate::validate_word_list(word_list)?;
+        check_num_words(self)?;
+        check_compliance(self, word_list)?;
+        get_used_bytes(self, word_list)
+    }
+}
+
+/// This struct is the serialized form of a seed phrase.
+///
+/// The words are stored as a list and the index is `null` if there is no index.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct SerializedSeedPhrase {
+    /// The words.
+    words: Vec<String>,
+    /// The optional index.
+    #[serde(default)]
+    index: Option<u32>,
+    /// The optional set identifier.
+    #[serde(default)]
+    set_id: Option<u16>,
+    /// The optional threshold.
+    #[serde(default)]
+    threshold: Option<usize>,
+}
+
+#[cfg(feature = "serde")]
+impl From<SerializedSeedPhrase> for SeedPhrase {
+    /// The function converts the serialized form into a seed phrase.
+    ///
+    /// * `serialized` - The serialized seed phrase.
+    fn from(serialized: SerializedSeedPhrase) -> Self {
+        SeedPhrase {
+            words: get_shared_words(&serialized.words),
+            index: serialized.index,
+            set_id: serialized.set_id,
+            threshold: serialized.threshold,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<SeedPhrase> for SerializedSeedPhrase {
+    /// The function converts a seed phrase into its serialized form.
+    ///
+    /// * `seed_phrase` - The seed phrase.
+    fn from(seed_phrase: SeedPhrase) -> Self {
+        SerializedSeedPhrase {
+            words: seed_phrase
+                .words
+                .iter()
+                .map(|word| word.to_string())
+                .collect(),
+            index: seed_phrase.index,
+            set_id: seed_phrase.set_id,
+            threshold: seed_phrase.threshold,
+        }
+    }
+}
+
+/// This enumeration type defines how the index of a secret-shared seed phrase is encoded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum IndexEncoding {
+    /// The index is embedded in the bits of the last word, which are otherwise used for the
+    /// BIP-0039 checksum. At most [MAX_EMBEDDED_SHARES](crate::MAX_EMBEDDED_SHARES) shares are
+    /// supported and the seed phrases are not BIP-0039-compliant.
+    Embedded,
+    /// The index is provided explicitly alongside the seed phrase in the form
+    /// `[INDEX]: [SEED PHRASE]`. The seed phrases are BIP-0039-compliant.
+    Explicit,
+    /// The index and a small checksum are encoded in an additional word, which is appended to
+    /// the seed phrase. At most [MAX_INDEX_WORD_SHARES](crate::seed_phrase::MAX_INDEX_WORD_SHARES)
+    /// shares are supported and the words before the index word are BIP-0039-compliant.
+    IndexWord,
+}
+
+/// This enumeration type defines the standard that a seed phrase that is secret-shared complies
+/// with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PhraseStandard {
+    /// The seed phrase is BIP-0039-compliant, i.e., the last word contains checksum bits.
+    Bip39,
+    /// The seed phrase is an Electrum seed phrase, whose HMAC-SHA512 with the key
+    /// `Seed version` starts with a valid version prefix. Since an Electrum seed phrase with 12
+    /// words encodes 132 bits, it is carried by a BIP-0039 seed phrase with 15 words, i.e., the
+    /// secret-shared seed phrases have 15 words.
+    Electrum,
+}
+
+/// This enumeration type defines how strictly a seed phrase is parsed from a string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseMode {
+    /// Only lower-case words from the word list, separated by single spaces, are accepted.
+    /// An explicit index must be provided in the form `[INDEX]: [SEED PHRASE]`.
+    /// This mode is intended for automated pipelines.
+    Strict,
+    /// The input is folded to lower case, punctuation and additional white space are removed,
+    /// and words may be abbreviated by any prefix that identifies a unique word in the word
+    /// list. This mode is intended for input entered by humans.
+    Lenient,
+}
+
+/// This enumeration type defines how words that are not in the word list are handled when
+/// parsing a seed phrase.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TypoPolicy {
+    /// Words that are not in the word list are rejected.
+    Reject,
+    /// Words that are not in the word list are rejected, but the error message suggests words
+    /// that differ by a single typo.
+    SuggestOnly,
+    /// A word that is not in the word list is replaced by the unique word in the word list that
+    /// differs by a single typo, if there is one. The correction is recorded in the
+    /// [ParseReport](crate::seed_phrase::ParseReport).
+    AutoCorrect,
+}
+
+/// This struct describes a word that was corrected automatically when parsing a seed phrase.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WordCorrection {
+    /// The position of the word in the seed phrase, starting at 0.
+    position: usize,
+    /// The original word.
+    original: String,
+    /// The corrected word.
+    corrected: String,
+}
+
+impl WordCorrection {
+    /// The function returns the position of the word in the seed phrase, starting at 0.
+    pub fn get_position(&self) -> usize {
+        self.position
+    }
+
+    /// The function returns the original word.
+    pub fn get_original(&self) -> &str {
+        &self.original
+    }
+
+    /// The function returns the corrected word.
+    pub fn get_corrected(&self) -> &str {
+        &self.corrected
+    }
+}
+
+impl fmt::Display for WordCorrection {
+    /// A correction is displayed as the position of the word (starting at 1) together with the
+    /// original and the corrected word.
+    ///
+    /// * `formatter` - The formatter.
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "word {}: '{}' -> '{}'",
+            self.position + 1,
+            self.original,
+            self.corrected
+        )
+    }
+}
+
+/// This struct holds a parsed seed phrase together with all applied corrections.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseReport {
+    /// The parsed seed phrase.
+    seed_phrase: SeedPhrase,
+    /// The applied corrections.
+    corrections: Vec<WordCorrection>,
+}
+
+impl ParseReport {
+    /// The function returns the parsed seed phrase.
+    pub fn get_seed_phrase(&self) -> &SeedPhrase {
+        &self.seed_phrase
+    }
+
+    /// The function returns the applied corrections.
+    pub fn get_corrections(&self) -> &[WordCorrection] {
+        &self.corrections
+    }
+}
+
+/// This struct describes a word of a seed phrase that is not in the word list.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownWord {
+    /// The position of the word in the seed phrase, starting at 0.
+    position: usize,
+    /// The unknown word.
+    word: String,
+    /// The words in the word list that differ by a single typo, at most
+    /// [MAX_WORD_SUGGESTIONS](crate::seed_phrase::MAX_WORD_SUGGESTIONS) of them.
+    suggestions: Vec<String>,
+}
+
+impl UnknownWord {
+    /// The function returns the position of the word in the seed phrase, starting at 0.
+    pub fn get_position(&self) -> usize {
+        self.position
+    }
+
+    /// The function returns the unknown word.
+    pub fn get_word(&self) -> &str {
+        &self.word
+    }
+
+    /// The function returns the words in the word list that differ by a single typo.
+    pub fn get_suggestions(&self) -> &[String] {
+        &self.suggestions
+    }
+}
+
+impl fmt::Display for UnknownWord {
+    /// An unknown word is displayed as its position (starting at 1) together with the word and
+    /// the suggestions, if any.
+    ///
+    /// * `formatter` - The formatter.
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "word {}: '{}'", self.position + 1, self.word)?;
+        if !self.suggestions.is_empty() {
+            write!(
+                formatter,
+                " (did you mean {}?)",
+                self.suggestions.join(", ")
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// This struct holds the detailed validation result of a seed phrase.
+///
+/// It lists all detected problems: words that are not in the word list, an invalid number of
+/// words, and a checksum mismatch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SeedPhraseDiagnostics {
+    /// The number of words.
+    num_words: usize,
+    /// The words that are not in the word list.
+    unknown_words: Vec<UnknownWord>,
+    /// The result of the checksum verification, if the checksum can be verified.
+    valid_checksum: Option<bool>,
+}
+
+impl SeedPhraseDiagnostics {
+    /// The function returns the number of words.
+    pub fn get_num_words(&self) -> usize {
+        self.num_words
+    }
+
+    /// The function returns true if the number of words is permissible.
+    pub fn has_valid_num_words(&self) -> bool {
+        is_valid_num_words(self.num_words)
+    }
+
+    /// The function returns the words that are not in the word list.
+    pub fn get_unknown_words(&self) -> &[UnknownWord] {
+        &self.unknown_words
+    }
+
+    /// The function returns the result of the checksum verification.
+    ///
+    /// The checksum can only be verified if the number of words is permissible and all words
+    /// are in the word list. Otherwise, `None` is returned.
+    pub fn has_valid_checksum(&self) -> Option<bool> {
+        self.valid_checksum
+    }
+
+    /// The function returns true if no problem was detected, i.e., if the seed phrase is
+    /// BIP-0039 compliant.
+    pub fn is_valid(&self) -> bool {
+        self.valid_checksum == Some(true)
+    }
+}
+
+impl fmt::Display for SeedPhraseDiagnostics {
+    /// The diagnostics are displayed as a list of all detected problems.
+    ///
+    /// * `formatter` - The formatter.
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_valid() {
+            return write!(formatter, "The seed phrase is BIP-0039-compliant.");
+        }
+        let mut problems = vec![];
+        if !self.has_valid_num_words() {
+            problems.push(format!(
+                "The seed phrase has {} words. {}",
+                self.num_words, INVALID_NUM_WORDS_MESSAGE
+            ));
+        }
+        for unknown_word in &self.unknown_words {
+            problems.push(format!("Unknown {}.", unknown_word));
+        }
+        if self.valid_checksum == Some(false) {
+            problems.push("The checksum of the seed phrase is invalid.".to_string());
+        }
+        write!(formatter, "{}", problems.join("\n"))
+    }
+}
+
+/// This struct holds the validation result of a single secret-shared seed phrase.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ShareValidation {
+    /// The number of words of the secret-shared seed phrase including an index word, if any.
+    num_words: usize,
+    /// The detected index encoding, if it can be determined.
+    index_encoding: Option<IndexEncoding>,
+    /// The decoded index, if it can be determined.
+    index: Option<u32>,
+    /// The error message if the secret-shared seed phrase appears to be mistyped.
+    error: Option<String>,
+    /// The fingerprint of the secret-shared seed phrase, if all words are valid.
+    fingerprint: Option<String>,
+    /// The position of an earlier secret-shared seed phrase with the same fingerprint, if any.
+    duplicate_of: Option<usize>,
+}
+
+impl ShareValidation {
+    /// The function returns the number of words.
+    pub fn get_num_words(&self) -> usize {
+        self.num_words
+    }
+
+    /// The function returns the detected index encoding, if it can be determined.
+    pub fn get_index_encoding(&self) -> Option<IndexEncoding> {
+        self.index_encoding
+    }
+
+    /// The function returns the decoded index, if it can be determined.
+    pub fn get_index(&self) -> Option<u32> {
+        self.index
+    }
+
+    /// The function returns true if no problem was detected.
+    pub fn is_valid(&self) -> bool {
+        self.error.is_none()
+    }
+
+    /// The function returns the error message if the secret-shared seed phrase appears to be
+    /// mistyped.
+    pub fn get_error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    /// The function returns the fingerprint of the secret-shared seed phrase, if all words are
+    /// valid.
+    ///
+    /// The fingerprint is a short hex string derived from the encoded value and the index. It
+    /// does not reveal the secret-shared seed phrase and can be used to refer to it, e.g., to
+    /// detect duplicate copies.
+    pub fn get_fingerprint(&self) -> Option<&str> {
+        self.fingerprint.as_deref()
+    }
+
+    /// The function returns the position of an earlier secret-shared seed phrase with the same
+    /// fingerprint, if any.
+    pub fn get_duplicate_of(&self) -> Option<usize> {
+        self.duplicate_of
+    }
+
+    /// The function sets the position of an earlier secret-shared seed phrase with the same
+    /// fingerprint.
+    ///
+    /// * `position` - The position of the earlier secret-shared seed phrase.
+    pub(crate) fn set_duplicate_of(&mut self, position: usize) {
+        self.duplicate_of = Some(position);
+    }
+}
+
+/// This struct describes a secret-shared seed phrase that was provided for a reconstruction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ShareProvenance {
+    /// The position of the secret-shared seed phrase in the input, starting at 0.
+    position: usize,
+    /// The label of the secret-shared seed phrase, e.g., the source file, if provided.
+    label: Option<String>,
+    /// The decoded index, if it can be determined.
+    index: Option<u32>,
+    /// The fingerprint of the secret-shared seed phrase, if all words are valid.
+    fingerprint: Option<String>,
+    /// The position of an earlier secret-shared seed phrase with the same fingerprint, if any.
+    duplicate_of: Option<usize>,
+}
+
+impl ShareProvenance {
+    /// The function creates the provenance of a secret-shared seed phrase.
+    ///
+    /// * `position` - The position of the secret-shared seed phrase in the input.
+    /// * `label` - The label of the secret-shared seed phrase, if provided.
+    /// * `validation` - The validation result of the secret-shared seed phrase.
+    pub(crate) fn new(position: usize, label: Option<&str>, validation: &ShareValidation) -> Self {
+        ShareProvenance {
+            position,
+            label: label.map(str::to_string),
+            index: validation.get_index(),
+            fingerprint: validation.get_fingerprint().map(str::to_string),
+            duplicate_of: validation.get_duplicate_of(),
+        }
+    }
+
+    /// The function returns the position of the secret-shared seed phrase in the input, starting
+    /// at 0.
+    pub fn get_position(&self) -> usize {
+        self.position
+    }
+
+    /// The function returns the label of the secret-shared seed phrase, if provided.
+    pub fn get_label(&self) -> Option<&str> {
+        self.label.as_deref()
+    }
+
+    /// The function returns the decoded index, if it can be determined.
+    pub fn get_index(&self) -> Option<u32> {
+        self.index
+    }
+
+    /// The function returns the fingerprint of the secret-shared seed phrase, if all words are
+    /// valid.
+    pub fn get_fingerprint(&self) -> Option<&str> {
+        self.fingerprint.as_deref()
+    }
+
+    /// The function returns the position of an earlier secret-shared seed phrase with the same
+    /// fingerprint, if any.
+    pub fn get_duplicate_of(&self) -> Option<usize> {
+        self.duplicate_of
+    }
+}
+
+impl fmt::Display for ShareProvenance {
+    /// The provenance is displayed as the position (starting at 1), the label if provided, the
+    /// index, and the fingerprint.
+    ///
+    /// * `formatter` - The formatter.
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "share {}", self.position + 1)?;
+        if let Some(label) = &self.label {
+            write!(formatter, " ({})", label)?;
+        }
+        match self.index {
+            Some(index) => write!(formatter, ", index {}", index)?,
+            None => write!(formatter, ", unknown index")?,
+        }
+        match &self.fingerprint {
+            Some(fingerprint) => write!(formatter, ", fingerprint {}", fingerprint),
+            None => write!(formatter, ", no fingerprint"),
+        }
+    }
+}
+
+/// This struct holds a reconstructed seed phrase together with the provenance of the
+/// secret-shared seed phrases, which makes it possible to document a reconstruction precisely.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReconstructionReport {
+    /// The reconstructed seed phrase.
+    seed_phrase: SeedPhrase,
+    /// The fingerprint of the reconstructed seed phrase.
+    fingerprint: String,
+    /// The secret-shared seed phrases that were used.
+    used_shares: Vec<ShareProvenance>,
+    /// The secret-shared seed phrases that were ignored.
+    ignored_shares: Vec<ShareProvenance>,
+    /// The secret-shared seed phrases that were identified as corrupted.
+    corrupted_shares: Vec<ShareProvenance>,
+    /// The warnings that were detected.
+    warnings: Vec<String>,
+    /// The consistency of the secret-shared seed phrases, if it could be checked.
+    consistency: Option<bool>,
+}
+
+impl ReconstructionReport {
+    /// The function creates a reconstruction report.
+    ///
+    /// * `seed_phrase` - The reconstructed seed phrase.
+    /// * `fingerprint` - The fingerprint of the reconstructed seed phrase.
+    /// * `used_shares` - The secret-shared seed phrases that were used.
+    /// * `ignored_shares` - The secret-shared seed phrases that were ignored.
+    /// * `corrupted_shares` - The secret-shared seed phrases that were identified as corrupted.
+    /// * `warnings` - The warnings that were detected.
+    /// * `consistency` - The consistency of the secret-shared seed phrases, if it was checked.
+    pub(crate) fn new(
+        seed_phrase: SeedPhrase,
+        fingerprint: String,
+        used_shares: Vec<ShareProvenance>,
+        ignored_shares: Vec<ShareProvenance>,
+        corrupted_shares: Vec<ShareProvenance>,
+        warnings: Vec<String>,
+        consistency: Option<bool>,
+    ) -> Self {
+        ReconstructionReport {
+            seed_phrase,
+            fingerprint,
+            used_shares,
+            ignored_shares,
+            corrupted_shares,
+            warnings,
+            consistency,
+        }
+    }
+
+    /// The function returns the reconstructed seed phrase.
+    pub fn get_seed_phrase(&self) -> &SeedPhrase {
+        &self.seed_phrase
+    }
+
+    /// The function returns the fingerprint of the reconstructed seed phrase.
+    ///
+    /// The fingerprint is a short hex string that does not reveal the seed phrase. It can be
+    /// recorded to confirm later that the same seed phrase was reconstructed.
+    pub fn get_fingerprint(&self) -> &str {
+        &self.fingerprint
+    }
+
+    /// The function returns the secret-shared seed phrases that were used.
+    pub fn get_used_shares(&self) -> &[ShareProvenance] {
+        &self.used_shares
+    }
+
+    /// The function returns the secret-shared seed phrases that were ignored.
+    ///
+    /// A secret-shared seed phrase is ignored if it is a duplicate of an earlier one or if a
+    /// later secret-shared seed phrase has the same index.
+    pub fn get_ignored_shares(&self) -> &[ShareProvenance] {
+        &self.ignored_shares
+    }
+
+    /// The function returns the secret-shared seed phrases that were identified as corrupted.
+    ///
+    /// If the secret-shared seed phrases are inconsistent, see
+    /// [is_consistent](ReconstructionReport::is_consistent), the secret-shared seed phrases
+    /// that do not agree with a consistent majority are identified as corrupted and not used
+    /// for the reconstruction.
+    pub fn get_corrupted_shares(&self) -> &[ShareProvenance] {
+        &self.corrupted_shares
+    }
+
+    /// The function returns the warnings that were detected.
+    pub fn get_warnings(&self) -> &[String] {
+        &self.warnings
+    }
+
+    /// The function returns whether the used secret-shared seed phrases are consistent.
+    ///
+    /// The consistency can only be checked if the secret-shared seed phrases record their
+    /// threshold and more secret-shared seed phrases than the threshold are used. In this case,
+    /// the function returns false if at least one of them is corrupted. In this case, the
+    /// reconstructed seed phrase is almost certainly incorrect unless the corrupted
+    /// secret-shared seed phrases could be identified, see
+    /// [get_corrupted_shares](ReconstructionReport::get_corrupted_shares). Otherwise, `None` is
+    /// returned.
+    pub fn is_consistent(&self) -> Option<bool> {
+        self.consistency
+    }
+}
+
+/// The function returns the fingerprint of the given seed phrase.
+///
+/// The fingerprint is a short hex string derived from the encoded bytes, which does not reveal
+/// the seed phrase.
+///
+/// * `seed_phrase` - The seed phrase.
+/// * `word_list` - The word list.
+pub(crate) fn get_seed_phrase_fingerprint(
+    seed_phrase: &SeedPhrase,
+    word_list: &[&str],
+) -> HarpoResult<String> {
+    let bytes = get_used_bytes(seed_phrase, word_list)?;
+    let mut hasher = Sha256::new();
+    hasher.update(b"harpo seed phrase fingerprint");
+    hasher.update(&bytes);
+    let hash = hasher.finalize();
+    Ok(hash[..4]
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect::<String>())
+}
+
+/// The function converts the given words into the shared storage used by seed phrases.
+///
+/// * `words` - The words.
+fn get_shared_words(words: &[String]) -> Arc<[Box<str>]> {
+    words.iter().map(|word| word.as_str().into()).collect()
+}
+
+impl Clone for SeedPhrase {
+    /// The function defines how a seed phrase is cloned.
+    ///
+    /// Since the words are immutable, the clone shares the word storage with the original.
+    fn clone(&self) -> SeedPhrase {
+        SeedPhrase {
+            words: Arc::clone(&self.words),
+            index: self.index,
+            set_id: self.set_id,
+            threshold: self.threshold,
+        }
+    }
+}
+
+impl Drop for SeedPhrase {
+    /// The words are wiped from memory when the last seed phrase sharing them is dropped.
+    fn drop(&mut self) {
+        if let Some(words) = Arc::get_mut(&mut self.words) {
+            for word in words.iter_mut() {
+                word.zeroize();
+            }
+        }
+    }
+}
+
+impl fmt::Debug for SeedPhrase {
+    /// The words of the seed phrase are not revealed in debug output.
+    /// Instead, the number of words, the index, and a short fingerprint of the words are shown.
+    ///
+    /// * `formatter` - The formatter.
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut hasher = Sha256::new();
+        hasher.update(b"harpo seed phrase debug fingerprint");
+        for word in self.words.iter() {
+            hasher.update(normalize(word).as_bytes());
+            hasher.update(b" ");
+        }
+        let hash = hasher.finalize();
+        write!(formatter, "SeedPhrase({} words, index: ", self.words.len())?;
+        match self.index {
+            Some(index) => write!(formatter, "{}", index)?,
+            None => write!(formatter, "none")?,
+        }
+        if let Some(set_id) = self.set_id {
+            write!(formatter, ", set: {:04x}", set_id)?;
+        }
+        if let Some(threshold) = self.threshold {
+            write!(formatter, ", threshold: {}", threshold)?;
+        }
+        write!(formatter, ", fingerprint: ")?;
+        for byte in &hash[..4] {
+            write!(formatter, "{:02x}", byte)?;
+        }
+        write!(formatter, ")")
+    }
+}
+
+/// This struct reveals the words of a seed phrase in debug output.
+///
+/// It is obtained by calling [reveal](SeedPhrase::reveal) on a seed phrase.
+pub struct RevealedSeedPhrase<'a>(&'a SeedPhrase);
+
+impl fmt::Debug for RevealedSeedPhrase<'_> {
+    /// The words and the index of the seed phrase are shown in debug output.
+    ///
+    /// * `formatter` - The formatter.
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct("SeedPhrase")
+            .field("words", &self.0.get_words())
+            .field("index", &self.0.index)
+            .field("set_id", &self.0.set_id)
+            .field("threshold", &self.0.threshold)
+            .finish()
+    }
+}
+
+impl fmt::Display for SeedPhrase {
+    /// A seed phrase is displayed as a space-delimited string.
+    /// If it has an associated index, the index followed by a colon is prepended to the
+    /// list of words. A set identifier is appended to the index as a slash followed by four
+    /// hex digits and a threshold as a slash followed by the letter `t` and the threshold,
+    /// e.g., `3/1a2b/t2: [SEED PHRASE]`. If the index is embedded in the words or encoded in
+    /// an index word, the set identifier and the threshold are prepended without the index,
+    /// e.g., `/1a2b/t2: [SEED PHRASE]`.
+    ///
+    /// * `formatter` - The formatter.
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut words_with_spaces = String::new();
+        // Create a space-delimited string of all words.
+        for index in 0..(self.words.len() - 1) {
+            words_with_spaces.push_str(&self.words[index]);
+            words_with_spaces.push(' ');
+        }
+        words_with_spaces.push_str(&self.words[self.words.len() - 1]);
+        // If there is an index, a set identifier, or a threshold, prepend them.
+        if self.index.is_some() || self.set_id.is_some() || self.threshold.is_some() {
+            if let Some(index) = self.index {
+                write!(formatter, "{}", index)?;
+            }
+            if let Some(set_id) = self.set_id {
+                write!(formatter, "/{:04x}", set_id)?;
+            }
+            if let Some(threshold) = self.threshold {
+                write!(formatter, "/t{}", threshold)?;
+            }
+            write!(formatter, ": ")?;
+        }
+        write!(formatter, "{}", words_with_spaces)
+    }
+}
+
+impl PartialEq for SeedPhrase {
+    /// Equality of two seed phrases is defined based on the words that make up the seed phrases.
+    ///
+    /// * `other` - The other seed phrase.
+    fn eq(&self, other: &Self) -> bool {
+        self.words == other.words
+    }
+}
+
+impl FromStr for SeedPhrase {
+    type Err = HarpoError;
+
+    /// A seed phrase is parsed from a space-delimited string, optionally preceded by an
+    /// explicit index in the form `[INDEX]: [SEED PHRASE]`.
+    ///
+    /// The input is parsed leniently, see [ParseMode](crate::seed_phrase::ParseMode), and all
+    /// words must be in the default word list. If a word is not in the word list, the error
+    /// message suggests up to three words that differ by a single typo.
+    ///
+    /// * `input` - The seed phrase as a string.
+    fn from_str(input: &str) -> SeedPhraseResult {
+        let report = parse_seed_phrase_with_typo_policy(
+            input,
+            ParseMode::Lenient,
+            TypoPolicy::SuggestOnly,
+            DEFAULT_WORD_LIST,
+        )?;
+        check_num_words(&report.seed_phrase)?;
+        Ok(report.seed_phrase)
+    }
+}
+
+impl TryFrom<&[&str]> for SeedPhrase {
+    type Error = HarpoError;
+
+    /// A seed phrase without an index is created from the given words.
+    ///
+    /// All words must be in the default word list.
+    ///
+    /// * `words` - The words.
+    fn try_from(words: &[&str]) -> SeedPhraseResult {
+        let words: Vec<String> = words.iter().map(|word| word.to_string()).collect();
+        SeedPhrase::try_from(words)
+    }
+}
+
+impl TryFrom<Vec<String>> for SeedPhrase {
+    type Error = HarpoError;
+
+    /// A seed phrase without an index is created from the given words.
+    ///
+    /// All words must be in the default word list.
+    ///
+    /// * `words` - The words.
+    fn try_from(words: Vec<String>) -> SeedPhraseResult {
+        let trie = get_word_trie(DEFAULT_WORD_LIST);
+        if let Some(position) = words.iter().position(|word| trie.get_index(word).is_none()) {
+            return Err(get_unknown_word_error(
+                &words[position],
+                position,
+                DEFAULT_WORD_LIST,
+            ));
+        }
+        let seed_phrase = SeedPhrase::new(&words);
+        check_num_words(&seed_phrase)?;
+        Ok(seed_phrase)
+    }
+}
+
+/// The function returns an error if the given seed phrase has an invalid number of words.
+///
+/// * `seed_phrase` - The seed phrase.
+fn check_num_words(seed_phrase: &SeedPhrase) -> HarpoResult<()> {
+    if is_valid_num_words(seed_phrase.len()) {
+        Ok(())
+    } else {
+        Err(HarpoError::WrongWordCount {
+            got: seed_phrase.len(),
+        })
+    }
+}
+
+/// The function returns a random seed phrase.
+///
+/// The function generates a random, BIP-0039-compliant seed phrase with the desired number
+/// of words taken from the provided word list.
+/// * `num_words` - The requested number of words in the random seed phrase.
+/// * `word-list` - The word list.
+/// * `rng` - The cryptographically secure random number generator.
+pub(crate) fn get_random_seed_phrase<R: RngCore + CryptoRng>(
+    num_words: usize,
+    word_list: &[&str],
+    rng: &mut R,
+) -> SeedPhraseResult {
+    if !is_valid_num_words(num_words) {
+        return Err(HarpoError::InvalidParameter(
+            INVALID_NUM_WORDS_MESSAGE.to_string(),
+        ));
+    }
+    // Determine the number of bits based on the number of words.
+    let num_bits = ((num_words * NUM_BITS_PER_WORD) / ENTROPY_INCREMENT) * ENTROPY_INCREMENT;
+    match get_modulus_for_words(num_words) {
+        Some(modulus) => {
+            // Create a random finite field element.
+            let element = FiniteFieldElement::new_random_with_rng(num_bits, modulus, rng);
+            // Return the seed phrase derived from this element.
+            get_seed_phrase_for_element(&element, word_list)
+        }
+        None => Err(HarpoError::InvalidSeedPhrase(
+            "Could not generate a seed phrase.".to_string(),
+        )),
+    }
+}
+
+/// The function returns true if a seed phrase may consist of the given number of words.
+///
+/// * `num_words` - The number of words.
+pub(crate) fn is_valid_num_words(num_words: usize) -> bool {
+    VALID_NUM_WORDS.contains(&num_words)
+}
+
+/// The function returns true if an index can be embedded in a seed phrase with the given
+/// number of words.
+///
+/// An index can only be embedded if there are at least `NUM_BITS_FOR_INDEX` bits that are not
+/// used to encode the secret, which is not the case for the insecure demo seed phrases.
+///
+/// * `num_words` - The number of words.
+pub fn can_embed_index(num_words: usize) -> bool {
+    let num_bits = num_words * NUM_BITS_PER_WORD;
+    num_bits - (num_bits / ENTROPY_INCREMENT) * ENTROPY_INCREMENT >= NUM_BITS_FOR_INDEX
+}
+
+/// The function returns the header line that records the share format version.
+///
+/// The header line is a comment, which is skipped when reading seed phrases from a file.
+pub fn get_share_format_header() -> String {
+    format!(
+        "{} {} (harpo {})",
+        SHARE_FORMAT_HEADER_PREFIX,
+        SHARE_FORMAT_VERSION,
+        env!("CARGO_PKG_VERSION")
+    )
+}
+
+/// The function returns the share format version recorded in the given line, if any.
+///
+/// * `line` - The line, which may be a share format header.
+pub fn parse_share_format_header(line: &str) -> Option<u32> {
+    line.trim()
+        .strip_prefix(SHARE_FORMAT_HEADER_PREFIX)?
+        .split_whitespace()
+        .next()?
+        .parse::<u32>()
+        .ok()
+}
+
+/// The function checks whether shares of the given share format version can be processed.
+///
+/// Shares produced by newer versions of `harpo` using a newer share format are rejected with an
+/// explicit error message instead of being decoded incorrectly. Since the version is only known
+/// if it is recorded, index words of earlier versions are detected from their checksum when
+/// they are decoded.
+///
+/// * `version` - The share format version.
+pub fn check_share_format_version(version: u32) -> HarpoResult<()> {
+    if version == 0 || version > SHARE_FORMAT_VERSION {
+        Err(HarpoError::InvalidSeedPhrase(format!(
+            "The shares use share format version {}, which was produced by a different harpo \
+            version. This version of harpo (version {}) supports share format version {}.",
+            version,
+            env!("CARGO_PKG_VERSION"),
+            SHARE_FORMAT_VERSION
+        )))
+    } else {
+        Ok(())
+    }
+}
+
+/// The function parses a seed phrase from the given string.
+///
+/// The function takes a space-delimited seed phrase, optionally preceded by an explicit index
+/// in the form `[INDEX]: [SEED PHRASE]` or by a prefix without an index such as
+/// `/1a2b/t2: [SEED PHRASE]`, and returns a seed phrase if the string contains at
+/// least one word. The words are converted to lower case and ideographic spaces are treated as
+/// word separators. This is the parsing applied by the command-line tool.
+/// Note that the function does not verify the validity of the provided words, see
+/// [parse_seed_phrase](crate::parse_seed_phrase) for a parser that checks the words against a
+/// word list.
+///
+/// * `input` - The input seed phrase as a space-delimited string.
+pub fn parse_phrase(input: &str) -> SeedPhraseResult {
+    // Break the input into words.
+    let mut words: Vec<String> = input
+        .replace(':', ": ") // If there is an index, ensure that it is a separate word.
+        .replace('\u{3000}', " ") // Ideographic spaces separate words as well.
+        .to_lowercase() // No upper-case words are allowed.
+        .trim() // Remove white spaces in the beginning and at the end.
+        .split(' ') // Split the string.
+        .filter(|word| !word.is_empty()) // Keep only words with a positive length.
+        .map(str::to_string) // Map the string slices to strings.
+        .collect(); // Collect the vector.
+    if words.is_empty() {
+        // Make sure that there are sufficiently many words.
+        return Err(HarpoError::InvalidSeedPhrase(
+            "No seed phrase provided.".to_string(),
+        ));
+    }
+    // If there is an explicit index, extract it from the list of words.
+    if words[0].contains(':') {
+        let index_string = words.remove(0);
+        Ok(parse_index_prefix(&index_string.replace(':', ""))?.get_seed_phrase(&words))
+    } else {
+        // Otherwise, create a seed phrase without an index.
+        Ok(SeedPhrase::new(&words))
+    }
+}
+
+/// This struct holds the optional explicit index of a seed phrase together with the optional
+/// set identifier and threshold.
+struct IndexPrefix {
+    /// The optional index.
+    index: Option<u32>,
+    /// The optional set identifier.
+    set_id: Option<u16>,
+    /// The optional threshold.
+    threshold: Option<usize>,
+}
+
+impl IndexPrefix {
+    /// The function returns a seed phrase with the given words and the parsed index, set
+    /// identifier, and threshold.
+    ///
+    /// * `words` - The words that make up the seed phrase.
+    fn get_seed_phrase(&self, words: &[String]) -> SeedPhrase {
+        let mut seed_phrase = match self.index {
+            Some(index) => SeedPhrase::new_with_index(words, index),
+            None => SeedPhrase::new(words),
+        };
+        seed_phrase.set_id = self.set_id;
+        seed_phrase.threshold = self.threshold;
+        seed_phrase
+    }
+}
+
+/// The function parses an explicit index, optionally followed by a slash and a set identifier
+/// consisting of four hex digits and by a slash and a threshold preceded by the letter `t`,
+/// e.g., `3`, `3/1a2b`, or `3/1a2b/t2`. The index is omitted for seed phrases with embedded
+/// indices or index words, e.g., `/1a2b/t2`.
+///
+/// * `prefix` - The part of a seed phrase string in front of the colon.
+fn parse_index_prefix(prefix: &str) -> HarpoResult<IndexPrefix> {
+    let mut parts = prefix.split('/').peekable();
+    let index_string = parts.next().unwrap_or_default();
+    if !index_string.chars().all(|c| c.is_ascii_digit()) {
+        return Err(HarpoError::InvalidSeedPhrase(format!(
+            "Invalid index of seed phrase: '{}'",
+            index_string
+        )));
+    }
+    // The index may only be omitted if a set identifier or a threshold follows.
+    let index = if index_string.is_empty() && parts.peek().is_some() {
+        None
+    } else {
+        Some(index_string.parse::<u32>().map_err(|_| {
+            HarpoError::InvalidSeedPhrase("Could not parse index of seed phrase.".to_string())
+        })?)
+    };
+    let mut set_id = None;
+    let mut threshold = None;
+    for part in parts {
+        if let Some(threshold_string) = part.strip_prefix('t') {
+            match threshold_string.parse::<usize>() {
+                Ok(value) if value > 0 && threshold.is_none() => threshold = Some(value),
+                _ => {
+                    return Err(HarpoError::InvalidSeedPhrase(format!(
+                        "Invalid threshold of seed phrase: '{}'",
+                        part
+                    )))
+                }
+            }
+        } else if part.len() == 4
+            && part.chars().all(|c| c.is_ascii_hexdigit())
+            && set_id.is_none()
+            && threshold.is_none()
+        {
+            set_id = u16::from_str_radix(part, 16).ok();
+        } else {
+            return Err(HarpoError::InvalidSeedPhrase(format!(
+                "Invalid set identifier of seed phrase: '{}'",
+                part
+            )));
+        }
+    }
+    Ok(IndexPrefix {
+        index,
+        set_id,
+        threshold,
+    })
+}
+
+/// The function parses all seed phrases provided by the given reader.
+///
+/// Each non-empty line that does not start with `#` is parsed using [parse_phrase]. If the
+/// input contains a share format header, see [get_share_format_header], the share format
+/// version is checked before any seed phrase is parsed. An error is returned if any line
+/// cannot be parsed.
+///
+/// * `reader` - The reader, e.g., a buffered file.
+pub fn parse_phrases_from_reader<R: BufRead>(reader: R) -> HarpoResult<Vec<SeedPhrase>> {
+    let lines = reader.lines().collect::<Result<Vec<String>, _>>()?;
+    // Check the share format version if it is recorded in the input.
+    if let Some(version) = lines
+        .iter()
+        .find_map(|line| parse_share_format_header(line))
+    {
+        check_share_format_version(version)?;
+    }
+    lines
+        .iter()
+        .filter(|line| !line.starts_with('#') && !line.is_empty())
+        .map(|line| {
+            parse_phrase(line).map_err(|_| {
+                HarpoError::InvalidSeedPhrase(
+                    "Encountered an invalid seed phrase in the file.".to_string(),
+                )
+            })
+        })
+        .collect()
+}
+
+/// The function returns true if a seed phrase with the given number of words carries an index
+/// word.
+///
+/// A seed phrase carries an index word if removing the last word results in a permissible
+/// number of words.
+///
+/// * `num_words` - The number of words.
+pub fn has_index_word(num_words: usize) -> bool {
+    num_words > 0 && is_valid_num_words(num_words - 1)
+}
+
+/// The function returns the checksum of the index word.
+///
+/// The checksum consists of the highest-order bits of the SHA-256 hash of the tag of the
+/// index word encoding, if any, the secret-bearing bytes, and the index.
+///
+/// * `bytes` - The bytes encoded in the secret-bearing words.
+/// * `index` - The index.
+/// * `tag` - The tag, which is `None` for the index words of the first versions.
+fn get_index_word_checksum(bytes: &[u8], index: u32, tag: Option<u32>) -> usize {
+    let mut hasher = Sha256::new();
+    // The tag of the index word encoding is bound to the checksum.
+    if let Some(tag) = tag {
+        hasher.update(tag.to_be_bytes());
+    }
+    hasher.update(bytes);
+    hasher.update(index.to_be_bytes());
+    let hash = hasher.finalize();
+    (hash[0] >> (8 - NUM_CHECKSUM_BITS_IN_INDEX_WORD)) as usize
+}
+
+/// The function returns the bytes encoded in the words of the given seed phrase without the
+/// hash bits.
+///
+/// * `seed_phrase` - The seed phrase.
+/// * `word_list` - The word list.
+fn get_used_bytes(seed_phrase: &SeedPhrase, word_list: &[&str]) -> HarpoResult<Vec<u8>> {
+    let index_list = Zeroizing::new(get_index_list(seed_phrase, word_list)?);
+    let mut bytes = get_bytes_from_indices(&index_list, get_num_bits_per_word(word_list));
+    // The number of bytes used to build the element is a multiple of 32 bits = 4 bytes.
+    let num_used_bytes = (bytes.len() >> 2) << 2;
+    bytes[num_used_bytes..].zeroize();
+    bytes.truncate(num_used_bytes);
+    Ok(bytes)
+}
+
+/// The function appends an index word to the given seed phrase.
+///
+/// The index word encodes the index and a checksum. The returned seed phrase does not have an
+/// explicit index.
+///
+/// * `seed_phrase` - The seed phrase.
+/// * `index` - The index to be encoded.
+/// * `word_list` - The word list.
+pub(crate) fn append_index_word(
+    seed_phrase: &SeedPhrase,
+    index: u32,
+    word_list: &[&str],
+) -> SeedPhraseResult {
+    if index < 1 || index as usize > MAX_INDEX_WORD_SHARES {
+        return Err(HarpoError::InvalidParameter(format!(
+            "The index must be between 1 and {} to be encoded in an index word.",
+            MAX_INDEX_WORD_SHARES
+        )));
+    }
+    let bytes = get_used_bytes(seed_phrase, word_list)?;
+    // Since the index is at least 1, we subtract 1 so that we can use one more index.
+    let word_index = (((index - 1) as usize) << NUM_CHECKSUM_BITS_IN_INDEX_WORD)
+        + get_index_word_checksum(&bytes, index, Some(INDEX_WORD_CHECKSUM_TAG));
+    let mut words: Vec<String> = seed_phrase
+        .get_words()
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+    words.push(word_list[word_index].to_string());
+    Ok(SeedPhrase::new(&words))
+}
+
+/// The function removes the index word from the given seed phrase.
+///
+/// The returned seed phrase consists of the secret-bearing words and carries the decoded
+/// index explicitly. Index words created by earlier versions of `harpo` are detected by their
+/// checksum, see [LEGACY_INDEX_WORD_CHECKSUM_TAGS]. An error is returned if the checksum does
+/// not match or if the decoded index contradicts an explicitly provided index.
+///
+/// * `seed_phrase` - The seed phrase including the index word.
+/// * `word_list` - The word list.
+pub(crate) fn remove_index_word(seed_phrase: &SeedPhrase, word_list: &[&str]) -> SeedPhraseResult {
+    let num_words = seed_phrase.len();
+    if !has_index_word(num_words) {
+        return Err(HarpoError::InvalidSeedPhrase(format!(
+            "A seed phrase with {} words does not have an index word.",
+            num_words
+        )));
+    }
+    let words: Vec<String> = seed_phrase.get_words()[..num_words - 1]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+    let index_word = seed_phrase.get_words()[num_words - 1];
+    let word_index = match get_index(index_word, word_list) {
+        Some(word_index) => word_index,
+        None => return Err(get_unknown_word_error(index_word, num_words - 1, word_list)),
+    };
+    // We add 1 because 1 was subtracted when encoding the index.
+    let index = ((word_index >> NUM_CHECKSUM_BITS_IN_INDEX_WORD) + 1) as u32;
+    let checksum = word_index % (1 << NUM_CHECKSUM_BITS_IN_INDEX_WORD);
+    let mut secret_seed_phrase = SeedPhrase::new_with_index(&words, index);
+    secret_seed_phrase.set_id = seed_phrase.set_id;
+    secret_seed_phrase.threshold = seed_phrase.threshold;
+    let bytes = get_used_bytes(&secret_seed_phrase, word_list)?;
+    // The checksums of earlier versions are accepted as well.
+    let is_valid_checksum = std::iter::once(Some(INDEX_WORD_CHECKSUM_TAG))
+        .chain(LEGACY_INDEX_WORD_CHECKSUM_TAGS)
+        .any(|tag| checksum == get_index_word_checksum(&bytes, index, tag));
+    if !is_valid_checksum {
+        return Err(HarpoError::ChecksumMismatch);
+    }
+    match seed_phrase.get_index() {
+        Some(explicit_index) if explicit_index != index => {
+            Err(HarpoError::InvalidSeedPhrase(format!(
+                "The explicit index {} does not match the index {} in the index word.",
+                explicit_index, index
+            )))
+        }
+        _ => Ok(secret_seed_phrase),
+    }
+}
+
+/// The function returns the index of a word in a word list, if any.
+///
+/// The function looks up the given word in the prefix tree of the given word list and returns
+/// the index in the list if it finds it. Otherwise, it returns `None`.
+/// The words are compared after NFKD normalization.
+///
+/// * `word` - The word that is looked up.
+/// * `word_list` - The list of words.
+fn get_index(word: &str, word_list: &[&str]) -> Option<usize> {
+    get_word_trie(word_list).get_index(word)
+}
+
+/// The function parses a seed phrase from the given string using the given parse mode.
+///
+/// All words must be in the provided word list. The number of words is not checked.
+///
+/// * `input` - The seed phrase as a string, optionally preceded by an explicit index.
+/// * `parse_mode` - The parse mode.
+/// * `word_list` - The word list.
+pub(crate) fn parse_seed_phrase(
+    input: &str,
+    parse_mode: ParseMode,
+    word_list: &[&str],
+) -> SeedPhraseResult {
+    let report =
+        parse_seed_phrase_with_typo_policy(input, parse_mode, TypoPolicy::Reject, word_list)?;
+    Ok(report.seed_phrase)
+}
+
+/// The function parses a seed phrase from the given string using the given parse mode and
+/// typo policy.
+///
+/// Words that are not in the word list are handled according to the typo policy. The
+/// returned report contains the seed phrase and all applied corrections.
+///
+/// * `input` - The seed phrase as a string, optionally preceded by an explicit index.
+/// * `parse_mode` - The parse mode.
+/// * `typo_policy` - The typo policy.
+/// * `word_list` - The word list.
+pub(crate) fn parse_seed_phrase_with_typo_policy(
+    input: &str,
+    parse_mode: ParseMode,
+    typo_policy: TypoPolicy,
+    word_list: &[&str],
+) -> HarpoResult<ParseReport> {
+    trace_span!("parse");
+    let input = match parse_mode {
+        ParseMode::Strict => input.to_string(),
+        ParseMode::Lenient => input.trim().to_lowercase(),
+    };
+    // The words are compared after NFKD normalization.
+    let input = normalize(&input).into_owned();
+    // Extract the explicit index, if any.
+    let (index, words_string) = match input.split_once(':') {
+        Some((index_string, words_string)) => {
+            let index_string = match parse_mode {
+                ParseMode::Strict => index_string,
+                ParseMode::Lenient => index_string.trim(),
+            };
+            let index_prefix = parse_index_prefix(index_string)?;
+            let words_string = match parse_mode {
+                ParseMode::Strict => words_string.strip_prefix(' ').ok_or_else(|| {
+                    HarpoError::InvalidSeedPhrase(
+                        "The index must be followed by a single space.".to_string(),
+                    )
+                })?,
+                ParseMode::Lenient => words_string,
+            };
+            (Some(index_prefix), words_string)
         }
+        None => (None, input.as_str()),
+    };
+    let trie = get_word_trie(word_list);
+    let tokens = match parse_mode {
+        ParseMode::Strict => get_strict_tokens(words_string)?,
+        ParseMode::Lenient => segment_tokens_with_trie(get_lenient_tokens(words_string), &trie)?,
+    };
+    if tokens.is_empty() {
+        return Err(HarpoError::InvalidSeedPhrase(
+            "No seed phrase provided.".to_string(),
+        ));
     }
-
-    /// The function returns the number of words that make up the seed phrase.
-    pub fn len(&self) -> usize {
-        self.words.len()
-    }
-
-    /// The function returns true if the seed phrase is empty.
-    pub fn is_empty(&self) -> bool {
-        self.words.len() == 0
+    let mut words = vec![];
+    let mut corrections = vec![];
+    for (position, token) in tokens.into_iter().enumerate() {
+        let add_position =
+            |error: HarpoError| error.with_context(ErrorContext::WordPosition(position));
+        let word = match resolve_word(&token, parse_mode, &trie, word_list).map_err(add_position)? {
+            Some(word) => word,
+            None => {
+                let word = correct_word(&token, position, typo_policy, word_list)?;
+                corrections.push(WordCorrection {
+                    position,
+                    original: token,
+                    corrected: word.clone(),
+                });
+                word
+            }
+        };
+        words.push(word);
     }
+    trace_event!(
+        "parse.result",
+        "num_words" => words.len(),
+        "num_corrections" => corrections.len(),
+    );
+    let seed_phrase = match index {
+        Some(index_prefix) => index_prefix.get_seed_phrase(&words),
+        None => SeedPhrase::new(&words),
+    };
+    Ok(ParseReport {
+        seed_phrase,
+        corrections,
+    })
+}
 
-    /// The function returns the words that make up the seed phrase.
-    pub fn get_words(&self) -> Vec<&str> {
-        self.words.iter().map(|s| s.as_str()).collect()
+/// The function returns the tokens of a strictly formatted string.
+///
+/// The tokens must be separated by single spaces.
+///
+/// * `input` - The space-delimited words.
+fn get_strict_tokens(input: &str) -> HarpoResult<Vec<String>> {
+    let mut tokens = vec![];
+    for token in input.split(' ') {
+        if token.is_empty() {
+            return Err(HarpoError::InvalidSeedPhrase(
+                "The words must be separated by single spaces.".to_string(),
+            ));
+        }
+        tokens.push(token.to_string());
     }
+    Ok(tokens)
+}
 
-    /// The function returns the index of the seed phrase, if any.
-    pub fn get_index(&self) -> Option<u32> {
-        self.index
-    }
+/// The function returns the tokens of a string with punctuation removed.
+///
+/// * `input` - The words, delimited by white space.
+fn get_lenient_tokens(input: &str) -> Vec<String> {
+    input
+        .split_whitespace()
+        .map(|token| {
+            token
+                .chars()
+                .filter(|c| !c.is_ascii_punctuation())
+                .collect::<String>()
+        })
+        .filter(|token| !token.is_empty())
+        .collect()
+}
 
-    /// The function returns the security level of the seed phrase in bits.
-    pub fn get_num_bits(&self) -> usize {
-        // The number of security bits is the total number of bits rounded down to the
-        // nearest multiple of 'ENTROPY_INCREMENT'.
-        ((self.words.len() * NUM_BITS_PER_WORD) / ENTROPY_INCREMENT) * ENTROPY_INCREMENT
-    }
+/// The function splits tokens that consist of several concatenated words into these words.
+///
+/// Only tokens that are not in the word list and contain non-ASCII characters are considered,
+/// which covers Chinese and Japanese seed phrases written without spaces. Tokens that cannot be
+/// segmented are returned unchanged.
+///
+/// * `tokens` - The tokens.
+/// * `word_list` - The word list.
+pub(crate) fn segment_tokens(tokens: Vec<String>, word_list: &[&str]) -> HarpoResult<Vec<String>> {
+    segment_tokens_with_trie(tokens, &get_word_trie(word_list))
 }
 
-impl Clone for SeedPhrase {
-    /// The function defines how a seed phrase is cloned.
-    fn clone(&self) -> SeedPhrase {
-        SeedPhrase {
-            words: self.words.clone(),
-            index: self.index,
+/// The function splits tokens that consist of several concatenated words into these words using
+/// the prefix tree of the word list, see [segment_tokens].
+///
+/// * `tokens` - The tokens.
+/// * `trie` - The prefix tree of the word list.
+fn segment_tokens_with_trie(tokens: Vec<String>, trie: &WordTrie) -> HarpoResult<Vec<String>> {
+    let mut segmented_tokens = vec![];
+    for token in tokens {
+        if token.is_ascii() || trie.get_index(&token).is_some() {
+            segmented_tokens.push(token);
+            continue;
+        }
+        match segment_token(&token, trie)? {
+            Some(words) => segmented_tokens.extend(words),
+            None => segmented_tokens.push(token),
         }
     }
+    Ok(segmented_tokens)
 }
 
-impl fmt::Display for SeedPhrase {
-    /// A seed phrase is displayed as a space-delimited string.
-    /// If it has an associated index, the index followed by a colon is prepended to the
-    /// list of words.
-    ///
-    /// * `formatter` - The formatter.
-    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let mut words_with_spaces = String::new();
-        // Create a space-delimited string of all words.
-        for index in 0..(self.words.len() - 1) {
-            words_with_spaces.push_str(&self.words[index]);
-            words_with_spaces.push(' ');
+/// The function returns the unique segmentation of the given token into words of the word list.
+///
+/// `None` is returned if there is no segmentation. An error is returned if there is more than
+/// one segmentation because the words cannot be determined unambiguously.
+///
+/// * `token` - The token.
+/// * `trie` - The prefix tree of the word list.
+fn segment_token(token: &str, trie: &WordTrie) -> HarpoResult<Option<Vec<String>>> {
+    // For each byte offset, the number of segmentations of the remainder of the token (capped
+    // at 2) and the length of the first word of a segmentation are stored.
+    let mut num_segmentations = vec![0usize; token.len() + 1];
+    let mut word_lengths = vec![0usize; token.len() + 1];
+    num_segmentations[token.len()] = 1;
+    for offset in (0..token.len()).rev() {
+        if !token.is_char_boundary(offset) {
+            continue;
         }
-        words_with_spaces.push_str(&self.words[self.words.len() - 1]);
-        // If there is an index, prepend it.
-        match self.index {
-            Some(index) => write!(formatter, "{}: {}", index, words_with_spaces),
-            None => write!(formatter, "{}", words_with_spaces),
+        for word_length in trie.get_prefix_word_lengths(&token[offset..]) {
+            let count = num_segmentations[offset + word_length];
+            if count > 0 {
+                num_segmentations[offset] = (num_segmentations[offset] + count).min(2);
+                word_lengths[offset] = word_length;
+            }
         }
     }
-}
-
-impl PartialEq for SeedPhrase {
-    /// Equality of two seed phrases is defined based on the words that make up the seed phrases.
-    ///
-    /// * `other` - The other seed phrase.
-    fn eq(&self, other: &Self) -> bool {
-        self.words == other.words
+    match num_segmentations[0] {
+        0 => Ok(None),
+        1 => {
+            let mut words = vec![];
+            let mut offset = 0;
+            while offset < token.len() {
+                let end = offset + word_lengths[offset];
+                words.push(token[offset..end].to_string());
+                offset = end;
+            }
+            Ok(Some(words))
+        }
+        _ => Err(HarpoError::InvalidSeedPhrase(format!(
+            "The words in '{}' cannot be separated unambiguously. Separate the words using spaces.",
+            token
+        ))),
     }
 }
 
-/// The function returns a random seed phrase.
+/// The function returns the word in the word list that corresponds to the given token, if any.
 ///
-/// The function generates a random, BIP-0039-compliant seed phrase with the desired number
-/// of words taken from the provided word list.
-/// * `num_words` - The requested number of words in the random seed phrase.
-/// * `word-list` - The word list.
-pub(crate) fn get_random_seed_phrase(num_words: usize, word_list: &[&str]) -> SeedPhraseResult {
-    if num_words % 3 != 0 || num_words < 12 || num_words > 24 {
-        return Err(HarpoError::InvalidParameter(
-            "The number of words must be 12, 15, 18, 21, or 24.".to_string(),
-        ));
+/// In strict mode, the token must match a word exactly. In lenient mode, the token may also be
+/// a prefix of a unique word in the word list. An error is returned if the prefix is ambiguous.
+///
+/// * `token` - The token.
+/// * `parse_mode` - The parse mode.
+/// * `trie` - The prefix tree of the word list.
+/// * `word_list` - The word list.
+fn resolve_word(
+    token: &str,
+    parse_mode: ParseMode,
+    trie: &WordTrie,
+    word_list: &[&str],
+) -> HarpoResult<Option<String>> {
+    // An exact match takes precedence over prefix matches. The word is returned as it appears
+    // in the word list, which may differ from the token in its normalization.
+    if let Some(index) = trie.get_index(token) {
+        return Ok(Some(word_list[index].to_string()));
     }
-    // Determine the number of bits based on the number of words.
-    let num_bits = ((num_words * NUM_BITS_PER_WORD) / ENTROPY_INCREMENT) * ENTROPY_INCREMENT;
-    match get_modulus_for_words(num_words) {
-        Some(modulus) => {
-            // Create a random finite field element.
-            let element = FiniteFieldElement::new_random(num_bits, &modulus);
-            // Return the seed phrase derived from this element.
-            get_seed_phrase_for_element(&element, word_list)
+    if parse_mode == ParseMode::Strict {
+        return Ok(None);
+    }
+    match trie.get_num_completions(token) {
+        0 => Ok(None),
+        1 => Ok(Some(word_list[trie.get_completions(token)[0]].to_string())),
+        num_completions => {
+            let candidates: Vec<&str> = trie
+                .get_completions(token)
+                .into_iter()
+                .take(MAX_LISTED_COMPLETIONS)
+                .map(|index| word_list[index])
+                .collect();
+            let ellipsis = if num_completions > MAX_LISTED_COMPLETIONS {
+                ", ..."
+            } else {
+                ""
+            };
+            Err(HarpoError::InvalidSeedPhrase(format!(
+                "The abbreviation '{}' matches more than one word ({}{}).",
+                token,
+                candidates.join(", "),
+                ellipsis
+            )))
         }
-        None => Err(HarpoError::InvalidSeedPhrase(
-            "Could not generate a seed phrase.".to_string(),
-        )),
     }
 }
 
-/// The function returns the index of a word in a word list, if any.
+/// The function handles a token that is not in the word list according to the typo policy.
 ///
-/// The function searches for the given word in the given word list and returns the index
-/// in the list if it finds it. Otherwise, it returns `None`.
+/// If the typo policy permits automatic correction and there is exactly one word in the word
+/// list that differs from the token by a single typo, this word is returned.
+/// Otherwise, an error is returned, which contains suggestions unless the policy is
+/// [TypoPolicy::Reject](crate::seed_phrase::TypoPolicy::Reject).
 ///
-/// * `word` - The word that is looked up.
-/// * `word_list` - The list of words.
-fn get_index(word: &str, word_list: &[&str]) -> Option<usize> {
-    // Use a standard binary search to look for the word if it is the English word list.
-    // Otherwise, a linear search is used because string comparison fails when words contain
-    // diacritics.
-    if word_list[0] == DEFAULT_WORD_LIST[0] {
-        let mut left = 0;
-        let mut right = word_list.len() - 1;
-        while left <= right {
-            let mid = ((left + right) / 2) as usize;
-            match word_list[mid] {
-                w if w == word => return Some(mid),
-                w if w < word => left = mid + 1,
-                _ => right = mid - 1,
-            };
-        }
+/// * `token` - The token that is not in the word list.
+/// * `position` - The position of the token in the seed phrase.
+/// * `typo_policy` - The typo policy.
+/// * `word_list` - The word list.
+fn correct_word(
+    token: &str,
+    position: usize,
+    typo_policy: TypoPolicy,
+    word_list: &[&str],
+) -> HarpoResult<String> {
+    let suggestions = match typo_policy {
+        TypoPolicy::Reject => vec![],
+        _ => get_word_suggestions(token, word_list),
+    };
+    if typo_policy == TypoPolicy::AutoCorrect && suggestions.len() == 1 {
+        return Ok(suggestions[0].to_string());
+    }
+    Err(HarpoError::UnknownWord {
+        word: token.to_string(),
+        position,
+        suggestions: suggestions
+            .into_iter()
+            .take(MAX_WORD_SUGGESTIONS)
+            .map(str::to_string)
+            .collect(),
+    })
+}
+
+/// The function returns all words in the word list that differ from the given token by a single
+/// typo, i.e., by a substitution, insertion, or deletion of one character or by swapping two
+/// adjacent characters.
+///
+/// * `token` - The token.
+/// * `word_list` - The word list.
+pub(crate) fn get_word_suggestions<'a>(token: &str, word_list: &[&'a str]) -> Vec<&'a str> {
+    word_list
+        .iter()
+        .filter(|word| is_single_typo(token, word))
+        .copied()
+        .collect()
+}
+
+/// The function returns true if the two given strings differ by a single typo, i.e., if their
+/// Damerau-Levenshtein distance is 1.
+///
+/// * `first` - The first string.
+/// * `second` - The second string.
+fn is_single_typo(first: &str, second: &str) -> bool {
+    let first: Vec<char> = first.chars().collect();
+    let second: Vec<char> = second.chars().collect();
+    // Ensure that the first string is not longer than the second string.
+    let (shorter, longer) = if first.len() <= second.len() {
+        (first, second)
     } else {
-        for (index, word_at_index) in word_list.iter().enumerate() {
-            if word_at_index == &word {
-                return Some(index);
-            }
+        (second, first)
+    };
+    // Skip the common prefix.
+    let prefix_length = shorter
+        .iter()
+        .zip(longer.iter())
+        .take_while(|(a, b)| a == b)
+        .count();
+    match longer.len() - shorter.len() {
+        // A substitution or a transposition of adjacent characters: the remainders after the
+        // differing characters must match.
+        0 => {
+            prefix_length < shorter.len()
+                && (shorter[prefix_length + 1..] == longer[prefix_length + 1..]
+                    || (prefix_length + 1 < shorter.len()
+                        && shorter[prefix_length] == longer[prefix_length + 1]
+                        && shorter[prefix_length + 1] == longer[prefix_length]
+                        && shorter[prefix_length + 2..] == longer[prefix_length + 2..]))
         }
+        // An insertion or deletion: the longer string has one additional character.
+        1 => shorter[prefix_length..] == longer[prefix_length + 1..],
+        _ => false,
     }
-    None
 }
 
 /// The function returns the finite field element corresponding to the given seed phrase.
@@ -211,28 +1801,106 @@ pub(crate) fn get_element_for_seed_phrase(
 /// * `word_list` - The word list.
 fn get_index_list(seed_phrase: &SeedPhrase, word_list: &[&str]) -> HarpoResult<Vec<usize>> {
     // Verify that the seed phrase has a permissible number of words.
-    let num_words = seed_phrase.len();
-    if num_words % 3 != 0 || num_words < 12 || num_words > 24 {
-        return Err(HarpoError::InvalidParameter(
-            "The number of words must be 12, 15, 18, 21, or 24.".to_string(),
-        ));
+    if !is_valid_num_words(seed_phrase.len()) {
+        return Err(HarpoError::WrongWordCount {
+            got: seed_phrase.len(),
+        });
     }
+    let trie = get_word_trie(word_list);
     let mut index_list: Vec<usize> = vec![];
     // Iterate through all the words and get the index, if available.
-    for word in seed_phrase.get_words() {
-        match get_index(word, word_list) {
+    for (position, word) in seed_phrase.get_words().into_iter().enumerate() {
+        match trie.get_index(word) {
             Some(index) => index_list.push(index),
-            None => {
-                return Err(HarpoError::InvalidSeedPhrase(format!(
-                    "Invalid word in the seed phrase: {}",
-                    word
-                )))
-            }
+            None => return Err(get_unknown_word_error(word, position, word_list)),
         };
     }
     Ok(index_list)
 }
 
+/// The function returns the detailed validation result of the given seed phrase.
+///
+/// * `seed_phrase` - The seed phrase.
+/// * `word_list` - The word list.
+pub(crate) fn diagnose_seed_phrase(
+    seed_phrase: &SeedPhrase,
+    word_list: &[&str],
+) -> SeedPhraseDiagnostics {
+    let trie = get_word_trie(word_list);
+    let unknown_words: Vec<UnknownWord> = seed_phrase
+        .get_words()
+        .into_iter()
+        .enumerate()
+        .filter(|(_, word)| trie.get_index(word).is_none())
+        .map(|(position, word)| UnknownWord {
+            position,
+            word: word.to_string(),
+            suggestions: get_word_suggestions(word, word_list)
+                .into_iter()
+                .take(MAX_WORD_SUGGESTIONS)
+                .map(str::to_string)
+                .collect(),
+        })
+        .collect();
+    let valid_checksum = if unknown_words.is_empty() && is_valid_num_words(seed_phrase.len()) {
+        Some(is_compliant(seed_phrase, word_list))
+    } else {
+        None
+    };
+    SeedPhraseDiagnostics {
+        num_words: seed_phrase.len(),
+        unknown_words,
+        valid_checksum,
+    }
+}
+
+/// The function returns an error describing the first problem that prevents the BIP-0039
+/// compliance of the seed phrase for the given word list, if any.
+///
+/// Unknown words are reported first, followed by an invalid number of words and a checksum
+/// mismatch.
+///
+/// * `seed_phrase` - The seed phrase.
+/// * `word_list` - The word list.
+pub(crate) fn check_compliance(seed_phrase: &SeedPhrase, word_list: &[&str]) -> HarpoResult<()> {
+    if is_compliant(seed_phrase, word_list) {
+        return Ok(());
+    }
+    let diagnostics = diagnose_seed_phrase(seed_phrase, word_list);
+    if let Some(unknown_word) = diagnostics.unknown_words.first() {
+        return Err(HarpoError::UnknownWord {
+            word: unknown_word.word.clone(),
+            position: unknown_word.position,
+            suggestions: unknown_word.suggestions.clone(),
+        });
+    }
+    if !diagnostics.has_valid_num_words() {
+        return Err(HarpoError::WrongWordCount {
+            got: diagnostics.num_words,
+        });
+    }
+    Err(HarpoError::ChecksumMismatch)
+}
+
+/// The function returns the error for a word that is not in the given word list.
+///
+/// The error contains the words in the word list that differ by a single typo.
+///
+/// * `word` - The unknown word.
+/// * `position` - The position of the word in the seed phrase.
+/// * `word_list` - The word list.
+fn get_unknown_word_error(word: &str, position: usize, word_list: &[&str]) -> HarpoError {
+    HarpoError::UnknownWord {
+        word: word.to_string(),
+        position,
+        suggestions: get_word_suggestions(word, word_list)
+            .into_iter()
+            .take(MAX_WORD_SUGGESTIONS)
+            .map(str::to_string)
+            .collect(),
+    }
+}
+
 /// The function checks BIP-0039 compliance of the seed phrase for the given word list.
 ///
 /// For the given word list, the function checks whether the last word is the expected word
@@ -244,7 +1912,7 @@ pub(crate) fn is_compliant(seed_phrase: &SeedPhrase, word_list: &[&str]) -> bool
     // The words are mapped to their indices in the word list.
     match get_index_list(seed_phrase, word_list) {
         Ok(index_list) => {
-            let bytes = get_bytes_from_indices(&index_list);
+            let bytes = get_bytes_from_indices(&index_list, get_num_bits_per_word(word_list));
             // The number of bytes used to build the element is a multiple of 32 bits = 4 bytes.
             let num_used_bytes = (bytes.len() >> 2) << 2;
             let mut used_bytes: Vec<u8> = vec![0; num_used_bytes];
@@ -254,7 +1922,8 @@ pub(crate) fn is_compliant(seed_phrase: &SeedPhrase, word_list: &[&str]) -> bool
             hasher.update(&used_bytes);
             let hash = hasher.finalize();
             let num_words = seed_phrase.len();
-            let num_hash_bits = NUM_BITS_PER_WORD * num_words - (num_used_bytes << 3);
+            let num_hash_bits =
+                get_num_bits_per_word(word_list) * num_words - (num_used_bytes << 3);
             let num_zero_bits = 8 - num_hash_bits;
             // Set the unused bits to zero.
             let hash_byte = (hash[0] >> num_zero_bits) << num_zero_bits;
@@ -265,6 +1934,138 @@ pub(crate) fn is_compliant(seed_phrase: &SeedPhrase, word_list: &[&str]) -> bool
     }
 }
 
+/// The function checks the remaining checksum bits of a seed phrase with an embedded index.
+///
+/// The embedded index replaces the 4 highest-order bits of the BIP-0039 checksum. Seed phrases
+/// with more than 12 words have additional checksum bits, which are checked by this function.
+/// For 12-word seed phrases, there are no remaining checksum bits and the function always
+/// returns true if all words are in the word list.
+///
+/// * `seed_phrase` - The seed phrase.
+/// * `word_list` - The word list.
+fn has_valid_embedded_checksum(seed_phrase: &SeedPhrase, word_list: &[&str]) -> bool {
+    match get_index_list(seed_phrase, word_list) {
+        Ok(index_list) => {
+            let bytes = get_bytes_from_indices(&index_list, get_num_bits_per_word(word_list));
+            let num_used_bytes = (bytes.len() >> 2) << 2;
+            let mut hasher = Sha256::new();
+            hasher.update(&bytes[0..num_used_bytes]);
+            let hash = hasher.finalize();
+            let num_hash_bits =
+                get_num_bits_per_word(word_list) * seed_phrase.len() - (num_used_bytes << 3);
+            // The mask selects the hash bits that are present and not used for the index.
+            let hash_mask = (0xff_u8 << (8 - num_hash_bits)) & (0xff_u8 >> NUM_BITS_FOR_INDEX);
+            (bytes[num_used_bytes] & hash_mask) == (hash[0] & hash_mask)
+        }
+        Err(_) => false,
+    }
+}
+
+/// The function checks the internal consistency of a secret-shared seed phrase.
+///
+/// Depending on the index encoding, the function checks the index word checksum, the BIP-0039
+/// compliance of seed phrases with explicit indices, or the remaining checksum bits of seed
+/// phrases with embedded indices. An error describing the problem is returned if the seed
+/// phrase appears to be mistyped.
+///
+/// * `seed_phrase` - The secret-shared seed phrase.
+/// * `word_list` - The word list.
+pub(crate) fn check_secret_shared_seed_phrase(
+    seed_phrase: &SeedPhrase,
+    word_list: &[&str],
+) -> HarpoResult<()> {
+    if has_index_word(seed_phrase.len()) {
+        let secret_seed_phrase = remove_index_word(seed_phrase, word_list)?;
+        if !is_compliant(&secret_seed_phrase, word_list) {
+            return Err(HarpoError::InvalidSeedPhrase(
+                "The words before the index word are not BIP-0039-compliant.".to_string(),
+            ));
+        }
+        return Ok(());
+    }
+    // Check that the number of words is valid and all words are in the word list.
+    get_index_list(seed_phrase, word_list)?;
+    if seed_phrase.get_index().is_some() {
+        if !is_compliant(seed_phrase, word_list) {
+            return Err(HarpoError::InvalidSeedPhrase(
+                "The seed phrase with an explicit index is not BIP-0039-compliant.".to_string(),
+            ));
+        }
+    } else if !can_embed_index(seed_phrase.len()) {
+        return Err(HarpoError::InvalidSeedPhrase(format!(
+            "Seed phrases with {} words must be provided with an explicit index.",
+            seed_phrase.len()
+        )));
+    } else if !has_valid_embedded_checksum(seed_phrase, word_list) {
+        return Err(HarpoError::ChecksumMismatch);
+    }
+    Ok(())
+}
+
+/// The function returns the validation result for the given secret-shared seed phrase.
+///
+/// The position of duplicates is not set by this function.
+///
+/// * `seed_phrase` - The secret-shared seed phrase.
+/// * `word_list` - The word list.
+pub(crate) fn validate_share(seed_phrase: &SeedPhrase, word_list: &[&str]) -> ShareValidation {
+    let num_words = seed_phrase.len();
+    let index_encoding = if has_index_word(num_words) {
+        Some(IndexEncoding::IndexWord)
+    } else if seed_phrase.get_index().is_some() {
+        Some(IndexEncoding::Explicit)
+    } else if can_embed_index(num_words) {
+        Some(IndexEncoding::Embedded)
+    } else {
+        None
+    };
+    // Get the secret-bearing seed phrase with an explicit index, if possible.
+    let secret_seed_phrase = match index_encoding {
+        Some(IndexEncoding::IndexWord) => remove_index_word(seed_phrase, word_list).ok(),
+        Some(IndexEncoding::Explicit) => Some(seed_phrase.clone()),
+        Some(IndexEncoding::Embedded) => {
+            get_element_and_index_for_seed_phrase(seed_phrase, word_list)
+                .ok()
+                .map(|(_, index)| {
+                    let words: Vec<String> = seed_phrase
+                        .get_words()
+                        .iter()
+                        .map(|s| s.to_string())
+                        .collect();
+                    SeedPhrase::new_with_index(&words, index)
+                })
+        }
+        None => None,
+    };
+    let index = secret_seed_phrase
+        .as_ref()
+        .and_then(|secret_seed_phrase| secret_seed_phrase.get_index());
+    let fingerprint = secret_seed_phrase.as_ref().and_then(|secret_seed_phrase| {
+        let bytes = get_used_bytes(secret_seed_phrase, word_list).ok()?;
+        let mut hasher = Sha256::new();
+        hasher.update(b"harpo share fingerprint");
+        hasher.update(&bytes);
+        hasher.update(secret_seed_phrase.get_index()?.to_be_bytes());
+        let hash = hasher.finalize();
+        Some(
+            hash[..4]
+                .iter()
+                .map(|byte| format!("{:02x}", byte))
+                .collect::<String>(),
+        )
+    });
+    ShareValidation {
+        num_words,
+        index_encoding,
+        index,
+        error: check_secret_shared_seed_phrase(seed_phrase, word_list)
+            .err()
+            .map(|error| error.to_string()),
+        fingerprint,
+        duplicate_of: None,
+    }
+}
+
 /// The function returns the finite field element and index encoded in the given seed phrase.
 ///
 /// Given a seed phrase and a word list, the words are turned into numbers, corresponding to their
@@ -279,11 +2080,15 @@ pub(crate) fn get_element_and_index_for_seed_phrase(
     word_list: &[&str],
 ) -> HarpoResult<(FiniteFieldElement, u32)> {
     // The words are mapped to their indices in the word list and then converted into a byte array.
-    let index_list = get_index_list(seed_phrase, word_list)?;
-    let bytes = get_bytes_from_indices(&index_list);
+    // The intermediate buffers are wiped when they are dropped.
+    let index_list = Zeroizing::new(get_index_list(seed_phrase, word_list)?);
+    let bytes = Zeroizing::new(get_bytes_from_indices(
+        &index_list,
+        get_num_bits_per_word(word_list),
+    ));
     // The number of bytes used to build the element is a multiple of 32 bits = 4 bytes.
     let num_used_bytes = (bytes.len() >> 2) << 2;
-    let mut used_bytes: Vec<u8> = vec![0; num_used_bytes];
+    let mut used_bytes = Zeroizing::new(vec![0; num_used_bytes]);
     used_bytes.clone_from_slice(&bytes[0..num_used_bytes]);
     let num_words = seed_phrase.len();
     // Get the modulus. Calling unwrap() is okay here because the number of words is checked
@@ -296,53 +2101,52 @@ pub(crate) fn get_element_and_index_for_seed_phrase(
         // We add 1 because 1 was subtracted when encoding the index.
         ((bytes[num_used_bytes] >> (8 - NUM_BITS_FOR_INDEX)) + 1) as u32
     };
-    Ok((FiniteFieldElement::new(&bytes, &modulus), index))
+    Ok((FiniteFieldElement::new(&used_bytes, modulus), index))
+}
+
+/// The function returns the number of bits that each word of the given word list represents.
+///
+/// The number of bits is the base-2 logarithm of the size of the word list, rounded down.
+/// For example, each word of a BIP-0039 word list with 2048 words represents 11 bits, whereas
+/// each word of a SLIP-0039 word list with 1024 words represents 10 bits.
+///
+/// * `word_list` - The word list.
+pub(crate) fn get_num_bits_per_word(word_list: &[&str]) -> usize {
+    word_list.len().checked_ilog2().unwrap_or(0) as usize
 }
 
 /// The function encodes the given indices in a byte array.
 ///
-/// The indices are encoded in the byte array according to the BIP-0039 specification.
+/// Each index is encoded using `num_bits_per_word` bits, starting with the highest-order bit.
+/// The indices are concatenated and the last byte is padded with zeros, which corresponds to
+/// the BIP-0039 specification for 11 bits per word.
 ///
 /// * `indices` - The array of indices.
-fn get_bytes_from_indices(indices: &[usize]) -> Vec<u8> {
+/// * `num_bits_per_word` - The number of bits per index.
+fn get_bytes_from_indices(indices: &[usize], num_bits_per_word: usize) -> Vec<u8> {
     // Round the number of bytes up so that there is space for all indices.
-    let size = (indices.len() * NUM_BITS_PER_WORD + 7) / 8;
-    let mut bytes: Vec<u8> = vec![0; size];
-    // The number of used bits in the current byte.
-    let mut num_used_bits = 0;
-    // The index of the current byte.
-    let mut current_index = 0;
+    let size = (indices.len() * num_bits_per_word).div_ceil(8);
+    let mut bytes: Vec<u8> = Vec::with_capacity(size);
+    // The bits that have not been written to a byte yet.
+    let mut buffer: usize = 0;
+    // The number of bits in the buffer, which is always less than 8 between indices.
+    let mut num_buffered_bits = 0;
     // Iterate over all indices.
     for index in indices {
-        // Determine the number of bits spread over two or three bytes.
-        let num_bits_first_byte = 8 - num_used_bits;
-        let num_bits_second_byte = cmp::min(8, 11 - num_bits_first_byte);
-        let num_bits_third_byte = cmp::max(0, 11 - num_bits_first_byte - num_bits_second_byte);
-        // Compute the part for the first byte.
-        let first_byte_part = (index >> (11 - num_bits_first_byte)) as u8;
-        bytes[current_index] += first_byte_part;
-        current_index += 1;
-        // Compute the part for the second byte.
-        let second_byte_part = ((index >> num_bits_third_byte) % (1 << num_bits_second_byte)) as u8;
-        bytes[current_index] = second_byte_part << (8 - num_bits_second_byte);
-        // Check if there are remaining bits for the third byte.
-        if num_bits_third_byte > 0 {
-            current_index += 1;
-            // The third part consists of the `num_bits_third_byte` lowest-order bits.
-            let third_byte_part = (index % (1 << num_bits_third_byte)) as u8;
-            // These bits are placed in the highest-order positions.
-            bytes[current_index] = third_byte_part << (8 - num_bits_third_byte);
-            num_used_bits = num_bits_third_byte;
-        } else if num_bits_second_byte == 8 {
-            // If the index exactly consumes all bits of the second byte,
-            // the index is increased as the byte is full.
-            current_index += 1;
-            num_used_bits = 0;
-        } else {
-            // Otherwise, the number of used bits is the number of bits written to the
-            // second byte.
-            num_used_bits = num_bits_second_byte;
+        // Append the bits of the index to the buffer.
+        buffer = (buffer << num_bits_per_word) | (index % (1 << num_bits_per_word));
+        num_buffered_bits += num_bits_per_word;
+        // Write all full bytes.
+        while num_buffered_bits >= 8 {
+            num_buffered_bits -= 8;
+            bytes.push((buffer >> num_buffered_bits) as u8);
         }
+        // Only keep the bits that have not been written yet.
+        buffer %= 1 << num_buffered_bits;
+    }
+    // The remaining bits are placed in the highest-order positions of the last byte.
+    if num_buffered_bits > 0 {
+        bytes.push((buffer << (8 - num_buffered_bits)) as u8);
     }
     // Return the byte array.
     bytes
@@ -384,14 +2188,16 @@ pub(crate) fn get_seed_phrase_for_element_with_embedding(
             "No index is provided to embed in the seed phrase.".into(),
         ));
     }
-    let bytes = element.get_bytes();
+    // The intermediate buffers are wiped when they are dropped.
+    let bytes = Zeroizing::new(element.get_bytes());
     let mut hasher = Sha256::new();
     hasher.update(&bytes);
     let hash = hasher.finalize();
     // Create the bytes with bits of the hash appended.
-    let num_words = ((bytes.len() << 3) + NUM_BITS_PER_WORD - 1) / NUM_BITS_PER_WORD;
-    let total_num_bits = num_words * NUM_BITS_PER_WORD;
-    let mut encoded_words = vec![0; (total_num_bits + 7) >> 3];
+    let num_bits_per_word = get_num_bits_per_word(word_list);
+    let num_words = (bytes.len() << 3).div_ceil(num_bits_per_word);
+    let total_num_bits = num_words * num_bits_per_word;
+    let mut encoded_words = Zeroizing::new(vec![0; (total_num_bits + 7) >> 3]);
     encoded_words[..bytes.len()].clone_from_slice(&bytes[..]);
     // When embedding the index of the seed phrase, it is placed in the 4 higher-order bits
     // of the byte that holds the first byte of the hash.
@@ -405,11 +2211,17 @@ pub(crate) fn get_seed_phrase_for_element_with_embedding(
         hash[0]
     };
     // Retrieve the indices from the given byte array and turn them into words.
-    let indices = get_indices_from_bytes(&encoded_words, num_words)?;
-    let words: Vec<String> = indices
-        .iter()
-        .map(|index| word_list[*index].to_string())
-        .collect();
+    let indices = Zeroizing::new(get_indices_from_bytes(
+        &encoded_words,
+        num_words,
+        num_bits_per_word,
+    )?);
+    let words = Zeroizing::new(
+        indices
+            .iter()
+            .map(|index| word_list[*index].to_string())
+            .collect::<Vec<String>>(),
+    );
     if !embed_index {
         // If the index is not embedded but there is an index, it must be provided explicitly.
         match index {
@@ -421,49 +2233,87 @@ pub(crate) fn get_seed_phrase_for_element_with_embedding(
     }
 }
 
+/// The function converts the given entropy into a BIP-0039-compliant seed phrase.
+///
+/// Unlike [get_seed_phrase_for_element](crate::seed_phrase::get_seed_phrase_for_element), the
+/// entropy is not required to be an element of the finite field for the corresponding number of
+/// words, which is needed for entropy obtained from other sources.
+///
+/// * `entropy` - The entropy, which must consist of 16, 20, 24, 28, or 32 bytes.
+/// * `word_list` - The word list.
+pub(crate) fn get_seed_phrase_for_entropy(entropy: &[u8], word_list: &[&str]) -> SeedPhraseResult {
+    if entropy.is_empty()
+        || entropy.len() > 32
+        || !(entropy.len() << 3).is_multiple_of(ENTROPY_INCREMENT)
+    {
+        return Err(HarpoError::InvalidParameter(format!(
+            "The entropy length of {} bytes is not supported.",
+            entropy.len()
+        )));
+    }
+    let mut hasher = Sha256::new();
+    hasher.update(entropy);
+    let hash = hasher.finalize();
+    // The first byte of the hash contains all checksum bits.
+    // The intermediate buffers are wiped when they are dropped.
+    let mut encoded_words = Zeroizing::new(Vec::with_capacity(entropy.len() + 1));
+    encoded_words.extend_from_slice(entropy);
+    encoded_words.push(hash[0]);
+    let num_bits_per_word = get_num_bits_per_word(word_list);
+    let num_words = (entropy.len() << 3).div_ceil(num_bits_per_word);
+    let indices = Zeroizing::new(get_indices_from_bytes(
+        &encoded_words,
+        num_words,
+        num_bits_per_word,
+    )?);
+    let words = Zeroizing::new(
+        indices
+            .iter()
+            .map(|index| word_list[*index].to_string())
+            .collect::<Vec<String>>(),
+    );
+    Ok(SeedPhrase::new(&words))
+}
+
 /// The function returns the indices encoded in the given byte array.
 ///
-/// The indices of the words are retrieved based on the BIP-0039 specification.
+/// Each index is encoded using `num_bits_per_word` bits, starting with the highest-order bit,
+/// which corresponds to the BIP-0039 specification for 11 bits per word.
 ///
 /// * `bytes` - The given byte array
 /// * `num_words` - The number of encoded words.
-fn get_indices_from_bytes(bytes: &[u8], num_words: usize) -> HarpoResult<Vec<usize>> {
-    let mut current_index: usize = 0;
-    let mut read_bits = 0;
-    let mut indices = vec![];
+/// * `num_bits_per_word` - The number of bits per index.
+fn get_indices_from_bytes(
+    bytes: &[u8],
+    num_words: usize,
+    num_bits_per_word: usize,
+) -> HarpoResult<Vec<usize>> {
+    if num_bits_per_word == 0 || bytes.len() << 3 < num_words * num_bits_per_word {
+        return Err(HarpoError::InvalidSeedPhrase(
+            "Error parsing indices from byte array.".to_string(),
+        ));
+    }
+    let mut indices = Vec::with_capacity(num_words);
+    // The bits that have not been assigned to an index yet.
+    let mut buffer: usize = 0;
+    // The number of bits in the buffer.
+    let mut num_buffered_bits = 0;
     // Process every byte.
     for byte in bytes {
-        // If `NUM_BITS_PER_WORD` bits are read including the current byte, a new word index
-        // is computed.
-        if read_bits + 8 >= NUM_BITS_PER_WORD {
-            // Keep track of the number of processed bits.
-            let processed_bits = NUM_BITS_PER_WORD - read_bits;
-            // The remaining bits are used for the next index.
-            let remaining_bits = 8 - processed_bits;
-            // Remove the remaining bits to get the processed part.
-            let processed_part = (*byte as usize) >> remaining_bits;
-            // The current index is finalized by appending the processed part.
-            current_index = (current_index << processed_bits) + processed_part;
-            // Add the index.
-            indices.push(current_index);
-            // Update the current index with the remaining bits.
-            current_index = (*byte as usize) % (1 << remaining_bits);
-            // The number of read bits is the number of remaining bits.
-            read_bits = remaining_bits;
-        } else {
-            // The whole byte is appended to the current index.
-            current_index = (current_index << 8) + (*byte as usize);
-            // The number of read bytes increases by 8.
-            read_bits += 8;
+        buffer = (buffer << 8) | (*byte as usize);
+        num_buffered_bits += 8;
+        // Once `num_bits_per_word` bits have been read, a new word index is computed.
+        while num_buffered_bits >= num_bits_per_word && indices.len() < num_words {
+            num_buffered_bits -= num_bits_per_word;
+            indices.push(buffer >> num_buffered_bits);
+            buffer %= 1 << num_buffered_bits;
         }
         // Once we have read the desired number of words, return them.
         if indices.len() == num_words {
-            return Ok(indices);
+            break;
         }
     }
-    Err(HarpoError::InvalidSeedPhrase(
-        "Error parsing indices from byte array.".to_string(),
-    ))
+    Ok(indices)
 }
 
 // ******************************** TESTS ********************************
@@ -471,7 +2321,7 @@ fn get_indices_from_bytes(bytes: &[u8], num_words: usize) -> HarpoResult<Vec<usi
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::secret_sharing::get_modulus_for_bits;
+    use crate::secret_sharing::get_field_modulus_for_bits;
     use rand::{seq::SliceRandom, Rng};
     use std::error::Error;
 
@@ -484,7 +2334,7 @@ mod tests {
     ///
     /// * `input` - The input in the form of a Hex string.
     fn decode_hex_bytes(input: &str) -> Result<Vec<u8>, Box<dyn Error>> {
-        if input.len() % 2 != 0 {
+        if !input.len().is_multiple_of(2) {
             Err("Error decoding hex string: The input length is not a multiple of 2.".into())
         } else {
             (0..input.len())
@@ -494,6 +2344,194 @@ mod tests {
         }
     }
 
+    #[test]
+    /// The function tests that the debug output of a seed phrase does not reveal the words.
+    fn test_redacted_debug_output() {
+        let words: Vec<String> = DEFAULT_WORD_LIST[..12]
+            .iter()
+            .map(|word| word.to_string())
+            .collect();
+        let seed_phrase = SeedPhrase::new_with_index(&words, 7);
+        let debug_output = format!("{:?}", seed_phrase);
+        assert!(debug_output.starts_with("SeedPhrase(12 words, index: 7, fingerprint: "));
+        assert!(words
+            .iter()
+            .all(|word| !debug_output.contains(word.as_str())));
+        // The fingerprint depends on the words but not on the index.
+        let other_seed_phrase = SeedPhrase::new(&words);
+        assert_eq!(
+            format!("{:?}", other_seed_phrase)
+                .split("fingerprint")
+                .nth(1),
+            debug_output.split("fingerprint").nth(1)
+        );
+        let mut other_words = words.clone();
+        other_words.reverse();
+        assert_ne!(
+            format!("{:?}", SeedPhrase::new(&other_words))
+                .split("fingerprint")
+                .nth(1),
+            debug_output.split("fingerprint").nth(1)
+        );
+        // The words are revealed explicitly.
+        let revealed_output = format!("{:?}", seed_phrase.reveal());
+        assert!(words
+            .iter()
+            .all(|word| revealed_output.contains(word.as_str())));
+        assert!(revealed_output.contains("Some(7)"));
+    }
+
+    #[test]
+    /// The function tests that words are compared after NFKD normalization.
+    fn test_normalized_parsing() {
+        let word_list = ["\u{e9}l\u{e8}ve", "abri", "ni\u{f1}o", "\u{ac00}"];
+        for input in [
+            "e\u{301}le\u{300}ve abri nin\u{303}o \u{1100}\u{1161}",
+            "\u{c9}L\u{c8}VE ABRI NI\u{d1}O \u{ac00}",
+        ] {
+            let seed_phrase = parse_seed_phrase(input, ParseMode::Lenient, &word_list)
+                .expect("The parsing should work.");
+            // The words are returned as they appear in the word list.
+            assert_eq!(seed_phrase.get_words(), &word_list);
+        }
+        let seed_phrase = parse_seed_phrase(
+            "e\u{301}le\u{300}ve abri nin\u{303}o \u{1100}\u{1161}",
+            ParseMode::Strict,
+            &word_list,
+        )
+        .expect("The parsing should work.");
+        assert_eq!(seed_phrase.get_words(), &word_list);
+        assert_eq!(get_index("nin\u{303}o", &word_list), Some(2));
+    }
+
+    #[test]
+    /// The function tests the detailed validation of seed phrases.
+    fn test_diagnose_seed_phrase() {
+        let words: Vec<String> =
+            "legal winner thank year wave sausage worth useful legal winner thank yellow"
+                .split(' ')
+                .map(str::to_string)
+                .collect();
+        let diagnostics = diagnose_seed_phrase(&SeedPhrase::new(&words), DEFAULT_WORD_LIST);
+        assert!(diagnostics.is_valid());
+        assert!(diagnostics.get_unknown_words().is_empty());
+        // A checksum mismatch is detected.
+        let mut mismatch = words.clone();
+        mismatch[11] = "year".to_string();
+        let diagnostics = diagnose_seed_phrase(&SeedPhrase::new(&mismatch), DEFAULT_WORD_LIST);
+        assert_eq!(diagnostics.has_valid_checksum(), Some(false));
+        assert!(!diagnostics.is_valid());
+        // Unknown words are reported with their positions and suggestions.
+        let mut unknown = words.clone();
+        unknown[2] = "thenk".to_string();
+        unknown[5] = "xyzzy".to_string();
+        let diagnostics = diagnose_seed_phrase(&SeedPhrase::new(&unknown), DEFAULT_WORD_LIST);
+        assert_eq!(diagnostics.has_valid_checksum(), None);
+        let unknown_words = diagnostics.get_unknown_words();
+        assert_eq!(unknown_words.len(), 2);
+        assert_eq!(unknown_words[0].get_position(), 2);
+        assert_eq!(unknown_words[0].get_word(), "thenk");
+        assert!(unknown_words[0]
+            .get_suggestions()
+            .contains(&"thank".to_string()));
+        assert_eq!(unknown_words[1].get_position(), 5);
+        assert!(diagnostics.to_string().contains("word 3: 'thenk'"));
+        // An invalid number of words is detected.
+        let diagnostics = diagnose_seed_phrase(&SeedPhrase::new(&words[..11]), DEFAULT_WORD_LIST);
+        assert!(!diagnostics.has_valid_num_words());
+        assert_eq!(diagnostics.has_valid_checksum(), None);
+    }
+
+    #[test]
+    /// The function tests the conversion between entropy and seed phrases.
+    fn test_entropy_conversion() {
+        // The test vectors are taken from the BIP-0039 specification.
+        let seed_phrase = SeedPhrase::from_entropy(&[0x7f; 16]).expect("The entropy is valid.");
+        assert_eq!(
+            seed_phrase.to_string(),
+            "legal winner thank year wave sausage worth useful legal winner thank yellow"
+        );
+        let seed_phrase = SeedPhrase::from_entropy(&[0x80; 24]).expect("The entropy is valid.");
+        assert_eq!(
+            seed_phrase.to_string(),
+            "letter advice cage absurd amount doctor acoustic avoid letter advice cage absurd \
+            amount doctor acoustic avoid letter always"
+        );
+        let mut rng = rand::thread_rng();
+        for num_bytes in [16, 20, 24, 28, 32] {
+            let entropy: Vec<u8> = (0..num_bytes).map(|_| rng.gen()).collect();
+            let seed_phrase = SeedPhrase::from_entropy(&entropy).expect("The entropy is valid.");
+            assert_eq!(seed_phrase.len(), num_bytes * 3 / 4);
+            assert!(is_compliant(&seed_phrase, DEFAULT_WORD_LIST));
+            assert_eq!(
+                seed_phrase.to_entropy().expect("The seed phrase is valid."),
+                entropy
+            );
+        }
+        assert!(SeedPhrase::from_entropy(&[0; 17]).is_err());
+        assert!(SeedPhrase::from_entropy(&[0; 36]).is_err());
+        // A seed phrase with an invalid checksum is rejected.
+        let words: Vec<String> = vec!["abandon".to_string(); 12];
+        assert!(SeedPhrase::new(&words).to_entropy().is_err());
+    }
+
+    #[test]
+    /// The function tests the parsing of seed phrases from strings and readers.
+    fn test_parse_phrases() {
+        let seed_phrase =
+            parse_phrase(" 3:Abandon\u{3000}ability  able ").expect("Parsing should work.");
+        assert_eq!(seed_phrase.get_words(), vec!["abandon", "ability", "able"]);
+        assert_eq!(seed_phrase.get_index(), Some(3));
+        assert_eq!(parse_phrase("abandon ability").unwrap().get_index(), None);
+        assert!(parse_phrase("  ").is_err());
+        assert!(parse_phrase("x: abandon ability").is_err());
+        let input = format!(
+            "{}\n# A comment\n\n1: abandon ability\n2: able about\n",
+            get_share_format_header()
+        );
+        let seed_phrases =
+            parse_phrases_from_reader(input.as_bytes()).expect("Parsing should work.");
+        assert_eq!(seed_phrases.len(), 2);
+        assert_eq!(seed_phrases[1].get_index(), Some(2));
+        assert!(parse_phrases_from_reader("1: abandon\nx: able\n".as_bytes()).is_err());
+        let input = format!("{} 99\nabandon ability\n", SHARE_FORMAT_HEADER_PREFIX);
+        assert!(parse_phrases_from_reader(input.as_bytes()).is_err());
+    }
+
+    #[test]
+    /// The function tests the conversions of strings and word lists into seed phrases.
+    fn test_seed_phrase_conversions() {
+        let words: Vec<&str> = DEFAULT_WORD_LIST[..12].to_vec();
+        let seed_phrase = SeedPhrase::try_from(words.as_slice()).expect("The words are valid.");
+        assert_eq!(seed_phrase.get_words(), words);
+        assert_eq!(seed_phrase.get_index(), None);
+        let owned_words: Vec<String> = words.iter().map(|word| word.to_string()).collect();
+        assert_eq!(
+            SeedPhrase::try_from(owned_words).expect("The words are valid."),
+            seed_phrase
+        );
+        // The index is parsed from the string.
+        let input = format!("7: {}", words.join(" "));
+        let parsed: SeedPhrase = input.parse().expect("The input is valid.");
+        assert_eq!(parsed, seed_phrase);
+        assert_eq!(parsed.get_index(), Some(7));
+        // The string representation can be parsed again.
+        let reparsed: SeedPhrase = parsed.to_string().parse().expect("The input is valid.");
+        assert_eq!(reparsed.get_index(), Some(7));
+        let parsed: SeedPhrase = format!("  {}  ", words.join(" ").to_uppercase())
+            .parse()
+            .expect("The input is valid.");
+        assert_eq!(parsed, seed_phrase);
+        // Invalid words, numbers of words, and indices are rejected.
+        assert!(SeedPhrase::try_from(&["abandon", "notaword"][..]).is_err());
+        assert!(SeedPhrase::try_from(&words[..11]).is_err());
+        assert!("".parse::<SeedPhrase>().is_err());
+        assert!(format!("x: {}", words.join(" "))
+            .parse::<SeedPhrase>()
+            .is_err());
+        assert!(words[..11].join(" ").parse::<SeedPhrase>().is_err());
+    }
+
     #[test]
     /// A simple test function that tests the conversion from
     ///      107      139       93      210      150       45
@@ -515,15 +2553,41 @@ mod tests {
         // A test with 4 words.
         let num_words = 4;
         let bytes: &[u8] = &[107, 139, 93, 210, 150, 45];
-        let indices = get_indices_from_bytes(bytes, num_words).unwrap();
+        let indices = get_indices_from_bytes(bytes, num_words, NUM_BITS_PER_WORD).unwrap();
         let expected_indices: Vec<usize> = vec![860, 727, 933, 354];
         assert_eq!(indices, expected_indices);
         // A test with 5 words.
         let num_words = 5;
         let bytes: &[u8] = &[229, 26, 179, 110, 211, 38, 214];
-        let indices = get_indices_from_bytes(bytes, num_words).unwrap();
+        let indices = get_indices_from_bytes(bytes, num_words, NUM_BITS_PER_WORD).unwrap();
         let expected_indices: Vec<usize> = vec![1832, 1708, 1757, 1330, 875];
         assert_eq!(indices, expected_indices);
+        // The same bytes interpreted as 10-bit indices.
+        let indices = get_indices_from_bytes(bytes, num_words, 10).unwrap();
+        let expected_indices: Vec<usize> = vec![916, 427, 219, 723, 155];
+        assert_eq!(indices, expected_indices);
+        // Too few bytes.
+        assert!(get_indices_from_bytes(bytes, 6, 10).is_err());
+    }
+
+    #[test]
+    /// This function tests that indices can be converted into bytes and back for different
+    /// numbers of bits per word.
+    fn test_bytes_from_indices() {
+        let mut rng = rand::thread_rng();
+        for num_bits_per_word in 1..=16 {
+            for num_words in 1..=24 {
+                let indices: Vec<usize> = (0..num_words)
+                    .map(|_| rng.gen_range(0..(1 << num_bits_per_word)))
+                    .collect();
+                let bytes = get_bytes_from_indices(&indices, num_bits_per_word);
+                assert_eq!(bytes.len(), (num_words * num_bits_per_word).div_ceil(8));
+                let decoded = get_indices_from_bytes(&bytes, num_words, num_bits_per_word);
+                assert_eq!(decoded.unwrap(), indices);
+            }
+        }
+        assert_eq!(get_num_bits_per_word(DEFAULT_WORD_LIST), NUM_BITS_PER_WORD);
+        assert_eq!(get_num_bits_per_word(&DEFAULT_WORD_LIST[..1024]), 10);
     }
 
     /// This function tests the conversion from a byte array to a seed phrase
@@ -535,9 +2599,9 @@ mod tests {
         // Obtain the bytes from the hexadecimal encoding.
         let value = decode_hex_bytes(hex_number).unwrap();
         // Get the modulus from the size of the byte array.
-        let modulus = get_modulus_for_bits(value.len() << 3).unwrap();
+        let modulus = get_field_modulus_for_bits(value.len() << 3).unwrap();
         // Create the corresponding finite field element.
-        let element = FiniteFieldElement::new(&value, &modulus);
+        let element = FiniteFieldElement::new(&value, modulus);
         // Get the seed phrase for the element.
         let seed_phrase = get_seed_phrase_for_element(&element, DEFAULT_WORD_LIST).unwrap();
         let target_list: Vec<&str> = phrase.split(' ').collect();
@@ -553,6 +2617,237 @@ mod tests {
         assert_eq!(derived_element, element);
     }
 
+    #[test]
+    /// This function tests strict and lenient parsing of seed phrases.
+    fn test_parse_modes() {
+        let phrase = "legal winner thank year wave sausage worth useful legal winner thank yellow";
+        let expected = parse_seed_phrase(phrase, ParseMode::Strict, DEFAULT_WORD_LIST).unwrap();
+        assert_eq!(expected.len(), 12);
+        assert_eq!(expected.get_index(), None);
+        let with_index = parse_seed_phrase(
+            &format!("3: {}", phrase),
+            ParseMode::Strict,
+            DEFAULT_WORD_LIST,
+        )
+        .unwrap();
+        assert_eq!(with_index.get_index(), Some(3));
+        // Strict parsing rejects anything but the exact format.
+        let invalid_inputs = [
+            "Legal winner thank year wave sausage worth useful legal winner thank yellow",
+            "legal  winner thank year wave sausage worth useful legal winner thank yellow",
+            " legal winner thank year wave sausage worth useful legal winner thank yellow",
+            "legal winner thank year wave sausage worth useful legal winner thank yellow\n",
+            "legal, winner thank year wave sausage worth useful legal winner thank yellow",
+            "lega winner thank year wave sausage worth useful legal winner thank yellow",
+            "3:legal winner thank year wave sausage worth useful legal winner thank yellow",
+            "a",
+            "",
+        ];
+        for input in &invalid_inputs {
+            assert!(parse_seed_phrase(input, ParseMode::Strict, DEFAULT_WORD_LIST).is_err());
+        }
+        // Lenient parsing accepts all of them except for the empty input and the unknown word.
+        for input in &invalid_inputs[..5] {
+            let seed_phrase =
+                parse_seed_phrase(input, ParseMode::Lenient, DEFAULT_WORD_LIST).unwrap();
+            assert_eq!(seed_phrase, expected);
+        }
+        let seed_phrase =
+            parse_seed_phrase(invalid_inputs[6], ParseMode::Lenient, DEFAULT_WORD_LIST).unwrap();
+        assert_eq!(seed_phrase.get_index(), Some(3));
+        // Unique prefixes are expanded, ambiguous prefixes are rejected.
+        let abbreviated = "LEGA winn. THAN year wave saus worth usef legal winn than yell";
+        let seed_phrase =
+            parse_seed_phrase(abbreviated, ParseMode::Lenient, DEFAULT_WORD_LIST).unwrap();
+        assert_eq!(seed_phrase, expected);
+        assert!(parse_seed_phrase("ab", ParseMode::Lenient, DEFAULT_WORD_LIST).is_err());
+        // The candidate words of an ambiguous abbreviation are listed.
+        let error = parse_seed_phrase("zo", ParseMode::Lenient, DEFAULT_WORD_LIST).unwrap_err();
+        assert!(error.to_string().contains("(zone, zoo)"));
+        assert!(parse_seed_phrase("xyz", ParseMode::Lenient, DEFAULT_WORD_LIST).is_err());
+        assert!(parse_seed_phrase(" , ", ParseMode::Lenient, DEFAULT_WORD_LIST).is_err());
+    }
+
+    #[test]
+    /// This function tests the segmentation of seed phrases written without spaces.
+    fn test_segmentation() {
+        // A word list with 2048 distinct CJK characters.
+        let words: Vec<String> = (0..2048u32)
+            .map(|offset| char::from_u32(0x4e00 + offset).unwrap().to_string())
+            .collect();
+        let word_list: Vec<&str> = words.iter().map(|word| word.as_str()).collect();
+        let phrase: String = word_list[..12].concat();
+        let report = parse_seed_phrase_with_typo_policy(
+            &phrase,
+            ParseMode::Lenient,
+            TypoPolicy::Reject,
+            &word_list,
+        )
+        .unwrap();
+        assert_eq!(report.get_seed_phrase().get_words(), &word_list[..12]);
+        // Ideographic spaces and partially segmented input are handled as well.
+        let phrase = format!(
+            "{}\u{3000}{}",
+            word_list[..6].concat(),
+            word_list[6..12].concat()
+        );
+        let report = parse_seed_phrase_with_typo_policy(
+            &phrase,
+            ParseMode::Lenient,
+            TypoPolicy::Reject,
+            &word_list,
+        )
+        .unwrap();
+        assert_eq!(report.get_seed_phrase().get_words(), &word_list[..12]);
+        // Ambiguous segmentations are rejected.
+        let word_list = [
+            "\u{3042}\u{3044}",
+            "\u{3046}",
+            "\u{3042}",
+            "\u{3044}\u{3046}",
+        ];
+        assert!(segment_token("\u{3042}\u{3044}\u{3046}", &WordTrie::new(&word_list)).is_err());
+        assert_eq!(
+            segment_token(
+                "\u{3042}\u{3044}\u{3046}\u{3046}",
+                &WordTrie::new(&word_list[..2])
+            )
+            .unwrap(),
+            Some(vec![
+                "\u{3042}\u{3044}".to_string(),
+                "\u{3046}".to_string(),
+                "\u{3046}".to_string()
+            ])
+        );
+        assert_eq!(
+            segment_token("\u{3048}", &WordTrie::new(&word_list)).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    /// This function tests the handling of typos according to the typo policy.
+    fn test_typo_policy() {
+        let phrase = "legal winner thank year wave sausage worth useful legal winner thank yellow";
+        let expected = parse_seed_phrase(phrase, ParseMode::Strict, DEFAULT_WORD_LIST).unwrap();
+        // "legak" is a substitution, "wiinner" and "thanks" insertions.
+        let typo_phrase =
+            "legak wiinner thanks year wave sausage worth useful legal winner thank yellow";
+        for parse_mode in [ParseMode::Strict, ParseMode::Lenient] {
+            let result = parse_seed_phrase_with_typo_policy(
+                typo_phrase,
+                parse_mode,
+                TypoPolicy::Reject,
+                DEFAULT_WORD_LIST,
+            );
+            assert!(!result.unwrap_err().to_string().contains("did you mean"));
+            let result = parse_seed_phrase_with_typo_policy(
+                typo_phrase,
+                parse_mode,
+                TypoPolicy::SuggestOnly,
+                DEFAULT_WORD_LIST,
+            );
+            assert!(result.unwrap_err().to_string().contains("'legal'"));
+        }
+        let report = parse_seed_phrase_with_typo_policy(
+            typo_phrase,
+            ParseMode::Strict,
+            TypoPolicy::AutoCorrect,
+            DEFAULT_WORD_LIST,
+        )
+        .unwrap();
+        assert_eq!(report.get_seed_phrase(), &expected);
+        let corrections: Vec<(usize, &str, &str)> = report
+            .get_corrections()
+            .iter()
+            .map(|c| (c.get_position(), c.get_original(), c.get_corrected()))
+            .collect();
+        assert_eq!(
+            corrections,
+            vec![
+                (0, "legak", "legal"),
+                (1, "wiinner", "winner"),
+                (2, "thanks", "thank")
+            ]
+        );
+        // Ambiguous typos are not corrected: "bat" could be "bag", "bar", "cat", ...
+        let result = parse_seed_phrase_with_typo_policy(
+            "bat",
+            ParseMode::Strict,
+            TypoPolicy::AutoCorrect,
+            DEFAULT_WORD_LIST,
+        );
+        assert!(result.unwrap_err().to_string().contains("did you mean"));
+        assert!(is_single_typo("abc", "abd"));
+        assert!(is_single_typo("abc", "ab"));
+        assert!(is_single_typo("abc", "xabc"));
+        assert!(!is_single_typo("abc", "abc"));
+        assert!(!is_single_typo("abc", "cba"));
+        assert!(is_single_typo("abc", "bac"));
+        assert!(is_single_typo("abc", "acb"));
+        assert!(!is_single_typo("abcd", "badc"));
+        // Transposed characters are suggested, and at most three suggestions are listed.
+        let error = "lettre".parse::<SeedPhrase>().unwrap_err();
+        assert!(error.to_string().contains("'letter'"));
+        let error = parse_seed_phrase_with_typo_policy(
+            "bat",
+            ParseMode::Strict,
+            TypoPolicy::SuggestOnly,
+            DEFAULT_WORD_LIST,
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.to_string().matches(" or ").count(),
+            MAX_WORD_SUGGESTIONS - 1
+        );
+        assert!(!is_single_typo("abc", "a"));
+    }
+
+    #[test]
+    /// This function tests the share format header.
+    fn test_share_format_header() {
+        let header = get_share_format_header();
+        assert!(header.starts_with('#'));
+        assert_eq!(
+            parse_share_format_header(&header),
+            Some(SHARE_FORMAT_VERSION)
+        );
+        assert_eq!(parse_share_format_header("# some comment"), None);
+        assert_eq!(parse_share_format_header("# harpo share format 7"), Some(7));
+        assert!(check_share_format_version(SHARE_FORMAT_VERSION).is_ok());
+        assert!(check_share_format_version(SHARE_FORMAT_VERSION + 1).is_err());
+        assert!(check_share_format_version(0).is_err());
+    }
+
+    #[test]
+    /// This function tests that errors when handling index words do not reveal the words.
+    fn test_index_word_errors() {
+        let seed_phrase: SeedPhrase =
+            "legal winner thank year wave sausage worth useful legal winner thank yellow"
+                .parse()
+                .expect("The seed phrase is valid.");
+        let error = remove_index_word(&seed_phrase, DEFAULT_WORD_LIST)
+            .expect_err("A 12-word seed phrase does not have an index word.");
+        for word in seed_phrase.get_words() {
+            assert!(!error.to_string().contains(word));
+        }
+    }
+
+    #[test]
+    /// This function tests that clones share the word storage and preserve the index.
+    fn test_seed_phrase_clone() {
+        let words: Vec<String> = ["cat", "swing", "flag"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let seed_phrase = SeedPhrase::new_with_index(&words, 7);
+        let clone = seed_phrase.clone();
+        assert!(Arc::ptr_eq(&seed_phrase.words, &clone.words));
+        assert_eq!(clone.get_index(), Some(7));
+        assert_eq!(clone.get_words(), vec!["cat", "swing", "flag"]);
+        assert_eq!(seed_phrase, clone);
+    }
+
     #[test]
     /// This function generates random seed phrases and tests the correct conversion.
     fn test_random_seed_phrase_conversion() {
@@ -566,8 +2861,8 @@ mod tests {
             let mut random_key: Vec<u8> = vec![0; *size];
             random_key.clone_from_slice(&random_bytes[..*size]);
             // Generate the corresponding finite field element.
-            let modulus = get_modulus_for_bits(size << 3).unwrap();
-            let element = FiniteFieldElement::new(&random_key, &modulus);
+            let modulus = get_field_modulus_for_bits(size << 3).unwrap();
+            let element = FiniteFieldElement::new(&random_key, modulus);
             // Generate the seed phrase.
             let seed_phrase = get_seed_phrase_for_element(&element, DEFAULT_WORD_LIST).unwrap();
             // Derive the element from the seed phrase.
@@ -589,8 +2884,9 @@ mod tests {
             let num_words = valid_num_words
                 .choose(&mut rng)
                 .expect("A valid random number of words should be chosen.");
-            let seed_phrase = get_random_seed_phrase(*num_words, DEFAULT_WORD_LIST)
-                .expect("A valid seed phrase should be generated.");
+            let seed_phrase =
+                get_random_seed_phrase(*num_words, DEFAULT_WORD_LIST, &mut rand::rngs::OsRng)
+                    .expect("A valid seed phrase should be generated.");
             // Make sure that the number of words is correct.
             assert_eq!(seed_phrase.len(), *num_words);
             // Make sure it is BIP-0039-compliant.
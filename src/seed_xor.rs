@@ -0,0 +1,161 @@
+//! The `seed_xor` module provides the Seed XOR scheme as implemented by the Coldcard hardware
+//! wallet.
+//!
+//! A seed phrase is split into parts that are themselves BIP-0039-compliant seed phrases of the
+//! same length. All parts but the last are random and the entropy of the last part is the XOR of
+//! the entropy of the seed phrase and the entropies of the other parts. All parts are required
+//! to reconstruct the seed phrase, which is obtained by XORing the entropies of the parts.
+//! Seed phrases are split into 2 or 3 parts. Since Coldcard also supports 4 parts, any number of
+//! at least two parts can be combined.
+//!
+
+use crate::seed_phrase::{get_seed_phrase_for_entropy, SeedPhrase};
+use crate::{HarpoError, HarpoResult, SeedPhraseResult};
+use rand::{rngs::OsRng, RngCore};
+use zeroize::Zeroizing;
+
+/// The minimum number of parts.
+pub(crate) const MIN_NUM_PARTS: usize = 2;
+/// The maximum number of parts into which a seed phrase is split.
+pub(crate) const MAX_NUM_PARTS: usize = 3;
+
+/// The function splits the given seed phrase into the given number of Seed XOR parts.
+///
+/// * `seed_phrase` - The seed phrase.
+/// * `num_parts` - The number of parts.
+/// * `word_list` - The word list.
+pub(crate) fn split_seed_phrase(
+    seed_phrase: &SeedPhrase,
+    num_parts: usize,
+    word_list: &[&str],
+) -> HarpoResult<Vec<SeedPhrase>> {
+    if !(MIN_NUM_PARTS..=MAX_NUM_PARTS).contains(&num_parts) {
+        return Err(HarpoError::InvalidParameter(format!(
+            "The number of Seed XOR parts must be between {} and {}.",
+            MIN_NUM_PARTS, MAX_NUM_PARTS
+        )));
+    }
+    let mut last_entropy = Zeroizing::new(seed_phrase.to_entropy_for_word_list(word_list)?);
+    let mut parts = vec![];
+    for _ in 1..num_parts {
+        let mut entropy = Zeroizing::new(vec![0; last_entropy.len()]);
+        OsRng.fill_bytes(&mut entropy);
+        for (last_byte, byte) in last_entropy.iter_mut().zip(entropy.iter()) {
+            *last_byte ^= byte;
+        }
+        parts.push(get_seed_phrase_for_entropy(&entropy, word_list)?);
+    }
+    parts.push(get_seed_phrase_for_entropy(&last_entropy, word_list)?);
+    Ok(parts)
+}
+
+/// The function reconstructs the seed phrase from the given Seed XOR parts.
+///
+/// An error is returned if fewer than two parts are provided, if a part is not
+/// BIP-0039-compliant, or if the parts have different lengths.
+///
+/// * `parts` - The parts.
+/// * `word_list` - The word list.
+pub(crate) fn combine_parts(parts: &[SeedPhrase], word_list: &[&str]) -> SeedPhraseResult {
+    if parts.len() < MIN_NUM_PARTS {
+        return Err(HarpoError::NotEnoughShares {
+            provided: parts.len(),
+            required: MIN_NUM_PARTS,
+        });
+    }
+    let mut entropy = Zeroizing::new(parts[0].to_entropy_for_word_list(word_list)?);
+    for part in &parts[1..] {
+        let part_entropy = Zeroizing::new(part.to_entropy_for_word_list(word_list)?);
+        if part_entropy.len() != entropy.len() {
+            return Err(HarpoError::LengthMismatchAcrossShares);
+        }
+        for (byte, part_byte) in entropy.iter_mut().zip(part_entropy.iter()) {
+            *byte ^= part_byte;
+        }
+    }
+    get_seed_phrase_for_entropy(&entropy, word_list)
+}
+
+// ******************************** TESTS ********************************
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::seed_phrase::{is_compliant, parse_phrase};
+    use crate::word_list::DEFAULT_WORD_LIST;
+
+    #[test]
+    /// The function tests the reconstruction using the Seed XOR example of Coldcard.
+    fn test_seed_xor_example() {
+        let parts: Vec<SeedPhrase> = [
+            "romance wink lottery autumn shop bring dawn tongue range crater truth ability miss \
+            spice fitness easy legal release recall obey exchange recycle dragon room",
+            "lion misery divide hurry latin fluid camp advance illegal lab pyramid unaware eager \
+            fringe sick camera series noodle toy crowd jeans select depth lounge",
+            "vault nominee cradle silk own frown throw leg cactus recall talent worry gadget \
+            surface shy planet purpose coffee drip few seven term squeeze educate",
+        ]
+        .iter()
+        .map(|phrase| parse_phrase(phrase).unwrap())
+        .collect();
+        let seed_phrase = parse_phrase(
+            "silent toe meat possible chair blossom wait occur this worth option bag nurse find \
+            fish scene bench asthma bike wage world quit primary indoor",
+        )
+        .unwrap();
+        assert_eq!(
+            combine_parts(&parts, DEFAULT_WORD_LIST).unwrap(),
+            seed_phrase
+        );
+        // The order of the parts does not matter, but all parts are required.
+        let reversed_parts: Vec<SeedPhrase> = parts.iter().rev().cloned().collect();
+        assert_eq!(
+            combine_parts(&reversed_parts, DEFAULT_WORD_LIST).unwrap(),
+            seed_phrase
+        );
+        assert_ne!(
+            combine_parts(&parts[..2], DEFAULT_WORD_LIST).unwrap(),
+            seed_phrase
+        );
+        assert!(combine_parts(&parts[..1], DEFAULT_WORD_LIST).is_err());
+    }
+
+    #[test]
+    /// The function tests the splitting of seed phrases into Seed XOR parts.
+    fn test_split_seed_phrase() {
+        let seed_phrase = parse_phrase(
+            "legal winner thank year wave sausage worth useful legal winner thank yellow",
+        )
+        .unwrap();
+        for num_parts in MIN_NUM_PARTS..=MAX_NUM_PARTS {
+            let parts = split_seed_phrase(&seed_phrase, num_parts, DEFAULT_WORD_LIST).unwrap();
+            assert_eq!(parts.len(), num_parts);
+            assert!(parts
+                .iter()
+                .all(|part| part.len() == 12 && is_compliant(part, DEFAULT_WORD_LIST)));
+            assert_eq!(
+                combine_parts(&parts, DEFAULT_WORD_LIST).unwrap(),
+                seed_phrase
+            );
+        }
+        assert!(split_seed_phrase(&seed_phrase, 1, DEFAULT_WORD_LIST).is_err());
+        assert!(split_seed_phrase(&seed_phrase, 4, DEFAULT_WORD_LIST).is_err());
+        // Four parts as created by Coldcard can still be combined.
+        let mut parts = split_seed_phrase(&seed_phrase, 2, DEFAULT_WORD_LIST).unwrap();
+        let last_parts = split_seed_phrase(&parts[1], 3, DEFAULT_WORD_LIST).unwrap();
+        parts.truncate(1);
+        parts.extend(last_parts);
+        assert_eq!(
+            combine_parts(&parts, DEFAULT_WORD_LIST).unwrap(),
+            seed_phrase
+        );
+        // Parts of different lengths are rejected.
+        let long_seed_phrase = parse_phrase(
+            "letter advice cage absurd amount doctor acoustic avoid letter advice cage absurd \
+            amount doctor acoustic avoid letter always",
+        )
+        .unwrap();
+        let parts = [seed_phrase, long_seed_phrase];
+        assert!(combine_parts(&parts, DEFAULT_WORD_LIST).is_err());
+    }
+}
@@ -0,0 +1,930 @@
+//! The `slip39` module provides the import and export of secret shares in the
+//! [SLIP-0039](https://github.com/satoshilabs/slips/blob/master/slip-0039.md) format, which is
+//! used by hardware wallets for Shamir backups.
+//!
+//! The master secret is encrypted with a passphrase using a four-round Feistel network based on
+//! PBKDF2-HMAC-SHA256. The encrypted master secret is secret-shared among groups over GF(2^8)
+//! and each group secret is secret-shared among the members of the group. Each share is encoded
+//! as a mnemonic of 10-bit words, consisting of the identifier, the iteration exponent, the
+//! group and member parameters, the share value, and an RS1024 checksum.
+//! Shares of both the original and the extendable variant can be parsed. New shares use the
+//! original variant, which is supported by all implementations.
+//!
+
+use crate::byte_sharing::{gf256_inverse, gf256_mul};
+use crate::seed_phrase::{get_word_suggestions, MAX_WORD_SUGGESTIONS};
+use crate::{HarpoError, HarpoResult};
+use hmac::{Hmac, Mac};
+use num_bigint::BigUint;
+use num_traits::ToPrimitive;
+use rand::{rngs::OsRng, RngCore};
+use sha2::Sha256;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+use zeroize::{Zeroize, Zeroizing};
+
+/// The SLIP-0039 word list as specified here:
+/// <https://github.com/satoshilabs/slips/blob/master/slip-0039/wordlist.txt>
+pub const WORD_LIST: &[&str] = &[
+    "academic", "acid", "acne", "acquire", "acrobat", "activity", "actress", "adapt", "adequate",
+    "adjust", "admit", "adorn", "adult", "advance", "advocate", "afraid", "again", "agency",
+    "agree", "aide", "aircraft", "airline", "airport", "ajar", "alarm", "album", "alcohol",
+    "alien", "alive", "alpha", "already", "alto", "aluminum", "always", "amazing", "ambition",
+    "amount", "amuse", "analysis", "anatomy", "ancestor", "ancient", "angel", "angry", "animal",
+    "answer", "antenna", "anxiety", "apart", "aquatic", "arcade", "arena", "argue", "armed",
+    "artist", "artwork", "aspect", "auction", "august", "aunt", "average", "aviation", "avoid",
+    "award", "away", "axis", "axle", "beam", "beard", "beaver", "become", "bedroom", "behavior",
+    "being", "believe", "belong", "benefit", "best", "beyond", "bike", "biology", "birthday",
+    "bishop", "black", "blanket", "blessing", "blimp", "blind", "blue", "body", "bolt", "boring",
+    "born", "both", "boundary", "bracelet", "branch", "brave", "breathe", "briefing", "broken",
+    "brother", "browser", "bucket", "budget", "building", "bulb", "bulge", "bumpy", "bundle",
+    "burden", "burning", "busy", "buyer", "cage", "calcium", "camera", "campus", "canyon",
+    "capacity", "capital", "capture", "carbon", "cards", "careful", "cargo", "carpet", "carve",
+    "category", "cause", "ceiling", "center", "ceramic", "champion", "change", "charity", "check",
+    "chemical", "chest", "chew", "chubby", "cinema", "civil", "class", "clay", "cleanup", "client",
+    "climate", "clinic", "clock", "clogs", "closet", "clothes", "club", "cluster", "coal",
+    "coastal", "coding", "column", "company", "corner", "costume", "counter", "course", "cover",
+    "cowboy", "cradle", "craft", "crazy", "credit", "cricket", "criminal", "crisis", "critical",
+    "crowd", "crucial", "crunch", "crush", "crystal", "cubic", "cultural", "curious", "curly",
+    "custody", "cylinder", "daisy", "damage", "dance", "darkness", "database", "daughter",
+    "deadline", "deal", "debris", "debut", "decent", "decision", "declare", "decorate", "decrease",
+    "deliver", "demand", "density", "deny", "depart", "depend", "depict", "deploy", "describe",
+    "desert", "desire", "desktop", "destroy", "detailed", "detect", "device", "devote", "diagnose",
+    "dictate", "diet", "dilemma", "diminish", "dining", "diploma", "disaster", "discuss",
+    "disease", "dish", "dismiss", "display", "distance", "dive", "divorce", "document", "domain",
+    "domestic", "dominant", "dough", "downtown", "dragon", "dramatic", "dream", "dress", "drift",
+    "drink", "drove", "drug", "dryer", "duckling", "duke", "duration", "dwarf", "dynamic", "early",
+    "earth", "easel", "easy", "echo", "eclipse", "ecology", "edge", "editor", "educate", "either",
+    "elbow", "elder", "election", "elegant", "element", "elephant", "elevator", "elite", "else",
+    "email", "emerald", "emission", "emperor", "emphasis", "employer", "empty", "ending",
+    "endless", "endorse", "enemy", "energy", "enforce", "engage", "enjoy", "enlarge", "entrance",
+    "envelope", "envy", "epidemic", "episode", "equation", "equip", "eraser", "erode", "escape",
+    "estate", "estimate", "evaluate", "evening", "evidence", "evil", "evoke", "exact", "example",
+    "exceed", "exchange", "exclude", "excuse", "execute", "exercise", "exhaust", "exotic",
+    "expand", "expect", "explain", "express", "extend", "extra", "eyebrow", "facility", "fact",
+    "failure", "faint", "fake", "false", "family", "famous", "fancy", "fangs", "fantasy", "fatal",
+    "fatigue", "favorite", "fawn", "fiber", "fiction", "filter", "finance", "findings", "finger",
+    "firefly", "firm", "fiscal", "fishing", "fitness", "flame", "flash", "flavor", "flea",
+    "flexible", "flip", "float", "floral", "fluff", "focus", "forbid", "force", "forecast",
+    "forget", "formal", "fortune", "forward", "founder", "fraction", "fragment", "frequent",
+    "freshman", "friar", "fridge", "friendly", "frost", "froth", "frozen", "fumes", "funding",
+    "furl", "fused", "galaxy", "game", "garbage", "garden", "garlic", "gasoline", "gather",
+    "general", "genius", "genre", "genuine", "geology", "gesture", "glad", "glance", "glasses",
+    "glen", "glimpse", "goat", "golden", "graduate", "grant", "grasp", "gravity", "gray",
+    "greatest", "grief", "grill", "grin", "grocery", "gross", "group", "grownup", "grumpy",
+    "guard", "guest", "guilt", "guitar", "gums", "hairy", "hamster", "hand", "hanger", "harvest",
+    "have", "havoc", "hawk", "hazard", "headset", "health", "hearing", "heat", "helpful", "herald",
+    "herd", "hesitate", "hobo", "holiday", "holy", "home", "hormone", "hospital", "hour", "huge",
+    "human", "humidity", "hunting", "husband", "hush", "husky", "hybrid", "idea", "identify",
+    "idle", "image", "impact", "imply", "improve", "impulse", "include", "income", "increase",
+    "index", "indicate", "industry", "infant", "inform", "inherit", "injury", "inmate", "insect",
+    "inside", "install", "intend", "intimate", "invasion", "involve", "iris", "island", "isolate",
+    "item", "ivory", "jacket", "jerky", "jewelry", "join", "judicial", "juice", "jump", "junction",
+    "junior", "junk", "jury", "justice", "kernel", "keyboard", "kidney", "kind", "kitchen",
+    "knife", "knit", "laden", "ladle", "ladybug", "lair", "lamp", "language", "large", "laser",
+    "laundry", "lawsuit", "leader", "leaf", "learn", "leaves", "lecture", "legal", "legend",
+    "legs", "lend", "length", "level", "liberty", "library", "license", "lift", "likely", "lilac",
+    "lily", "lips", "liquid", "listen", "literary", "living", "lizard", "loan", "lobe", "location",
+    "losing", "loud", "loyalty", "luck", "lunar", "lunch", "lungs", "luxury", "lying", "lyrics",
+    "machine", "magazine", "maiden", "mailman", "main", "makeup", "making", "mama", "manager",
+    "mandate", "mansion", "manual", "marathon", "march", "market", "marvel", "mason", "material",
+    "math", "maximum", "mayor", "meaning", "medal", "medical", "member", "memory", "mental",
+    "merchant", "merit", "method", "metric", "midst", "mild", "military", "mineral", "minister",
+    "miracle", "mixed", "mixture", "mobile", "modern", "modify", "moisture", "moment", "morning",
+    "mortgage", "mother", "mountain", "mouse", "move", "much", "mule", "multiple", "muscle",
+    "museum", "music", "mustang", "nail", "national", "necklace", "negative", "nervous", "network",
+    "news", "nuclear", "numb", "numerous", "nylon", "oasis", "obesity", "object", "observe",
+    "obtain", "ocean", "often", "olympic", "omit", "oral", "orange", "orbit", "order", "ordinary",
+    "organize", "ounce", "oven", "overall", "owner", "paces", "pacific", "package", "paid",
+    "painting", "pajamas", "pancake", "pants", "papa", "paper", "parcel", "parking", "party",
+    "patent", "patrol", "payment", "payroll", "peaceful", "peanut", "peasant", "pecan", "penalty",
+    "pencil", "percent", "perfect", "permit", "petition", "phantom", "pharmacy", "photo", "phrase",
+    "physics", "pickup", "picture", "piece", "pile", "pink", "pipeline", "pistol", "pitch",
+    "plains", "plan", "plastic", "platform", "playoff", "pleasure", "plot", "plunge", "practice",
+    "prayer", "preach", "predator", "pregnant", "premium", "prepare", "presence", "prevent",
+    "priest", "primary", "priority", "prisoner", "privacy", "prize", "problem", "process",
+    "profile", "program", "promise", "prospect", "provide", "prune", "public", "pulse", "pumps",
+    "punish", "puny", "pupal", "purchase", "purple", "python", "quantity", "quarter", "quick",
+    "quiet", "race", "racism", "radar", "railroad", "rainbow", "raisin", "random", "ranked",
+    "rapids", "raspy", "reaction", "realize", "rebound", "rebuild", "recall", "receiver",
+    "recover", "regret", "regular", "reject", "relate", "remember", "remind", "remove", "render",
+    "repair", "repeat", "replace", "require", "rescue", "research", "resident", "response",
+    "result", "retailer", "retreat", "reunion", "revenue", "review", "reward", "rhyme", "rhythm",
+    "rich", "rival", "river", "robin", "rocky", "romantic", "romp", "roster", "round", "royal",
+    "ruin", "ruler", "rumor", "sack", "safari", "salary", "salon", "salt", "satisfy", "satoshi",
+    "saver", "says", "scandal", "scared", "scatter", "scene", "scholar", "science", "scout",
+    "scramble", "screw", "script", "scroll", "seafood", "season", "secret", "security", "segment",
+    "senior", "shadow", "shaft", "shame", "shaped", "sharp", "shelter", "sheriff", "short",
+    "should", "shrimp", "sidewalk", "silent", "silver", "similar", "simple", "single", "sister",
+    "skin", "skunk", "slap", "slavery", "sled", "slice", "slim", "slow", "slush", "smart", "smear",
+    "smell", "smirk", "smith", "smoking", "smug", "snake", "snapshot", "sniff", "society",
+    "software", "soldier", "solution", "soul", "source", "space", "spark", "speak", "species",
+    "spelling", "spend", "spew", "spider", "spill", "spine", "spirit", "spit", "spray", "sprinkle",
+    "square", "squeeze", "stadium", "staff", "standard", "starting", "station", "stay", "steady",
+    "step", "stick", "stilt", "story", "strategy", "strike", "style", "subject", "submit", "sugar",
+    "suitable", "sunlight", "superior", "surface", "surprise", "survive", "sweater", "swimming",
+    "swing", "switch", "symbolic", "sympathy", "syndrome", "system", "tackle", "tactics",
+    "tadpole", "talent", "task", "taste", "taught", "taxi", "teacher", "teammate", "teaspoon",
+    "temple", "tenant", "tendency", "tension", "terminal", "testify", "texture", "thank", "that",
+    "theater", "theory", "therapy", "thorn", "threaten", "thumb", "thunder", "ticket", "tidy",
+    "timber", "timely", "ting", "tofu", "together", "tolerate", "total", "toxic", "tracks",
+    "traffic", "training", "transfer", "trash", "traveler", "treat", "trend", "trial", "tricycle",
+    "trip", "triumph", "trouble", "true", "trust", "twice", "twin", "type", "typical", "ugly",
+    "ultimate", "umbrella", "uncover", "undergo", "unfair", "unfold", "unhappy", "union",
+    "universe", "unkind", "unknown", "unusual", "unwrap", "upgrade", "upstairs", "username",
+    "usher", "usual", "valid", "valuable", "vampire", "vanish", "various", "vegan", "velvet",
+    "venture", "verdict", "verify", "very", "veteran", "vexed", "victim", "video", "view",
+    "vintage", "violence", "viral", "visitor", "visual", "vitamins", "vocal", "voice", "volume",
+    "voter", "voting", "walnut", "warmth", "warn", "watch", "wavy", "wealthy", "weapon", "webcam",
+    "welcome", "welfare", "western", "width", "wildlife", "window", "wine", "wireless", "wisdom",
+    "withdraw", "wits", "wolf", "woman", "work", "worthy", "wrap", "wrist", "writing", "wrote",
+    "year", "yelp", "yield", "yoga", "zero",
+];
+
+/// The number of bits encoded by each word.
+const NUM_BITS_PER_WORD: usize = 10;
+/// The number of bits of the identifier.
+const IDENTIFIER_NUM_BITS: usize = 15;
+/// The number of bits of each group and member parameter.
+const PARAMETER_NUM_BITS: usize = 4;
+/// The maximum number of groups and of members per group.
+const MAX_NUM_SHARES: usize = 1 << PARAMETER_NUM_BITS;
+/// The number of words that encode the identifier, the iteration exponent, and the group and
+/// member parameters.
+const METADATA_NUM_WORDS: usize = 4;
+/// The number of words of the checksum.
+const CHECKSUM_NUM_WORDS: usize = 3;
+/// The minimum number of bytes of a master secret.
+const MIN_SECRET_LENGTH: usize = 16;
+/// The minimum number of words of a share, which holds a master secret of minimum length.
+const MIN_NUM_WORDS: usize = METADATA_NUM_WORDS + CHECKSUM_NUM_WORDS + 13;
+/// The customization string of the checksum of shares of the original variant.
+const CUSTOMIZATION_STRING: &[u8] = b"shamir";
+/// The customization string of the checksum of shares of the extendable variant.
+const EXTENDABLE_CUSTOMIZATION_STRING: &[u8] = b"shamir_extendable";
+/// The generator of the RS1024 checksum.
+const RS1024_GENERATOR: [u32; 10] = [
+    0xe0e040, 0x1c1c080, 0x3838100, 0x7070200, 0xe0e0009, 0x1c0c2412, 0x38086c24, 0x3090fc48,
+    0x21b1f890, 0x3f3f120,
+];
+/// The x-coordinate at which the secret is stored.
+const SECRET_INDEX: u8 = 255;
+/// The x-coordinate at which the digest of the secret is stored.
+const DIGEST_INDEX: u8 = 254;
+/// The number of bytes of the digest of the secret.
+const DIGEST_LENGTH: usize = 4;
+/// The total number of PBKDF2 iterations for the iteration exponent 0.
+const BASE_ITERATION_COUNT: u32 = 10000;
+/// The number of rounds of the Feistel network.
+const NUM_ROUNDS: u8 = 4;
+/// The iteration exponent of new shares.
+const ITERATION_EXPONENT: u8 = 1;
+
+/// This struct holds a share in the SLIP-0039 format.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Slip39Share {
+    /// The random identifier of the master secret.
+    identifier: u16,
+    /// The flag whether the share belongs to the extendable variant.
+    extendable: bool,
+    /// The exponent that determines the number of PBKDF2 iterations.
+    iteration_exponent: u8,
+    /// The index of the group, starting at 0.
+    group_index: u8,
+    /// The number of groups required for the reconstruction.
+    group_threshold: u8,
+    /// The total number of groups.
+    group_count: u8,
+    /// The index of the member within the group, starting at 0.
+    member_index: u8,
+    /// The number of members of the group required to reconstruct the group secret.
+    member_threshold: u8,
+    /// The share value.
+    value: Vec<u8>,
+}
+
+impl Slip39Share {
+    /// The function returns the identifier of the master secret.
+    pub fn get_identifier(&self) -> u16 {
+        self.identifier
+    }
+
+    /// The function returns whether the share belongs to the extendable variant.
+    pub fn is_extendable(&self) -> bool {
+        self.extendable
+    }
+
+    /// The function returns the iteration exponent.
+    pub fn get_iteration_exponent(&self) -> u8 {
+        self.iteration_exponent
+    }
+
+    /// The function returns the index of the group, starting at 0.
+    pub fn get_group_index(&self) -> u8 {
+        self.group_index
+    }
+
+    /// The function returns the number of groups required for the reconstruction.
+    pub fn get_group_threshold(&self) -> u8 {
+        self.group_threshold
+    }
+
+    /// The function returns the total number of groups.
+    pub fn get_group_count(&self) -> u8 {
+        self.group_count
+    }
+
+    /// The function returns the index of the member within the group, starting at 0.
+    pub fn get_member_index(&self) -> u8 {
+        self.member_index
+    }
+
+    /// The function returns the number of members required to reconstruct the group secret.
+    pub fn get_member_threshold(&self) -> u8 {
+        self.member_threshold
+    }
+
+    /// The function returns the share value.
+    pub fn get_value(&self) -> &[u8] {
+        &self.value
+    }
+
+    /// The function returns the customization string of the checksum.
+    fn get_customization_string(&self) -> &'static [u8] {
+        if self.extendable {
+            EXTENDABLE_CUSTOMIZATION_STRING
+        } else {
+            CUSTOMIZATION_STRING
+        }
+    }
+
+    /// The function returns the indices of the words of the share without the checksum.
+    fn get_data_indices(&self) -> Vec<u32> {
+        let mut indices = vec![];
+        let header = (u32::from(self.identifier) << 5)
+            | (u32::from(self.extendable) << PARAMETER_NUM_BITS)
+            | u32::from(self.iteration_exponent);
+        // The group and member parameters are stored as thresholds and counts minus 1.
+        let parameters = [
+            self.group_index,
+            self.group_threshold - 1,
+            self.group_count - 1,
+            self.member_index,
+            self.member_threshold - 1,
+        ]
+        .iter()
+        .fold(0, |parameters, parameter| {
+            (parameters << PARAMETER_NUM_BITS) | u32::from(*parameter)
+        });
+        for value in [header, parameters] {
+            indices.push(value >> NUM_BITS_PER_WORD);
+            indices.push(value & ((1 << NUM_BITS_PER_WORD) - 1));
+        }
+        // The value is padded with leading zero bits to a multiple of the word size.
+        let num_value_words = (self.value.len() * 8).div_ceil(NUM_BITS_PER_WORD);
+        let value = BigUint::from_bytes_be(&self.value);
+        for position in (0..num_value_words).rev() {
+            let word = (&value >> (position * NUM_BITS_PER_WORD)) % (1u32 << NUM_BITS_PER_WORD);
+            // The remainder is smaller than the word list size, so it always fits into a u32.
+            indices.push(word.to_u32().unwrap_or(0));
+        }
+        indices
+    }
+}
+
+impl Drop for Slip39Share {
+    /// The share value is wiped from memory when the share is dropped.
+    fn drop(&mut self) {
+        self.value.zeroize();
+    }
+}
+
+impl fmt::Debug for Slip39Share {
+    /// The share value is not revealed in debug output.
+    ///
+    /// * `formatter` - The formatter.
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "Slip39Share(identifier {}, group {} of {}, member {}, {} bytes)",
+            self.identifier,
+            self.group_index + 1,
+            self.group_count,
+            self.member_index + 1,
+            self.value.len()
+        )
+    }
+}
+
+impl fmt::Display for Slip39Share {
+    /// A SLIP-0039 share is displayed as its mnemonic, i.e., the words separated by spaces.
+    ///
+    /// * `formatter` - The formatter.
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut indices = Zeroizing::new(self.get_data_indices());
+        let checksum = get_checksum(self.get_customization_string(), &indices);
+        indices.extend(checksum);
+        let words: Vec<&str> = indices
+            .iter()
+            .map(|index| WORD_LIST[*index as usize])
+            .collect();
+        write!(formatter, "{}", words.join(" "))
+    }
+}
+
+impl FromStr for Slip39Share {
+    type Err = HarpoError;
+
+    /// The function parses a SLIP-0039 share given as a mnemonic.
+    ///
+    /// An error is returned if a word is unknown, if the checksum is invalid, or if the group
+    /// and member parameters or the padding of the share value are invalid.
+    ///
+    /// * `input` - The mnemonic.
+    fn from_str(input: &str) -> HarpoResult<Self> {
+        let words: Vec<String> = input
+            .split_whitespace()
+            .map(|word| word.to_lowercase())
+            .collect();
+        if words.len() < MIN_NUM_WORDS {
+            return Err(HarpoError::InvalidSeedPhrase(format!(
+                "A SLIP-0039 share must consist of at least {} words.",
+                MIN_NUM_WORDS
+            )));
+        }
+        let mut indices = Zeroizing::new(vec![]);
+        for (position, word) in words.iter().enumerate() {
+            match WORD_LIST.binary_search(&word.as_str()) {
+                Ok(index) => indices.push(index as u32),
+                Err(_) => {
+                    return Err(HarpoError::UnknownWord {
+                        word: word.to_string(),
+                        position,
+                        suggestions: get_word_suggestions(word, WORD_LIST)
+                            .into_iter()
+                            .take(MAX_WORD_SUGGESTIONS)
+                            .map(str::to_string)
+                            .collect(),
+                    })
+                }
+            }
+        }
+        let header = (indices[0] << NUM_BITS_PER_WORD) | indices[1];
+        let extendable = (header >> PARAMETER_NUM_BITS) & 1 == 1;
+        let customization_string = if extendable {
+            EXTENDABLE_CUSTOMIZATION_STRING
+        } else {
+            CUSTOMIZATION_STRING
+        };
+        if get_polymod(customization_string, &indices) != 1 {
+            return Err(HarpoError::ChecksumMismatch);
+        }
+        let parameters = (indices[2] << NUM_BITS_PER_WORD) | indices[3];
+        let get_parameter = |position: usize| {
+            ((parameters >> (position * PARAMETER_NUM_BITS)) & (MAX_NUM_SHARES as u32 - 1)) as u8
+        };
+        let group_threshold = get_parameter(3) + 1;
+        let group_count = get_parameter(2) + 1;
+        if group_threshold > group_count {
+            return Err(HarpoError::InvalidSeedPhrase(
+                "The group threshold of the SLIP-0039 share exceeds the group count.".to_string(),
+            ));
+        }
+        // The value is padded with at most 8 leading zero bits to a multiple of the word size.
+        let value_indices = &indices[METADATA_NUM_WORDS..indices.len() - CHECKSUM_NUM_WORDS];
+        let num_value_bits = value_indices.len() * NUM_BITS_PER_WORD;
+        let value_length = num_value_bits / 16 * 2;
+        let mut value = BigUint::default();
+        for index in value_indices {
+            value = (value << NUM_BITS_PER_WORD) + *index;
+        }
+        if num_value_bits % 16 > 8 || value.bits() > (value_length * 8) as u64 {
+            return Err(HarpoError::InvalidSeedPhrase(
+                "The padding of the SLIP-0039 share is invalid.".to_string(),
+            ));
+        }
+        let value_bytes = Zeroizing::new(value.to_bytes_be());
+        let mut share_value = vec![0; value_length];
+        // The value of zero is encoded as a single zero byte.
+        if value.bits() > 0 {
+            share_value[value_length - value_bytes.len()..].copy_from_slice(&value_bytes);
+        }
+        Ok(Slip39Share {
+            identifier: (header >> 5) as u16,
+            extendable,
+            iteration_exponent: (header & (MAX_NUM_SHARES as u32 - 1)) as u8,
+            group_index: get_parameter(4),
+            group_threshold,
+            group_count,
+            member_index: get_parameter(1),
+            member_threshold: get_parameter(0) + 1,
+            value: share_value,
+        })
+    }
+}
+
+/// The function computes the RS1024 polynomial modulus of the customization string followed by
+/// the given word indices.
+///
+/// * `customization_string` - The customization string.
+/// * `indices` - The word indices.
+fn get_polymod(customization_string: &[u8], indices: &[u32]) -> u32 {
+    let mut checksum = 1;
+    let values = customization_string
+        .iter()
+        .map(|byte| u32::from(*byte))
+        .chain(indices.iter().copied());
+    for value in values {
+        let top = checksum >> 20;
+        checksum = ((checksum & 0xfffff) << NUM_BITS_PER_WORD) ^ value;
+        for (bit, generator) in RS1024_GENERATOR.iter().enumerate() {
+            if (top >> bit) & 1 == 1 {
+                checksum ^= generator;
+            }
+        }
+    }
+    checksum
+}
+
+/// The function returns the indices of the checksum words for the given word indices.
+///
+/// * `customization_string` - The customization string.
+/// * `indices` - The word indices without the checksum.
+fn get_checksum(customization_string: &[u8], indices: &[u32]) -> Vec<u32> {
+    let mut padded_indices = indices.to_vec();
+    padded_indices.extend([0; CHECKSUM_NUM_WORDS]);
+    let polymod = get_polymod(customization_string, &padded_indices) ^ 1;
+    (0..CHECKSUM_NUM_WORDS)
+        .rev()
+        .map(|position| {
+            (polymod >> (position * NUM_BITS_PER_WORD)) & ((1 << NUM_BITS_PER_WORD) - 1)
+        })
+        .collect()
+}
+
+/// The function computes HMAC-SHA256 for the given key and data.
+///
+/// The output is wiped from memory when it is dropped.
+///
+/// * `key` - The HMAC key.
+/// * `data` - The data.
+fn get_hmac_sha256(key: &[u8], data: &[u8]) -> Zeroizing<Vec<u8>> {
+    // The unwrap() call is okay because HMAC accepts keys of any length.
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).unwrap();
+    mac.update(data);
+    Zeroizing::new(mac.finalize().into_bytes().to_vec())
+}
+
+/// The function encrypts or decrypts the given secret with the given passphrase.
+///
+/// The Feistel network applies the rounds in reverse order for the decryption.
+///
+/// * `input` - The master secret or the encrypted master secret.
+/// * `passphrase` - The passphrase.
+/// * `identifier` - The identifier of the master secret.
+/// * `extendable` - The flag whether the shares belong to the extendable variant.
+/// * `iteration_exponent` - The iteration exponent.
+/// * `encrypt` - The flag whether the input is encrypted or decrypted.
+fn apply_feistel_network(
+    input: &[u8],
+    passphrase: &str,
+    identifier: u16,
+    extendable: bool,
+    iteration_exponent: u8,
+    encrypt: bool,
+) -> Zeroizing<Vec<u8>> {
+    let half = input.len() / 2;
+    let mut left = Zeroizing::new(input[..half].to_vec());
+    let mut right = Zeroizing::new(input[half..].to_vec());
+    // The identifier is only part of the salt for shares of the original variant.
+    let mut salt = vec![];
+    if !extendable {
+        salt.extend_from_slice(CUSTOMIZATION_STRING);
+        salt.extend_from_slice(&identifier.to_be_bytes());
+    }
+    let num_iterations = (BASE_ITERATION_COUNT << iteration_exponent) / u32::from(NUM_ROUNDS);
+    let rounds: Vec<u8> = if encrypt {
+        (0..NUM_ROUNDS).collect()
+    } else {
+        (0..NUM_ROUNDS).rev().collect()
+    };
+    for round in rounds {
+        let mut password = Zeroizing::new(vec![round]);
+        password.extend_from_slice(passphrase.as_bytes());
+        let mut round_salt = Zeroizing::new(salt.clone());
+        round_salt.extend_from_slice(&right);
+        let mut round_key = Zeroizing::new(vec![0; right.len()]);
+        pbkdf2::pbkdf2_hmac::<Sha256>(&password, &round_salt, num_iterations, &mut round_key);
+        let new_right = Zeroizing::new(
+            left.iter()
+                .zip(round_key.iter())
+                .map(|(left_byte, key_byte)| left_byte ^ key_byte)
+                .collect(),
+        );
+        left = right;
+        right = new_right;
+    }
+    let mut output = Zeroizing::new(right.to_vec());
+    output.extend_from_slice(&left);
+    output
+}
+
+/// The function evaluates the polynomial through the given points at the given x-coordinate.
+///
+/// The x-coordinates of the points must be distinct.
+///
+/// * `points` - The x-coordinates and the values of the points.
+/// * `x` - The x-coordinate at which the polynomial is evaluated.
+fn interpolate(points: &[(u8, &[u8])], x: u8) -> Zeroizing<Vec<u8>> {
+    if let Some((_, value)) = points.iter().find(|(index, _)| *index == x) {
+        return Zeroizing::new(value.to_vec());
+    }
+    let mut result = Zeroizing::new(vec![0; points[0].1.len()]);
+    for (index, value) in points {
+        // The Lagrange basis polynomial of the point, evaluated at x.
+        let (numerator, denominator) = points
+            .iter()
+            .filter(|(other_index, _)| other_index != index)
+            .fold((1, 1), |(numerator, denominator), (other_index, _)| {
+                (
+                    gf256_mul(numerator, x ^ other_index),
+                    gf256_mul(denominator, index ^ other_index),
+                )
+            });
+        let coefficient = gf256_mul(numerator, gf256_inverse(denominator));
+        for (result_byte, value_byte) in result.iter_mut().zip(value.iter()) {
+            *result_byte ^= gf256_mul(*value_byte, coefficient);
+        }
+    }
+    result
+}
+
+/// The function returns the digest that protects the given secret.
+///
+/// * `random_part` - The random part of the digest share.
+/// * `secret` - The secret.
+fn get_digest(random_part: &[u8], secret: &[u8]) -> Vec<u8> {
+    get_hmac_sha256(random_part, secret)[..DIGEST_LENGTH].to_vec()
+}
+
+/// The function secret-shares the given secret, returning the secret shares ordered by index.
+///
+/// Unless the threshold is 1, a digest of the secret is embedded so that the reconstruction
+/// can detect inconsistent secret shares.
+///
+/// * `threshold` - The threshold.
+/// * `num_shares` - The number of secret shares.
+/// * `secret` - The secret.
+fn split_secret(threshold: usize, num_shares: usize, secret: &[u8]) -> Vec<Zeroizing<Vec<u8>>> {
+    if threshold == 1 {
+        return (0..num_shares)
+            .map(|_| Zeroizing::new(secret.to_vec()))
+            .collect();
+    }
+    let random_bytes = || {
+        let mut bytes = Zeroizing::new(vec![0; secret.len()]);
+        OsRng.fill_bytes(&mut bytes);
+        bytes
+    };
+    // The first threshold - 2 secret shares are random.
+    let mut shares: Vec<Zeroizing<Vec<u8>>> = (0..threshold - 2).map(|_| random_bytes()).collect();
+    let mut digest_share = random_bytes();
+    let digest = get_digest(&digest_share[DIGEST_LENGTH..], secret);
+    digest_share[..DIGEST_LENGTH].copy_from_slice(&digest);
+    let mut points: Vec<(u8, &[u8])> = shares
+        .iter()
+        .enumerate()
+        .map(|(index, share)| (index as u8, &share[..]))
+        .collect();
+    points.push((DIGEST_INDEX, &digest_share));
+    points.push((SECRET_INDEX, secret));
+    let interpolated_shares: Vec<Zeroizing<Vec<u8>>> = (threshold - 2..num_shares)
+        .map(|index| interpolate(&points, index as u8))
+        .collect();
+    shares.extend(interpolated_shares);
+    shares
+}
+
+/// The function reconstructs the secret from the given secret shares and checks its digest.
+///
+/// * `threshold` - The threshold.
+/// * `shares` - The indices and the values of exactly `threshold` secret shares.
+fn recover_secret(threshold: usize, shares: &[(u8, &[u8])]) -> HarpoResult<Zeroizing<Vec<u8>>> {
+    if threshold == 1 {
+        return Ok(Zeroizing::new(shares[0].1.to_vec()));
+    }
+    let secret = interpolate(shares, SECRET_INDEX);
+    let digest_share = interpolate(shares, DIGEST_INDEX);
+    if get_digest(&digest_share[DIGEST_LENGTH..], &secret) != digest_share[..DIGEST_LENGTH] {
+        return Err(HarpoError::InvalidSeedPhrase(
+            "The SLIP-0039 shares are inconsistent.".to_string(),
+        ));
+    }
+    Ok(secret)
+}
+
+/// The function checks the parameters for the creation of SLIP-0039 shares.
+///
+/// * `master_secret` - The master secret.
+/// * `passphrase` - The passphrase.
+/// * `group_threshold` - The number of groups required for the reconstruction.
+/// * `groups` - The member threshold and the number of members of each group.
+fn check_split_parameters(
+    master_secret: &[u8],
+    passphrase: &str,
+    group_threshold: usize,
+    groups: &[(usize, usize)],
+) -> HarpoResult<()> {
+    if master_secret.len() < MIN_SECRET_LENGTH || !master_secret.len().is_multiple_of(2) {
+        return Err(HarpoError::InvalidParameter(format!(
+            "The master secret must consist of an even number of at least {} bytes.",
+            MIN_SECRET_LENGTH
+        )));
+    }
+    if !passphrase.bytes().all(|byte| (32..=126).contains(&byte)) {
+        return Err(HarpoError::InvalidParameter(
+            "The passphrase must consist of printable ASCII characters.".to_string(),
+        ));
+    }
+    if group_threshold == 0 || group_threshold > groups.len() || groups.len() > MAX_NUM_SHARES {
+        return Err(HarpoError::InvalidParameter(format!(
+            "The group threshold must be at least 1 and at most the number of groups, which \
+            must not exceed {}.",
+            MAX_NUM_SHARES
+        )));
+    }
+    for (member_threshold, num_members) in groups {
+        if *member_threshold == 0 || member_threshold > num_members || *num_members > MAX_NUM_SHARES
+        {
+            return Err(HarpoError::InvalidParameter(format!(
+                "The member threshold must be at least 1 and at most the number of members, \
+                which must not exceed {}.",
+                MAX_NUM_SHARES
+            )));
+        }
+        if *member_threshold == 1 && *num_members > 1 {
+            return Err(HarpoError::InvalidParameter(
+                "Groups with a member threshold of 1 must consist of a single member.".to_string(),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// The function creates SLIP-0039 shares of the given master secret.
+///
+/// The shares are returned ordered by group and member.
+///
+/// * `master_secret` - The master secret.
+/// * `passphrase` - The passphrase.
+/// * `group_threshold` - The number of groups required for the reconstruction.
+/// * `groups` - The member threshold and the number of members of each group.
+pub(crate) fn split_master_secret(
+    master_secret: &[u8],
+    passphrase: &str,
+    group_threshold: usize,
+    groups: &[(usize, usize)],
+) -> HarpoResult<Vec<Slip39Share>> {
+    check_split_parameters(master_secret, passphrase, group_threshold, groups)?;
+    let identifier = (OsRng.next_u32() & ((1 << IDENTIFIER_NUM_BITS) - 1)) as u16;
+    let encrypted_secret = apply_feistel_network(
+        master_secret,
+        passphrase,
+        identifier,
+        false,
+        ITERATION_EXPONENT,
+        true,
+    );
+    let group_secrets = split_secret(group_threshold, groups.len(), &encrypted_secret);
+    let mut shares = vec![];
+    for (group_index, (group_secret, (member_threshold, num_members))) in
+        group_secrets.iter().zip(groups).enumerate()
+    {
+        let member_secrets = split_secret(*member_threshold, *num_members, group_secret);
+        for (member_index, member_secret) in member_secrets.iter().enumerate() {
+            shares.push(Slip39Share {
+                identifier,
+                extendable: false,
+                iteration_exponent: ITERATION_EXPONENT,
+                group_index: group_index as u8,
+                group_threshold: group_threshold as u8,
+                group_count: groups.len() as u8,
+                member_index: member_index as u8,
+                member_threshold: *member_threshold as u8,
+                value: member_secret.to_vec(),
+            });
+        }
+    }
+    Ok(shares)
+}
+
+/// The function reconstructs the master secret from the given SLIP-0039 shares.
+///
+/// All shares must belong to the same master secret. Surplus shares of a group and surplus
+/// groups are ignored. An error is returned if the shares do not suffice or if they are
+/// inconsistent.
+///
+/// * `shares` - The SLIP-0039 shares.
+/// * `passphrase` - The passphrase.
+pub(crate) fn combine_shares(
+    shares: &[Slip39Share],
+    passphrase: &str,
+) -> HarpoResult<Zeroizing<Vec<u8>>> {
+    let first_share = shares.first().ok_or_else(|| {
+        HarpoError::InvalidParameter("No SLIP-0039 shares were provided.".to_string())
+    })?;
+    let mut groups: BTreeMap<u8, Vec<&Slip39Share>> = BTreeMap::new();
+    for share in shares {
+        if share.identifier != first_share.identifier
+            || share.extendable != first_share.extendable
+            || share.iteration_exponent != first_share.iteration_exponent
+            || share.group_threshold != first_share.group_threshold
+            || share.group_count != first_share.group_count
+        {
+            return Err(HarpoError::InvalidParameter(
+                "The SLIP-0039 shares belong to different master secrets.".to_string(),
+            ));
+        }
+        if share.value.len() != first_share.value.len() {
+            return Err(HarpoError::LengthMismatchAcrossShares);
+        }
+        let group = groups.entry(share.group_index).or_default();
+        if group
+            .iter()
+            .any(|member| member.member_threshold != share.member_threshold)
+        {
+            return Err(HarpoError::InvalidParameter(format!(
+                "The SLIP-0039 shares of group {} have different member thresholds.",
+                share.group_index + 1
+            )));
+        }
+        // Duplicate shares are ignored.
+        if !group
+            .iter()
+            .any(|member| member.member_index == share.member_index)
+        {
+            group.push(share);
+        }
+    }
+    let group_threshold = usize::from(first_share.group_threshold);
+    let mut group_secrets = vec![];
+    for (group_index, members) in &groups {
+        let member_threshold = usize::from(members[0].member_threshold);
+        if members.len() >= member_threshold && group_secrets.len() < group_threshold {
+            let member_shares: Vec<(u8, &[u8])> = members[..member_threshold]
+                .iter()
+                .map(|member| (member.member_index, &member.value[..]))
+                .collect();
+            group_secrets.push((
+                *group_index,
+                recover_secret(member_threshold, &member_shares)?,
+            ));
+        }
+    }
+    if group_secrets.len() < group_threshold {
+        return Err(HarpoError::InvalidParameter(format!(
+            "The SLIP-0039 shares complete {} of the {} required groups.",
+            group_secrets.len(),
+            group_threshold
+        )));
+    }
+    let group_shares: Vec<(u8, &[u8])> = group_secrets
+        .iter()
+        .map(|(group_index, group_secret)| (*group_index, &group_secret[..]))
+        .collect();
+    let encrypted_secret = recover_secret(group_threshold, &group_shares)?;
+    Ok(apply_feistel_network(
+        &encrypted_secret,
+        passphrase,
+        first_share.identifier,
+        first_share.extendable,
+        first_share.iteration_exponent,
+        false,
+    ))
+}
+
+// ******************************** TESTS ********************************
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The function encodes the given bytes as a lower-case hex string.
+    ///
+    /// * `bytes` - The bytes.
+    fn encode_hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+    }
+
+    /// The function parses the given mnemonics.
+    ///
+    /// * `mnemonics` - The mnemonics.
+    fn parse_shares(mnemonics: &[&str]) -> Vec<Slip39Share> {
+        mnemonics
+            .iter()
+            .map(|mnemonic| mnemonic.parse::<Slip39Share>().unwrap())
+            .collect()
+    }
+
+    #[test]
+    /// The function tests the reconstruction using the SLIP-0039 test vectors.
+    fn test_slip39_vectors() {
+        let test_vectors = [
+            (
+                vec![
+                    "duckling enlarge academic academic agency result length solution fridge \
+                    kidney coal piece deal husband erode duke ajar critical decision keyboard",
+                ],
+                "bb54aac4b89dc868ba37d9cc21b2cece",
+            ),
+            (
+                vec![
+                    "shadow pistol academic always adequate wildlife fancy gross oasis cylinder \
+                    mustang wrist rescue view short owner flip making coding armed",
+                    "shadow pistol academic acid actress prayer class unknown daughter sweater \
+                    depict flip twice unkind craft early superior advocate guest smoking",
+                ],
+                "b43ceb7e57a0ea8766221624d01b0864",
+            ),
+            (
+                vec![
+                    "theory painting academic academic armed sweater year military elder \
+                    discuss acne wildlife boring employer fused large satoshi bundle carbon \
+                    diagnose anatomy hamster leaves tracks paces beyond phantom capital marvel \
+                    lips brave detect luck",
+                ],
+                "989baf9dcaad5b10ca33dfd8cc75e42477025dce88ae83e75a230086a0e00e92",
+            ),
+        ];
+        for (mnemonics, master_secret) in test_vectors {
+            let shares = parse_shares(&mnemonics);
+            for (share, mnemonic) in shares.iter().zip(&mnemonics) {
+                assert_eq!(
+                    share.to_string(),
+                    mnemonic.split_whitespace().collect::<Vec<_>>().join(" ")
+                );
+            }
+            assert_eq!(
+                encode_hex(&combine_shares(&shares, "TREZOR").unwrap()),
+                master_secret
+            );
+        }
+        // A single share of a 2-of-3 scheme does not suffice.
+        let shares = parse_shares(&[
+            "shadow pistol academic always adequate wildlife fancy gross oasis cylinder mustang \
+            wrist rescue view short owner flip making coding armed",
+        ]);
+        assert!(combine_shares(&shares, "TREZOR").is_err());
+        // A modified word invalidates the checksum.
+        assert!(matches!(
+            "duckling enlarge academic academic agency result length solution fridge kidney coal \
+            piece deal husband erode duke ajar critical decision kidney"
+                .parse::<Slip39Share>(),
+            Err(HarpoError::ChecksumMismatch)
+        ));
+        assert!(matches!(
+            "duckling enlarge academic academic agency result length solution fridge kidney coal \
+            piece deal husband erode duke ajar critical decision keybord"
+                .parse::<Slip39Share>(),
+            Err(HarpoError::UnknownWord { position: 19, .. })
+        ));
+    }
+
+    #[test]
+    /// The function tests the creation and reconstruction of SLIP-0039 shares.
+    fn test_split_and_combine() {
+        let master_secret: Vec<u8> = (0..32).collect();
+        let shares =
+            split_master_secret(&master_secret, "passphrase", 2, &[(2, 3), (1, 1), (3, 5)])
+                .unwrap();
+        assert_eq!(shares.len(), 9);
+        let shares: Vec<Slip39Share> = shares
+            .iter()
+            .map(|share| share.to_string().parse::<Slip39Share>().unwrap())
+            .collect();
+        // The second and the third group suffice.
+        let selected_shares = [
+            shares[3].clone(),
+            shares[8].clone(),
+            shares[4].clone(),
+            shares[6].clone(),
+        ];
+        assert_eq!(
+            combine_shares(&selected_shares, "passphrase").unwrap()[..],
+            master_secret[..]
+        );
+        // A different passphrase results in a different master secret.
+        assert_ne!(
+            combine_shares(&selected_shares, "").unwrap()[..],
+            master_secret[..]
+        );
+        // The third group is incomplete.
+        assert!(combine_shares(&selected_shares[..3], "passphrase").is_err());
+        // Invalid parameters are rejected.
+        assert!(split_master_secret(&master_secret[..15], "", 1, &[(1, 1)]).is_err());
+        assert!(split_master_secret(&master_secret, "", 2, &[(1, 1)]).is_err());
+        assert!(split_master_secret(&master_secret, "", 1, &[(1, 2)]).is_err());
+        assert!(split_master_secret(&master_secret, "", 1, &[(2, 17)]).is_err());
+    }
+}
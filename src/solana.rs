@@ -0,0 +1,278 @@
+//! The `solana` module provides Solana private keys, i.e., Ed25519 secret keys, which can be
+//! secret-shared as byte arrays.
+//!
+//! A Solana private key is given either as a keypair, i.e., the Base58 encoding of the 32-byte
+//! secret key followed by the 32-byte public key, or as a raw Ed25519 secret key of 64 hex
+//! characters. The encoding is retained so that a reconstructed key is emitted in the same
+//! encoding. When reconstructing a key, the derived address can be used to confirm that the
+//! expected key was reconstructed.
+//!
+
+use crate::bitcoin::{decode_base58, encode_base58};
+use crate::{HarpoError, HarpoResult};
+use ed25519_dalek::{SigningKey, SECRET_KEY_LENGTH};
+use std::fmt;
+use std::str::FromStr;
+use subtle::ConstantTimeEq;
+use zeroize::{Zeroize, ZeroizeOnDrop, Zeroizing};
+
+/// The number of bytes of an Ed25519 secret key and of an Ed25519 public key.
+const KEY_LENGTH: usize = SECRET_KEY_LENGTH;
+
+/// The serialization tag of keys in the keypair encoding.
+const KEYPAIR_TAG: u8 = 0x01;
+/// The serialization tag of keys in the raw secret key encoding.
+const SECRET_KEY_TAG: u8 = 0x02;
+
+/// This enumeration type defines the encoding of a Solana private key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SolanaKeyEncoding {
+    /// The Base58 encoding of the secret key followed by the public key.
+    Keypair,
+    /// The hex encoding of the raw Ed25519 secret key.
+    SecretKey,
+}
+
+/// This struct holds a Solana private key together with its encoding.
+///
+/// The secret key is wiped from memory when it is dropped, and private keys are compared in
+/// constant time.
+#[derive(Clone, Eq)]
+pub struct SolanaPrivateKey {
+    /// The Ed25519 secret key.
+    secret_key: [u8; KEY_LENGTH],
+    /// The encoding of the private key.
+    encoding: SolanaKeyEncoding,
+}
+
+impl SolanaPrivateKey {
+    /// The function creates a Solana private key from the given Ed25519 secret key.
+    ///
+    /// * `secret_key` - The Ed25519 secret key.
+    /// * `encoding` - The encoding of the private key.
+    pub fn new(secret_key: [u8; KEY_LENGTH], encoding: SolanaKeyEncoding) -> Self {
+        SolanaPrivateKey {
+            secret_key,
+            encoding,
+        }
+    }
+
+    /// The function returns the Ed25519 secret key.
+    pub fn get_secret_key(&self) -> &[u8; KEY_LENGTH] {
+        &self.secret_key
+    }
+
+    /// The function returns the encoding of the private key.
+    pub fn get_encoding(&self) -> SolanaKeyEncoding {
+        self.encoding
+    }
+
+    /// The function returns the Ed25519 public key.
+    pub fn get_public_key(&self) -> [u8; KEY_LENGTH] {
+        SigningKey::from_bytes(&self.secret_key)
+            .verifying_key()
+            .to_bytes()
+    }
+
+    /// The function returns the Solana address, i.e., the Base58 encoding of the public key.
+    pub fn get_address(&self) -> String {
+        encode_base58(&self.get_public_key())
+    }
+
+    /// The function returns the serialization of the private key, consisting of a tag for the
+    /// encoding followed by the secret key.
+    pub(crate) fn get_serialized_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![match self.encoding {
+            SolanaKeyEncoding::Keypair => KEYPAIR_TAG,
+            SolanaKeyEncoding::SecretKey => SECRET_KEY_TAG,
+        }];
+        bytes.extend_from_slice(&self.secret_key);
+        bytes
+    }
+
+    /// The function parses the serialization of a private key.
+    ///
+    /// * `bytes` - The serialized private key.
+    pub(crate) fn from_serialized_bytes(bytes: &[u8]) -> HarpoResult<Self> {
+        let encoding = match bytes.first() {
+            Some(&KEYPAIR_TAG) => SolanaKeyEncoding::Keypair,
+            Some(&SECRET_KEY_TAG) => SolanaKeyEncoding::SecretKey,
+            _ => {
+                return Err(HarpoError::InvalidParameter(
+                    "Unknown encoding of the Solana private key.".to_string(),
+                ))
+            }
+        };
+        if bytes.len() != KEY_LENGTH + 1 {
+            return Err(HarpoError::InvalidParameter(
+                "Invalid length of the Solana private key.".to_string(),
+            ));
+        }
+        let mut secret_key = Zeroizing::new([0; KEY_LENGTH]);
+        secret_key.copy_from_slice(&bytes[1..]);
+        Ok(SolanaPrivateKey::new(*secret_key, encoding))
+    }
+}
+
+impl PartialEq for SolanaPrivateKey {
+    /// The secret keys are compared in constant time.
+    ///
+    /// * `other` - The other private key.
+    fn eq(&self, other: &Self) -> bool {
+        bool::from(self.secret_key.ct_eq(&other.secret_key)) && self.encoding == other.encoding
+    }
+}
+
+impl Zeroize for SolanaPrivateKey {
+    /// The secret key is overwritten with zeros.
+    fn zeroize(&mut self) {
+        self.secret_key.zeroize();
+    }
+}
+
+impl Drop for SolanaPrivateKey {
+    /// The secret key is wiped from memory when it is dropped.
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for SolanaPrivateKey {}
+
+impl fmt::Debug for SolanaPrivateKey {
+    /// The private key is not revealed in debug output.
+    ///
+    /// * `formatter` - The formatter.
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "SolanaPrivateKey({}, {:?})",
+            self.get_address(),
+            self.encoding
+        )
+    }
+}
+
+impl fmt::Display for SolanaPrivateKey {
+    /// A Solana private key is displayed in its encoding: a keypair in Base58 encoding and a raw
+    /// secret key as a lower-case hex string.
+    ///
+    /// * `formatter` - The formatter.
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.encoding {
+            SolanaKeyEncoding::Keypair => {
+                let mut keypair = Zeroizing::new(self.secret_key.to_vec());
+                keypair.extend_from_slice(&self.get_public_key());
+                write!(formatter, "{}", encode_base58(&keypair))
+            }
+            SolanaKeyEncoding::SecretKey => {
+                for byte in &self.secret_key {
+                    write!(formatter, "{:02x}", byte)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl FromStr for SolanaPrivateKey {
+    type Err = HarpoError;
+
+    /// The function parses a Solana private key.
+    ///
+    /// A string of 64 hex characters, optionally preceded by `0x`, is parsed as a raw secret key.
+    /// Any other string is parsed as a Base58-encoded keypair, whose public key must match the
+    /// secret key.
+    ///
+    /// * `input` - The encoded private key.
+    fn from_str(input: &str) -> HarpoResult<Self> {
+        let input = input.trim();
+        let hex_input = input.strip_prefix("0x").unwrap_or(input);
+        let mut secret_key = Zeroizing::new([0; KEY_LENGTH]);
+        if hex_input.len() == 2 * KEY_LENGTH && hex_input.chars().all(|c| c.is_ascii_hexdigit()) {
+            for (index, byte) in secret_key.iter_mut().enumerate() {
+                *byte = u8::from_str_radix(&hex_input[2 * index..2 * index + 2], 16)?;
+            }
+            return Ok(SolanaPrivateKey::new(
+                *secret_key,
+                SolanaKeyEncoding::SecretKey,
+            ));
+        }
+        let keypair = Zeroizing::new(decode_base58(input)?);
+        if keypair.len() != 2 * KEY_LENGTH {
+            return Err(HarpoError::InvalidParameter(format!(
+                "A Solana keypair must consist of {} bytes.",
+                2 * KEY_LENGTH
+            )));
+        }
+        secret_key.copy_from_slice(&keypair[..KEY_LENGTH]);
+        let private_key = SolanaPrivateKey::new(*secret_key, SolanaKeyEncoding::Keypair);
+        if private_key.get_public_key()[..] != keypair[KEY_LENGTH..] {
+            return Err(HarpoError::InvalidParameter(
+                "The public key of the Solana keypair does not match the secret key.".to_string(),
+            ));
+        }
+        Ok(private_key)
+    }
+}
+
+// ******************************** TESTS ********************************
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// The function tests the parsing and encoding of Solana private keys.
+    fn test_solana_private_key() {
+        let secret_key = "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60";
+        let private_key = secret_key.parse::<SolanaPrivateKey>().unwrap();
+        assert_eq!(private_key.get_encoding(), SolanaKeyEncoding::SecretKey);
+        assert_eq!(private_key.to_string(), secret_key);
+        let address = private_key.get_address();
+        assert_eq!(decode_base58(&address).unwrap()[..2], [0xd7, 0x5a]);
+        // The public keys match the test vectors of RFC 8032.
+        let test_vectors = [
+            (
+                "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60",
+                "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a",
+            ),
+            (
+                "4ccd089b28ff96da9db6c346ec114e0f5b8a319f35aba624da8cf6ed4fb8a6fb",
+                "3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c",
+            ),
+        ];
+        for (secret_key, public_key) in test_vectors {
+            let public_key_hex: String = secret_key
+                .parse::<SolanaPrivateKey>()
+                .unwrap()
+                .get_public_key()
+                .iter()
+                .map(|byte| format!("{:02x}", byte))
+                .collect();
+            assert_eq!(public_key_hex, public_key);
+        }
+        // The same secret key in the keypair encoding.
+        let keypair =
+            SolanaPrivateKey::new(*private_key.get_secret_key(), SolanaKeyEncoding::Keypair)
+                .to_string();
+        let parsed_keypair = keypair.parse::<SolanaPrivateKey>().unwrap();
+        assert_eq!(parsed_keypair.get_encoding(), SolanaKeyEncoding::Keypair);
+        assert_eq!(parsed_keypair.to_string(), keypair);
+        assert_eq!(parsed_keypair.get_address(), address);
+        // The serialization retains the encoding.
+        let serialized_keypair = parsed_keypair.get_serialized_bytes();
+        assert_eq!(
+            SolanaPrivateKey::from_serialized_bytes(&serialized_keypair).unwrap(),
+            parsed_keypair
+        );
+        assert!(SolanaPrivateKey::from_serialized_bytes(&serialized_keypair[1..]).is_err());
+        // Keypairs with a mismatching public key are rejected.
+        let mut bytes = decode_base58(&keypair).unwrap();
+        bytes[2 * KEY_LENGTH - 1] ^= 1;
+        assert!(encode_base58(&bytes).parse::<SolanaPrivateKey>().is_err());
+        assert!(encode_base58(&bytes[1..])
+            .parse::<SolanaPrivateKey>()
+            .is_err());
+    }
+}
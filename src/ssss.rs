@@ -0,0 +1,704 @@
+//! The `ssss` module provides the import and export of secret shares in the format of the
+//! classic [ssss](http://point-at-infinity.org/ssss/) tool.
+//!
+//! The tool `ssss-split` secret-shares the secret over the binary field GF(2^m), where the
+//! security level m is a multiple of 8 up to 1024 bits, by default 8 bits per character of the
+//! secret. Each share is displayed as its index, optionally preceded by a token, followed by its
+//! value as a hex string, e.g., `3-fa1c3a9c6df8af07`. Unless disabled, a diffusion layer based on
+//! the XTEA block cipher is applied to the secret if the security level is at least 64 bits.
+//! Note that the shares reveal neither the threshold, which must be provided when reconstructing
+//! the secret, nor whether they are consistent, i.e., wrong shares result in a wrong secret.
+//!
+
+use crate::{HarpoError, HarpoResult};
+use num_bigint::BigUint;
+use num_traits::{One, Zero};
+use rand::{rngs::OsRng, RngCore};
+use std::collections::HashSet;
+use std::fmt;
+use std::str::FromStr;
+use zeroize::{Zeroize, Zeroizing};
+
+/// The maximum number of secret shares, which ensures that all indices are field elements.
+pub const MAX_NUM_SHARES: usize = 255;
+/// The maximum length of a secret in bytes, i.e., the maximum security level is 1024 bits.
+const MAX_SECRET_LENGTH: usize = 128;
+/// The maximum length of a token.
+const MAX_TOKEN_LENGTH: usize = 128;
+/// The minimum security level in bits at which the diffusion layer is applied.
+const MIN_DIFFUSION_SECURITY_LEVEL: usize = 64;
+/// The number of XTEA applications of the diffusion layer per byte of the secret.
+const NUM_DIFFUSION_ROUNDS_PER_BYTE: usize = 40;
+/// The number of rounds of the XTEA block cipher.
+const XTEA_NUM_ROUNDS: u32 = 32;
+/// The key schedule constant of the XTEA block cipher.
+const XTEA_DELTA: u32 = 0x9e3779b9;
+/// The exponents of the middle terms of the irreducible pentanomials that define the fields of
+/// the security levels 8, 16, ..., 1024 bits.
+const IRREDUCIBLE_COEFFICIENTS: [(usize, usize, usize); MAX_SECRET_LENGTH] = [
+    (4, 3, 1),
+    (5, 3, 1),
+    (4, 3, 1),
+    (7, 3, 2),
+    (5, 4, 3),
+    (5, 3, 2),
+    (7, 4, 2),
+    (4, 3, 1),
+    (10, 9, 3),
+    (9, 4, 2),
+    (7, 6, 2),
+    (10, 9, 6),
+    (4, 3, 1),
+    (5, 4, 3),
+    (4, 3, 1),
+    (7, 2, 1),
+    (5, 3, 2),
+    (7, 4, 2),
+    (6, 3, 2),
+    (5, 3, 2),
+    (15, 3, 2),
+    (11, 3, 2),
+    (9, 8, 7),
+    (7, 2, 1),
+    (5, 3, 2),
+    (9, 3, 1),
+    (7, 3, 1),
+    (9, 8, 3),
+    (9, 4, 2),
+    (8, 5, 3),
+    (15, 14, 10),
+    (10, 5, 2),
+    (9, 6, 2),
+    (9, 3, 2),
+    (9, 5, 2),
+    (11, 10, 1),
+    (7, 3, 2),
+    (11, 2, 1),
+    (9, 7, 4),
+    (4, 3, 1),
+    (8, 3, 1),
+    (7, 4, 1),
+    (7, 2, 1),
+    (13, 11, 6),
+    (5, 3, 2),
+    (7, 3, 2),
+    (8, 7, 5),
+    (12, 3, 2),
+    (13, 10, 6),
+    (5, 3, 2),
+    (5, 3, 2),
+    (9, 5, 2),
+    (9, 7, 2),
+    (13, 4, 3),
+    (4, 3, 1),
+    (11, 6, 4),
+    (18, 9, 6),
+    (19, 18, 13),
+    (11, 3, 2),
+    (15, 9, 6),
+    (4, 3, 1),
+    (16, 5, 2),
+    (15, 14, 6),
+    (8, 5, 2),
+    (15, 11, 2),
+    (11, 6, 2),
+    (7, 5, 3),
+    (8, 3, 1),
+    (19, 16, 9),
+    (11, 9, 6),
+    (15, 7, 6),
+    (13, 4, 3),
+    (14, 13, 3),
+    (13, 6, 3),
+    (9, 5, 2),
+    (19, 13, 6),
+    (19, 10, 3),
+    (11, 6, 5),
+    (9, 2, 1),
+    (14, 3, 2),
+    (13, 3, 1),
+    (7, 5, 4),
+    (11, 9, 8),
+    (11, 6, 5),
+    (23, 16, 9),
+    (19, 14, 6),
+    (23, 10, 2),
+    (8, 3, 2),
+    (5, 4, 3),
+    (9, 6, 4),
+    (4, 3, 2),
+    (13, 8, 6),
+    (13, 11, 1),
+    (13, 10, 3),
+    (11, 6, 5),
+    (19, 17, 4),
+    (15, 14, 7),
+    (13, 9, 6),
+    (9, 7, 3),
+    (9, 7, 1),
+    (14, 3, 2),
+    (11, 8, 2),
+    (11, 6, 4),
+    (13, 5, 2),
+    (11, 5, 1),
+    (11, 4, 1),
+    (19, 10, 3),
+    (21, 10, 6),
+    (13, 3, 1),
+    (15, 7, 5),
+    (19, 18, 10),
+    (7, 5, 3),
+    (12, 7, 2),
+    (7, 5, 1),
+    (14, 9, 6),
+    (10, 3, 2),
+    (15, 13, 12),
+    (12, 11, 9),
+    (16, 9, 7),
+    (12, 9, 3),
+    (9, 5, 2),
+    (17, 10, 6),
+    (24, 9, 3),
+    (17, 15, 13),
+    (5, 4, 3),
+    (19, 17, 8),
+    (15, 6, 3),
+    (19, 6, 1),
+];
+
+/// This struct holds the binary field GF(2^m) of a security level.
+struct BinaryField {
+    /// The degree m of the field.
+    degree: usize,
+    /// The irreducible polynomial that defines the field.
+    modulus: BigUint,
+}
+
+impl BinaryField {
+    /// The function returns the field for the given security level.
+    ///
+    /// * `security_level` - The security level in bits, a multiple of 8 up to 1024.
+    fn new(security_level: usize) -> Self {
+        let (first, second, third) = IRREDUCIBLE_COEFFICIENTS[security_level / 8 - 1];
+        let mut modulus = BigUint::one();
+        for exponent in [security_level, first, second, third] {
+            modulus.set_bit(exponent as u64, true);
+        }
+        BinaryField {
+            degree: security_level,
+            modulus,
+        }
+    }
+
+    /// The function returns the product of the given field elements.
+    ///
+    /// * `first` - The first factor.
+    /// * `second` - The second factor.
+    fn mul(&self, first: &BigUint, second: &BigUint) -> BigUint {
+        let mut addend = first.clone();
+        let mut product = BigUint::zero();
+        for bit in 0..second.bits() {
+            if second.bit(bit) {
+                product ^= &addend;
+            }
+            addend <<= 1;
+            if addend.bit(self.degree as u64) {
+                addend ^= &self.modulus;
+            }
+        }
+        product
+    }
+
+    /// The function returns the inverse of the given non-zero field element.
+    ///
+    /// The inverse is computed using the extended Euclidean algorithm for binary polynomials.
+    ///
+    /// * `value` - The element that is to be inverted.
+    fn inverse(&self, value: &BigUint) -> BigUint {
+        let mut remainder = value.clone();
+        let mut divisor = self.modulus.clone();
+        let mut coefficient = BigUint::one();
+        let mut divisor_coefficient = BigUint::zero();
+        while !remainder.is_one() {
+            if remainder.bits() < divisor.bits() {
+                std::mem::swap(&mut remainder, &mut divisor);
+                std::mem::swap(&mut coefficient, &mut divisor_coefficient);
+            }
+            let shift = (remainder.bits() - divisor.bits()) as usize;
+            remainder ^= &divisor << shift;
+            coefficient ^= &divisor_coefficient << shift;
+        }
+        coefficient
+    }
+}
+
+/// This struct holds a secret share in the format of `ssss`.
+#[derive(Clone, PartialEq, Eq)]
+pub struct SsssShare {
+    /// The optional token that precedes the index.
+    token: Option<String>,
+    /// The index of the secret share.
+    index: u32,
+    /// The number of digits of the displayed index, which is padded with leading zeros.
+    index_width: usize,
+    /// The value of the secret share in big-endian format.
+    value: Vec<u8>,
+}
+
+impl SsssShare {
+    /// The function returns the token of the secret share, if any.
+    pub fn get_token(&self) -> Option<&str> {
+        self.token.as_deref()
+    }
+
+    /// The function returns the index of the secret share.
+    pub fn get_index(&self) -> u32 {
+        self.index
+    }
+
+    /// The function returns the value of the secret share in big-endian format.
+    pub fn get_value(&self) -> &[u8] {
+        &self.value
+    }
+
+    /// The function returns the security level in bits.
+    pub fn get_security_level(&self) -> usize {
+        self.value.len() * 8
+    }
+}
+
+impl Drop for SsssShare {
+    /// The value of the secret share is wiped from memory when the secret share is dropped.
+    fn drop(&mut self) {
+        self.value.zeroize();
+    }
+}
+
+impl fmt::Debug for SsssShare {
+    /// The value of the secret share is not revealed in debug output.
+    ///
+    /// * `formatter` - The formatter.
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "SsssShare(index {}, {} bits)",
+            self.index,
+            self.get_security_level()
+        )
+    }
+}
+
+impl fmt::Display for SsssShare {
+    /// A secret share is displayed as `[TOKEN]-[INDEX]-[HEX VALUE]` or `[INDEX]-[HEX VALUE]`
+    /// without a token.
+    ///
+    /// * `formatter` - The formatter.
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(token) = &self.token {
+            write!(formatter, "{}-", token)?;
+        }
+        write!(
+            formatter,
+            "{:0width$}-",
+            self.index,
+            width = self.index_width
+        )?;
+        for byte in &self.value {
+            write!(formatter, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for SsssShare {
+    type Err = HarpoError;
+
+    /// The function parses a secret share in the form `[TOKEN]-[INDEX]-[HEX VALUE]` or
+    /// `[INDEX]-[HEX VALUE]`.
+    ///
+    /// An error is returned if the index is 0 or not a field element, or if the value does not
+    /// correspond to a security level of the form 8k bits for k between 1 and 128.
+    ///
+    /// * `input` - The secret share as a string.
+    fn from_str(input: &str) -> HarpoResult<Self> {
+        let parts: Vec<&str> = input.trim().split('-').collect();
+        let (token, index_string, value_string) = match parts[..] {
+            [index_string, value_string] => (None, index_string, value_string),
+            [token, index_string, value_string] => (Some(token), index_string, value_string),
+            _ => {
+                return Err(HarpoError::InvalidParameter(
+                    "An ssss share must have the form '[TOKEN]-[INDEX]-[HEX VALUE]'.".to_string(),
+                ))
+            }
+        };
+        let index = index_string.parse::<u32>()?;
+        if value_string.is_empty()
+            || value_string.len() > 2 * MAX_SECRET_LENGTH
+            || !value_string.len().is_multiple_of(2)
+            || !value_string.chars().all(|c| c.is_ascii_hexdigit())
+        {
+            return Err(HarpoError::InvalidParameter(format!(
+                "The value of an ssss share must be a hex string of an even number of at most {} \
+                characters.",
+                2 * MAX_SECRET_LENGTH
+            )));
+        }
+        let value = (0..value_string.len())
+            .step_by(2)
+            .map(|position| u8::from_str_radix(&value_string[position..position + 2], 16))
+            .collect::<Result<Vec<u8>, _>>()?;
+        if index == 0 || (u32::BITS - index.leading_zeros()) as usize > value.len() * 8 {
+            return Err(HarpoError::InvalidParameter(
+                "The index of an ssss share must be a non-zero field element.".to_string(),
+            ));
+        }
+        Ok(SsssShare {
+            token: token.map(str::to_string),
+            index,
+            index_width: index_string.len(),
+            value,
+        })
+    }
+}
+
+/// The function encrypts the given block using XTEA with the all-zero key.
+///
+/// * `block` - The 64-bit block.
+fn encipher_block(block: &mut [u32; 2]) {
+    let mut sum: u32 = 0;
+    for _ in 0..XTEA_NUM_ROUNDS {
+        block[0] = block[0]
+            .wrapping_add((((block[1] << 4) ^ (block[1] >> 5)).wrapping_add(block[1])) ^ sum);
+        sum = sum.wrapping_add(XTEA_DELTA);
+        block[1] = block[1]
+            .wrapping_add((((block[0] << 4) ^ (block[0] >> 5)).wrapping_add(block[0])) ^ sum);
+    }
+}
+
+/// The function decrypts the given block using XTEA with the all-zero key.
+///
+/// * `block` - The 64-bit block.
+fn decipher_block(block: &mut [u32; 2]) {
+    let mut sum = XTEA_DELTA.wrapping_mul(XTEA_NUM_ROUNDS);
+    for _ in 0..XTEA_NUM_ROUNDS {
+        block[1] = block[1]
+            .wrapping_sub((((block[0] << 4) ^ (block[0] >> 5)).wrapping_add(block[0])) ^ sum);
+        sum = sum.wrapping_sub(XTEA_DELTA);
+        block[0] = block[0]
+            .wrapping_sub((((block[1] << 4) ^ (block[1] >> 5)).wrapping_add(block[1])) ^ sum);
+    }
+}
+
+/// The function encrypts or decrypts the 8 bytes of the given data starting at the given
+/// position, wrapping around at the end of the data.
+///
+/// * `data` - The data.
+/// * `position` - The position of the first byte.
+/// * `encode` - The flag whether the bytes are encrypted or decrypted.
+fn process_slice(data: &mut [u8], position: usize, encode: bool) {
+    let length = data.len();
+    let mut block = [0u32; 2];
+    for (half, word) in block.iter_mut().enumerate() {
+        *word = (0..4).fold(0, |word, offset| {
+            (word << 8) | u32::from(data[(position + 4 * half + offset) % length])
+        });
+    }
+    if encode {
+        encipher_block(&mut block);
+    } else {
+        decipher_block(&mut block);
+    }
+    for (half, word) in block.iter().enumerate() {
+        for (offset, byte) in word.to_be_bytes().iter().enumerate() {
+            data[(position + 4 * half + offset) % length] = *byte;
+        }
+    }
+    block.zeroize();
+}
+
+/// The function applies or removes the diffusion layer of `ssss`.
+///
+/// The field element is stored as 16-bit big-endian words, starting with the least significant
+/// word, and overlapping slices of 8 bytes are encrypted repeatedly. If the security level is not
+/// a multiple of 16 bits, the most significant byte is moved next to the other bytes first.
+///
+/// * `value` - The field element.
+/// * `security_level` - The security level in bits.
+/// * `encode` - The flag whether the diffusion layer is applied or removed.
+fn apply_diffusion(value: &BigUint, security_level: usize, encode: bool) -> BigUint {
+    let length = security_level / 8;
+    let mut bytes = Zeroizing::new(vec![0; (security_level + 8) / 16 * 2]);
+    // Swapping adjacent bytes turns the little-endian bytes into big-endian words.
+    for (position, byte) in Zeroizing::new(value.to_bytes_le()).iter().enumerate() {
+        bytes[position ^ 1] = *byte;
+    }
+    let is_odd_length = security_level % 16 == 8;
+    if is_odd_length {
+        bytes[length - 1] = bytes[length];
+    }
+    let num_slices = NUM_DIFFUSION_ROUNDS_PER_BYTE * length;
+    if encode {
+        for position in (0..num_slices).step_by(2) {
+            process_slice(&mut bytes[..length], position, true);
+        }
+    } else {
+        for position in (0..num_slices).step_by(2).rev() {
+            process_slice(&mut bytes[..length], position, false);
+        }
+    }
+    if is_odd_length {
+        bytes[length] = bytes[length - 1];
+        bytes[length - 1] = 0;
+    }
+    let mut value_bytes = Zeroizing::new(vec![0; bytes.len()]);
+    for (position, byte) in bytes.iter().enumerate() {
+        value_bytes[position ^ 1] = *byte;
+    }
+    BigUint::from_bytes_le(&value_bytes)
+}
+
+/// The function returns the given field element as a big-endian byte array of the given length.
+///
+/// * `value` - The field element.
+/// * `length` - The length in bytes.
+fn to_bytes(value: &BigUint, length: usize) -> Zeroizing<Vec<u8>> {
+    let value_bytes = Zeroizing::new(value.to_bytes_be());
+    let mut bytes = Zeroizing::new(vec![0; length]);
+    // The value of zero is encoded as a single zero byte.
+    if !value.is_zero() {
+        bytes[length - value_bytes.len()..].copy_from_slice(&value_bytes);
+    }
+    bytes
+}
+
+/// The function returns x^exponent for the given field element x.
+///
+/// * `field` - The field.
+/// * `x` - The field element.
+/// * `exponent` - The exponent.
+fn get_power(field: &BinaryField, x: &BigUint, exponent: usize) -> BigUint {
+    (0..exponent).fold(BigUint::one(), |power, _| field.mul(&power, x))
+}
+
+/// The function creates secret shares of the given secret in the format of `ssss`.
+///
+/// The security level is 8 bits per byte of the secret. As in `ssss`, the secret is the
+/// constant term of a polynomial of degree `threshold` with leading coefficient 1.
+///
+/// * `secret` - The secret.
+/// * `threshold` - The threshold.
+/// * `num_shares` - The number of secret shares.
+/// * `token` - The optional token that precedes the index of each secret share.
+/// * `diffusion` - The flag whether the diffusion layer is applied.
+pub(crate) fn split_secret(
+    secret: &[u8],
+    threshold: usize,
+    num_shares: usize,
+    token: Option<&str>,
+    diffusion: bool,
+) -> HarpoResult<Vec<SsssShare>> {
+    if secret.is_empty() || secret.len() > MAX_SECRET_LENGTH {
+        return Err(HarpoError::InvalidParameter(format!(
+            "The secret must consist of between 1 and {} bytes.",
+            MAX_SECRET_LENGTH
+        )));
+    }
+    if threshold < 2 || threshold > num_shares || num_shares > MAX_NUM_SHARES {
+        return Err(HarpoError::InvalidParameter(format!(
+            "The threshold must be at least 2 and at most the number of secret shares, which must \
+            not exceed {}.",
+            MAX_NUM_SHARES
+        )));
+    }
+    if let Some(token) = token {
+        if token.is_empty()
+            || token.len() > MAX_TOKEN_LENGTH
+            || token.contains(|c: char| c == '-' || c.is_whitespace())
+        {
+            return Err(HarpoError::InvalidParameter(format!(
+                "The token must consist of between 1 and {} characters other than '-' and \
+                whitespace.",
+                MAX_TOKEN_LENGTH
+            )));
+        }
+    }
+    let security_level = secret.len() * 8;
+    let field = BinaryField::new(security_level);
+    let mut constant_term = BigUint::from_bytes_be(secret);
+    if diffusion && security_level >= MIN_DIFFUSION_SECURITY_LEVEL {
+        constant_term = apply_diffusion(&constant_term, security_level, true);
+    }
+    let mut coefficients = vec![constant_term];
+    for _ in 1..threshold {
+        let mut random_bytes = Zeroizing::new(vec![0; secret.len()]);
+        OsRng.fill_bytes(&mut random_bytes);
+        coefficients.push(BigUint::from_bytes_be(&random_bytes));
+    }
+    let index_width = num_shares.to_string().len();
+    let mut shares = vec![];
+    for index in 1..=num_shares as u32 {
+        let x = BigUint::from(index);
+        // Horner's method, starting with the leading coefficient 1.
+        let mut y = x.clone();
+        for coefficient in coefficients[1..].iter().rev() {
+            y ^= coefficient;
+            y = field.mul(&y, &x);
+        }
+        y ^= &coefficients[0];
+        shares.push(SsssShare {
+            token: token.map(str::to_string),
+            index,
+            index_width,
+            value: to_bytes(&y, secret.len()).to_vec(),
+        });
+    }
+    Ok(shares)
+}
+
+/// The function reconstructs the secret from the given secret shares in the format of `ssss`.
+///
+/// The first `threshold` secret shares are used. The secret is returned as a big-endian byte
+/// array whose length corresponds to the security level.
+///
+/// * `shares` - The secret shares.
+/// * `threshold` - The threshold.
+/// * `diffusion` - The flag whether the diffusion layer was applied.
+pub(crate) fn combine_shares(
+    shares: &[SsssShare],
+    threshold: usize,
+    diffusion: bool,
+) -> HarpoResult<Zeroizing<Vec<u8>>> {
+    if threshold < 2 {
+        return Err(HarpoError::InvalidParameter(
+            "The threshold must be at least 2.".to_string(),
+        ));
+    }
+    if shares.len() < threshold {
+        return Err(HarpoError::NotEnoughShares {
+            provided: shares.len(),
+            required: threshold,
+        });
+    }
+    let shares = &shares[..threshold];
+    let length = shares[0].value.len();
+    if shares.iter().any(|share| share.value.len() != length) {
+        return Err(HarpoError::LengthMismatchAcrossShares);
+    }
+    let mut indices = HashSet::new();
+    if !shares.iter().all(|share| indices.insert(share.index)) {
+        return Err(HarpoError::InvalidParameter(
+            "The ssss shares must have distinct indices.".to_string(),
+        ));
+    }
+    let security_level = length * 8;
+    let field = BinaryField::new(security_level);
+    // Removing the leading term x^threshold leaves a polynomial of degree threshold - 1.
+    let points: Vec<(BigUint, BigUint)> = shares
+        .iter()
+        .map(|share| {
+            let x = BigUint::from(share.index);
+            let y = BigUint::from_bytes_be(&share.value) ^ get_power(&field, &x, threshold);
+            (x, y)
+        })
+        .collect();
+    // Lagrange interpolation at 0.
+    let mut secret = BigUint::zero();
+    for (x, y) in &points {
+        let (numerator, denominator) = points.iter().filter(|(other_x, _)| other_x != x).fold(
+            (BigUint::one(), BigUint::one()),
+            |(numerator, denominator), (other_x, _)| {
+                (
+                    field.mul(&numerator, other_x),
+                    field.mul(&denominator, &(x ^ other_x)),
+                )
+            },
+        );
+        let coefficient = field.mul(&numerator, &field.inverse(&denominator));
+        secret ^= field.mul(y, &coefficient);
+    }
+    if diffusion && security_level >= MIN_DIFFUSION_SECURITY_LEVEL {
+        secret = apply_diffusion(&secret, security_level, false);
+    }
+    Ok(to_bytes(&secret, length))
+}
+
+// ******************************** TESTS ********************************
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The function parses the given secret shares.
+    ///
+    /// * `shares` - The secret shares as strings.
+    fn parse_shares(shares: &[&str]) -> Vec<SsssShare> {
+        shares
+            .iter()
+            .map(|share| share.parse::<SsssShare>().unwrap())
+            .collect()
+    }
+
+    #[test]
+    /// The function tests the reconstruction using the example of the `ssss` documentation.
+    fn test_ssss_example() {
+        let shares = parse_shares(&[
+            "1-1c41ef496eccfbeba439714085df8437236298da8dd824",
+            "2-fbc74a03a50e14ab406c225afb5f45c40ae11976d2b665",
+            "3-fa1c3a9c6df8af0779c36de6c33f6e36e989d0e0b91309",
+            "4-468de7d6eb36674c9cf008c8e8fc8c566537ad6301eb9e",
+            "5-4756974923c0dce0a55f4774d09ca7a4865f64f56a4ee0",
+        ]);
+        assert_eq!(shares[0].get_security_level(), 184);
+        for selection in [[0, 1, 2], [2, 4, 1], [4, 3, 0]] {
+            let selected_shares: Vec<SsssShare> = selection
+                .iter()
+                .map(|position| shares[*position].clone())
+                .collect();
+            assert_eq!(
+                &combine_shares(&selected_shares, 3, true).unwrap()[..],
+                b"my secret root password"
+            );
+        }
+        assert!(combine_shares(&shares[..2], 3, true).is_err());
+        // The reconstruction fails silently without the diffusion layer.
+        assert_ne!(
+            &combine_shares(&shares, 3, false).unwrap()[..],
+            b"my secret root password"
+        );
+    }
+
+    #[test]
+    /// The function tests the creation and reconstruction of secret shares.
+    fn test_split_and_combine() {
+        for (length, diffusion) in [(1, true), (8, true), (9, true), (32, false), (33, true)] {
+            let secret: Vec<u8> = (0..length as u8).map(|byte| byte ^ 0xa5).collect();
+            let shares = split_secret(&secret, 3, 10, Some("token"), diffusion).unwrap();
+            let shares: Vec<SsssShare> = shares
+                .iter()
+                .map(|share| share.to_string().parse().unwrap())
+                .collect();
+            assert!(shares[0].to_string().starts_with("token-01-"));
+            assert_eq!(shares[9].get_index(), 10);
+            assert_eq!(
+                &combine_shares(&shares[4..], 3, diffusion).unwrap()[..],
+                &secret[..]
+            );
+        }
+        // The diffusion layer is a permutation.
+        let value = BigUint::from(0x0123456789abcdefu64);
+        for security_level in [64, 72, 1024] {
+            let encoded = apply_diffusion(&value, security_level, true);
+            assert!(encoded.bits() <= security_level as u64);
+            assert_eq!(apply_diffusion(&encoded, security_level, false), value);
+        }
+        // Invalid parameters and shares are rejected.
+        assert!(split_secret(&[1; 16], 1, 3, None, true).is_err());
+        assert!(split_secret(&[1; 16], 2, 256, None, true).is_err());
+        assert!(split_secret(&[1; 16], 2, 3, Some("a-b"), true).is_err());
+        assert!(split_secret(&[1; 129], 2, 3, None, true).is_err());
+        assert!("0-1234".parse::<SsssShare>().is_err());
+        assert!("256-12".parse::<SsssShare>().is_err());
+        assert!("1-123".parse::<SsssShare>().is_err());
+        assert!("a-b-1-12".parse::<SsssShare>().is_err());
+    }
+}
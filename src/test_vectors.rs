@@ -0,0 +1,319 @@
+//! The `test_vectors` module provides reproducible test vectors for independent implementations
+//! of the secret sharing of seed phrases.
+//!
+//! All secrets and polynomial coefficients are derived deterministically from a seed so that the
+//! same seed always results in the same test vectors. The test vectors cover several seed phrase
+//! lengths, thresholds, and index encodings. They are meant for validating implementations only
+//! and must never be used to protect real secrets.
+//!
+
+use crate::math::FiniteFieldElement;
+use crate::secret_sharing::{get_modulus_for_words, SecretPolynomial};
+use crate::seed_phrase::{get_seed_phrase_for_element, IndexEncoding, SeedPhrase};
+use crate::{create_secret_shared_seed_phrases_for_polynomial, HarpoError, HarpoResult};
+use std::fmt;
+
+/// The version of the test vector format.
+const TEST_VECTOR_FORMAT_VERSION: u32 = 1;
+/// The numbers of words of the seed phrases in the test vectors.
+const TEST_VECTOR_NUM_WORDS: [usize; 3] = [12, 18, 24];
+/// The thresholds and numbers of shares in the test vectors.
+const TEST_VECTOR_PARAMETERS: [(usize, usize); 2] = [(2, 3), (3, 5)];
+/// The index encodings in the test vectors.
+const TEST_VECTOR_INDEX_ENCODINGS: [IndexEncoding; 3] = [
+    IndexEncoding::Embedded,
+    IndexEncoding::Explicit,
+    IndexEncoding::IndexWord,
+];
+
+/// This struct holds a single test vector.
+#[derive(Debug, Clone)]
+pub struct TestVector {
+    /// The identifier of the test vector.
+    id: u32,
+    /// The secret seed phrase.
+    seed_phrase: SeedPhrase,
+    /// The threshold.
+    threshold: usize,
+    /// The encoding of the seed phrase indices.
+    index_encoding: IndexEncoding,
+    /// The modulus of the finite field as a big-endian hex string.
+    modulus: String,
+    /// The coefficients of the polynomial as big-endian hex strings, starting with the secret.
+    coefficients: Vec<String>,
+    /// The expected secret-shared seed phrases.
+    shares: Vec<SeedPhrase>,
+}
+
+impl TestVector {
+    /// The function returns the identifier of the test vector.
+    pub fn get_id(&self) -> u32 {
+        self.id
+    }
+
+    /// The function returns the secret seed phrase.
+    pub fn get_seed_phrase(&self) -> &SeedPhrase {
+        &self.seed_phrase
+    }
+
+    /// The function returns the threshold.
+    pub fn get_threshold(&self) -> usize {
+        self.threshold
+    }
+
+    /// The function returns the encoding of the seed phrase indices.
+    pub fn get_index_encoding(&self) -> IndexEncoding {
+        self.index_encoding
+    }
+
+    /// The function returns the coefficients of the polynomial as big-endian hex strings,
+    /// starting with the secret.
+    pub fn get_coefficients(&self) -> &[String] {
+        &self.coefficients
+    }
+
+    /// The function returns the expected secret-shared seed phrases.
+    pub fn get_shares(&self) -> &[SeedPhrase] {
+        &self.shares
+    }
+}
+
+/// This struct holds a suite of test vectors derived from a seed.
+#[derive(Debug, Clone)]
+pub struct TestVectorSuite {
+    /// The seed from which the test vectors are derived.
+    seed: Vec<u8>,
+    /// The test vectors.
+    test_vectors: Vec<TestVector>,
+}
+
+impl TestVectorSuite {
+    /// The function returns the seed from which the test vectors are derived.
+    pub fn get_seed(&self) -> &[u8] {
+        &self.seed
+    }
+
+    /// The function returns the test vectors.
+    pub fn get_test_vectors(&self) -> &[TestVector] {
+        &self.test_vectors
+    }
+}
+
+impl fmt::Display for TestVectorSuite {
+    /// A test vector suite is displayed as a JSON document.
+    ///
+    /// * `formatter` - The formatter.
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(formatter, "{{")?;
+        writeln!(formatter, "  \"version\": {},", TEST_VECTOR_FORMAT_VERSION)?;
+        writeln!(formatter, "  \"seed\": \"{}\",", encode_hex(&self.seed))?;
+        writeln!(formatter, "  \"test_vectors\": [")?;
+        for (position, test_vector) in self.test_vectors.iter().enumerate() {
+            writeln!(formatter, "    {{")?;
+            writeln!(formatter, "      \"id\": {},", test_vector.id)?;
+            writeln!(
+                formatter,
+                "      \"seed_phrase\": \"{}\",",
+                test_vector.seed_phrase
+            )?;
+            writeln!(formatter, "      \"threshold\": {},", test_vector.threshold)?;
+            writeln!(
+                formatter,
+                "      \"num_shares\": {},",
+                test_vector.shares.len()
+            )?;
+            writeln!(
+                formatter,
+                "      \"index_encoding\": \"{}\",",
+                get_index_encoding_name(test_vector.index_encoding)
+            )?;
+            writeln!(formatter, "      \"modulus\": \"{}\",", test_vector.modulus)?;
+            write_json_array(formatter, "coefficients", &test_vector.coefficients, true)?;
+            let shares: Vec<String> = test_vector
+                .shares
+                .iter()
+                .map(|share| share.to_string())
+                .collect();
+            write_json_array(formatter, "shares", &shares, false)?;
+            if position + 1 < self.test_vectors.len() {
+                writeln!(formatter, "    }},")?;
+            } else {
+                writeln!(formatter, "    }}")?;
+            }
+        }
+        writeln!(formatter, "  ]")?;
+        write!(formatter, "}}")
+    }
+}
+
+/// The function writes the given strings as a JSON array of a test vector.
+///
+/// * `formatter` - The formatter.
+/// * `name` - The name of the array.
+/// * `values` - The strings in the array.
+/// * `has_successor` - Flag indicating whether another field follows the array.
+fn write_json_array(
+    formatter: &mut fmt::Formatter<'_>,
+    name: &str,
+    values: &[String],
+    has_successor: bool,
+) -> fmt::Result {
+    writeln!(formatter, "      \"{}\": [", name)?;
+    for (position, value) in values.iter().enumerate() {
+        let separator = if position + 1 < values.len() { "," } else { "" };
+        writeln!(formatter, "        \"{}\"{}", value, separator)?;
+    }
+    writeln!(formatter, "      ]{}", if has_successor { "," } else { "" })
+}
+
+/// The function returns the name of the given index encoding used in the test vectors.
+///
+/// * `index_encoding` - The index encoding.
+fn get_index_encoding_name(index_encoding: IndexEncoding) -> &'static str {
+    match index_encoding {
+        IndexEncoding::Embedded => "embedded",
+        IndexEncoding::Explicit => "explicit",
+        IndexEncoding::IndexWord => "index-word",
+    }
+}
+
+/// The function encodes the given bytes as a lower-case hex string.
+///
+/// * `bytes` - The bytes.
+fn encode_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// The function returns the given finite field element as a big-endian hex string.
+///
+/// * `element` - The finite field element.
+fn get_element_hex(element: &FiniteFieldElement) -> String {
+    let mut bytes = element.get_bytes();
+    bytes.reverse();
+    encode_hex(&bytes)
+}
+
+/// The function creates the test vector with the given identifier and parameters.
+///
+/// * `seed` - The seed of the test vector suite.
+/// * `id` - The identifier of the test vector.
+/// * `num_words` - The number of words of the secret seed phrase.
+/// * `threshold` - The threshold.
+/// * `num_shares` - The number of secret-shared seed phrases.
+/// * `index_encoding` - The encoding of the seed phrase indices.
+/// * `word_list` - The word list.
+fn create_test_vector(
+    seed: &[u8],
+    id: u32,
+    num_words: usize,
+    (threshold, num_shares): (usize, usize),
+    index_encoding: IndexEncoding,
+    word_list: &[&str],
+) -> HarpoResult<TestVector> {
+    // Every test vector uses its own seed, which is derived from the seed of the suite.
+    let mut vector_seed = seed.to_vec();
+    vector_seed.extend_from_slice(&id.to_be_bytes());
+    let modulus = get_modulus_for_words(num_words).ok_or_else(|| {
+        HarpoError::InvalidParameter("Invalid number of words in a test vector.".to_string())
+    })?;
+    let num_bits = modulus.get_num_bits();
+    let element = FiniteFieldElement::new_deterministic(&vector_seed, "secret", num_bits, modulus);
+    let seed_phrase = get_seed_phrase_for_element(&element, word_list)?;
+    let polynomial =
+        SecretPolynomial::new_deterministic(&element, num_bits, threshold - 1, &vector_seed)
+            .ok_or_else(|| {
+                HarpoError::InvalidParameter(
+                    "Could not instantiate the required secret polynomial.".to_string(),
+                )
+            })?;
+    let shares = create_secret_shared_seed_phrases_for_polynomial(
+        &polynomial,
+        num_shares,
+        index_encoding,
+        word_list,
+        &mut |_, _| {},
+    )?;
+    Ok(TestVector {
+        id,
+        seed_phrase,
+        threshold,
+        index_encoding,
+        modulus: encode_hex(&modulus.to_big_uint().to_bytes_be()),
+        coefficients: polynomial
+            .get_coefficients()
+            .iter()
+            .map(get_element_hex)
+            .collect(),
+        shares,
+    })
+}
+
+/// The function creates the test vector suite for the given seed.
+///
+/// * `seed` - The seed from which all secrets and coefficients are derived.
+/// * `word_list` - The word list.
+pub(crate) fn create_test_vector_suite(
+    seed: &[u8],
+    word_list: &[&str],
+) -> HarpoResult<TestVectorSuite> {
+    let mut test_vectors = vec![];
+    for num_words in TEST_VECTOR_NUM_WORDS {
+        for parameters in TEST_VECTOR_PARAMETERS {
+            for index_encoding in TEST_VECTOR_INDEX_ENCODINGS {
+                let id = test_vectors.len() as u32;
+                test_vectors.push(create_test_vector(
+                    seed,
+                    id,
+                    num_words,
+                    parameters,
+                    index_encoding,
+                    word_list,
+                )?);
+            }
+        }
+    }
+    Ok(TestVectorSuite {
+        seed: seed.to_vec(),
+        test_vectors,
+    })
+}
+
+// ******************************** TESTS ********************************
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::word_list::DEFAULT_WORD_LIST;
+    use crate::{reconstruct_seed_phrase, seed_phrase::is_compliant};
+
+    #[test]
+    /// The function tests that the test vectors are reproducible and consistent.
+    fn test_create_test_vector_suite() {
+        let suite = create_test_vector_suite(&[0x42; 16], DEFAULT_WORD_LIST).unwrap();
+        let other_suite = create_test_vector_suite(&[0x42; 16], DEFAULT_WORD_LIST).unwrap();
+        assert_eq!(suite.to_string(), other_suite.to_string());
+        let different_suite = create_test_vector_suite(&[0x43; 16], DEFAULT_WORD_LIST).unwrap();
+        assert_ne!(suite.to_string(), different_suite.to_string());
+        assert_eq!(
+            suite.get_test_vectors().len(),
+            TEST_VECTOR_NUM_WORDS.len()
+                * TEST_VECTOR_PARAMETERS.len()
+                * TEST_VECTOR_INDEX_ENCODINGS.len()
+        );
+        for test_vector in suite.get_test_vectors() {
+            assert!(is_compliant(
+                test_vector.get_seed_phrase(),
+                DEFAULT_WORD_LIST
+            ));
+            assert_eq!(
+                test_vector.get_coefficients().len(),
+                test_vector.get_threshold()
+            );
+            let shares = &test_vector.get_shares()[..test_vector.get_threshold()];
+            assert_eq!(
+                &reconstruct_seed_phrase(shares).unwrap(),
+                test_vector.get_seed_phrase()
+            );
+        }
+    }
+}
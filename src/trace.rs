@@ -0,0 +1,126 @@
+//! The `trace` module provides optional instrumentation of the library.
+//!
+//! When the `tracing` feature is enabled, the library emits spans and events of the
+//! [tracing](https://docs.rs/tracing) crate around the splitting, reconstruction, parsing, and
+//! verification steps. Applications collect them by installing any `tracing` subscriber.
+//! All spans and events are recorded at the `TRACE` level.
+//! Spans and events never contain secret payloads such as words, secrets, or shares, only names
+//! and non-secret parameters such as thresholds or numbers of words.
+//! When the feature is disabled, the instrumentation is compiled out entirely.
+//!
+
+/// The macro enters a span with the given name, which is exited at the end of the enclosing
+/// block.
+macro_rules! trace_span {
+    ($name:literal) => {
+        #[cfg(feature = "tracing")]
+        let _span_guard = tracing::span!(tracing::Level::TRACE, $name).entered();
+    };
+}
+
+/// The macro records an event with the given name and non-secret fields.
+///
+/// The values of the fields are recorded using their `Display` implementation.
+macro_rules! trace_event {
+    ($name:literal $(, $key:literal => $value:expr)+ $(,)?) => {
+        #[cfg(feature = "tracing")]
+        tracing::event!(name: $name, tracing::Level::TRACE, $($key = %$value),+);
+    };
+}
+
+// ******************************** TESTS ********************************
+
+#[cfg(all(test, feature = "tracing"))]
+mod tests {
+    use crate::{create_secret_shared_seed_phrases, generate_seed_phrase, reconstruct_seed_phrase};
+    use std::fmt::Debug;
+    use std::sync::{Arc, Mutex};
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event, Metadata, Subscriber};
+
+    /// A subscriber that records the names of all spans and events.
+    struct RecordingSubscriber {
+        /// The names of the spans, where the identifier of a span is its position plus 1.
+        spans: Mutex<Vec<&'static str>>,
+        /// The recorded names.
+        records: Arc<Mutex<Vec<String>>>,
+    }
+
+    /// A visitor that collects the fields of an event as key-value pairs.
+    struct FieldCollector(Vec<String>);
+
+    impl Visit for FieldCollector {
+        fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+            self.0.push(format!("{}={:?}", field.name(), value));
+        }
+    }
+
+    impl RecordingSubscriber {
+        /// The function returns the name of the span with the given identifier.
+        fn get_name(&self, span: &Id) -> &'static str {
+            self.spans.lock().unwrap()[span.into_u64() as usize - 1]
+        }
+    }
+
+    impl Subscriber for RecordingSubscriber {
+        fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, span: &Attributes<'_>) -> Id {
+            let mut spans = self.spans.lock().unwrap();
+            spans.push(span.metadata().name());
+            Id::from_u64(spans.len() as u64)
+        }
+
+        fn record(&self, _span: &Id, _values: &Record<'_>) {}
+
+        fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+        fn event(&self, event: &Event<'_>) {
+            let mut collector = FieldCollector(vec![]);
+            event.record(&mut collector);
+            self.records.lock().unwrap().push(format!(
+                "event {} {}",
+                event.metadata().name(),
+                collector.0.join(" ")
+            ));
+        }
+
+        fn enter(&self, span: &Id) {
+            let record = format!("enter {}", self.get_name(span));
+            self.records.lock().unwrap().push(record);
+        }
+
+        fn exit(&self, span: &Id) {
+            let record = format!("exit {}", self.get_name(span));
+            self.records.lock().unwrap().push(record);
+        }
+    }
+
+    #[test]
+    /// The function tests that spans and events are reported to the installed subscriber.
+    fn test_tracing() {
+        let records = Arc::new(Mutex::new(vec![]));
+        let subscriber = RecordingSubscriber {
+            spans: Mutex::new(vec![]),
+            records: records.clone(),
+        };
+        tracing::subscriber::with_default(subscriber, || {
+            let seed_phrase = generate_seed_phrase(12).unwrap();
+            let shares = create_secret_shared_seed_phrases(&seed_phrase, 2, 3, true).unwrap();
+            reconstruct_seed_phrase(&shares[..2]).unwrap();
+        });
+        let records = records.lock().unwrap();
+        for expected in [
+            "enter split",
+            "exit split",
+            "enter reconstruct",
+            "exit reconstruct",
+            "event split.parameters threshold=2 num_shares=3 num_bits=128",
+        ] {
+            assert!(records.iter().any(|record| record == expected));
+        }
+    }
+}
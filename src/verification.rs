@@ -0,0 +1,349 @@
+//! The `verification` module provides the public data that is required to verify
+//! secret-shared seed phrases.
+//!
+//! The verification data is created together with the secret-shared seed phrases, see
+//! [ShareSet](crate::ShareSet). It contains the creation parameters, the identifier of the set
+//! of secret-shared seed phrases, the commitment to the secret polynomial, and a commitment to
+//! each individual secret-shared seed phrase. It does not reveal any information about the
+//! secret and can therefore be stored publicly alongside the distributed secret-shared seed
+//! phrases. A single secret-shared seed phrase can be verified using
+//! [verify_share](crate::verify_share) and a set of at least the threshold number of
+//! secret-shared seed phrases can be verified using [verify_set](crate::verify_set).
+//!
+//! The verification data has a stable, line-based text encoding, e.g.:
+//!
+//! ```text
+//! # harpo verification data 1
+//! threshold: 2
+//! shares: 3
+//! words: 12
+//! index-encoding: explicit
+//! share-format: 2
+//! set-id: 1a2b
+//! commitment: [64 HEX CHARACTERS]
+//! share 1: [64 HEX CHARACTERS]
+//! share 2: [64 HEX CHARACTERS]
+//! share 3: [64 HEX CHARACTERS]
+//! ```
+//!
+
+use crate::commitment::{PolynomialCommitment, COMMITMENT_LENGTH};
+use crate::seed_phrase::IndexEncoding;
+use crate::{HarpoError, HarpoResult};
+use std::fmt;
+use std::str::FromStr;
+
+/// The version of the text encoding of verification data.
+pub const VERIFICATION_DATA_VERSION: u32 = 1;
+
+/// The prefix of the header line of the text encoding of verification data.
+const VERIFICATION_DATA_HEADER_PREFIX: &str = "# harpo verification data";
+
+/// This struct holds the public data that is required to verify secret-shared seed phrases.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VerificationData {
+    /// The threshold.
+    threshold: usize,
+    /// The number of secret-shared seed phrases.
+    num_shares: usize,
+    /// The number of words of the seed phrase that was secret-shared.
+    num_words: usize,
+    /// The encoding of the seed phrase indices.
+    index_encoding: IndexEncoding,
+    /// The version of the share format.
+    share_format_version: u32,
+    /// The identifier of the set of secret-shared seed phrases.
+    set_id: u16,
+    /// The commitment to the polynomial underlying the secret-shared seed phrases.
+    commitment: PolynomialCommitment,
+    /// The commitments to the secret-shared seed phrases with indices 1, 2, and so on.
+    share_commitments: Vec<[u8; COMMITMENT_LENGTH]>,
+}
+
+impl VerificationData {
+    /// The function creates verification data.
+    ///
+    /// * `threshold` - The threshold.
+    /// * `num_words` - The number of words of the seed phrase that was secret-shared.
+    /// * `index_encoding` - The encoding of the seed phrase indices.
+    /// * `share_format_version` - The version of the share format.
+    /// * `set_id` - The identifier of the set of secret-shared seed phrases.
+    /// * `commitment` - The polynomial commitment.
+    /// * `share_commitments` - The commitments to the secret-shared seed phrases, starting with
+    ///   index 1.
+    pub(crate) fn new(
+        threshold: usize,
+        num_words: usize,
+        index_encoding: IndexEncoding,
+        share_format_version: u32,
+        set_id: u16,
+        commitment: PolynomialCommitment,
+        share_commitments: Vec<[u8; COMMITMENT_LENGTH]>,
+    ) -> Self {
+        VerificationData {
+            threshold,
+            num_shares: share_commitments.len(),
+            num_words,
+            index_encoding,
+            share_format_version,
+            set_id,
+            commitment,
+            share_commitments,
+        }
+    }
+
+    /// The function returns the threshold.
+    pub fn get_threshold(&self) -> usize {
+        self.threshold
+    }
+
+    /// The function returns the number of secret-shared seed phrases.
+    pub fn get_num_shares(&self) -> usize {
+        self.num_shares
+    }
+
+    /// The function returns the number of words of the seed phrase that was secret-shared.
+    pub fn get_num_words(&self) -> usize {
+        self.num_words
+    }
+
+    /// The function returns the encoding of the seed phrase indices.
+    pub fn get_index_encoding(&self) -> IndexEncoding {
+        self.index_encoding
+    }
+
+    /// The function returns the version of the share format.
+    pub fn get_share_format_version(&self) -> u32 {
+        self.share_format_version
+    }
+
+    /// The function returns the identifier of the set of secret-shared seed phrases.
+    pub fn get_set_id(&self) -> u16 {
+        self.set_id
+    }
+
+    /// The function returns the commitment to the polynomial underlying the secret-shared seed
+    /// phrases.
+    pub fn get_commitment(&self) -> PolynomialCommitment {
+        self.commitment
+    }
+
+    /// The function returns the commitment to the secret-shared seed phrase with the given
+    /// index, if there is one.
+    ///
+    /// * `index` - The index of the secret-shared seed phrase.
+    pub fn get_share_commitment(&self, index: u32) -> Option<&[u8; COMMITMENT_LENGTH]> {
+        let position = (index as usize).checked_sub(1)?;
+        self.share_commitments.get(position)
+    }
+}
+
+/// The function returns the name of the given index encoding in the text encoding.
+///
+/// * `index_encoding` - The index encoding.
+fn get_index_encoding_name(index_encoding: IndexEncoding) -> &'static str {
+    match index_encoding {
+        IndexEncoding::Embedded => "embedded",
+        IndexEncoding::Explicit => "explicit",
+        IndexEncoding::IndexWord => "index-word",
+    }
+}
+
+/// The function parses a hex-encoded commitment.
+///
+/// * `input` - The hex string.
+fn parse_commitment_bytes(input: &str) -> HarpoResult<[u8; COMMITMENT_LENGTH]> {
+    Ok(*input.parse::<PolynomialCommitment>()?.get_bytes())
+}
+
+impl fmt::Display for VerificationData {
+    /// Verification data is displayed using its line-based text encoding.
+    ///
+    /// * `formatter` - The formatter.
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            formatter,
+            "{} {}",
+            VERIFICATION_DATA_HEADER_PREFIX, VERIFICATION_DATA_VERSION
+        )?;
+        writeln!(formatter, "threshold: {}", self.threshold)?;
+        writeln!(formatter, "shares: {}", self.num_shares)?;
+        writeln!(formatter, "words: {}", self.num_words)?;
+        writeln!(
+            formatter,
+            "index-encoding: {}",
+            get_index_encoding_name(self.index_encoding)
+        )?;
+        writeln!(formatter, "share-format: {}", self.share_format_version)?;
+        writeln!(formatter, "set-id: {:04x}", self.set_id)?;
+        writeln!(formatter, "commitment: {}", self.commitment)?;
+        for (position, share_commitment) in self.share_commitments.iter().enumerate() {
+            writeln!(
+                formatter,
+                "share {}: {}",
+                position + 1,
+                PolynomialCommitment::new(*share_commitment)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for VerificationData {
+    type Err = HarpoError;
+
+    /// The function parses verification data from its line-based text encoding.
+    ///
+    /// Empty lines are ignored. All fields must be present exactly once and the commitments to
+    /// the secret-shared seed phrases must be listed in the order of their indices.
+    ///
+    /// * `input` - The text encoding.
+    fn from_str(input: &str) -> HarpoResult<Self> {
+        let invalid = |message: String| HarpoError::InvalidParameter(message);
+        let mut lines = input.lines().map(str::trim).filter(|line| !line.is_empty());
+        let version = lines
+            .next()
+            .and_then(|line| line.strip_prefix(VERIFICATION_DATA_HEADER_PREFIX))
+            .ok_or_else(|| invalid("The verification data header is missing.".to_string()))?;
+        if version.trim().parse::<u32>()? != VERIFICATION_DATA_VERSION {
+            return Err(invalid(format!(
+                "The verification data version {} is not supported.",
+                version.trim()
+            )));
+        }
+        let mut fields = std::collections::HashMap::new();
+        let mut share_commitments = vec![];
+        for line in lines {
+            let (key, value) = line
+                .split_once(':')
+                .ok_or_else(|| invalid(format!("Invalid line in verification data: '{}'", line)))?;
+            let value = value.trim();
+            if let Some(index) = key.strip_prefix("share ") {
+                if index.parse::<usize>()? != share_commitments.len() + 1 {
+                    return Err(invalid(format!(
+                        "The share commitment with index {} is out of order.",
+                        index
+                    )));
+                }
+                share_commitments.push(parse_commitment_bytes(value)?);
+            } else if fields.insert(key, value).is_some() {
+                return Err(invalid(format!(
+                    "The field '{}' occurs more than once in the verification data.",
+                    key
+                )));
+            }
+        }
+        let mut get_field = |key: &str| {
+            fields
+                .remove(key)
+                .ok_or_else(|| invalid(format!("The field '{}' is missing.", key)))
+        };
+        let threshold = get_field("threshold")?.parse::<usize>()?;
+        let num_shares = get_field("shares")?.parse::<usize>()?;
+        let num_words = get_field("words")?.parse::<usize>()?;
+        let index_encoding = match get_field("index-encoding")? {
+            "embedded" => IndexEncoding::Embedded,
+            "explicit" => IndexEncoding::Explicit,
+            "index-word" => IndexEncoding::IndexWord,
+            name => return Err(invalid(format!("Unknown index encoding '{}'.", name))),
+        };
+        let share_format_version = get_field("share-format")?.parse::<u32>()?;
+        let set_id = u16::from_str_radix(get_field("set-id")?, 16)?;
+        let commitment = get_field("commitment")?.parse::<PolynomialCommitment>()?;
+        if let Some(key) = fields.keys().next() {
+            return Err(invalid(format!(
+                "Unknown field '{}' in the verification data.",
+                key
+            )));
+        }
+        if num_shares != share_commitments.len() || threshold < 1 || threshold > num_shares {
+            return Err(invalid(
+                "The parameters of the verification data are inconsistent.".to_string(),
+            ));
+        }
+        Ok(VerificationData::new(
+            threshold,
+            num_words,
+            index_encoding,
+            share_format_version,
+            set_id,
+            commitment,
+            share_commitments,
+        ))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for VerificationData {
+    /// Verification data is serialized using its text encoding.
+    ///
+    /// * `serializer` - The serializer.
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for VerificationData {
+    /// Verification data is deserialized from its text encoding.
+    ///
+    /// * `deserializer` - The deserializer.
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let encoding = String::deserialize(deserializer)?;
+        encoding.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+// ******************************** TESTS ********************************
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// The function tests that the text encoding of verification data can be parsed.
+    fn test_verification_data_encoding() {
+        let share_commitments: Vec<[u8; COMMITMENT_LENGTH]> = (1..=3)
+            .map(|index| [index as u8; COMMITMENT_LENGTH])
+            .collect();
+        let verification_data = VerificationData::new(
+            2,
+            12,
+            IndexEncoding::Explicit,
+            2,
+            0x1a2b,
+            PolynomialCommitment::new([7; COMMITMENT_LENGTH]),
+            share_commitments,
+        );
+        let encoding = verification_data.to_string();
+        assert!(encoding.starts_with("# harpo verification data 1\nthreshold: 2\n"));
+        assert!(encoding.contains("set-id: 1a2b\n"));
+        assert_eq!(
+            encoding.parse::<VerificationData>().unwrap(),
+            verification_data
+        );
+        assert_eq!(
+            verification_data.get_share_commitment(2),
+            Some(&[2; COMMITMENT_LENGTH])
+        );
+        assert_eq!(verification_data.get_share_commitment(0), None);
+        assert_eq!(verification_data.get_share_commitment(4), None);
+        // Invalid encodings are rejected.
+        assert!(encoding[2..].parse::<VerificationData>().is_err());
+        assert!(encoding
+            .replace("threshold: 2", "threshold: 4")
+            .parse::<VerificationData>()
+            .is_err());
+        assert!(encoding
+            .replace("share 2:", "share 3:")
+            .parse::<VerificationData>()
+            .is_err());
+        assert!(encoding
+            .replace("words: 12\n", "")
+            .parse::<VerificationData>()
+            .is_err());
+        assert!(format!("{}words: 12\n", encoding)
+            .parse::<VerificationData>()
+            .is_err());
+    }
+}
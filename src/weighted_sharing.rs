@@ -0,0 +1,105 @@
+//! The `weighted_sharing` module provides bundles of secret-shared seed phrases for weighted
+//! secret sharing.
+//!
+//! A holder with weight `w` receives `w` secret-shared seed phrases of the same set, which are
+//! bundled in a single [WeightedShare](crate::weighted_sharing::WeightedShare). For example, for
+//! the threshold 2 and the weights 2, 1, and 1, the first holder can reconstruct the seed phrase
+//! alone while the other two holders are required otherwise.
+//!
+//! A weighted share is displayed as its secret-shared seed phrases separated by ` | `, e.g.:
+//!
+//! ```text
+//! 1/1a2b/t2: [WORDS] | 2/1a2b/t2: [WORDS]
+//! ```
+//!
+
+use crate::seed_phrase::{parse_phrase, SeedPhrase};
+use crate::{HarpoError, HarpoResult};
+use std::fmt;
+use std::str::FromStr;
+
+/// The separator between the secret-shared seed phrases of a weighted share.
+const WEIGHTED_SHARE_SEPARATOR: &str = " | ";
+
+/// This struct holds the bundled secret-shared seed phrases of a holder.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WeightedShare {
+    /// The secret-shared seed phrases of the holder.
+    seed_phrases: Vec<SeedPhrase>,
+}
+
+impl WeightedShare {
+    /// The function creates a weighted share from the given secret-shared seed phrases.
+    ///
+    /// An error is returned if no secret-shared seed phrases are provided.
+    ///
+    /// * `seed_phrases` - The secret-shared seed phrases of the holder.
+    pub fn new(seed_phrases: Vec<SeedPhrase>) -> HarpoResult<Self> {
+        if seed_phrases.is_empty() {
+            return Err(HarpoError::InvalidSeedPhrase(
+                "A weighted share must contain at least one secret-shared seed phrase.".to_string(),
+            ));
+        }
+        Ok(WeightedShare { seed_phrases })
+    }
+
+    /// The function returns the weight, i.e., the number of bundled secret-shared seed phrases.
+    pub fn get_weight(&self) -> usize {
+        self.seed_phrases.len()
+    }
+
+    /// The function returns the bundled secret-shared seed phrases.
+    pub fn get_seed_phrases(&self) -> &[SeedPhrase] {
+        &self.seed_phrases
+    }
+}
+
+impl fmt::Display for WeightedShare {
+    /// A weighted share is displayed as its secret-shared seed phrases separated by ` | `.
+    ///
+    /// * `formatter` - The formatter.
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let seed_phrases: Vec<String> = self
+            .seed_phrases
+            .iter()
+            .map(|seed_phrase| seed_phrase.to_string())
+            .collect();
+        write!(formatter, "{}", seed_phrases.join(WEIGHTED_SHARE_SEPARATOR))
+    }
+}
+
+impl FromStr for WeightedShare {
+    type Err = HarpoError;
+
+    /// A weighted share is parsed from its secret-shared seed phrases separated by `|`.
+    ///
+    /// * `input` - The weighted share as a string.
+    fn from_str(input: &str) -> HarpoResult<Self> {
+        let seed_phrases = input
+            .split(WEIGHTED_SHARE_SEPARATOR.trim())
+            .map(parse_phrase)
+            .collect::<HarpoResult<Vec<SeedPhrase>>>()?;
+        WeightedShare::new(seed_phrases)
+    }
+}
+
+// ******************************** TESTS ********************************
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// The function tests the string representation of weighted shares.
+    fn test_weighted_share_encoding() {
+        let words = "abandon ability able about above absent absorb abstract absurd abuse access \
+            accident";
+        let input = format!("1/1a2b/t2: {} | 2/1a2b/t2: {}", words, words);
+        let weighted_share: WeightedShare = input.parse().expect("The parsing should work.");
+        assert_eq!(weighted_share.get_weight(), 2);
+        assert_eq!(weighted_share.get_seed_phrases()[1].get_index(), Some(2));
+        assert_eq!(weighted_share.to_string(), input);
+        assert!(WeightedShare::new(vec![]).is_err());
+        assert!(format!("{} |", input).parse::<WeightedShare>().is_err());
+    }
+}
@@ -1,6 +1,229 @@
-//! The `word_list` module provides the default word list.
+//! The `word_list` module provides the default word list, the
+//! [WordList](crate::word_list::WordList) trait for arbitrary word lists, and a prefix tree for
+//! fast lookups in word lists.
 //!
 
+use crate::normalization::normalize;
+use crate::{HarpoError, HarpoResult};
+use std::collections::{BTreeMap, HashSet};
+use std::fmt;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+
+// The official word lists for languages other than English, each behind its own feature.
+#[cfg(feature = "chinese-simplified")]
+mod chinese_simplified;
+#[cfg(feature = "chinese-traditional")]
+mod chinese_traditional;
+#[cfg(feature = "czech")]
+mod czech;
+#[cfg(feature = "french")]
+mod french;
+#[cfg(feature = "italian")]
+mod italian;
+#[cfg(feature = "japanese")]
+mod japanese;
+#[cfg(feature = "korean")]
+mod korean;
+#[cfg(feature = "portuguese")]
+mod portuguese;
+#[cfg(feature = "spanish")]
+mod spanish;
+
+#[cfg(feature = "chinese-simplified")]
+pub use chinese_simplified::CHINESE_SIMPLIFIED_WORD_LIST;
+#[cfg(feature = "chinese-traditional")]
+pub use chinese_traditional::CHINESE_TRADITIONAL_WORD_LIST;
+#[cfg(feature = "czech")]
+pub use czech::CZECH_WORD_LIST;
+#[cfg(feature = "french")]
+pub use french::FRENCH_WORD_LIST;
+#[cfg(feature = "italian")]
+pub use italian::ITALIAN_WORD_LIST;
+#[cfg(feature = "japanese")]
+pub use japanese::JAPANESE_WORD_LIST;
+#[cfg(feature = "korean")]
+pub use korean::KOREAN_WORD_LIST;
+#[cfg(feature = "portuguese")]
+pub use portuguese::PORTUGUESE_WORD_LIST;
+#[cfg(feature = "spanish")]
+pub use spanish::SPANISH_WORD_LIST;
+
+/// The word lists bundled with `harpo`, each together with the name of its language.
+pub(crate) const BUNDLED_WORD_LISTS: &[(&str, &[&str])] = &[
+    ("English", DEFAULT_WORD_LIST),
+    #[cfg(feature = "japanese")]
+    ("Japanese", JAPANESE_WORD_LIST),
+    #[cfg(feature = "french")]
+    ("French", FRENCH_WORD_LIST),
+    #[cfg(feature = "spanish")]
+    ("Spanish", SPANISH_WORD_LIST),
+    #[cfg(feature = "italian")]
+    ("Italian", ITALIAN_WORD_LIST),
+    #[cfg(feature = "czech")]
+    ("Czech", CZECH_WORD_LIST),
+    #[cfg(feature = "portuguese")]
+    ("Portuguese", PORTUGUESE_WORD_LIST),
+    #[cfg(feature = "korean")]
+    ("Korean", KOREAN_WORD_LIST),
+    #[cfg(feature = "chinese-simplified")]
+    ("Chinese Simplified", CHINESE_SIMPLIFIED_WORD_LIST),
+    #[cfg(feature = "chinese-traditional")]
+    ("Chinese Traditional", CHINESE_TRADITIONAL_WORD_LIST),
+];
+
+/// The languages of the bundled word lists.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Language {
+    /// The English word list, which is the default word list.
+    #[default]
+    English,
+    /// The Japanese word list.
+    #[cfg(feature = "japanese")]
+    Japanese,
+    /// The French word list.
+    #[cfg(feature = "french")]
+    French,
+    /// The Spanish word list.
+    #[cfg(feature = "spanish")]
+    Spanish,
+    /// The Italian word list.
+    #[cfg(feature = "italian")]
+    Italian,
+    /// The Czech word list.
+    #[cfg(feature = "czech")]
+    Czech,
+    /// The Portuguese word list.
+    #[cfg(feature = "portuguese")]
+    Portuguese,
+    /// The Korean word list.
+    #[cfg(feature = "korean")]
+    Korean,
+    /// The Chinese Simplified word list.
+    #[cfg(feature = "chinese-simplified")]
+    ChineseSimplified,
+    /// The Chinese Traditional word list.
+    #[cfg(feature = "chinese-traditional")]
+    ChineseTraditional,
+}
+
+impl Language {
+    /// All languages for which a word list is bundled.
+    pub const ALL: &'static [Language] = &[
+        Language::English,
+        #[cfg(feature = "japanese")]
+        Language::Japanese,
+        #[cfg(feature = "french")]
+        Language::French,
+        #[cfg(feature = "spanish")]
+        Language::Spanish,
+        #[cfg(feature = "italian")]
+        Language::Italian,
+        #[cfg(feature = "czech")]
+        Language::Czech,
+        #[cfg(feature = "portuguese")]
+        Language::Portuguese,
+        #[cfg(feature = "korean")]
+        Language::Korean,
+        #[cfg(feature = "chinese-simplified")]
+        Language::ChineseSimplified,
+        #[cfg(feature = "chinese-traditional")]
+        Language::ChineseTraditional,
+    ];
+
+    /// The function returns the name of the language.
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            Language::English => "English",
+            #[cfg(feature = "japanese")]
+            Language::Japanese => "Japanese",
+            #[cfg(feature = "french")]
+            Language::French => "French",
+            #[cfg(feature = "spanish")]
+            Language::Spanish => "Spanish",
+            #[cfg(feature = "italian")]
+            Language::Italian => "Italian",
+            #[cfg(feature = "czech")]
+            Language::Czech => "Czech",
+            #[cfg(feature = "portuguese")]
+            Language::Portuguese => "Portuguese",
+            #[cfg(feature = "korean")]
+            Language::Korean => "Korean",
+            #[cfg(feature = "chinese-simplified")]
+            Language::ChineseSimplified => "Chinese Simplified",
+            #[cfg(feature = "chinese-traditional")]
+            Language::ChineseTraditional => "Chinese Traditional",
+        }
+    }
+
+    /// The function returns the bundled word list for the language.
+    pub fn get_word_list(&self) -> &'static [&'static str] {
+        match self {
+            Language::English => DEFAULT_WORD_LIST,
+            #[cfg(feature = "japanese")]
+            Language::Japanese => JAPANESE_WORD_LIST,
+            #[cfg(feature = "french")]
+            Language::French => FRENCH_WORD_LIST,
+            #[cfg(feature = "spanish")]
+            Language::Spanish => SPANISH_WORD_LIST,
+            #[cfg(feature = "italian")]
+            Language::Italian => ITALIAN_WORD_LIST,
+            #[cfg(feature = "czech")]
+            Language::Czech => CZECH_WORD_LIST,
+            #[cfg(feature = "portuguese")]
+            Language::Portuguese => PORTUGUESE_WORD_LIST,
+            #[cfg(feature = "korean")]
+            Language::Korean => KOREAN_WORD_LIST,
+            #[cfg(feature = "chinese-simplified")]
+            Language::ChineseSimplified => CHINESE_SIMPLIFIED_WORD_LIST,
+            #[cfg(feature = "chinese-traditional")]
+            Language::ChineseTraditional => CHINESE_TRADITIONAL_WORD_LIST,
+        }
+    }
+}
+
+impl fmt::Display for Language {
+    /// The language is displayed using its name.
+    ///
+    /// * `formatter` - The formatter.
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "{}", self.get_name())
+    }
+}
+
+impl FromStr for Language {
+    type Err = HarpoError;
+
+    /// The function parses the name of a language, ignoring the case. Spaces, hyphens, and
+    /// underscores are interchangeable, e.g., `chinese-simplified` is accepted as well.
+    ///
+    /// * `input` - The name of the language.
+    fn from_str(input: &str) -> HarpoResult<Self> {
+        let simplify = |name: &str| name.trim().to_ascii_lowercase().replace(['-', '_'], " ");
+        Language::ALL
+            .iter()
+            .find(|language| simplify(language.get_name()) == simplify(input))
+            .copied()
+            .ok_or_else(|| {
+                HarpoError::InvalidParameter(format!(
+                    "There is no bundled word list for the language '{}'.",
+                    input.trim()
+                ))
+            })
+    }
+}
+
+impl WordList for Language {
+    fn get_words(&self) -> &[&str] {
+        self.get_word_list()
+    }
+
+    fn get_language(&self) -> Option<&str> {
+        Some(self.get_name())
+    }
+}
+
 /// The default word list as specified here:
 /// <https://github.com/bitcoin/bips/blob/master/bip-0039/english.txt>
 pub const DEFAULT_WORD_LIST: &[&str] = &[
@@ -216,3 +439,509 @@ pub const DEFAULT_WORD_LIST: &[&str] = &[
     "write", "wrong", "yard", "year", "yellow", "you", "young", "youth", "zebra", "zero", "zone",
     "zoo",
 ];
+
+/// This trait provides access to a word list.
+///
+/// All functions that accept a word list accept any type that implements this trait. It is
+/// implemented for slices, arrays, and vectors of words, including the bundled
+/// [DEFAULT_WORD_LIST](crate::word_list::DEFAULT_WORD_LIST). Custom types can provide
+/// precomputed lookup structures or metadata such as the language.
+pub trait WordList {
+    /// The function returns the words of the word list in order.
+    fn get_words(&self) -> &[&str];
+
+    /// The function returns the word at the given index, if any.
+    ///
+    /// * `index` - The index of the word.
+    fn get_word(&self, index: usize) -> Option<&str> {
+        self.get_words().get(index).copied()
+    }
+
+    /// The function returns the index of the given word, if it is in the word list.
+    ///
+    /// By default, the index is looked up in the cached prefix tree of the word list.
+    ///
+    /// * `word` - The word.
+    fn get_index(&self, word: &str) -> Option<usize> {
+        get_word_trie(self.get_words()).get_index(word)
+    }
+
+    /// The function returns the language of the word list, if known.
+    ///
+    /// By default, the language is known if the words match a bundled word list.
+    fn get_language(&self) -> Option<&str> {
+        let words = self.get_words();
+        BUNDLED_WORD_LISTS
+            .iter()
+            .find(|(_, word_list)| *word_list == words)
+            .map(|(language, _)| *language)
+    }
+}
+
+impl WordList for [&str] {
+    fn get_words(&self) -> &[&str] {
+        self
+    }
+}
+
+impl<const N: usize> WordList for [&str; N] {
+    fn get_words(&self) -> &[&str] {
+        self
+    }
+}
+
+impl WordList for Vec<&str> {
+    fn get_words(&self) -> &[&str] {
+        self
+    }
+}
+
+/// The number of words in a valid word list.
+pub const WORD_LIST_LENGTH: usize = 2048;
+
+/// The number of leading characters that should identify each word uniquely.
+pub const UNIQUE_PREFIX_LENGTH: usize = 4;
+
+/// This struct describes words of a word list that share the same leading characters.
+///
+/// BIP-0039 recommends that the first four characters identify each word uniquely so that
+/// seed phrases can be stored using abbreviated words.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SharedPrefix {
+    /// The shared prefix.
+    prefix: String,
+    /// The words that start with the prefix, in the order of the word list.
+    words: Vec<String>,
+}
+
+impl SharedPrefix {
+    /// The function returns the shared prefix.
+    pub fn get_prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    /// The function returns the words that start with the prefix.
+    pub fn get_words(&self) -> &[String] {
+        &self.words
+    }
+}
+
+impl fmt::Display for SharedPrefix {
+    /// A shared prefix is displayed together with the words that start with it.
+    ///
+    /// * `formatter` - The formatter.
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "The words {} share the prefix '{}'.",
+            self.words.join(", "),
+            self.prefix
+        )
+    }
+}
+
+/// The function checks the given word list.
+///
+/// An error is returned if the word list does not consist of exactly
+/// [WORD_LIST_LENGTH](crate::word_list::WORD_LIST_LENGTH) distinct, non-empty words without
+/// white space. Words are compared after NFKD normalization. Otherwise, the prefixes of length
+/// [UNIQUE_PREFIX_LENGTH](crate::word_list::UNIQUE_PREFIX_LENGTH) that are shared by several
+/// words are returned as warnings.
+///
+/// * `word_list` - The word list.
+pub(crate) fn check_word_list(word_list: &[&str]) -> HarpoResult<Vec<SharedPrefix>> {
+    if word_list.len() != WORD_LIST_LENGTH {
+        return Err(HarpoError::InvalidParameter(format!(
+            "The word list contains {} words instead of {}.",
+            word_list.len(),
+            WORD_LIST_LENGTH
+        )));
+    }
+    let mut normalized_words = HashSet::new();
+    let mut words_per_prefix: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for (position, word) in word_list.iter().enumerate() {
+        if word.is_empty() || word.chars().any(char::is_whitespace) {
+            return Err(HarpoError::InvalidParameter(format!(
+                "The word '{}' on line {} is empty or contains white space.",
+                word,
+                position + 1
+            )));
+        }
+        if !normalized_words.insert(normalize(word)) {
+            return Err(HarpoError::InvalidParameter(format!(
+                "The word '{}' on line {} occurs more than once.",
+                word,
+                position + 1
+            )));
+        }
+        let prefix: String = word.chars().take(UNIQUE_PREFIX_LENGTH).collect();
+        words_per_prefix
+            .entry(prefix)
+            .or_default()
+            .push(word.to_string());
+    }
+    Ok(words_per_prefix
+        .into_iter()
+        .filter(|(_, words)| words.len() > 1)
+        .map(|(prefix, words)| SharedPrefix { prefix, words })
+        .collect())
+}
+
+/// The maximum number of word lists for which the prefix tree is cached.
+const MAX_CACHED_WORD_TRIES: usize = 4;
+
+/// The prefix trees of recently used word lists.
+///
+/// Word lists are passed around as slices, so the prefix tree of a word list is built when it is
+/// first used and then reused when parsing many seed phrases.
+static WORD_TRIES: Mutex<Vec<Arc<WordTrie>>> = Mutex::new(Vec::new());
+
+/// This struct holds a node of a [WordTrie](crate::word_list::WordTrie).
+#[derive(Debug, Default)]
+struct TrieNode {
+    /// The positions of the child nodes, sorted by the character on the edge.
+    children: Vec<(char, usize)>,
+    /// The index of the word that ends at this node, if any.
+    word_index: Option<usize>,
+    /// The number of words that end at this node or below it.
+    num_words: usize,
+}
+
+/// This struct is a prefix tree over the words of a word list.
+///
+/// Words are looked up, prefixes are resolved, and completions are found in time proportional
+/// to the length of the input rather than the size of the word list. Since the tree works on
+/// characters, it handles all languages, including words with diacritics, in the same way.
+/// The words are stored and looked up in NFKD normalization, so composed and decomposed forms
+/// of the same word are equivalent.
+#[derive(Debug)]
+pub(crate) struct WordTrie {
+    /// The words of the word list.
+    words: Vec<String>,
+    /// The nodes of the prefix tree. The first node is the root.
+    nodes: Vec<TrieNode>,
+}
+
+impl WordTrie {
+    /// The function builds the prefix tree for the given word list.
+    ///
+    /// If a word occurs more than once, the first occurrence determines its index.
+    ///
+    /// * `word_list` - The word list.
+    pub(crate) fn new(word_list: &[&str]) -> Self {
+        let mut nodes = vec![TrieNode::default()];
+        for (index, word) in word_list.iter().enumerate() {
+            let mut position = 0;
+            let mut path = vec![position];
+            for character in normalize(word).chars() {
+                position = match nodes[position]
+                    .children
+                    .binary_search_by_key(&character, |(edge, _)| *edge)
+                {
+                    Ok(child) => nodes[position].children[child].1,
+                    Err(child) => {
+                        nodes.push(TrieNode::default());
+                        let new_position = nodes.len() - 1;
+                        nodes[position]
+                            .children
+                            .insert(child, (character, new_position));
+                        new_position
+                    }
+                };
+                path.push(position);
+            }
+            if nodes[position].word_index.is_none() {
+                nodes[position].word_index = Some(index);
+                for node in path {
+                    nodes[node].num_words += 1;
+                }
+            }
+        }
+        WordTrie {
+            words: word_list.iter().map(|word| word.to_string()).collect(),
+            nodes,
+        }
+    }
+
+    /// The function returns true if the prefix tree was built for the given word list.
+    ///
+    /// * `word_list` - The word list.
+    fn is_for_word_list(&self, word_list: &[&str]) -> bool {
+        self.words.len() == word_list.len()
+            && self
+                .words
+                .iter()
+                .zip(word_list)
+                .all(|(word, other)| word == other)
+    }
+
+    /// The function returns the position of the node that is reached by the given prefix, if
+    /// any.
+    ///
+    /// * `prefix` - The prefix.
+    fn find_node(&self, prefix: &str) -> Option<usize> {
+        let mut position = 0;
+        for character in normalize(prefix).chars() {
+            let children = &self.nodes[position].children;
+            let child = children
+                .binary_search_by_key(&character, |(edge, _)| *edge)
+                .ok()?;
+            position = children[child].1;
+        }
+        Some(position)
+    }
+
+    /// The function returns the index of the given word in the word list, if any.
+    ///
+    /// * `word` - The word.
+    pub(crate) fn get_index(&self, word: &str) -> Option<usize> {
+        self.find_node(word)
+            .and_then(|position| self.nodes[position].word_index)
+    }
+
+    /// The function returns the number of words that start with the given prefix.
+    ///
+    /// * `prefix` - The prefix.
+    pub(crate) fn get_num_completions(&self, prefix: &str) -> usize {
+        self.find_node(prefix)
+            .map_or(0, |position| self.nodes[position].num_words)
+    }
+
+    /// The function returns the indices of all words that start with the given prefix in the
+    /// order of the word list.
+    ///
+    /// * `prefix` - The prefix.
+    pub(crate) fn get_completions(&self, prefix: &str) -> Vec<usize> {
+        let mut indices = vec![];
+        let mut stack: Vec<usize> = self.find_node(prefix).into_iter().collect();
+        while let Some(position) = stack.pop() {
+            let node = &self.nodes[position];
+            indices.extend(node.word_index);
+            stack.extend(node.children.iter().map(|(_, child)| *child));
+        }
+        indices.sort_unstable();
+        indices
+    }
+
+    /// The function returns the byte lengths of all non-empty words that are prefixes of the
+    /// given text, in increasing order.
+    ///
+    /// The text must be in NFKD normalization.
+    ///
+    /// * `text` - The text.
+    pub(crate) fn get_prefix_word_lengths(&self, text: &str) -> Vec<usize> {
+        let mut lengths = vec![];
+        let mut position = 0;
+        for (offset, character) in text.char_indices() {
+            let children = &self.nodes[position].children;
+            match children.binary_search_by_key(&character, |(edge, _)| *edge) {
+                Ok(child) => position = children[child].1,
+                Err(_) => break,
+            }
+            if self.nodes[position].word_index.is_some() {
+                lengths.push(offset + character.len_utf8());
+            }
+        }
+        lengths
+    }
+}
+
+/// The function returns the prefix tree for the given word list.
+///
+/// The prefix trees of recently used word lists are cached.
+///
+/// * `word_list` - The word list.
+pub(crate) fn get_word_trie(word_list: &[&str]) -> Arc<WordTrie> {
+    // A poisoned lock is recovered because the cache only holds data derived from word lists.
+    let mut cache = WORD_TRIES.lock().unwrap_or_else(|error| error.into_inner());
+    if let Some(trie) = cache.iter().find(|trie| trie.is_for_word_list(word_list)) {
+        return Arc::clone(trie);
+    }
+    let trie = Arc::new(WordTrie::new(word_list));
+    if cache.len() >= MAX_CACHED_WORD_TRIES {
+        cache.remove(0);
+    }
+    cache.push(Arc::clone(&trie));
+    trie
+}
+
+// ******************************** TESTS ********************************
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// The function tests lookups, completions, and prefix words using the prefix tree.
+    fn test_word_trie() {
+        let trie = WordTrie::new(DEFAULT_WORD_LIST);
+        for (index, word) in DEFAULT_WORD_LIST.iter().enumerate() {
+            assert_eq!(trie.get_index(word), Some(index));
+        }
+        assert_eq!(trie.get_index("abando"), None);
+        assert_eq!(trie.get_index("abandons"), None);
+        assert_eq!(trie.get_num_completions(""), DEFAULT_WORD_LIST.len());
+        assert_eq!(trie.get_num_completions("zo"), 2);
+        assert_eq!(trie.get_completions("zo"), vec![2046, 2047]);
+        assert!(trie.get_completions("zz").is_empty());
+        // Words with diacritics are handled like any other word.
+        let word_list = ["\u{e9}l\u{e8}ve", "\u{e9}lan", "\u{e9}", "abri"];
+        let trie = WordTrie::new(&word_list);
+        assert_eq!(trie.get_index("\u{e9}lan"), Some(1));
+        assert_eq!(trie.get_completions("\u{e9}l"), vec![0, 1]);
+        // Composed and decomposed forms are equivalent.
+        assert_eq!(trie.get_index("e\u{301}lan"), Some(1));
+        assert_eq!(trie.get_prefix_word_lengths("e\u{301}lanabri"), vec![3, 6]);
+        assert!(Arc::ptr_eq(
+            &get_word_trie(&word_list),
+            &get_word_trie(&word_list)
+        ));
+    }
+
+    /// A word list that reverses the bundled word list.
+    struct ReversedWordList {
+        words: Vec<&'static str>,
+    }
+
+    impl WordList for ReversedWordList {
+        fn get_words(&self) -> &[&str] {
+            &self.words
+        }
+
+        fn get_language(&self) -> Option<&str> {
+            Some("Reversed English")
+        }
+    }
+
+    #[test]
+    /// The function tests the checks of custom word lists.
+    fn test_check_word_list() {
+        assert!(check_word_list(DEFAULT_WORD_LIST)
+            .expect("The default word list is valid.")
+            .is_empty());
+        let mut words: Vec<String> = DEFAULT_WORD_LIST
+            .iter()
+            .map(|word| word.to_string())
+            .collect();
+        words[1] = "abandonment".to_string();
+        let word_list: Vec<&str> = words.iter().map(|word| word.as_str()).collect();
+        let shared_prefixes = check_word_list(&word_list).expect("The word list is valid.");
+        assert_eq!(shared_prefixes.len(), 1);
+        assert_eq!(shared_prefixes[0].get_prefix(), "aban");
+        assert_eq!(shared_prefixes[0].get_words(), &["abandon", "abandonment"]);
+        // Missing, duplicate, and malformed words are rejected.
+        assert!(check_word_list(&word_list[1..]).is_err());
+        let mut invalid_list = word_list.clone();
+        invalid_list[1] = "abandon";
+        assert!(check_word_list(&invalid_list).is_err());
+        invalid_list[1] = "ability two";
+        assert!(check_word_list(&invalid_list).is_err());
+        invalid_list[1] = "";
+        assert!(check_word_list(&invalid_list).is_err());
+    }
+
+    #[test]
+    /// The function tests that all bundled word lists are valid and match the official lists
+    /// in the BIP-0039 repository, i.e., the SHA-256 hashes of the word list files match.
+    fn test_bundled_word_lists() {
+        use sha2::{Digest, Sha256};
+        let checksums = [
+            (
+                "English",
+                "2f5eed53a4727b4bf8880d8f3f199efc90e58503646d9ff8eff3a2ed3b24dbda",
+            ),
+            (
+                "Japanese",
+                "2eed0aef492291e061633d7ad8117f1a2b03eb80a29d0e4e3117ac2528d05ffd",
+            ),
+            (
+                "French",
+                "ebc3959ab7801a1df6bac4fa7d970652f1df76b683cd2f4003c941c63d517e59",
+            ),
+            (
+                "Spanish",
+                "46846a5a0139d1e3cb77293e521c2865f7bcdb82c44e8d0a06a2cd0ecba48c0b",
+            ),
+            (
+                "Italian",
+                "d392c49fdb700a24cd1fceb237c1f65dcc128f6b34a8aacb58b59384b5c648c2",
+            ),
+            (
+                "Czech",
+                "7e80e161c3e93d9554c2efb78d4e3cebf8fc727e9c52e03b83b94406bdcc95fc",
+            ),
+            (
+                "Portuguese",
+                "2685e9c194c82ae67e10ba59d9ea5345a23dc093e92276fc5361f6667d79cd3f",
+            ),
+            (
+                "Korean",
+                "9e95f86c167de88f450f0aaf89e87f6624a57f973c67b516e338e8e8b8897f60",
+            ),
+            (
+                "Chinese Simplified",
+                "5c5942792bd8340cb8b27cd592f1015edf56a8c5b26276ee18a482428e7c5726",
+            ),
+            (
+                "Chinese Traditional",
+                "417b26b3d8500a4ae3d59717d7011952db6fc2fb84b807f3f94ac734e89c1b5f",
+            ),
+        ];
+        for language in Language::ALL {
+            let word_list = language.get_word_list();
+            assert!(check_word_list(word_list).is_ok());
+            assert_eq!(word_list.get_language(), Some(language.get_name()));
+            assert_eq!(
+                language
+                    .get_name()
+                    .to_lowercase()
+                    .replace(' ', "-")
+                    .parse::<Language>()
+                    .unwrap(),
+                *language
+            );
+            let mut hasher = Sha256::new();
+            for word in word_list {
+                hasher.update(word.as_bytes());
+                hasher.update(b"\n");
+            }
+            let checksum: String = hasher
+                .finalize()
+                .iter()
+                .map(|byte| format!("{:02x}", byte))
+                .collect();
+            let expected = checksums
+                .iter()
+                .find(|(name, _)| *name == language.get_name());
+            assert_eq!(
+                Some(checksum.as_str()),
+                expected.map(|(_, checksum)| *checksum)
+            );
+        }
+    }
+
+    #[test]
+    /// The function tests the word list trait for bundled and custom word lists.
+    fn test_word_list_trait() {
+        assert_eq!("english".parse::<Language>().unwrap(), Language::English);
+        assert!("Klingon".parse::<Language>().is_err());
+        assert_eq!(Language::default().get_index("zoo"), Some(2047));
+        assert_eq!(Language::English.to_string(), "English");
+        assert_eq!(DEFAULT_WORD_LIST.get_word(0), Some("abandon"));
+        assert_eq!(DEFAULT_WORD_LIST.get_word(2048), None);
+        assert_eq!(DEFAULT_WORD_LIST.get_index("zoo"), Some(2047));
+        assert_eq!(DEFAULT_WORD_LIST.get_index("zoos"), None);
+        assert_eq!(DEFAULT_WORD_LIST.get_language(), Some("English"));
+        assert_eq!(DEFAULT_WORD_LIST.to_vec().get_language(), Some("English"));
+        assert_eq!(["a", "b"].get_language(), None);
+        // Custom word lists can be used wherever a word list is accepted.
+        let word_list = ReversedWordList {
+            words: DEFAULT_WORD_LIST.iter().rev().copied().collect(),
+        };
+        assert_eq!(word_list.get_index("zoo"), Some(0));
+        assert_eq!(word_list.get_language(), Some("Reversed English"));
+        let seed_phrase = crate::generate_seed_phrase_for_word_list(12, &word_list)
+            .expect("The generation should work.");
+        assert!(crate::validate_seed_phrase_for_word_list(&seed_phrase, &word_list).is_ok());
+    }
+}